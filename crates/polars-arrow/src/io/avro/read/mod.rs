@@ -8,7 +8,7 @@ use avro_schema::schema::Field as AvroField;
 
 mod deserialize;
 pub use deserialize::deserialize;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 
 mod nested;
 mod schema;
@@ -66,3 +66,51 @@ impl<R: Read> Iterator for Reader<R> {
             .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
     }
 }
+
+/// Location of a block within an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockLocation {
+    /// Byte offset of the start of the block, relative to the start of the first block.
+    pub offset: usize,
+    /// The number of rows in the block.
+    pub num_rows: usize,
+}
+
+/// Reads the locations of all blocks in `data`.
+///
+/// `data` must start at the first block of an Avro file, i.e. directly after its metadata. Only
+/// the block headers are decoded, so this does not decompress or deserialize any data.
+pub fn read_block_locations(data: &[u8], marker: [u8; 16]) -> PolarsResult<Vec<BlockLocation>> {
+    let mut locations = Vec::new();
+    let mut reader = data;
+
+    while !reader.is_empty() {
+        let offset = data.len() - reader.len();
+        let num_rows = util::zigzag_i64(&mut reader)?;
+        let num_bytes = util::zigzag_i64(&mut reader)?;
+
+        polars_ensure!(
+            num_rows >= 0 && num_bytes >= 0 && reader.len() >= num_bytes as usize + marker.len(),
+            oos = "invalid block header - corrupt avro file"
+        );
+
+        let (_, rest) = reader.split_at(num_bytes as usize);
+        let (block_marker, rest) = rest.split_at(marker.len());
+
+        polars_ensure!(
+            block_marker == marker,
+            oos = "block marker does not match the file marker - corrupt avro file"
+        );
+
+        reader = rest;
+
+        if num_rows > 0 {
+            locations.push(BlockLocation {
+                offset,
+                num_rows: num_rows as usize,
+            });
+        }
+    }
+
+    Ok(locations)
+}
@@ -1,12 +1,10 @@
 use arrow::array::Array;
-use polars_row::RowEncodingOptions;
 use polars_utils::idx_map::bytes_idx_map::{BytesIndexMap, Entry};
 use polars_utils::itertools::Itertools;
 use polars_utils::vec::PushUnchecked;
 
-use self::row_encode::get_row_encoding_context;
 use super::*;
-use crate::hash_keys::HashKeys;
+use crate::hash_keys::{HashKeys, decode_row_encoded_keys};
 
 #[derive(Default)]
 pub struct RowEncodedHashGrouper {
@@ -34,32 +32,6 @@ impl RowEncodedHashGrouper {
     fn contains_key(&self, hash: u64, key: &[u8]) -> bool {
         self.idx_map.contains_key(hash, key)
     }
-
-    fn finalize_keys(&self, key_schema: &Schema, mut key_rows: Vec<&[u8]>) -> DataFrame {
-        let key_dtypes = key_schema
-            .iter()
-            .map(|(_name, dt)| dt.to_physical().to_arrow(CompatLevel::newest()))
-            .collect::<Vec<_>>();
-        let ctxts = key_schema
-            .iter()
-            .map(|(_, dt)| get_row_encoding_context(dt))
-            .collect::<Vec<_>>();
-        let fields = vec![RowEncodingOptions::new_unsorted(); key_dtypes.len()];
-        let key_columns =
-            unsafe { polars_row::decode::decode_rows(&mut key_rows, &fields, &ctxts, &key_dtypes) };
-
-        let cols = key_schema
-            .iter()
-            .zip(key_columns)
-            .map(|((name, dt), col)| {
-                let s = Series::try_from((name.clone(), col)).unwrap();
-                unsafe { s.from_physical_unchecked(dt) }
-                    .unwrap()
-                    .into_column()
-            })
-            .collect();
-        unsafe { DataFrame::new_no_checks_height_from_first(cols) }
-    }
 }
 
 impl Grouper for RowEncodedHashGrouper {
@@ -111,7 +83,7 @@ impl Grouper for RowEncodedHashGrouper {
             for (_, key) in self.idx_map.iter_hash_keys() {
                 key_rows.push_unchecked(key);
             }
-            self.finalize_keys(schema, key_rows)
+            decode_row_encoded_keys(schema, key_rows)
         }
     }
 
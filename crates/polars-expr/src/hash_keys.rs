@@ -3,11 +3,13 @@ use std::hash::BuildHasher;
 
 use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, StaticArray, UInt64Array};
 use arrow::bitmap::Bitmap;
+use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::compute::utils::combine_validities_and_many;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::row_encode::_get_rows_encoded_unordered;
+use polars_core::prelude::row_encode::{_get_rows_encoded_unordered, get_row_encoding_context};
 use polars_core::prelude::{ChunkedArray, DataType, PlRandomState, PolarsDataType, *};
 use polars_core::series::Series;
+use polars_row::RowEncodingOptions;
 use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
@@ -180,6 +182,43 @@ impl HashKeys {
         self.len() == 0
     }
 
+    /// Returns an estimation of the total (heap) allocated size in bytes.
+    pub fn estimated_size(&self) -> usize {
+        match self {
+            HashKeys::RowEncoded(s) => {
+                estimated_bytes_size(&s.hashes) + estimated_bytes_size(&s.keys)
+            },
+            HashKeys::Binview(s) => estimated_bytes_size(&s.hashes) + estimated_bytes_size(&s.keys),
+            HashKeys::Single(s) => s.keys.estimated_size(),
+        }
+    }
+
+    /// Reconstructs the key columns these keys were computed from, using the
+    /// names and dtypes of the given schema.
+    pub fn to_df(&self, key_schema: &Schema) -> DataFrame {
+        match self {
+            HashKeys::RowEncoded(s) => {
+                decode_row_encoded_keys(key_schema, s.keys.values_iter().collect())
+            },
+            HashKeys::Binview(s) => {
+                let (name, dtype) = key_schema.get_at_index(0).unwrap();
+                let keys = if dtype == &DataType::String {
+                    unsafe { s.keys.to_utf8view_unchecked() }.boxed()
+                } else {
+                    s.keys.clone().boxed()
+                };
+                let keys = unsafe {
+                    Series::from_chunks_and_dtype_unchecked(name.clone(), vec![keys], dtype)
+                };
+                DataFrame::new(vec![keys.into_column()]).unwrap()
+            },
+            HashKeys::Single(s) => {
+                let (name, _dtype) = key_schema.get_at_index(0).unwrap();
+                DataFrame::new(vec![s.keys.clone().with_name(name.clone()).into_column()]).unwrap()
+            },
+        }
+    }
+
     pub fn validity(&self) -> Option<&Bitmap> {
         match self {
             HashKeys::RowEncoded(s) => s.keys.validity(),
@@ -327,6 +366,33 @@ impl HashKeys {
     }
 }
 
+/// Decodes unordered row-encoded keys back into columns with the given schema.
+pub(crate) fn decode_row_encoded_keys(key_schema: &Schema, mut key_rows: Vec<&[u8]>) -> DataFrame {
+    let key_dtypes = key_schema
+        .iter()
+        .map(|(_name, dt)| dt.to_physical().to_arrow(CompatLevel::newest()))
+        .collect::<Vec<_>>();
+    let ctxts = key_schema
+        .iter()
+        .map(|(_, dt)| get_row_encoding_context(dt))
+        .collect::<Vec<_>>();
+    let fields = vec![RowEncodingOptions::new_unsorted(); key_dtypes.len()];
+    let key_columns =
+        unsafe { polars_row::decode::decode_rows(&mut key_rows, &fields, &ctxts, &key_dtypes) };
+
+    let cols = key_schema
+        .iter()
+        .zip(key_columns)
+        .map(|((name, dt), col)| {
+            let s = Series::try_from((name.clone(), col)).unwrap();
+            unsafe { s.from_physical_unchecked(dt) }
+                .unwrap()
+                .into_column()
+        })
+        .collect();
+    unsafe { DataFrame::new_no_checks_height_from_first(cols) }
+}
+
 #[derive(Clone, Debug)]
 pub struct RowEncodedKeys {
     pub hashes: UInt64Array, // Always non-null, we use the validity of keys.
@@ -4,10 +4,35 @@ use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions;
+
+/// Reads the metadata of the Avro file in `bytes`.
+///
+/// Returns the metadata together with the offset of the first block in `bytes`.
+pub fn read_avro_metadata(
+    bytes: &[u8],
+) -> PolarsResult<(avro::avro_schema::file::FileMetadata, usize)> {
+    let mut reader = std::io::Cursor::new(bytes);
+    let metadata = avro::avro_schema::read::read_metadata(&mut reader).map_err(to_compute_err)?;
+    Ok((metadata, reader.position() as usize))
+}
+
+/// Counts the rows of the Avro file in `bytes`. This only decodes the headers of the blocks.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let (metadata, data_start) = read_avro_metadata(bytes)?;
+    let blocks = read::read_block_locations(&bytes[data_start..], metadata.marker)?;
+    Ok(blocks.iter().map(|block| block.num_rows).sum())
+}
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -2,13 +2,100 @@ use std::io::Write;
 
 pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec of the data blocks of an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCodec> for AvroCompression {
+    fn from(value: AvroCodec) -> Self {
+        match value {
+            AvroCodec::Deflate => AvroCompression::Deflate,
+            AvroCodec::Snappy => AvroCompression::Snappy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Compression of the data blocks.
+    pub compression: Option<AvroCodec>,
+    /// Name of the record in the Avro schema.
+    pub name: PlSmallStr,
+}
+
+/// Creates the Avro record that `schema` is written as.
+pub fn schema_to_avro_record(schema: &Schema, name: &str) -> PolarsResult<Record> {
+    let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+    write::to_record(&schema, name.to_string())
+}
+
+/// Writes the metadata (header) of an Avro file.
+pub fn write_avro_metadata<W: Write>(
+    writer: &mut W,
+    record: Record,
+    compression: Option<AvroCompression>,
+) -> PolarsResult<()> {
+    avro_schema::write::write_metadata(writer, record, compression).map_err(to_compute_err)
+}
+
+/// Encodes the chunks of `df` as Avro blocks, including their headers and sync markers, and
+/// writes them to `writer` one block at a time.
+///
+/// `record` must have been created from the schema of `df` by [`schema_to_avro_record`].
+pub fn write_avro_blocks<W: Write>(
+    writer: &mut W,
+    df: &DataFrame,
+    record: &Record,
+    compression: Option<AvroCompression>,
+    parallel: bool,
+) -> PolarsResult<()> {
+    let mut block = avro_schema::file::Block::default();
+    let mut compressed_block = avro_schema::file::CompressedBlock::default();
+
+    for chunk in df.iter_chunks(CompatLevel::oldest(), parallel) {
+        let num_rows = chunk.len();
+        if num_rows == 0 {
+            continue;
+        }
+
+        let mut serializers = chunk
+            .iter()
+            .zip(record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        block.number_of_rows = num_rows;
+        block.data.clear();
+        write::serialize(&mut serializers, &mut block);
+
+        avro_schema::write::compress(&mut block, &mut compressed_block, compression)
+            .map_err(to_compute_err)?;
+        avro_schema::write::write_block(writer, &compressed_block).map_err(to_compute_err)?;
+
+        compressed_block.data.clear();
+        compressed_block.number_of_rows = 0;
+    }
+
+    Ok(())
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -64,38 +151,10 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
-
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
-        }
+        let record = schema_to_avro_record(df.schema(), &self.name)?;
+        write_avro_metadata(&mut self.writer, record.clone(), self.compression)?;
+
+        write_avro_blocks(&mut self.writer, df, &record, self.compression, true)?;
 
         Ok(())
     }
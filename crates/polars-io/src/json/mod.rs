@@ -63,6 +63,7 @@
 //! ```
 //!
 pub(crate) mod infer;
+mod path;
 
 use std::io::Write;
 use std::num::NonZeroUsize;
@@ -78,6 +79,10 @@ use polars_json::json::write::FallibleStreamingIterator;
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+pub use self::path::{
+    JsonPath, JsonRows, count_json_rows, for_each_json_row, json_rows, json_rows_to_ndjson,
+    write_ndjson_row,
+};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
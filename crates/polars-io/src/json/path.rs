@@ -0,0 +1,520 @@
+//! Locate the rows of a (non-newline-delimited) JSON document.
+//!
+//! The rows of a JSON document are the elements of a (possibly nested) array, e.g. the
+//! top-level array of `[{"a": 1}, {"a": 2}]` or the array at `$.data.items` of
+//! `{"data": {"items": [{"a": 1}, {"a": 2}]}}`. The document is not materialized; the bytes are
+//! scanned once and every row is handed out as a slice of the original bytes.
+use std::borrow::Cow;
+use std::fmt;
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::remove_bom;
+
+/// Path to the value that holds the rows of a JSON document, e.g. `$.data.items`.
+///
+/// Only object member access is supported, either as `.key` or as `['key']` / `["key"]` for
+/// keys that contain `.` or `[`. The root path `$` refers to the document itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct JsonPath {
+    keys: Vec<PlSmallStr>,
+}
+
+impl JsonPath {
+    /// The path to the document itself (`$`).
+    pub fn root() -> Self {
+        Self::default()
+    }
+
+    pub fn parse(path: &str) -> PolarsResult<Self> {
+        let invalid =
+            |reason: &str| polars_err!(ComputeError: "invalid JSON path '{}': {}", path, reason);
+
+        let Some(mut rest) = path.trim().strip_prefix('$') else {
+            return Err(invalid("must start with '$'"));
+        };
+
+        let mut keys = vec![];
+
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('.') {
+                let end = r.find(['.', '[']).unwrap_or(r.len());
+                if end == 0 {
+                    return Err(invalid("expected a key after '.'"));
+                }
+                keys.push(PlSmallStr::from_str(&r[..end]));
+                rest = &r[end..];
+            } else if let Some(r) = rest.strip_prefix('[') {
+                let Some(quote) = r.chars().next().filter(|c| *c == '\'' || *c == '"') else {
+                    return Err(invalid("expected a quoted key after '['"));
+                };
+                let r = &r[1..];
+                let Some(end) = r.find(quote) else {
+                    return Err(invalid("unterminated quoted key"));
+                };
+                keys.push(PlSmallStr::from_str(&r[..end]));
+                let Some(r) = r[end + 1..].strip_prefix(']') else {
+                    return Err(invalid("expected ']' after quoted key"));
+                };
+                rest = r;
+            } else {
+                return Err(invalid("expected '.' or '['"));
+            }
+        }
+
+        Ok(Self { keys })
+    }
+
+    pub fn keys(&self) -> &[PlSmallStr] {
+        &self.keys
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("$")?;
+        for key in &self.keys {
+            if key.contains(['.', '[']) {
+                write!(f, "['{key}']")?;
+            } else {
+                write!(f, ".{key}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns an iterator over the bytes of every row of the JSON document in `bytes`, in order.
+///
+/// The rows are the elements of the array at `path`. If the value at `path` is an object, it is
+/// the only row. The document is only scanned as far as the rows are consumed.
+///
+/// This only checks the structure of the document up to the rows; the rows themselves are
+/// validated when they are parsed.
+pub fn json_rows<'a>(bytes: &'a [u8], path: &JsonPath) -> PolarsResult<JsonRows<'a>> {
+    let mut scanner = Scanner {
+        bytes: remove_bom(bytes)?,
+        pos: 0,
+    };
+
+    for key in path.keys() {
+        scanner.skip_whitespace();
+        if scanner.peek() != Some(b'{') {
+            polars_bail!(
+                ComputeError: "JSON path '{}': expected an object at byte {} to look up key '{}'",
+                path, scanner.pos, key
+            )
+        }
+        scanner.pos += 1;
+
+        loop {
+            scanner.skip_whitespace();
+            if scanner.peek() == Some(b'}') {
+                polars_bail!(ComputeError: "JSON path '{}': key '{}' not found", path, key)
+            }
+
+            let member_key = scanner.read_key()?;
+            scanner.skip_whitespace();
+            scanner.expect(b':')?;
+            scanner.skip_whitespace();
+
+            if member_key == key.as_str() {
+                break;
+            }
+
+            scanner.skip_value()?;
+            scanner.skip_whitespace();
+            match scanner.next() {
+                Some(b',') => {},
+                Some(b'}') => {
+                    polars_bail!(ComputeError: "JSON path '{}': key '{}' not found", path, key)
+                },
+                _ => return Err(scanner.error("expected ',' or '}'")),
+            }
+        }
+    }
+
+    scanner.skip_whitespace();
+
+    let state = match scanner.peek() {
+        Some(b'[') => {
+            scanner.pos += 1;
+            JsonRowsState::FirstElement
+        },
+        Some(b'{') => JsonRowsState::Object,
+        _ => polars_bail!(
+            ComputeError: "JSON path '{}': expected an array or an object at byte {}",
+            path, scanner.pos
+        ),
+    };
+
+    Ok(JsonRows {
+        scanner,
+        state,
+        is_root: path.is_root(),
+    })
+}
+
+/// Iterator over the rows of a JSON document, see [`json_rows`].
+pub struct JsonRows<'a> {
+    scanner: Scanner<'a>,
+    state: JsonRowsState,
+    /// Data after the rows is only an error if the rows are the document itself.
+    is_root: bool,
+}
+
+enum JsonRowsState {
+    FirstElement,
+    NextElement,
+    Object,
+    AfterObject,
+    Done,
+}
+
+impl<'a> JsonRows<'a> {
+    fn next_row(&mut self) -> PolarsResult<Option<&'a [u8]>> {
+        let scanner = &mut self.scanner;
+
+        match self.state {
+            JsonRowsState::FirstElement => {
+                scanner.skip_whitespace();
+                if scanner.peek() == Some(b']') {
+                    scanner.pos += 1;
+                    return self.finish();
+                }
+            },
+            JsonRowsState::NextElement => {
+                scanner.skip_whitespace();
+                match scanner.next() {
+                    Some(b',') => scanner.skip_whitespace(),
+                    Some(b']') => return self.finish(),
+                    _ => return Err(scanner.error("expected ',' or ']'")),
+                }
+            },
+            JsonRowsState::Object => {},
+            JsonRowsState::AfterObject => return self.finish(),
+            JsonRowsState::Done => return Ok(None),
+        }
+
+        let start = scanner.pos;
+        scanner.skip_value()?;
+        self.state = match self.state {
+            JsonRowsState::Object => JsonRowsState::AfterObject,
+            _ => JsonRowsState::NextElement,
+        };
+
+        let bytes = self.scanner.bytes;
+        Ok(Some(&bytes[start..self.scanner.pos]))
+    }
+
+    fn finish(&mut self) -> PolarsResult<Option<&'a [u8]>> {
+        self.state = JsonRowsState::Done;
+
+        if self.is_root {
+            self.scanner.skip_whitespace();
+            if self.scanner.peek().is_some() {
+                return Err(self.scanner.error("unexpected data after the JSON document"));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'a> Iterator for JsonRows<'a> {
+    type Item = PolarsResult<&'a [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let out = self.next_row();
+        if out.is_err() {
+            self.state = JsonRowsState::Done;
+        }
+        out.transpose()
+    }
+}
+
+/// Calls `f` with the bytes of every row of the JSON document in `bytes`, in order.
+///
+/// See [`json_rows`] for how the rows are located. Iteration stops after `n_rows` rows if given.
+/// Returns the number of rows visited.
+pub fn for_each_json_row(
+    bytes: &[u8],
+    path: &JsonPath,
+    n_rows: Option<usize>,
+    mut f: impl FnMut(&[u8]),
+) -> PolarsResult<usize> {
+    let n_rows = n_rows.unwrap_or(usize::MAX);
+    let mut rows = json_rows(bytes, path)?;
+    let mut n_visited = 0;
+
+    while n_visited < n_rows {
+        let Some(row) = rows.next().transpose()? else {
+            break;
+        };
+        f(row);
+        n_visited += 1;
+    }
+
+    Ok(n_visited)
+}
+
+/// Appends `row` to `out` as a line of newline-delimited JSON.
+///
+/// Line breaks within the row are replaced by spaces, which is valid as JSON strings cannot
+/// contain raw line breaks.
+pub fn write_ndjson_row(row: &[u8], out: &mut Vec<u8>) {
+    out.extend(row.iter().map(|&b| match b {
+        b'\n' | b'\r' => b' ',
+        b => b,
+    }));
+    out.push(b'\n');
+}
+
+/// Rewrites the rows of the JSON document in `bytes` as newline-delimited JSON into `out`.
+///
+/// See [`json_rows`] for how the rows are located and [`write_ndjson_row`] for how they are
+/// written.
+pub fn json_rows_to_ndjson(
+    bytes: &[u8],
+    path: &JsonPath,
+    n_rows: Option<usize>,
+    out: &mut Vec<u8>,
+) -> PolarsResult<usize> {
+    for_each_json_row(bytes, path, n_rows, |row| write_ndjson_row(row, out))
+}
+
+/// Count the number of rows of the JSON document in `bytes`.
+pub fn count_json_rows(bytes: &[u8], path: &JsonPath) -> PolarsResult<usize> {
+    for_each_json_row(bytes, path, None, |_| {})
+}
+
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn error(&self, msg: &str) -> PolarsError {
+        polars_err!(ComputeError: "invalid JSON at byte {}: {}", self.pos, msg)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let b = self.peek();
+        self.pos += b.is_some() as usize;
+        b
+    }
+
+    fn expect(&mut self, b: u8) -> PolarsResult<()> {
+        if self.next() != Some(b) {
+            self.pos = self.pos.saturating_sub(1);
+            return Err(self.error(&format!("expected '{}'", b as char)));
+        }
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skips a string, `self.pos` must point at the opening quote.
+    fn skip_string(&mut self) -> PolarsResult<()> {
+        let start = self.pos;
+        self.pos += 1;
+
+        while let Some(offset) = memchr::memchr2(b'"', b'\\', &self.bytes[self.pos..]) {
+            self.pos += offset;
+            if self.bytes[self.pos] == b'\\' {
+                // The escaped byte must exist; a trailing backslash means the input is truncated.
+                if self.pos + 1 >= self.bytes.len() {
+                    break;
+                }
+                self.pos += 2;
+            } else {
+                self.pos += 1;
+                return Ok(());
+            }
+        }
+
+        self.pos = start;
+        Err(self.error("unterminated string"))
+    }
+
+    fn read_key(&mut self) -> PolarsResult<Cow<'a, str>> {
+        if self.peek() != Some(b'"') {
+            return Err(self.error("expected an object key"));
+        }
+
+        let start = self.pos;
+        self.skip_string()?;
+        let raw = &self.bytes[start + 1..self.pos - 1];
+
+        if raw.contains(&b'\\') {
+            let mut quoted = self.bytes[start..self.pos].to_vec();
+            match simd_json::to_owned_value(&mut quoted) {
+                Ok(simd_json::OwnedValue::String(key)) => Ok(Cow::Owned(key)),
+                _ => Err(self.error("invalid object key")),
+            }
+        } else {
+            std::str::from_utf8(raw)
+                .map(Cow::Borrowed)
+                .map_err(|_| self.error("invalid UTF-8 in object key"))
+        }
+    }
+
+    /// Skips a single value. The value is only validated as far as needed to find its end.
+    fn skip_value(&mut self) -> PolarsResult<()> {
+        match self.peek() {
+            Some(b'"') => self.skip_string(),
+            Some(b'{' | b'[') => {
+                let mut depth = 0usize;
+
+                while let Some(b) = self.peek() {
+                    match b {
+                        b'"' => {
+                            self.skip_string()?;
+                            continue;
+                        },
+                        b'{' | b'[' => depth += 1,
+                        b'}' | b']' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                self.pos += 1;
+                                return Ok(());
+                            }
+                        },
+                        _ => {},
+                    }
+                    self.pos += 1;
+                }
+
+                Err(self.error("unexpected end of input"))
+            },
+            Some(b',' | b':' | b']' | b'}') | None => Err(self.error("expected a value")),
+            Some(_) => {
+                while !matches!(
+                    self.peek(),
+                    Some(b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r') | None
+                ) {
+                    self.pos += 1;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(json: &str, path: &str) -> PolarsResult<Vec<String>> {
+        let mut out = vec![];
+        for_each_json_row(json.as_bytes(), &JsonPath::parse(path)?, None, |row| {
+            out.push(String::from_utf8(row.to_vec()).unwrap())
+        })?;
+        Ok(out)
+    }
+
+    #[test]
+    fn test_json_path_parse() {
+        assert!(JsonPath::parse("$").unwrap().is_root());
+        let path = JsonPath::parse("$.data['a.b'][\"c\"].items").unwrap();
+        assert_eq!(path.keys(), ["data", "a.b", "c", "items"]);
+        assert_eq!(path.to_string(), "$.data['a.b'].c.items");
+
+        for invalid in ["", "data", "$.", "$..a", "$[a]", "$['a'", "$['a']b"] {
+            assert!(JsonPath::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_for_each_json_row() {
+        let json = r#" [ {"a": "x]\"}"}, {"a": [1, {"b": 2}]} ,
+            {"a": null}, 3 ] "#;
+        assert_eq!(
+            rows(json, "$").unwrap(),
+            [
+                r#"{"a": "x]\"}"}"#,
+                r#"{"a": [1, {"b": 2}]}"#,
+                r#"{"a": null}"#,
+                "3"
+            ]
+        );
+
+        let json =
+            r#"{"meta": {"data": [1]}, "d\"x": [5], "data": {"items": [{"a": 1}, {"a": 2}]}}"#;
+        assert_eq!(
+            rows(json, "$.data.items").unwrap(),
+            [r#"{"a": 1}"#, r#"{"a": 2}"#]
+        );
+        assert_eq!(
+            rows(json, "$.data").unwrap(),
+            [r#"{"items": [{"a": 1}, {"a": 2}]}"#]
+        );
+        assert!(rows(json, "$.missing").is_err());
+        assert_eq!(rows(json, "$['d\"x']").unwrap(), ["5"]);
+
+        assert!(rows("[]", "$").unwrap().is_empty());
+        assert!(rows("[1,]", "$").is_err());
+        assert!(rows("[1 2]", "$").is_err());
+        assert!(rows("[1] [2]", "$").is_err());
+        assert!(rows(r#"["abc]"#, "$").is_err());
+    }
+
+    #[test]
+    fn test_for_each_json_row_truncated() {
+        for (json, path) in [
+            (r#"[""#, "$"),
+            (r#"["a\"#, "$"),
+            (r#"["a\\"#, "$"),
+            (r#"[{"a": "\"#, "$"),
+            (r#"{"data\"#, "$.data"),
+            (r#"{"x": "\"#, "$.data"),
+        ] {
+            let err = rows(json, path).unwrap_err();
+            assert!(err.to_string().contains("unterminated string"), "{json}: {err}");
+        }
+        for json in [r#"[1, {"a": 2"#, "[1, [2, 3]"] {
+            assert!(rows(json, "$").is_err(), "{json}");
+        }
+
+        // Rows are located lazily, so the rows before the truncation are still returned.
+        let mut iter = json_rows(br#"[1, {"a": 2}, "x"#, &JsonPath::root()).unwrap();
+        assert_eq!(iter.next().unwrap().unwrap(), b"1");
+        assert_eq!(iter.next().unwrap().unwrap(), br#"{"a": 2}"#);
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_json_rows_to_ndjson() {
+        let json = "[\n  {\n    \"a\": 1\n  },\r\n  {\"a\": 2}\n]";
+        let mut out = vec![];
+        let n = json_rows_to_ndjson(json.as_bytes(), &JsonPath::root(), None, &mut out).unwrap();
+        assert_eq!(n, 2);
+        assert_eq!(out, b"{     \"a\": 1   }\n{\"a\": 2}\n");
+
+        out.clear();
+        let n = json_rows_to_ndjson(json.as_bytes(), &JsonPath::root(), Some(1), &mut out).unwrap();
+        assert_eq!(n, 1);
+        assert_eq!(
+            count_json_rows(json.as_bytes(), &JsonPath::root()).unwrap(),
+            2
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{hash_byte, hash_native, is_in_set};
+use polars_parquet::read::PhysicalType;
+
+use crate::predicates::SpecializedColumnPredicate;
+
+/// Computes the bloom filter hashes of the values a column chunk needs to contain (at least one
+/// of) for `predicate` to possibly hold.
+///
+/// `dtype` is the dtype of the column as read from the file. Returns `None` if the predicate
+/// cannot be checked with a bloom filter, e.g. because it contains a null or it is on a type for
+/// which the hash of the plain-encoded value cannot be reliably reproduced.
+pub fn bloom_filter_hashes(
+    predicate: &SpecializedColumnPredicate,
+    dtype: &DataType,
+    physical_type: PhysicalType,
+) -> Option<Vec<u64>> {
+    use SpecializedColumnPredicate as S;
+
+    match predicate {
+        S::Equal(scalar) => Some(vec![scalar_hash(scalar, dtype, physical_type)?]),
+        S::EqualOneOf(scalars) => scalars
+            .iter()
+            .map(|scalar| scalar_hash(scalar, dtype, physical_type))
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Returns whether the `bitset` of a split-block bloom filter may contain any of the `hashes`.
+pub fn bloom_filter_may_contain_any(bitset: &[u8], hashes: &[u64]) -> bool {
+    hashes.iter().any(|hash| is_in_set(bitset, *hash))
+}
+
+/// Hashes `scalar` as the plain-encoded parquet physical value it is written as.
+fn scalar_hash(scalar: &Scalar, dtype: &DataType, physical_type: PhysicalType) -> Option<u64> {
+    use {AnyValue as A, PhysicalType as P};
+
+    if scalar.dtype() != dtype {
+        return None;
+    }
+
+    // @NOTE: Time is not included here as it gets converted from other units on read.
+    Some(match (physical_type, scalar.value()) {
+        (P::Int32, A::Int8(v)) => hash_native(*v as i32),
+        (P::Int32, A::Int16(v)) => hash_native(*v as i32),
+        (P::Int32, A::Int32(v)) => hash_native(*v),
+        (P::Int32, A::UInt8(v)) => hash_native(*v as i32),
+        (P::Int32, A::UInt16(v)) => hash_native(*v as i32),
+        (P::Int32, A::UInt32(v)) => hash_native(*v as i32),
+        #[cfg(feature = "dtype-date")]
+        (P::Int32, A::Date(v)) => hash_native(*v),
+
+        (P::Int64, A::Int64(v)) => hash_native(*v),
+        (P::Int64, A::UInt64(v)) => hash_native(*v as i64),
+        #[cfg(feature = "dtype-datetime")]
+        (P::Int64, A::Datetime(v, _, _) | A::DatetimeOwned(v, _, _)) => hash_native(*v),
+        #[cfg(feature = "dtype-duration")]
+        (P::Int64, A::Duration(v, _)) => hash_native(*v),
+
+        (P::ByteArray, A::String(v)) => hash_byte(v),
+        (P::ByteArray, A::StringOwned(v)) => hash_byte(v.as_str()),
+        (P::ByteArray, A::Binary(v)) => hash_byte(v),
+        (P::ByteArray, A::BinaryOwned(v)) => hash_byte(v),
+
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_parquet::parquet::bloom_filter::insert;
+
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter_hashes() {
+        use SpecializedColumnPredicate as S;
+
+        let mut bitset = vec![0; 32];
+        insert(&mut bitset, hash_native(-1i32));
+        insert(&mut bitset, hash_byte("a"));
+
+        let hashes = bloom_filter_hashes(
+            &S::Equal(u32::MAX.into()),
+            &DataType::UInt32,
+            PhysicalType::Int32,
+        )
+        .unwrap();
+        assert!(bloom_filter_may_contain_any(&bitset, &hashes));
+
+        let hashes = bloom_filter_hashes(
+            &S::EqualOneOf(Box::new([
+                PlSmallStr::from("b").into(),
+                PlSmallStr::from("c").into(),
+            ])),
+            &DataType::String,
+            PhysicalType::ByteArray,
+        )
+        .unwrap();
+        assert!(!bloom_filter_may_contain_any(&bitset, &hashes));
+
+        // Nulls and mismatching types cannot use the bloom filter.
+        let null = Scalar::null(DataType::Int32);
+        assert!(
+            bloom_filter_hashes(&S::Equal(null), &DataType::Int32, PhysicalType::Int32).is_none()
+        );
+        assert!(
+            bloom_filter_hashes(
+                &S::Equal(1i64.into()),
+                &DataType::Int32,
+                PhysicalType::Int32
+            )
+            .is_none()
+        );
+        assert!(
+            bloom_filter_hashes(
+                &S::Equal(1i32.into()),
+                &DataType::Int32,
+                PhysicalType::Int64
+            )
+            .is_none()
+        );
+    }
+}
@@ -16,8 +16,10 @@
 
 #[cfg(feature = "cloud")]
 mod async_impl;
+mod bloom_filter;
 mod mmap;
 mod options;
+mod page_index;
 mod read_impl;
 mod reader;
 mod utils;
@@ -31,7 +33,9 @@ or set 'streaming'",
 
 #[cfg(feature = "cloud")]
 pub use async_impl::ParquetObjectStore;
+pub use bloom_filter::{bloom_filter_hashes, bloom_filter_may_contain_any};
 pub use options::{ParallelStrategy, ParquetOptions};
+pub use page_index::page_index_row_mask;
 use polars_error::{ErrString, PolarsError};
 pub use polars_parquet::arrow::read::infer_schema;
 pub use polars_parquet::read::FileMetadata;
@@ -40,7 +44,7 @@ pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
+    pub use super::mmap::{to_deserializer, to_deserializer_with_page_meta};
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -0,0 +1,257 @@
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+use polars_parquet::parquet::read::page_index::{ColumnIndex, OffsetIndex};
+use polars_parquet::read::PhysicalType;
+
+use crate::predicates::SpecializedColumnPredicate;
+
+/// How the min / max values of a column index are ordered.
+#[derive(Clone, Copy)]
+enum OrderKind {
+    Signed32,
+    Unsigned32,
+    Signed64,
+    Unsigned64,
+    Bytes,
+}
+
+/// A value that can be compared with the min / max values of a column index. Only values of the
+/// same [`OrderKind`] are compared to each other.
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum OrderKey<'a> {
+    Signed(i64),
+    Unsigned(u64),
+    Bytes(&'a [u8]),
+}
+
+impl OrderKind {
+    fn new(dtype: &DataType, physical_type: PhysicalType) -> Option<Self> {
+        use {DataType as D, PhysicalType as P};
+
+        // @NOTE: Time is not included here as it gets converted from other units on read.
+        Some(match (physical_type, dtype) {
+            (P::Int32, D::Int8 | D::Int16 | D::Int32 | D::UInt8 | D::UInt16) => Self::Signed32,
+            #[cfg(feature = "dtype-date")]
+            (P::Int32, D::Date) => Self::Signed32,
+            (P::Int32, D::UInt32) => Self::Unsigned32,
+            (P::Int64, D::Int64) => Self::Signed64,
+            #[cfg(feature = "dtype-datetime")]
+            (P::Int64, D::Datetime(_, _)) => Self::Signed64,
+            #[cfg(feature = "dtype-duration")]
+            (P::Int64, D::Duration(_)) => Self::Signed64,
+            (P::Int64, D::UInt64) => Self::Unsigned64,
+            (P::ByteArray, D::String | D::Binary) => Self::Bytes,
+            _ => return None,
+        })
+    }
+
+    /// Decodes a plain-encoded min / max value of the column index.
+    fn decode(self, bytes: &[u8]) -> Option<OrderKey<'_>> {
+        Some(match self {
+            Self::Signed32 => OrderKey::Signed(i32::from_le_bytes(bytes.try_into().ok()?) as i64),
+            Self::Unsigned32 => {
+                OrderKey::Unsigned(u32::from_le_bytes(bytes.try_into().ok()?) as u64)
+            },
+            Self::Signed64 => OrderKey::Signed(i64::from_le_bytes(bytes.try_into().ok()?)),
+            Self::Unsigned64 => OrderKey::Unsigned(u64::from_le_bytes(bytes.try_into().ok()?)),
+            Self::Bytes => OrderKey::Bytes(bytes),
+        })
+    }
+
+    fn scalar_key<'a>(self, scalar: &'a Scalar) -> Option<OrderKey<'a>> {
+        use AnyValue as A;
+
+        let av = scalar.value();
+
+        Some(match self {
+            Self::Signed32 | Self::Signed64 => OrderKey::Signed(match av {
+                A::Int8(v) => *v as i64,
+                A::Int16(v) => *v as i64,
+                A::Int32(v) => *v as i64,
+                A::Int64(v) => *v,
+                A::UInt8(v) => *v as i64,
+                A::UInt16(v) => *v as i64,
+                #[cfg(feature = "dtype-date")]
+                A::Date(v) => *v as i64,
+                #[cfg(feature = "dtype-datetime")]
+                A::Datetime(v, _, _) | A::DatetimeOwned(v, _, _) => *v,
+                #[cfg(feature = "dtype-duration")]
+                A::Duration(v, _) => *v,
+                _ => return None,
+            }),
+            Self::Unsigned32 | Self::Unsigned64 => OrderKey::Unsigned(match av {
+                A::UInt32(v) => *v as u64,
+                A::UInt64(v) => *v,
+                _ => return None,
+            }),
+            Self::Bytes => OrderKey::Bytes(match av {
+                A::String(v) => v.as_bytes(),
+                A::StringOwned(v) => v.as_bytes(),
+                A::Binary(v) => v,
+                A::BinaryOwned(v) => v,
+                _ => return None,
+            }),
+        })
+    }
+}
+
+/// Computes a mask of the rows of a flat column chunk that lie in data pages which may contain
+/// values for which `predicate` holds, using the column's page index.
+///
+/// `dtype` is the dtype of the column as read from the file. Returns `None` if the page index
+/// cannot be used for `predicate`.
+pub fn page_index_row_mask(
+    predicate: &SpecializedColumnPredicate,
+    dtype: &DataType,
+    physical_type: PhysicalType,
+    column_index: &ColumnIndex,
+    offset_index: &OffsetIndex,
+    num_rows: usize,
+) -> Option<Bitmap> {
+    use SpecializedColumnPredicate as S;
+
+    let kind = OrderKind::new(dtype, physical_type)?;
+
+    // Scalars may be given in the physical type of the column.
+    fn order_key<'a>(
+        kind: OrderKind,
+        scalar: &'a Scalar,
+        dtype: &DataType,
+    ) -> Option<OrderKey<'a>> {
+        if scalar.is_null() || scalar.dtype().to_physical() != dtype.to_physical() {
+            return None;
+        }
+        kind.scalar_key(scalar)
+    }
+    let key = |scalar| order_key(kind, scalar, dtype);
+
+    // Inclusive ranges of values of which a page needs to contain at least one.
+    let ranges = match predicate {
+        S::Equal(scalar) => {
+            let v = key(scalar)?;
+            vec![(v, v)]
+        },
+        S::EqualOneOf(scalars) => scalars
+            .iter()
+            .map(|scalar| key(scalar).map(|v| (v, v)))
+            .collect::<Option<Vec<_>>>()?,
+        S::Between(low, high) => vec![(key(low)?, key(high)?)],
+        _ => return None,
+    };
+
+    let pages = &offset_index.page_locations;
+    let num_pages = pages.len();
+
+    if num_pages == 0
+        || column_index.null_pages.len() != num_pages
+        || column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+    {
+        return None;
+    }
+
+    let mut mask = MutableBitmap::with_capacity(num_rows);
+
+    for (i, page) in pages.iter().enumerate() {
+        let start = usize::try_from(page.first_row_index).ok()?;
+        let end = match pages.get(i + 1) {
+            None => num_rows,
+            Some(next) => usize::try_from(next.first_row_index).ok()?,
+        };
+
+        if start != mask.len() || end < start || end > num_rows {
+            return None;
+        }
+
+        // A page containing only nulls never satisfies a predicate on non-null values.
+        let may_match = !column_index.null_pages[i] && {
+            let min = kind.decode(&column_index.min_values[i])?;
+            let max = kind.decode(&column_index.max_values[i])?;
+
+            ranges.iter().any(|(low, high)| *low <= max && min <= *high)
+        };
+
+        mask.extend_constant(end - start, may_match);
+    }
+
+    Some(mask.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_parquet::parquet::read::page_index::{BoundaryOrder, PageLocation};
+
+    use super::*;
+
+    fn page_index(pages: &[(i64, Option<(i32, i32)>)]) -> (ColumnIndex, OffsetIndex) {
+        let column_index = ColumnIndex {
+            null_pages: pages.iter().map(|(_, mm)| mm.is_none()).collect(),
+            min_values: pages
+                .iter()
+                .map(|(_, mm)| mm.map_or(vec![0], |(min, _)| min.to_le_bytes().to_vec()))
+                .collect(),
+            max_values: pages
+                .iter()
+                .map(|(_, mm)| mm.map_or(vec![0], |(_, max)| max.to_le_bytes().to_vec()))
+                .collect(),
+            boundary_order: BoundaryOrder::UNORDERED,
+            null_counts: None,
+            repetition_level_histograms: None,
+            definition_level_histograms: None,
+        };
+        let offset_index = OffsetIndex {
+            page_locations: pages
+                .iter()
+                .map(|(first_row_index, _)| PageLocation {
+                    offset: 0,
+                    compressed_page_size: 0,
+                    first_row_index: *first_row_index,
+                })
+                .collect(),
+            unencoded_byte_array_data_bytes: None,
+        };
+        (column_index, offset_index)
+    }
+
+    #[test]
+    fn test_page_index_row_mask() {
+        use SpecializedColumnPredicate as S;
+
+        let (ci, oi) = page_index(&[(0, Some((0, 9))), (2, None), (3, Some((10, 19)))]);
+        let mask = |p: S| {
+            page_index_row_mask(&p, &DataType::Int32, PhysicalType::Int32, &ci, &oi, 5)
+                .map(|m| m.iter().collect::<Vec<_>>())
+        };
+
+        assert_eq!(
+            mask(S::Equal(15i32.into())),
+            Some(vec![false, false, false, true, true])
+        );
+        assert_eq!(
+            mask(S::EqualOneOf(Box::new([1i32.into(), 30i32.into()]))),
+            Some(vec![true, true, false, false, false])
+        );
+        assert_eq!(
+            mask(S::Between(20i32.into(), 25i32.into())),
+            Some(vec![false; 5])
+        );
+        assert_eq!(mask(S::Equal(Scalar::null(DataType::Int32))), None);
+        assert_eq!(mask(S::StartsWith(Box::new([]))), None);
+    }
+
+    #[test]
+    fn test_page_index_row_mask_unsigned() {
+        // u32::MAX is written as -1.
+        let (ci, oi) = page_index(&[(0, Some((0, -1)))]);
+        let mask = page_index_row_mask(
+            &SpecializedColumnPredicate::Equal(3_000_000_000u32.into()),
+            &DataType::UInt32,
+            PhysicalType::Int32,
+            &ci,
+            &oi,
+            1,
+        )
+        .unwrap();
+        assert_eq!(mask.set_bits(), 1);
+    }
+}
@@ -8,12 +8,20 @@ use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
 use super::{KeyValueMetadata, ParquetMetadataContext};
 
+/// The encoded and compressed columns of a row group, along with the optional bloom filter
+/// bitset of each leaf column.
+pub type EncodedRowGroup = (
+    RowGroupIterColumns<'static, PolarsError>,
+    Vec<Option<Vec<u8>>>,
+);
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
@@ -49,7 +57,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
@@ -81,7 +89,8 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write_with_bloom_filters(group, &bloom_filters)?;
         }
         Ok(())
     }
@@ -91,14 +100,18 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_bloom_filters(rg, bloom_filters)?;
         Ok(())
     }
 
@@ -106,14 +119,11 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
-            writer.write(group)?;
+        for (group, bloom_filters) in rgs {
+            writer.write_with_bloom_filters(group, &bloom_filters)?;
         }
         Ok(())
     }
@@ -150,7 +160,7 @@ fn prepare_rg_iter<'a>(
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
@@ -204,13 +214,24 @@ fn array_to_pages_iter(
     pages_iter_to_compressor(encoded_columns, options)
 }
 
+fn batch_to_bloom_filters(
+    batch: &RecordBatch,
+    column_options: &[ColumnWriteOptions],
+) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let mut bloom_filters = Vec::new();
+    for (array, column_options) in batch.columns().iter().zip(column_options) {
+        bloom_filters.extend(array_to_bloom_filters(array.as_ref(), column_options)?);
+    }
+    Ok(bloom_filters)
+}
+
 fn create_serializer(
     batch: RecordBatch,
     fields: &[ParquetType],
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<EncodedRowGroup> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -236,9 +257,10 @@ fn create_serializer(
             .collect::<Vec<_>>()
     };
 
+    let bloom_filters = batch_to_bloom_filters(&batch, column_options)?;
     let row_group = DynIter::new(columns.into_iter());
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters))
 }
 
 /// This serializer encodes and compresses all eagerly in memory.
@@ -248,7 +270,7 @@ fn create_eager_serializer(
     fields: &[ParquetType],
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<EncodedRowGroup> {
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -262,7 +284,8 @@ fn create_eager_serializer(
         .flat_map(func)
         .collect::<Vec<_>>();
 
+    let bloom_filters = batch_to_bloom_filters(&batch, column_options)?;
     let row_group = DynIter::new(columns.into_iter());
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters))
 }
@@ -1,7 +1,7 @@
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -57,6 +57,8 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a split-block bloom filter for this (flat) field.
+    pub bloom_filter: Option<BloomFilterOptions>,
 }
 
 /// The compression strategy to use for writing Parquet files.
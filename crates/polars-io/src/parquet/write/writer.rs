@@ -27,6 +27,7 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
     }
 }
 
@@ -113,6 +114,12 @@ where
         self
     }
 
+    /// Set per-field overwrites for writing properties, e.g. bloom filters.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set context information for the writer
     pub fn with_context_info(mut self, context_info: Option<PlHashMap<String, String>>) -> Self {
         self.context_info = context_info;
@@ -184,9 +191,9 @@ fn to_column_write_options_rec(
         required: None,
 
         // Dummy value.
-        children: ChildWriteOptions::Leaf(FieldWriteOptions {
-            encoding: Encoding::Plain,
-        }),
+        children: ChildWriteOptions::Leaf(FieldWriteOptions::default_with_encoding(
+            Encoding::Plain,
+        )),
     };
 
     if let Some(overwrites) = overwrites {
@@ -201,6 +208,7 @@ fn to_column_write_options_rec(
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
                 encoding: encoding_map(field.dtype()),
+                bloom_filter: overwrites.and_then(|o| o.bloom_filter),
             });
         },
         List | FixedSizeList | LargeList => {
@@ -12,6 +12,8 @@ pub mod pivot;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
@@ -20,6 +22,8 @@ pub use file_list_reader::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
+pub use json::*;
+#[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
@@ -913,6 +917,25 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(
+        self,
+        target: SinkTarget,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::File(FileSinkType {
+            target,
+            sink_options,
+            file_type: FileType::Avro(options),
+            cloud_options,
+        }))
+    }
+
     /// Stream a query result into an csv file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1007,6 +1030,34 @@ impl LazyFrame {
         }))
     }
 
+    /// Stream a query result into an avro file in a partitioned manner. This is useful if the
+    /// final result doesn't fit into memory. This methods will return an error if the query cannot
+    /// be completely done in a streaming fashion.
+    #[cfg(feature = "avro")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn sink_avro_partitioned(
+        self,
+        base_path: Arc<PlPath>,
+        file_path_cb: Option<PartitionTargetCallback>,
+        variant: PartitionVariant,
+        options: AvroWriterOptions,
+        cloud_options: Option<polars_io::cloud::CloudOptions>,
+        sink_options: SinkOptions,
+        per_partition_sort_by: Option<Vec<SortColumn>>,
+        finish_callback: Option<SinkFinishCallback>,
+    ) -> PolarsResult<Self> {
+        self.sink(SinkType::Partition(PartitionSinkType {
+            base_path,
+            file_path_cb,
+            sink_options,
+            variant,
+            file_type: FileType::Avro(options),
+            cloud_options,
+            per_partition_sort_by,
+            finish_callback,
+        }))
+    }
+
     /// Stream a query result into an csv file in a partitioned manner. This is useful if the final
     /// result doesn't fit into memory. This methods will return an error if the query cannot be
     /// completely done in a streaming fashion.
@@ -1262,6 +1313,7 @@ impl LazyFrame {
                 maintain_order: false,
                 dynamic_options: None,
                 rolling_options: None,
+                session_options: None,
             }
         }
 
@@ -1310,6 +1362,7 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: None,
             rolling_options: Some(options),
+            session_options: None,
         }
     }
 
@@ -1355,6 +1408,48 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: Some(options),
             rolling_options: None,
+            session_options: None,
+        }
+    }
+
+    /// Group based on sessions of a time value (or index value of type Int32, Int64).
+    ///
+    /// A session is a run of rows in which consecutive index values are less than `gap` apart,
+    /// so a session ends after an inactivity of `gap`. Different from
+    /// [`group_by_dynamic`][`Self::group_by_dynamic`], the windows are not of constant length and
+    /// every row is member of exactly one group. The index column of the output holds the start
+    /// of every session.
+    ///
+    /// The `group_by` argument should be empty `[]` if you don't want to combine this
+    /// with a ordinary group_by on these keys; otherwise the sessions are determined per key.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn group_by_session<E: AsRef<[Expr]>>(
+        mut self,
+        index_column: Expr,
+        group_by: E,
+        mut options: SessionGroupOptions,
+    ) -> LazyGroupBy {
+        if let Expr::Column(name) = index_column {
+            options.index_column = name;
+        } else {
+            let output_field = index_column
+                .to_field(&self.collect_schema().unwrap())
+                .unwrap();
+            return self.with_column(index_column).group_by_session(
+                Expr::Column(output_field.name().clone()),
+                group_by,
+                options,
+            );
+        }
+        let opt_state = self.get_opt_state();
+        LazyGroupBy {
+            logical_plan: self.logical_plan,
+            opt_state,
+            keys: group_by.as_ref().to_vec(),
+            maintain_order: true,
+            dynamic_options: None,
+            rolling_options: None,
+            session_options: Some(options),
         }
     }
 
@@ -1376,6 +1471,7 @@ impl LazyFrame {
                 maintain_order: true,
                 dynamic_options: None,
                 rolling_options: None,
+                session_options: None,
             }
         }
 
@@ -2083,6 +2179,8 @@ pub struct LazyGroupBy {
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     rolling_options: Option<RollingGroupOptions>,
+    #[cfg(feature = "dynamic_group_by")]
+    session_options: Option<SessionGroupOptions>,
 }
 
 impl From<LazyGroupBy> for LazyFrame {
@@ -2127,6 +2225,7 @@ impl LazyGroupBy {
                 self.maintain_order,
                 self.dynamic_options,
                 self.rolling_options,
+                self.session_options,
             )
             .build();
 
@@ -2170,6 +2269,7 @@ impl LazyGroupBy {
         let options = GroupbyOptions {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
+            session: self.session_options,
             slice: None,
         };
 
@@ -1,4 +1,6 @@
 pub(crate) use polars_expr::prelude::*;
+#[cfg(feature = "avro")]
+pub use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -19,7 +21,9 @@ pub use polars_plan::prelude::{PlanCallback, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
-pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
+pub use polars_time::{
+    DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions, SessionGroupOptions,
+};
 pub(crate) use polars_utils::arena::{Arena, Node};
 
 pub use crate::dsl::*;
@@ -1,10 +1,14 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "avro")]
+pub(super) mod avro;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
 pub(super) mod file_list_reader;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
 #[cfg(feature = "json")]
+pub(super) mod json;
+#[cfg(feature = "json")]
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
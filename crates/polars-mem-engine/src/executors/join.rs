@@ -78,75 +78,99 @@ impl Executor for JoinExec {
             Cow::Borrowed("")
         };
 
-        state.record(|| {
+        state.record(
+            || {
+                let left_on_series = self
+                    .left_on
+                    .iter()
+                    .map(|e| e.evaluate(&df_left, state))
+                    .collect::<PolarsResult<Vec<_>>>()?;
 
-            let left_on_series = self
-                .left_on
-                .iter()
-                .map(|e| e.evaluate(&df_left, state))
-                .collect::<PolarsResult<Vec<_>>>()?;
-
-            let right_on_series = self
-                .right_on
-                .iter()
-                .map(|e| e.evaluate(&df_right, state))
-                .collect::<PolarsResult<Vec<_>>>()?;
+                let right_on_series = self
+                    .right_on
+                    .iter()
+                    .map(|e| e.evaluate(&df_right, state))
+                    .collect::<PolarsResult<Vec<_>>>()?;
 
-            // prepare the tolerance
-            // we must ensure that we use the right units
-            #[cfg(feature = "asof_join")]
-            {
-                if let JoinType::AsOf(options) = &mut self.args.how {
-                    use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
-                    if let Some(tol) = &options.tolerance_str {
-                        let duration = polars_time::Duration::try_parse(tol)?;
-                        polars_ensure!(
-                            duration.months() == 0,
-                            ComputeError: "cannot use month offset in timedelta of an asof join; \
-                            consider using 4 weeks"
-                        );
+                // prepare the tolerance
+                // we must ensure that we use the right units
+                #[cfg(feature = "asof_join")]
+                {
+                    if let JoinType::AsOf(options) = &mut self.args.how {
                         let left_asof = df_left.column(left_on_series[0].name())?;
-                        use DataType::*;
-                        match left_asof.dtype() {
-                            Datetime(tu, _) | Duration(tu) => {
-                                let tolerance = match tu {
-                                    TimeUnit::Nanoseconds => duration.duration_ns(),
-                                    TimeUnit::Microseconds => duration.duration_us(),
-                                    TimeUnit::Milliseconds => duration.duration_ms(),
-                                };
-                                options.tolerance = Some(Scalar::from(tolerance))
-                            }
-                            Date => {
-                                let days = (duration.duration_ms() / MILLISECONDS_IN_DAY) as i32;
-                                options.tolerance = Some(Scalar::from(days))
-                            }
-                            Time => {
-                                let tolerance = duration.duration_ns();
-                                options.tolerance = Some(Scalar::from(tolerance))
-                            }
-                            _ => {
-                                panic!("can only use timedelta string language with Date/Datetime/Duration/Time dtypes")
-                            }
-                        }
+                        resolve_asof_tolerance(options, left_asof.dtype())?;
                     }
                 }
-            }
 
-            let df = df_left._join_impl(
-                &df_right,
-                left_on_series.into_iter().map(|c| c.take_materialized_series()).collect(),
-                right_on_series.into_iter().map(|c| c.take_materialized_series()).collect(),
-                self.args.clone(),
-                self.options.clone(),
-                true,
-                state.verbose(),
-            );
+                let df = df_left._join_impl(
+                    &df_right,
+                    left_on_series
+                        .into_iter()
+                        .map(|c| c.take_materialized_series())
+                        .collect(),
+                    right_on_series
+                        .into_iter()
+                        .map(|c| c.take_materialized_series())
+                        .collect(),
+                    self.args.clone(),
+                    self.options.clone(),
+                    true,
+                    state.verbose(),
+                );
 
-            if state.verbose() {
-                eprintln!("{:?} join dataframes finished", self.args.how);
-            };
-            df
+                if state.verbose() {
+                    eprintln!("{:?} join dataframes finished", self.args.how);
+                };
+                df
+            },
+            profile_name,
+        )
+    }
+}
 
-        }, profile_name)
+/// Converts the `tolerance_str` of an asof join (if any) into a `tolerance` in
+/// the physical unit of the asof key.
+#[cfg(feature = "asof_join")]
+pub fn resolve_asof_tolerance(
+    options: &mut polars_ops::frame::AsOfOptions,
+    key_dtype: &DataType,
+) -> PolarsResult<()> {
+    use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+    if let Some(tol) = &options.tolerance_str {
+        let duration = polars_time::Duration::try_parse(tol)?;
+        polars_ensure!(
+            duration.months() == 0,
+            ComputeError: "cannot use month offset in timedelta of an asof join; \
+            consider using 4 weeks"
+        );
+        polars_ensure!(
+            duration.business_days() == 0,
+            ComputeError: "cannot use business day offset in timedelta of an asof join"
+        );
+        use DataType::*;
+        match key_dtype {
+            Datetime(tu, _) | Duration(tu) => {
+                let tolerance = match tu {
+                    TimeUnit::Nanoseconds => duration.duration_ns(),
+                    TimeUnit::Microseconds => duration.duration_us(),
+                    TimeUnit::Milliseconds => duration.duration_ms(),
+                };
+                options.tolerance = Some(Scalar::from(tolerance))
+            },
+            Date => {
+                let days = (duration.duration_ms() / MILLISECONDS_IN_DAY) as i32;
+                options.tolerance = Some(Scalar::from(days))
+            },
+            Time => {
+                let tolerance = duration.duration_ns();
+                options.tolerance = Some(Scalar::from(tolerance))
+            },
+            _ => {
+                polars_bail!(
+                    InvalidOperation: "can only use timedelta string language with Date/Datetime/Duration/Time dtypes"
+                )
+            },
+        }
     }
+    Ok(())
 }
@@ -8,6 +8,8 @@ mod predicate;
 mod prelude;
 
 pub use executors::Executor;
+#[cfg(feature = "asof_join")]
+pub use executors::resolve_asof_tolerance;
 #[cfg(feature = "python")]
 pub use planner::python_scan_predicate;
 pub use planner::{
@@ -341,6 +341,16 @@ fn create_physical_plan_impl(
                                         .with_json_format(JsonFormat::JsonLines)
                                         .finish(&mut df)?;
                                 },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    use polars_io::avro::AvroWriter;
+
+                                    AvroWriter::new(BufWriter::new(writer))
+                                        .with_compression(options.compression.map(Into::into))
+                                        .with_name(options.name.to_string())
+                                        .finish(&mut df)?;
+                                },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
                             }
@@ -694,6 +704,20 @@ fn create_physical_plan_impl(
                 }));
             }
 
+            #[cfg(feature = "dynamic_group_by")]
+            if let Some(options) = options.session {
+                let input = recurse!(input, state)?;
+                return Ok(Box::new(executors::GroupBySessionExec {
+                    input,
+                    keys: phys_keys,
+                    aggs: phys_aggs,
+                    options,
+                    input_schema,
+                    slice: _slice,
+                    apply,
+                }));
+            }
+
             // We first check if we can partition the group_by on the latest moment.
             let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
@@ -0,0 +1,222 @@
+use std::borrow::Cow;
+
+use arrow::array::Array;
+#[cfg(feature = "dtype-array")]
+use arrow::array::FixedSizeListArray;
+use arrow::offset::{Offsets, OffsetsBuffer};
+#[cfg(feature = "dtype-array")]
+use polars_utils::format_tuple;
+
+use super::*;
+
+/// Number of `List` and `Array` levels nested in `dtype`.
+fn nesting_depth(mut dtype: &DataType) -> usize {
+    let mut depth = 0;
+    loop {
+        match dtype {
+            DataType::List(inner) => dtype = inner,
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner, _) => dtype = inner,
+            _ => return depth,
+        }
+        depth += 1;
+    }
+}
+
+/// The output dtype of [`list_flatten`] for a list of `dtype`.
+pub fn list_flatten_dtype(dtype: &DataType, depth: Option<usize>) -> PolarsResult<DataType> {
+    let DataType::List(inner) = dtype else {
+        polars_bail!(op = "list.flatten", got = dtype, expected = "List");
+    };
+    let max_depth = nesting_depth(inner);
+    let depth = depth.unwrap_or(max_depth);
+    polars_ensure!(
+        depth <= max_depth,
+        InvalidOperation: "cannot flatten {} levels of `{}`, it only has {} nested levels",
+        depth, dtype, max_depth
+    );
+
+    let mut inner = inner.as_ref();
+    for _ in 0..depth {
+        inner = inner.inner_dtype().unwrap();
+    }
+    Ok(DataType::List(Box::new(inner.clone())))
+}
+
+/// Remove a single level of nesting by concatenating the inner lists of every row.
+fn flatten_once(ca: &ListChunked) -> PolarsResult<ListChunked> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_as_array();
+    let inner = arr.values();
+
+    let (inner_offsets, leaf_values, leaf_dtype): (Cow<[i64]>, _, _) = match ca.inner_dtype() {
+        DataType::List(leaf_dtype) => {
+            let inner = inner.as_any().downcast_ref::<LargeListArray>().unwrap();
+            (
+                Cow::Borrowed(inner.offsets().as_slice()),
+                inner.values().clone(),
+                leaf_dtype.as_ref(),
+            )
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(leaf_dtype, width) => {
+            let inner = inner.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            (
+                Cow::Owned((0..=inner.len()).map(|i| (i * width) as i64).collect()),
+                inner.values().clone(),
+                leaf_dtype.as_ref(),
+            )
+        },
+        dt => polars_bail!(op = "list.flatten", got = dt, expected = "List or Array"),
+    };
+
+    // Null inner lists are skipped. As long as they are empty, the new offsets are simply the
+    // inner offsets at the outer offsets and the leaf values can be reused as they are.
+    let inner_validity = inner.validity();
+    let needs_gather = inner_validity.is_some_and(|validity| {
+        (!validity)
+            .true_idx_iter()
+            .any(|i| inner_offsets[i] != inner_offsets[i + 1])
+    });
+
+    let (offsets, values) = if needs_gather {
+        let inner_validity = inner_validity.unwrap();
+        let mut idx = Vec::with_capacity(leaf_values.len());
+        let mut lengths = Vec::with_capacity(arr.len());
+        for (start, length) in arr.offsets().offset_and_length_iter() {
+            let prev_len = idx.len();
+            for i in start..start + length {
+                if inner_validity.get_bit(i) {
+                    idx.extend(inner_offsets[i] as IdxSize..inner_offsets[i + 1] as IdxSize);
+                }
+            }
+            lengths.push(idx.len() - prev_len);
+        }
+
+        // SAFETY: the dtype of the leaf values matches the inner dtype of the inner lists.
+        let leaf = unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![leaf_values],
+                leaf_dtype,
+            )
+        };
+        let leaf = leaf.take_slice(&idx)?.rechunk();
+        let offsets: OffsetsBuffer<i64> = Offsets::try_from_lengths(lengths.into_iter())?.into();
+        (offsets, leaf.chunks()[0].clone())
+    } else {
+        let offsets = arr
+            .offsets()
+            .as_slice()
+            .iter()
+            .map(|&offset| inner_offsets[offset as usize])
+            .collect::<Vec<_>>();
+        // SAFETY: the inner offsets taken at increasing positions are increasing as well.
+        let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+        (offsets, leaf_values)
+    };
+
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.dtype().clone()),
+        offsets,
+        values,
+        arr.validity().cloned(),
+    );
+
+    // SAFETY: the values are the leaf values of the inner lists.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            ca.name().clone(),
+            vec![Box::new(arr)],
+            DataType::List(Box::new(leaf_dtype.clone())),
+        )
+    })
+}
+
+/// Remove `depth` levels of nesting from every list by concatenating the nested lists and
+/// arrays, or all levels if `depth` is `None`. Null nested lists are skipped.
+pub fn list_flatten(ca: &ListChunked, depth: Option<usize>) -> PolarsResult<ListChunked> {
+    let max_depth = nesting_depth(ca.inner_dtype());
+    let depth = depth.unwrap_or(max_depth);
+    polars_ensure!(
+        depth <= max_depth,
+        InvalidOperation: "cannot flatten {} levels of `{}`, it only has {} nested levels",
+        depth, ca.dtype(), max_depth
+    );
+
+    let mut out = ca.clone();
+    for _ in 0..depth {
+        out = flatten_once(&out)?;
+    }
+    Ok(out)
+}
+
+/// The output dtype of [`list_reshape`] for a list of `dtype`.
+#[cfg(feature = "dtype-array")]
+pub fn list_reshape_dtype(dtype: &DataType, dimensions: &[usize]) -> PolarsResult<DataType> {
+    polars_ensure!(
+        !dimensions.is_empty(),
+        InvalidOperation: "at least one dimension must be specified"
+    );
+    let leaf_dtype = list_flatten_dtype(dtype, None)?;
+    let mut dtype = leaf_dtype.inner_dtype().unwrap().clone();
+    for &dim in dimensions.iter().rev() {
+        dtype = DataType::Array(Box::new(dtype), dim);
+    }
+    Ok(dtype)
+}
+
+/// Reshape every list into a (nested) `Array` of the given `dimensions`.
+///
+/// Nested lists and arrays are flattened first, so every non-null list must contain exactly as
+/// many leaf values as the product of the `dimensions`.
+#[cfg(feature = "dtype-array")]
+pub fn list_reshape(ca: &ListChunked, dimensions: &[usize]) -> PolarsResult<Series> {
+    let dtype = list_reshape_dtype(ca.dtype(), dimensions)?;
+    let width = dimensions.iter().product::<usize>();
+
+    let flat = list_flatten(ca, None)?;
+    let flat = flat.rechunk();
+    let arr = flat.downcast_as_array();
+    for (i, length) in arr.offsets().lengths().enumerate() {
+        polars_ensure!(
+            length == width || !arr.is_valid(i),
+            InvalidOperation: "cannot reshape list of size {} into shape {}",
+            length, format_tuple!(dimensions)
+        );
+    }
+
+    let leaf = flat.get_inner();
+    let leaf = if arr.null_count() == 0 {
+        leaf.slice(*arr.offsets().first(), arr.len() * width)
+    } else {
+        // Null lists still take up `width` slots in the array.
+        let idx: IdxCa = arr
+            .offsets()
+            .offset_and_length_iter()
+            .enumerate()
+            .flat_map(|(i, (start, length))| {
+                let valid = arr.is_valid(i) && length == width;
+                (0..width).map(move |j| valid.then_some((start + j) as IdxSize))
+            })
+            .collect();
+        leaf.take(&idx)?
+    }
+    .rechunk();
+
+    let mut arrow_dtype = leaf.dtype().to_physical().to_arrow(CompatLevel::newest());
+    let mut values = leaf.chunks()[0].clone();
+    for (level, &dim) in dimensions.iter().enumerate().rev() {
+        let length = arr.len() * dimensions[..level].iter().product::<usize>();
+        let validity = if level == 0 {
+            arr.validity().cloned()
+        } else {
+            None
+        };
+        arrow_dtype = arrow_dtype.to_fixed_size_list(dim, true);
+        values = FixedSizeListArray::new(arrow_dtype.clone(), length, values, validity).boxed();
+    }
+
+    // SAFETY: the arrow dtype was built from the same dimensions as `dtype`.
+    Ok(unsafe { Series::from_chunks_and_dtype_unchecked(ca.name().clone(), vec![values], &dtype) })
+}
@@ -4,6 +4,8 @@ use polars_core::prelude::*;
 mod any_all;
 mod count;
 mod dispersion;
+#[cfg(feature = "list_flatten")]
+mod flatten;
 mod get;
 #[cfg(feature = "hash")]
 pub(crate) mod hash;
@@ -19,6 +21,8 @@ mod to_struct;
 pub use count::*;
 #[cfg(not(feature = "list_count"))]
 use count::*;
+#[cfg(feature = "list_flatten")]
+pub use flatten::*;
 pub use get::*;
 pub use namespace::*;
 #[cfg(feature = "list_sets")]
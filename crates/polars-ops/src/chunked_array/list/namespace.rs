@@ -2,6 +2,12 @@ use std::borrow::Cow;
 use std::fmt::Write;
 
 use arrow::array::ValueSize;
+#[cfg(any(feature = "list_pad", feature = "list_zip"))]
+use arrow::array::Array;
+#[cfg(any(feature = "list_pad", feature = "list_zip"))]
+use arrow::bitmap::BitmapBuilder;
+#[cfg(any(feature = "list_pad", feature = "list_zip"))]
+use arrow::offset::Offsets;
 #[cfg(feature = "list_gather")]
 use num_traits::ToPrimitive;
 #[cfg(feature = "list_gather")]
@@ -19,6 +25,8 @@ use crate::chunked_array::list::min_max::{list_max_function, list_min_function};
 use crate::chunked_array::list::sum_mean::sum_with_nulls;
 #[cfg(feature = "diff")]
 use crate::prelude::diff;
+#[cfg(feature = "list_index_of")]
+use crate::prelude::index_of;
 use crate::prelude::list::sum_mean::{mean_list_numerical, sum_list_numerical};
 use crate::series::ArgAgg;
 
@@ -685,6 +693,195 @@ pub trait ListNameSpaceImpl: AsList {
         out.map(|ok| self.same_type(ok))
     }
 
+    /// Get the index of the first occurrence of `needle` in every list, or null if it does not
+    /// occur. A null `needle` finds the first null element.
+    #[cfg(feature = "list_index_of")]
+    fn lst_index_of(&self, needle: &Series) -> PolarsResult<IdxCa> {
+        let ca = self.as_list();
+        let inner_dtype = ca.inner_dtype();
+        polars_ensure!(
+            !(inner_dtype.is_integer() && needle.dtype().is_float()),
+            InvalidOperation: "cannot cast lossless between {} and {}", inner_dtype, needle.dtype()
+        );
+        let needle = needle.strict_cast(inner_dtype)?;
+
+        polars_ensure!(
+            ca.len() == needle.len() || ca.len() == 1 || needle.len() == 1,
+            length_mismatch = "list.index_of",
+            ca.len(),
+            needle.len()
+        );
+
+        // Broadcast `self`
+        let mut ca = Cow::Borrowed(ca);
+        if ca.len() == 1 && needle.len() != 1 {
+            ca = Cow::Owned(ca.new_from_index(0, needle.len()));
+        }
+        let ca = ca.as_ref();
+
+        let find = |s: &Series, value: AnyValue| -> PolarsResult<Option<IdxSize>> {
+            let needle = Scalar::new(inner_dtype.clone(), value.into_static());
+            Ok(index_of(s, needle)?.map(|idx| idx as IdxSize))
+        };
+        let mut out: IdxCa = if needle.len() == 1 {
+            let value = needle.get(0)?;
+            ca.amortized_iter()
+                .map(|opt_s| opt_s.map_or(Ok(None), |s| find(s.as_ref(), value.clone())))
+                .collect::<PolarsResult<_>>()?
+        } else {
+            ca.amortized_iter()
+                .zip(needle.iter())
+                .map(|(opt_s, value)| opt_s.map_or(Ok(None), |s| find(s.as_ref(), value)))
+                .collect::<PolarsResult<_>>()?
+        };
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Pad every list at the end with `fill` until it has `length` elements. Lists that are
+    /// already long enough are left as they are.
+    #[cfg(feature = "list_pad")]
+    fn lst_pad_end(&self, length: &Series, fill: &Series) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let length_s = length.strict_cast(&IDX_DTYPE)?;
+        let length = length_s.idx()?;
+        let fill = fill.strict_cast(ca.inner_dtype())?;
+
+        let len = if ca.len() == 1 {
+            length.len().max(fill.len())
+        } else {
+            ca.len()
+        };
+        for arg_len in [ca.len(), length.len(), fill.len()] {
+            polars_ensure!(
+                arg_len == len || arg_len == 1,
+                length_mismatch = "list.pad_end",
+                len,
+                arg_len
+            );
+        }
+        if len == 0 {
+            return Ok(ca.clone());
+        }
+
+        // Broadcast `self`
+        let mut ca = Cow::Borrowed(ca);
+        if ca.len() != len {
+            ca = Cow::Owned(ca.new_from_index(0, len));
+        }
+        let ca = ca.rechunk();
+        let arr = ca.downcast_as_array();
+
+        // The fill values are appended to the inner values, so a single gather builds the output.
+        let mut values = ca.get_inner();
+        let fill_offset = values.len() as IdxSize;
+        values.append(&fill)?;
+
+        let lengths: Box<dyn Iterator<Item = Option<IdxSize>>> = if length.len() == 1 {
+            Box::new(std::iter::repeat_n(length.get(0), len))
+        } else {
+            Box::new(length.iter())
+        };
+
+        let mut idx = Vec::with_capacity(values.len());
+        let mut out_lengths = Vec::with_capacity(len);
+        let mut validity = BitmapBuilder::with_capacity(len);
+        for (i, target) in lengths.enumerate() {
+            let Some(target) = target.filter(|_| arr.is_valid(i)) else {
+                out_lengths.push(0);
+                validity.push(false);
+                continue;
+            };
+            let (start, end) = arr.offsets().start_end(i);
+            let fill_idx = fill_offset + if fill.len() == 1 { 0 } else { i as IdxSize };
+            let n_pad = (target as usize).saturating_sub(end - start);
+
+            idx.extend(start as IdxSize..end as IdxSize);
+            idx.extend(std::iter::repeat_n(fill_idx, n_pad));
+            out_lengths.push(end - start + n_pad);
+            validity.push(true);
+        }
+
+        let values = values.take_slice(&idx)?;
+        let out = list_from_values(ca.name().clone(), values, out_lengths, validity)?;
+        Ok(self.same_type(out))
+    }
+
+    /// Zip every list with the list at the same row of `other` into a list of structs with the
+    /// fields `field_0` and `field_1`. The output is as long as the shorter of both lists.
+    #[cfg(feature = "list_zip")]
+    fn lst_zip(&self, other: &ListChunked) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        polars_ensure!(
+            ca.len() == other.len() || ca.len() == 1 || other.len() == 1,
+            length_mismatch = "list.zip",
+            ca.len(),
+            other.len()
+        );
+        let len = if ca.len() == 1 { other.len() } else { ca.len() };
+
+        let field_names = [
+            PlSmallStr::from_static("field_0"),
+            PlSmallStr::from_static("field_1"),
+        ];
+        if len == 0 {
+            let dtype = DataType::Struct(vec![
+                Field::new(field_names[0].clone(), ca.inner_dtype().clone()),
+                Field::new(field_names[1].clone(), other.inner_dtype().clone()),
+            ]);
+            return Ok(ListChunked::full_null_with_dtype(
+                ca.name().clone(),
+                0,
+                &dtype,
+            ));
+        }
+
+        // Broadcast both sides
+        let broadcast = |ca: &ListChunked| {
+            if ca.len() == len {
+                ca.rechunk().into_owned()
+            } else {
+                ca.new_from_index(0, len)
+            }
+        };
+        let lhs = broadcast(ca);
+        let rhs = broadcast(other);
+        let lhs_arr = lhs.downcast_as_array();
+        let rhs_arr = rhs.downcast_as_array();
+
+        let mut lhs_idx = Vec::with_capacity(lhs_arr.values().len());
+        let mut rhs_idx = Vec::with_capacity(lhs_arr.values().len());
+        let mut out_lengths = Vec::with_capacity(len);
+        let mut validity = BitmapBuilder::with_capacity(len);
+        for i in 0..len {
+            if !(lhs_arr.is_valid(i) && rhs_arr.is_valid(i)) {
+                out_lengths.push(0);
+                validity.push(false);
+                continue;
+            }
+            let (lhs_start, lhs_end) = lhs_arr.offsets().start_end(i);
+            let (rhs_start, rhs_end) = rhs_arr.offsets().start_end(i);
+            let n = (lhs_end - lhs_start).min(rhs_end - rhs_start);
+
+            lhs_idx.extend((lhs_start..lhs_start + n).map(|i| i as IdxSize));
+            rhs_idx.extend((rhs_start..rhs_start + n).map(|i| i as IdxSize));
+            out_lengths.push(n);
+            validity.push(true);
+        }
+
+        let fields = [
+            lhs.get_inner()
+                .take_slice(&lhs_idx)?
+                .with_name(field_names[0].clone()),
+            rhs.get_inner()
+                .take_slice(&rhs_idx)?
+                .with_name(field_names[1].clone()),
+        ];
+        let values = StructChunked::from_series(ca.name().clone(), lhs_idx.len(), fields.iter())?
+            .into_series();
+        list_from_values(ca.name().clone(), values, out_lengths, validity)
+    }
+
     fn lst_concat(&self, other: &[Column]) -> PolarsResult<ListChunked> {
         let ca = self.as_list();
         let other_len = other.len();
@@ -830,6 +1027,33 @@ pub trait ListNameSpaceImpl: AsList {
 
 impl ListNameSpaceImpl for ListChunked {}
 
+/// Build a [`ListChunked`] from the flat `values`, split into lists of the given `lengths`.
+#[cfg(any(feature = "list_pad", feature = "list_zip"))]
+fn list_from_values(
+    name: PlSmallStr,
+    values: Series,
+    lengths: Vec<usize>,
+    validity: BitmapBuilder,
+) -> PolarsResult<ListChunked> {
+    let values = values.rechunk();
+    let offsets = Offsets::<i64>::try_from_lengths(lengths.into_iter())?;
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.chunks()[0].dtype().clone()),
+        offsets.into(),
+        values.chunks()[0].clone(),
+        validity.into_opt_validity(),
+    );
+
+    // SAFETY: the inner arrow dtype is derived from `values`.
+    Ok(unsafe {
+        ListChunked::from_chunks_and_dtype(
+            name,
+            vec![Box::new(arr)],
+            DataType::List(Box::new(values.dtype().clone())),
+        )
+    })
+}
+
 #[cfg(feature = "list_gather")]
 fn take_series(s: &Series, idx: Series, null_on_oob: bool) -> PolarsResult<Series> {
     let len = s.len();
@@ -0,0 +1,606 @@
+use std::fmt::{Display, Formatter, Write};
+
+use polars_core::prelude::*;
+
+/// Alignment of a formatted value within its field width.
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FormatAlign {
+    Left,
+    Right,
+    Center,
+    /// Pad between the sign (and base prefix) and the digits.
+    AfterSign,
+}
+
+/// A parsed `[[fill]align][sign][#][0][width][grouping][.precision][type]` specification, as in
+/// Python's format specification mini-language.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FormatSpec {
+    fill: Option<char>,
+    align: Option<FormatAlign>,
+    sign: Option<char>,
+    alternate: bool,
+    zero: bool,
+    width: usize,
+    grouping: Option<char>,
+    precision: Option<usize>,
+    ty: Option<char>,
+}
+
+/// A `{}` placeholder of a [`FormatTemplate`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FormatPlaceholder {
+    /// `{}`: the value cast to a string.
+    Plain,
+    /// `{:spec}` with a standard format specification.
+    Spec(FormatSpec),
+    /// `{:%Y-%m-%d}`: a strftime format for temporal values.
+    Strftime(PlSmallStr),
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FormatSegment {
+    Literal(PlSmallStr),
+    Placeholder(FormatPlaceholder),
+}
+
+/// A format template such as `"{:>8.2f} | {:08d}"`, parsed once and rendered for every row of
+/// its arguments.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FormatTemplate {
+    template: PlSmallStr,
+    segments: Vec<FormatSegment>,
+}
+
+impl Display for FormatTemplate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.template)
+    }
+}
+
+fn to_align(c: char) -> Option<FormatAlign> {
+    match c {
+        '<' => Some(FormatAlign::Left),
+        '>' => Some(FormatAlign::Right),
+        '^' => Some(FormatAlign::Center),
+        '=' => Some(FormatAlign::AfterSign),
+        _ => None,
+    }
+}
+
+fn take_number(chars: &[char], i: &mut usize) -> Result<Option<usize>, &'static str> {
+    let start = *i;
+    while chars.get(*i).is_some_and(|c| c.is_ascii_digit()) {
+        *i += 1;
+    }
+    if start == *i {
+        return Ok(None);
+    }
+    let digits: String = chars[start..*i].iter().collect();
+    digits.parse().map(Some).map_err(|_| "number too large")
+}
+
+fn parse_spec(spec: &str) -> Result<FormatSpec, &'static str> {
+    let chars: Vec<char> = spec.chars().collect();
+    let mut out = FormatSpec::default();
+    let mut i = 0;
+
+    if let Some(align) = chars.get(1).copied().and_then(to_align) {
+        out.fill = Some(chars[0]);
+        out.align = Some(align);
+        i = 2;
+    } else if let Some(align) = chars.first().copied().and_then(to_align) {
+        out.align = Some(align);
+        i = 1;
+    }
+    if let Some(&c @ ('+' | '-' | ' ')) = chars.get(i) {
+        out.sign = Some(c);
+        i += 1;
+    }
+    if chars.get(i) == Some(&'#') {
+        out.alternate = true;
+        i += 1;
+    }
+    if chars.get(i) == Some(&'0') {
+        out.zero = true;
+        i += 1;
+    }
+    out.width = take_number(&chars, &mut i)?.unwrap_or(0);
+    if let Some(&c @ (',' | '_')) = chars.get(i) {
+        out.grouping = Some(c);
+        i += 1;
+    }
+    if chars.get(i) == Some(&'.') {
+        i += 1;
+        out.precision = Some(take_number(&chars, &mut i)?.ok_or("missing precision")?);
+    }
+    if let Some(&c @ ('b' | 'd' | 'o' | 'x' | 'X' | 'e' | 'E' | 'f' | 'F' | '%' | 's')) =
+        chars.get(i)
+    {
+        out.ty = Some(c);
+        i += 1;
+    }
+    if i != chars.len() {
+        return Err("unexpected characters");
+    }
+
+    match out.ty {
+        Some('b' | 'd' | 'o' | 'x' | 'X') if out.precision.is_some() => {
+            Err("precision not allowed in integer format spec")
+        },
+        Some('b' | 'o' | 'x' | 'X' | 's') if out.grouping == Some(',') => {
+            Err("',' not allowed with this format type")
+        },
+        Some('s') if out.sign.is_some() => Err("sign not allowed in string format spec"),
+        _ => Ok(out),
+    }
+}
+
+fn parse_placeholder(field: &str) -> PolarsResult<FormatPlaceholder> {
+    if field.is_empty() {
+        return Ok(FormatPlaceholder::Plain);
+    }
+    let Some(spec) = field.strip_prefix(':') else {
+        polars_bail!(
+            InvalidOperation:
+            "invalid placeholder {{{field}}}: only `{{}}` and `{{:spec}}` placeholders are supported"
+        )
+    };
+    if spec.is_empty() {
+        return Ok(FormatPlaceholder::Plain);
+    }
+    match parse_spec(spec) {
+        Ok(spec) => Ok(FormatPlaceholder::Spec(spec)),
+        Err(_) if spec.contains('%') => Ok(FormatPlaceholder::Strftime(spec.into())),
+        Err(msg) => polars_bail!(InvalidOperation: "invalid format spec {spec:?}: {msg}"),
+    }
+}
+
+impl FormatTemplate {
+    /// Parses a template with `{}` and `{:spec}` placeholders, where `{{` and `}}` are literal
+    /// braces.
+    pub fn parse(template: &str) -> PolarsResult<Self> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '{' if chars.next_if(|(_, c)| *c == '{').is_some() => literal.push('{'),
+                '}' if chars.next_if(|(_, c)| *c == '}').is_some() => literal.push('}'),
+                '{' => {
+                    let Some(len) = template[start..].find('}') else {
+                        polars_bail!(InvalidOperation: "unclosed placeholder in format template {template:?}")
+                    };
+                    while chars.next_if(|(i, _)| *i < start + len).is_some() {}
+                    chars.next();
+                    if !literal.is_empty() {
+                        segments.push(FormatSegment::Literal(std::mem::take(&mut literal).into()));
+                    }
+                    let placeholder = parse_placeholder(&template[start + 1..start + len])?;
+                    segments.push(FormatSegment::Placeholder(placeholder));
+                },
+                '}' => {
+                    polars_bail!(InvalidOperation: "single '}}' encountered in format template {template:?}")
+                },
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(FormatSegment::Literal(literal.into()));
+        }
+
+        Ok(Self {
+            template: template.into(),
+            segments,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    pub fn segments(&self) -> &[FormatSegment] {
+        &self.segments
+    }
+
+    pub fn placeholders(&self) -> impl Iterator<Item = &FormatPlaceholder> {
+        self.segments.iter().filter_map(|s| match s {
+            FormatSegment::Placeholder(p) => Some(p),
+            FormatSegment::Literal(_) => None,
+        })
+    }
+
+    /// Whether all placeholders are plain `{}`, so that the template is a plain concatenation.
+    pub fn is_plain(&self) -> bool {
+        self.placeholders()
+            .all(|p| matches!(p, FormatPlaceholder::Plain))
+    }
+
+    /// Renders the template for every row of `args`, one argument per placeholder. Arguments of
+    /// length 1 are broadcast, and a null argument makes the row null.
+    ///
+    /// Strftime placeholders expect their argument to be formatted to strings already.
+    pub fn render(&self, args: &[Column]) -> PolarsResult<StringChunked> {
+        let placeholders: Vec<_> = self.placeholders().collect();
+        polars_ensure!(
+            args.len() == placeholders.len(),
+            ShapeMismatch: "number of placeholders should equal the number of arguments"
+        );
+        let len = args.iter().map(|c| c.len()).max().unwrap_or(1);
+        for c in args {
+            if c.len() != 1 && c.len() != len {
+                polars_bail!(length_mismatch = "format", c.len(), len);
+            }
+        }
+        let args = args
+            .iter()
+            .zip(placeholders)
+            .map(|(c, p)| FormatArg::new(c, p))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let name = args
+            .first()
+            .map_or(PlSmallStr::EMPTY, |arg| arg.name().clone());
+        let mut builder = StringChunkedBuilder::new(name, len);
+        let mut buf = String::new();
+        let mut scratch = String::new();
+        'rows: for i in 0..len {
+            buf.clear();
+            let mut args = args.iter();
+            for segment in &self.segments {
+                match segment {
+                    FormatSegment::Literal(s) => buf.push_str(s),
+                    FormatSegment::Placeholder(p) => {
+                        if !args.next().unwrap().write(i, p, &mut buf, &mut scratch) {
+                            builder.append_null();
+                            continue 'rows;
+                        }
+                    },
+                }
+            }
+            builder.append_value(&buf);
+        }
+        Ok(builder.finish())
+    }
+}
+
+enum FormatArg {
+    Str(StringChunked),
+    Signed(Int64Chunked),
+    Unsigned(UInt64Chunked),
+    Float(Float64Chunked),
+}
+
+impl FormatArg {
+    fn new(c: &Column, placeholder: &FormatPlaceholder) -> PolarsResult<Self> {
+        let dtype = c.dtype();
+        let spec = match placeholder {
+            FormatPlaceholder::Plain => return Self::cast(c, &DataType::String),
+            FormatPlaceholder::Strftime(fmt) => {
+                polars_ensure!(
+                    dtype.is_string(),
+                    InvalidOperation: "format spec {fmt:?} requires a temporal value, got {dtype}"
+                );
+                return Self::cast(c, &DataType::String);
+            },
+            FormatPlaceholder::Spec(spec) => spec,
+        };
+        let target = match spec.ty {
+            Some('b' | 'd' | 'o' | 'x' | 'X') => {
+                polars_ensure!(
+                    dtype.is_integer(),
+                    InvalidOperation: "format type '{}' requires an integer value, got {dtype}",
+                    spec.ty.unwrap()
+                );
+                None
+            },
+            Some('e' | 'E' | 'f' | 'F' | '%') => {
+                polars_ensure!(
+                    dtype.is_numeric(),
+                    InvalidOperation: "format type '{}' requires a numeric value, got {dtype}",
+                    spec.ty.unwrap()
+                );
+                Some(DataType::Float64)
+            },
+            None if dtype.is_integer() => {
+                polars_ensure!(
+                    spec.precision.is_none(),
+                    InvalidOperation: "precision not allowed when formatting integers"
+                );
+                None
+            },
+            None if dtype.is_float() => Some(DataType::Float64),
+            _ => {
+                polars_ensure!(
+                    spec.sign.is_none() && spec.align != Some(FormatAlign::AfterSign),
+                    InvalidOperation: "sign and '=' alignment not allowed when formatting {dtype}"
+                );
+                Some(DataType::String)
+            },
+        };
+        let target = target.unwrap_or(if dtype.is_unsigned_integer() {
+            DataType::UInt64
+        } else {
+            DataType::Int64
+        });
+        Self::cast(c, &target)
+    }
+
+    fn cast(c: &Column, dtype: &DataType) -> PolarsResult<Self> {
+        let s = c.as_materialized_series().strict_cast(dtype)?.rechunk();
+        Ok(match dtype {
+            DataType::String => Self::Str(s.str()?.clone()),
+            DataType::Int64 => Self::Signed(s.i64()?.clone()),
+            DataType::UInt64 => Self::Unsigned(s.u64()?.clone()),
+            _ => Self::Float(s.f64()?.clone()),
+        })
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        match self {
+            Self::Str(ca) => ca.name(),
+            Self::Signed(ca) => ca.name(),
+            Self::Unsigned(ca) => ca.name(),
+            Self::Float(ca) => ca.name(),
+        }
+    }
+
+    /// Writes the value at row `i` to `out`, returning `false` if it is null.
+    fn write(
+        &self,
+        i: usize,
+        placeholder: &FormatPlaceholder,
+        out: &mut String,
+        scratch: &mut String,
+    ) -> bool {
+        fn get<T: PolarsDataType>(ca: &ChunkedArray<T>, i: usize) -> Option<T::Physical<'_>> {
+            ca.get(if ca.len() == 1 { 0 } else { i })
+        }
+
+        let spec = match placeholder {
+            FormatPlaceholder::Spec(spec) => spec,
+            _ => {
+                let Self::Str(ca) = self else { unreachable!() };
+                return get(ca, i).map(|v| out.push_str(v)).is_some();
+            },
+        };
+        match self {
+            Self::Str(ca) => get(ca, i).map(|v| write_str(out, v, spec)).is_some(),
+            Self::Signed(ca) => get(ca, i)
+                .map(|v| write_int(out, v < 0, v.unsigned_abs(), spec, scratch))
+                .is_some(),
+            Self::Unsigned(ca) => get(ca, i)
+                .map(|v| write_int(out, false, v, spec, scratch))
+                .is_some(),
+            Self::Float(ca) => get(ca, i)
+                .map(|v| write_float(out, v, spec, scratch))
+                .is_some(),
+        }
+    }
+}
+
+fn pad(out: &mut String, sign: &str, body: &str, spec: &FormatSpec, default: FormatAlign) {
+    let fill = spec.fill.unwrap_or(if spec.zero { '0' } else { ' ' });
+    let align = spec.align.unwrap_or(if spec.zero {
+        FormatAlign::AfterSign
+    } else {
+        default
+    });
+    let n = spec
+        .width
+        .saturating_sub(sign.chars().count() + body.chars().count());
+    let fill_n = |out: &mut String, n: usize| out.extend(std::iter::repeat_n(fill, n));
+    match align {
+        FormatAlign::Left => {
+            out.push_str(sign);
+            out.push_str(body);
+            fill_n(out, n);
+        },
+        FormatAlign::Right => {
+            fill_n(out, n);
+            out.push_str(sign);
+            out.push_str(body);
+        },
+        FormatAlign::Center => {
+            fill_n(out, n / 2);
+            out.push_str(sign);
+            out.push_str(body);
+            fill_n(out, n - n / 2);
+        },
+        FormatAlign::AfterSign => {
+            out.push_str(sign);
+            fill_n(out, n);
+            out.push_str(body);
+        },
+    }
+}
+
+fn sign_str(negative: bool, spec: &FormatSpec) -> &'static str {
+    match (negative, spec.sign) {
+        (true, _) => "-",
+        (false, Some('+')) => "+",
+        (false, Some(' ')) => " ",
+        _ => "",
+    }
+}
+
+/// Inserts `sep` between every group of `size` digits of the leading digits of `s`.
+fn group_digits(s: &mut String, sep: char, size: usize) {
+    let n = s.bytes().take_while(|b| b.is_ascii_alphanumeric()).count();
+    let n = s[..n].find(['e', 'E']).unwrap_or(n);
+    let mut pos = n;
+    while pos > size {
+        pos -= size;
+        s.insert(pos, sep);
+    }
+}
+
+fn write_str(out: &mut String, v: &str, spec: &FormatSpec) {
+    let v = match spec.precision {
+        Some(p) => v.char_indices().nth(p).map_or(v, |(i, _)| &v[..i]),
+        None => v,
+    };
+    pad(out, "", v, spec, FormatAlign::Left);
+}
+
+fn write_int(out: &mut String, negative: bool, v: u64, spec: &FormatSpec, scratch: &mut String) {
+    scratch.clear();
+    let (prefix, group_size) = match spec.ty {
+        Some('b') => ("0b", 4),
+        Some('o') => ("0o", 4),
+        Some('x') => ("0x", 4),
+        Some('X') => ("0X", 4),
+        _ => ("", 3),
+    };
+    if spec.alternate {
+        scratch.push_str(prefix);
+    }
+    let start = scratch.len();
+    match spec.ty {
+        Some('b') => write!(scratch, "{v:b}"),
+        Some('o') => write!(scratch, "{v:o}"),
+        Some('x') => write!(scratch, "{v:x}"),
+        Some('X') => write!(scratch, "{v:X}"),
+        _ => write!(scratch, "{v}"),
+    }
+    .unwrap();
+    if let Some(sep) = spec.grouping {
+        let mut digits = scratch.split_off(start);
+        group_digits(&mut digits, sep, group_size);
+        scratch.push_str(&digits);
+    }
+    pad(
+        out,
+        sign_str(negative, spec),
+        scratch,
+        spec,
+        FormatAlign::Right,
+    );
+}
+
+fn write_float(out: &mut String, v: f64, spec: &FormatSpec, scratch: &mut String) {
+    scratch.clear();
+    let negative = v.is_sign_negative() && !v.is_nan();
+    let v = v.abs();
+    let precision = spec.precision.unwrap_or(6);
+    let ty = spec.ty.or(spec.precision.map(|_| 'f')).unwrap_or_default();
+    if !v.is_finite() {
+        let s = if v.is_nan() { "nan" } else { "inf" };
+        if matches!(ty, 'E' | 'F') {
+            scratch.push_str(&s.to_uppercase());
+        } else {
+            scratch.push_str(s);
+        }
+        if ty == '%' {
+            scratch.push('%');
+        }
+    } else {
+        match ty {
+            'f' | 'F' => write!(scratch, "{v:.precision$}").unwrap(),
+            '%' => write!(scratch, "{:.precision$}%", v * 100.0).unwrap(),
+            'e' | 'E' => {
+                let s = format!("{v:.precision$e}");
+                let (mantissa, exp) = s.split_once('e').unwrap();
+                let exp: i32 = exp.parse().unwrap();
+                let e = if ty == 'E' { 'E' } else { 'e' };
+                let exp_sign = if exp < 0 { '-' } else { '+' };
+                write!(scratch, "{mantissa}{e}{exp_sign}{:02}", exp.abs()).unwrap();
+            },
+            _ => write!(scratch, "{v:?}").unwrap(),
+        }
+        if let Some(sep) = spec.grouping {
+            group_digits(scratch, sep, 3);
+        }
+    }
+    pad(
+        out,
+        sign_str(negative, spec),
+        scratch,
+        spec,
+        FormatAlign::Right,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(template: &str, args: &[Column]) -> PolarsResult<Vec<Option<String>>> {
+        let ca = FormatTemplate::parse(template)?.render(args)?;
+        Ok(ca.into_iter().map(|v| v.map(String::from)).collect())
+    }
+
+    #[test]
+    fn test_format_numbers() -> PolarsResult<()> {
+        let floats = Column::new("a".into(), [Some(3.14999), Some(-2.5), None]);
+        let ints = Column::new("b".into(), [Some(42i32), Some(-7), Some(1)]);
+        let out = render("{:>8.2f} | {:08d}", &[floats, ints])?;
+        assert_eq!(
+            out,
+            [
+                Some("    3.15 | 00000042".into()),
+                Some("   -2.50 | -0000007".into()),
+                None
+            ]
+        );
+
+        let ints = Column::new("a".into(), [1234567i64, -255]);
+        let out = render(
+            "{:,}|{:#x}|{:+^9}|{:_b}",
+            &[ints.clone(), ints.clone(), ints.clone(), ints],
+        )?;
+        assert_eq!(
+            out,
+            [
+                Some("1,234,567|0x12d687|+1234567+|1_0010_1101_0110_1000_0111".into()),
+                Some("-255|-0xff|++-255+++|-1111_1111".into())
+            ]
+        );
+
+        let floats = Column::new("a".into(), [0.25f64, 12345.678, f64::NAN]);
+        let out = render(
+            "{:.1%} {:.2e} {:,.1f}",
+            &[floats.clone(), floats.clone(), floats],
+        )?;
+        assert_eq!(
+            out,
+            [
+                Some("25.0% 2.50e-01 0.2".into()),
+                Some("1234567.8% 1.23e+04 12,345.7".into()),
+                Some("nan% nan nan".into())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_strings() -> PolarsResult<()> {
+        let s = Column::new("s".into(), ["abc", "abcdef"]);
+        let out = render("[{:*^7.4}] {{{}}}", &[s.clone(), s])?;
+        assert_eq!(
+            out,
+            [
+                Some("[**abc**] {abc}".into()),
+                Some("[*abcd**] {abcdef}".into())
+            ]
+        );
+
+        assert!(FormatTemplate::parse("{:%Y-%m-%d}").is_ok());
+        assert!(FormatTemplate::parse("{:8.2q}").is_err());
+        assert!(FormatTemplate::parse("{0}").is_err());
+        assert!(FormatTemplate::parse("{").is_err());
+        assert!(FormatTemplate::parse("}").is_err());
+        assert!(render("{:d}", &[Column::new("a".into(), [1.5f64])]).is_err());
+        Ok(())
+    }
+}
@@ -538,6 +538,32 @@ pub trait StringNameSpaceImpl: AsString {
         split_helper(ca, by, str::split_inclusive)
     }
 
+    /// Split on the matches of a regex pattern.
+    fn split_regex(&self, pat: &StringChunked, inclusive: bool) -> PolarsResult<ListChunked> {
+        let ca = self.as_string();
+        split_regex_helper(ca, pat, inclusive)
+    }
+
+    /// Split exactly `n` times on the matches of a regex pattern.
+    #[cfg(feature = "dtype-struct")]
+    fn split_exact_regex(
+        &self,
+        pat: &StringChunked,
+        n: usize,
+        inclusive: bool,
+    ) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        split_regex_to_struct(ca, pat, n + 1, inclusive, false)
+    }
+
+    /// Split on the matches of a regex pattern into at most `n` substrings,
+    /// keeping the remainder of the string intact.
+    #[cfg(feature = "dtype-struct")]
+    fn splitn_regex(&self, pat: &StringChunked, n: usize) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        split_regex_to_struct(ca, pat, n, false, true)
+    }
+
     /// Extract each successive non-overlapping regex match in an individual string as an array.
     fn extract_all_many(&self, pat: &StringChunked) -> PolarsResult<ListChunked> {
         let ca = self.as_string();
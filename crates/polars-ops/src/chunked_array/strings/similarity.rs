@@ -0,0 +1,329 @@
+use std::hash::Hash;
+
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+use polars_utils::aliases::{PlHashMap, PlHashSet};
+
+/// Runs `$body` with `$a` and `$b` bound to the code units of both strings:
+/// their bytes if both are ASCII, their chars (decoded into `$bufs`) otherwise.
+macro_rules! with_units {
+    ($lhs:expr, $rhs:expr, $bufs:expr, |$a:ident, $b:ident| $body:expr) => {{
+        if $lhs.is_ascii() && $rhs.is_ascii() {
+            let ($a, $b) = ($lhs.as_bytes(), $rhs.as_bytes());
+            $body
+        } else {
+            let (buf_a, buf_b) = &mut *$bufs;
+            buf_a.clear();
+            buf_a.extend($lhs.chars());
+            buf_b.clear();
+            buf_b.extend($rhs.chars());
+            let ($a, $b) = (buf_a.as_slice(), buf_b.as_slice());
+            $body
+        }
+    }};
+}
+
+type CharBuffers = (Vec<char>, Vec<char>);
+
+fn strip_common_affixes<'a, T: PartialEq>(a: &'a [T], b: &'a [T]) -> (&'a [T], &'a [T]) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    (&a[..a.len() - suffix], &b[..b.len() - suffix])
+}
+
+fn levenshtein_units<T: PartialEq>(a: &[T], b: &[T], row: &mut Vec<u32>) -> u32 {
+    let (a, b) = strip_common_affixes(a, b);
+    if a.is_empty() || b.is_empty() {
+        return (a.len() + b.len()) as u32;
+    }
+
+    row.clear();
+    row.extend(0..=b.len() as u32);
+    for (i, x) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i as u32 + 1;
+        for (j, y) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diag
+            } else {
+                1 + diag.min(above).min(row[j])
+            };
+            diag = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Unrestricted Damerau-Levenshtein distance (Lowrance-Wagner), which allows
+/// edits between transposed units, unlike the optimal string alignment
+/// distance.
+fn damerau_levenshtein_units<T: Eq + Hash + Copy>(
+    a: &[T],
+    b: &[T],
+    matrix: &mut Vec<u32>,
+    last_row: &mut PlHashMap<T, usize>,
+) -> u32 {
+    let (n, m) = (a.len(), b.len());
+    if n == 0 || m == 0 {
+        return (n + m) as u32;
+    }
+
+    // The matrix has an extra leading row and column holding `max`, so that
+    // transpositions without an earlier match never win.
+    let max = (n + m) as u32;
+    let w = m + 2;
+    matrix.clear();
+    matrix.resize((n + 2) * w, 0);
+    for j in 0..w {
+        matrix[j] = max;
+        matrix[w + j] = j.saturating_sub(1) as u32;
+    }
+    matrix[w] = max;
+    for i in 1..=n {
+        matrix[(i + 1) * w] = max;
+        matrix[(i + 1) * w + 1] = i as u32;
+    }
+
+    last_row.clear();
+    for i in 1..=n {
+        let mut last_match_col = 0;
+        for j in 1..=m {
+            let i1 = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let j1 = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            let substitution = matrix[i * w + j] + cost;
+            let insertion = matrix[(i + 1) * w + j] + 1;
+            let deletion = matrix[i * w + j + 1] + 1;
+            let transposition = matrix[i1 * w + j1] + (i - i1 - 1) as u32 + 1 + (j - j1 - 1) as u32;
+            matrix[(i + 1) * w + j + 1] =
+                substitution.min(insertion).min(deletion).min(transposition);
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    matrix[(n + 1) * w + m + 1]
+}
+
+fn jaro_winkler_units<T: PartialEq>(a: &[T], b: &[T], flags: &mut Vec<bool>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    flags.clear();
+    flags.resize(a.len() + b.len(), false);
+    let (a_flags, b_flags) = flags.split_at_mut(a.len());
+
+    let mut matches = 0usize;
+    for (i, x) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_flags[j] && *x == b[j] {
+                a_flags[i] = true;
+                b_flags[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0usize;
+    let mut j = 0;
+    for (x, _) in a.iter().zip(a_flags.iter()).filter(|(_, f)| **f) {
+        while !b_flags[j] {
+            j += 1;
+        }
+        if *x != b[j] {
+            transpositions += 1;
+        }
+        j += 1;
+    }
+
+    let m = matches as f64;
+    let jaro =
+        (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64 / 2.0) / m) / 3.0;
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn hamming_units<T: PartialEq>(a: &[T], b: &[T]) -> Option<u32> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count() as u32)
+}
+
+fn ngrams<T: Eq + Hash>(s: &[T], n: usize) -> PlHashSet<&[T]> {
+    if s.len() < n {
+        (!s.is_empty()).then_some(s).into_iter().collect()
+    } else {
+        s.windows(n).collect()
+    }
+}
+
+fn jaccard_units<T: Eq + Hash>(a: &[T], b: &[T], n: usize) -> f64 {
+    let (a, b) = (ngrams(a, n), ngrams(b, n));
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = b.iter().filter(|gram| a.contains(*gram)).count();
+    let union = a.len() + b.len() - intersection;
+    intersection as f64 / union as f64
+}
+
+/// Levenshtein distance between the strings of `ca` and `other`, counted in
+/// characters.
+pub fn levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut bufs = CharBuffers::default();
+    let mut row = Vec::new();
+    broadcast_binary_elementwise(ca, other, |lhs: Option<&str>, rhs: Option<&str>| {
+        let (lhs, rhs) = (lhs?, rhs?);
+        Some(with_units!(lhs, rhs, &mut bufs, |a, b| levenshtein_units(
+            a, b, &mut row
+        )))
+    })
+}
+
+/// Damerau-Levenshtein distance between the strings of `ca` and `other`,
+/// counted in characters, where a transposition of two characters counts as a
+/// single edit.
+pub fn damerau_levenshtein(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut bufs = CharBuffers::default();
+    let mut matrix = Vec::new();
+    let mut last_row_bytes = PlHashMap::default();
+    let mut last_row_chars = PlHashMap::default();
+    broadcast_binary_elementwise(ca, other, |lhs: Option<&str>, rhs: Option<&str>| {
+        let (lhs, rhs) = (lhs?, rhs?);
+        if lhs.is_ascii() && rhs.is_ascii() {
+            Some(damerau_levenshtein_units(
+                lhs.as_bytes(),
+                rhs.as_bytes(),
+                &mut matrix,
+                &mut last_row_bytes,
+            ))
+        } else {
+            let (buf_a, buf_b) = &mut bufs;
+            buf_a.clear();
+            buf_a.extend(lhs.chars());
+            buf_b.clear();
+            buf_b.extend(rhs.chars());
+            Some(damerau_levenshtein_units(
+                buf_a,
+                buf_b,
+                &mut matrix,
+                &mut last_row_chars,
+            ))
+        }
+    })
+}
+
+/// Jaro-Winkler similarity between the strings of `ca` and `other`, in
+/// `[0, 1]` with 1 meaning equal.
+pub fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let mut bufs = CharBuffers::default();
+    let mut flags = Vec::new();
+    broadcast_binary_elementwise(ca, other, |lhs: Option<&str>, rhs: Option<&str>| {
+        let (lhs, rhs) = (lhs?, rhs?);
+        Some(with_units!(lhs, rhs, &mut bufs, |a, b| jaro_winkler_units(
+            a, b, &mut flags
+        )))
+    })
+}
+
+/// Hamming distance between the strings of `ca` and `other`, counted in
+/// characters. Strings of different lengths have no Hamming distance and
+/// yield null.
+pub fn hamming(ca: &StringChunked, other: &StringChunked) -> UInt32Chunked {
+    let mut bufs = CharBuffers::default();
+    broadcast_binary_elementwise(ca, other, |lhs: Option<&str>, rhs: Option<&str>| {
+        let (lhs, rhs) = (lhs?, rhs?);
+        with_units!(lhs, rhs, &mut bufs, |a, b| hamming_units(a, b))
+    })
+}
+
+/// Jaccard similarity between the sets of character `ngram`s of the strings of
+/// `ca` and `other`. A non-empty string shorter than `ngram` is its own single
+/// n-gram.
+pub fn jaccard(
+    ca: &StringChunked,
+    other: &StringChunked,
+    ngram: usize,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(ngram > 0, InvalidOperation: "jaccard 'ngram' must be positive");
+    let mut bufs = CharBuffers::default();
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |lhs: Option<&str>, rhs: Option<&str>| {
+            let (lhs, rhs) = (lhs?, rhs?);
+            Some(with_units!(lhs, rhs, &mut bufs, |a, b| jaccard_units(
+                a, b, ngram
+            )))
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_edit_distances() {
+        let mut row = Vec::new();
+        let mut matrix = Vec::new();
+        let mut last_row = PlHashMap::default();
+        for (a, b, lev, dl) in [
+            ("kitten", "sitting", 3, 3),
+            ("", "abc", 3, 3),
+            ("abc", "abc", 0, 0),
+            ("ca", "abc", 3, 2),
+            ("abcdef", "badcfe", 4, 3),
+            ("héllo", "hlélo", 2, 1),
+        ] {
+            let (a, b) = (chars(a), chars(b));
+            assert_eq!(levenshtein_units(&a, &b, &mut row), lev);
+            assert_eq!(
+                damerau_levenshtein_units(&a, &b, &mut matrix, &mut last_row),
+                dl
+            );
+        }
+    }
+
+    #[test]
+    fn test_similarities() {
+        let mut flags = Vec::new();
+        let jw = jaro_winkler_units(b"MARTHA", b"MARHTA", &mut flags);
+        assert!((jw - 0.9611111).abs() < 1e-6);
+        let jw = jaro_winkler_units(b"DIXON", b"DICKSONX", &mut flags);
+        assert!((jw - 0.8133333).abs() < 1e-6);
+        assert_eq!(jaro_winkler_units(b"", b"", &mut flags), 1.0);
+        assert_eq!(jaro_winkler_units(b"abc", b"", &mut flags), 0.0);
+
+        assert_eq!(hamming_units(b"karolin", b"kathrin"), Some(3));
+        assert_eq!(hamming_units(b"abc", b"ab"), None);
+
+        assert_eq!(jaccard_units(b"night", b"nacht", 2), 1.0 / 7.0);
+        assert_eq!(jaccard_units(b"ab", b"ab", 3), 1.0);
+        assert_eq!(jaccard_units(b"", b"", 2), 1.0);
+        assert_eq!(jaccard_units(b"", b"ab", 2), 0.0);
+    }
+}
@@ -1,7 +1,10 @@
 use arrow::array::ValueSize;
 #[cfg(feature = "dtype-struct")]
 use arrow::array::{MutableArray, MutableUtf8Array};
+use either::Either;
 use polars_core::chunked_array::ops::arity::binary_elementwise_for_each;
+use polars_utils::regex_cache::{compile_regex, with_regex_cache};
+use regex::Regex;
 
 use super::*;
 
@@ -223,3 +226,143 @@ where
         _ => polars_bail!(length_mismatch = "str.split", ca.len(), by.len()),
     })
 }
+
+/// Splits a string on the matches of a regex, keeping each match at the end of
+/// the substring it terminates, like [`str::split_inclusive`].
+struct SplitInclusiveRegex<'r, 'h> {
+    matches: regex::Matches<'r, 'h>,
+    s: &'h str,
+    last: usize,
+}
+
+impl<'h> Iterator for SplitInclusiveRegex<'_, 'h> {
+    type Item = &'h str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(m) = self.matches.next() {
+            let piece = &self.s[self.last..m.end()];
+            self.last = m.end();
+            Some(piece)
+        } else if self.last < self.s.len() {
+            let piece = &self.s[self.last..];
+            self.last = self.s.len();
+            Some(piece)
+        } else {
+            None
+        }
+    }
+}
+
+fn split_inclusive_regex<'r, 'h>(re: &'r Regex, s: &'h str) -> SplitInclusiveRegex<'r, 'h> {
+    SplitInclusiveRegex {
+        matches: re.find_iter(s),
+        s,
+        last: 0,
+    }
+}
+
+/// Calls `f` with every string of `ca` and the compiled regex of the matching
+/// pattern in `pat`, or with `None` if either is null. Unit-length inputs are
+/// broadcast.
+fn regex_for_each<'a>(
+    ca: &'a StringChunked,
+    pat: &'a StringChunked,
+    fn_name: &str,
+    mut f: impl FnMut(Option<(&'a str, &Regex)>),
+) -> PolarsResult<()> {
+    match (ca.len(), pat.len()) {
+        (_, 1) => match pat.get(0) {
+            Some(pat) => {
+                let re = compile_regex(pat)?;
+                ca.for_each(|opt_s| f(opt_s.map(|s| (s, &re))));
+            },
+            None => (0..ca.len()).for_each(|_| f(None)),
+        },
+        (a, b) if a == b || a == 1 => with_regex_cache(|re_cache| {
+            let lhs = if a == 1 {
+                Either::Left(std::iter::repeat_n(ca.get(0), b))
+            } else {
+                Either::Right(ca.iter())
+            };
+            for (opt_s, opt_pat) in lhs.zip(pat.iter()) {
+                match (opt_s, opt_pat) {
+                    (Some(s), Some(pat)) => f(Some((s, re_cache.compile(pat)?))),
+                    _ => f(None),
+                }
+            }
+            PolarsResult::Ok(())
+        })?,
+        (a, b) => polars_bail!(length_mismatch = fn_name, a, b),
+    }
+    Ok(())
+}
+
+/// Splits the strings of `ca` on the matches of the regex patterns in `pat`,
+/// optionally keeping the matched separators.
+pub fn split_regex_helper(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    inclusive: bool,
+) -> PolarsResult<ListChunked> {
+    let len = if ca.len() == 1 { pat.len() } else { ca.len() };
+    let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), len, ca.get_values_size());
+    regex_for_each(ca, pat, "str.split", |opt| match opt {
+        Some((s, re)) if inclusive => builder.append_values_iter(split_inclusive_regex(re, s)),
+        Some((s, re)) => builder.append_values_iter(re.split(s)),
+        None => builder.append_null(),
+    })?;
+    Ok(builder.finish())
+}
+
+/// Splits the strings of `ca` on the matches of the regex patterns in `pat`
+/// into a struct of `n` fields, padding with nulls. If `keep_remainder` is
+/// set, the last field holds the rest of the string, otherwise any further
+/// substrings are dropped.
+#[cfg(feature = "dtype-struct")]
+pub fn split_regex_to_struct(
+    ca: &StringChunked,
+    pat: &StringChunked,
+    n: usize,
+    inclusive: bool,
+    keep_remainder: bool,
+) -> PolarsResult<StructChunked> {
+    use polars_utils::format_pl_smallstr;
+
+    fn push_row<'b>(arrs: &mut [MutableUtf8Array<i64>], pieces: impl Iterator<Item = &'b str>) {
+        let mut arr_iter = arrs.iter_mut();
+        pieces
+            .zip(&mut arr_iter)
+            .for_each(|(splitted, arr)| arr.push(Some(splitted)));
+        // fill the remaining with null
+        for arr in arr_iter {
+            arr.push_null()
+        }
+    }
+
+    let len = if ca.len() == 1 { pat.len() } else { ca.len() };
+    let mut arrs = (0..n)
+        .map(|_| MutableUtf8Array::<i64>::with_capacity(len))
+        .collect::<Vec<_>>();
+
+    let fn_name = if keep_remainder {
+        "str.splitn"
+    } else {
+        "str.split_exact"
+    };
+    regex_for_each(ca, pat, fn_name, |opt| match opt {
+        Some((s, re)) if keep_remainder => push_row(&mut arrs, re.splitn(s, n)),
+        Some((s, re)) if inclusive => push_row(&mut arrs, split_inclusive_regex(re, s)),
+        Some((s, re)) => push_row(&mut arrs, re.split(s)),
+        None => push_row(&mut arrs, std::iter::empty()),
+    })?;
+
+    let fields = arrs
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut arr)| {
+            Series::try_from((format_pl_smallstr!("field_{i}"), arr.as_box())).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    StructChunked::from_series(ca.name().clone(), len, fields.iter())
+}
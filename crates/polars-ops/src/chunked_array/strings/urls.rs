@@ -0,0 +1,161 @@
+use std::fmt::Write;
+use std::sync::LazyLock;
+
+use arrow::bitmap::BitmapBuilder;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+use url::{ParseError, Url, form_urlencoded};
+
+/// Characters left as-is by [`percent_encode`]: the unreserved characters of
+/// RFC 3986.
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Base against which absolute paths (e.g. `/a/b?c=d`) are resolved, so that
+/// request paths from logs can be parsed as well.
+static RELATIVE_BASE: LazyLock<Url> = LazyLock::new(|| Url::parse("http://localhost/").unwrap());
+
+/// The fields of the struct returned by [`parse_url`].
+pub fn url_fields() -> Vec<Field> {
+    vec![
+        Field::new(PlSmallStr::from_static("scheme"), DataType::String),
+        Field::new(PlSmallStr::from_static("host"), DataType::String),
+        Field::new(PlSmallStr::from_static("port"), DataType::UInt16),
+        Field::new(PlSmallStr::from_static("path"), DataType::String),
+        Field::new(PlSmallStr::from_static("query"), DataType::String),
+        Field::new(PlSmallStr::from_static("fragment"), DataType::String),
+    ]
+}
+
+/// Parses `s` as an absolute URL, or as a path relative to an unknown host if
+/// it starts with `/`. Returns whether the scheme and authority are known.
+fn parse(s: &str) -> Result<(Url, bool, bool), ParseError> {
+    match Url::parse(s) {
+        Ok(url) => Ok((url, true, true)),
+        Err(ParseError::RelativeUrlWithoutBase) if s.starts_with('/') => {
+            let has_authority = s.starts_with("//");
+            Ok((RELATIVE_BASE.join(s)?, false, has_authority))
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// Splits URLs into a struct of their scheme, host, (explicit) port, path,
+/// query and fragment, normalized as per the WHATWG URL Standard. Strings
+/// starting with `/` are parsed as paths without a scheme. Invalid URLs raise
+/// an error if `strict`, and are null otherwise.
+pub fn parse_url(ca: &StringChunked, strict: bool) -> PolarsResult<StructChunked> {
+    let len = ca.len();
+    let mut scheme = StringChunkedBuilder::new(PlSmallStr::from_static("scheme"), len);
+    let mut host = StringChunkedBuilder::new(PlSmallStr::from_static("host"), len);
+    let mut port = PrimitiveChunkedBuilder::<UInt16Type>::new(PlSmallStr::from_static("port"), len);
+    let mut path = StringChunkedBuilder::new(PlSmallStr::from_static("path"), len);
+    let mut query = StringChunkedBuilder::new(PlSmallStr::from_static("query"), len);
+    let mut fragment = StringChunkedBuilder::new(PlSmallStr::from_static("fragment"), len);
+    let mut validity = BitmapBuilder::with_capacity(len);
+
+    for opt_s in ca.iter() {
+        let parsed = match opt_s.map(parse) {
+            Some(Ok(parsed)) => Some(parsed),
+            Some(Err(e)) if strict => polars_bail!(
+                ComputeError:
+                "invalid URL {:?}: {}; try setting `strict=false` to ignore",
+                opt_s.unwrap(), e
+            ),
+            _ => None,
+        };
+        validity.push(parsed.is_some());
+        match parsed {
+            Some((url, has_scheme, has_authority)) => {
+                scheme.append_option(has_scheme.then(|| url.scheme()));
+                host.append_option(url.host_str().filter(|_| has_authority));
+                port.append_option(url.port().filter(|_| has_authority));
+                path.append_value(url.path());
+                query.append_option(url.query());
+                fragment.append_option(url.fragment());
+            },
+            None => {
+                scheme.append_null();
+                host.append_null();
+                port.append_null();
+                path.append_null();
+                query.append_null();
+                fragment.append_null();
+            },
+        }
+    }
+
+    let fields = [
+        scheme.finish().into_series(),
+        host.finish().into_series(),
+        port.finish().into_series(),
+        path.finish().into_series(),
+        query.finish().into_series(),
+        fragment.finish().into_series(),
+    ];
+    let out = StructChunked::from_series(ca.name().clone(), len, fields.iter())?;
+    Ok(out.with_outer_validity(validity.into_opt_validity()))
+}
+
+/// Returns the query string of `s`: everything between the first `?` and the
+/// following `#`. A `?` in the fragment does not start a query.
+fn query_of(s: &str) -> Option<&str> {
+    let s = s.split_once('#').map_or(s, |(s, _)| s);
+    s.split_once('?').map(|(_, query)| query)
+}
+
+/// Extracts the (decoded) value of the first query parameter called `name`
+/// from each URL, or null if it has no such parameter.
+pub fn url_query_param(ca: &StringChunked, name: &StringChunked) -> StringChunked {
+    broadcast_binary_elementwise(ca, name, |s: Option<&str>, name: Option<&str>| {
+        let (s, name) = (s?, name?);
+        form_urlencoded::parse(query_of(s)?.as_bytes())
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    })
+}
+
+/// Percent-encodes all bytes of each string except for the unreserved
+/// characters `A-Z a-z 0-9 - . _ ~`.
+pub fn percent_encode(ca: &StringChunked) -> StringChunked {
+    ca.apply_into_string_amortized(|s, buf| {
+        write!(buf, "{}", utf8_percent_encode(s, COMPONENT)).unwrap()
+    })
+}
+
+/// Returns whether every `%` in `s` starts a valid `%XX` escape.
+fn has_valid_escapes(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.iter().enumerate().all(|(i, &b)| {
+        b != b'%'
+            || bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+    })
+}
+
+/// Decodes percent-encoded strings into their raw bytes, like the `hex` and
+/// `base64` decoders. Strings with malformed escapes (a `%` not followed by two
+/// hex digits) raise an error if `strict`, and are null otherwise.
+pub fn percent_decode(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let decode = |s: &str| has_valid_escapes(s).then(|| percent_decode_str(s).collect::<Vec<u8>>());
+    if strict {
+        ca.try_apply_nonnull_values_generic(|s| {
+            decode(s).ok_or_else(|| {
+                polars_err!(
+                    ComputeError:
+                    "invalid `percent` encoding found; try setting `strict=false` to ignore"
+                )
+            })
+        })
+    } else {
+        Ok(BinaryChunked::from_iter_options(
+            ca.name().clone(),
+            ca.iter().map(|opt_s| opt_s.and_then(decode)),
+        ))
+    }
+}
@@ -339,7 +339,9 @@ pub(super) fn iejoin_par(
     unsafe { materialize_join(left, right, &left_idx, &right_idx, suffix) }
 }
 
-pub(super) fn iejoin(
+/// Single-threaded inequality join of `left` and `right` on the given key
+/// columns, for callers that provide their own parallelism.
+pub fn iejoin(
     left: &DataFrame,
     right: &DataFrame,
     selected_left: Vec<Series>,
@@ -1,6 +1,6 @@
 use arrow::legacy::utils::{CustomIterTools, FromTrustedLenIterator};
 use polars_core::prelude::*;
-use polars_core::{with_match_categorical_physical_type, with_match_physical_numeric_polars_type};
+use polars_core::with_match_physical_numeric_polars_type;
 
 pub fn _merge_sorted_dfs(
     left: &DataFrame,
@@ -135,8 +135,9 @@ where
 }
 
 fn series_to_merge_indicator(lhs: &Series, rhs: &Series) -> PolarsResult<Vec<bool>> {
+    #[cfg(feature = "dtype-categorical")]
     if let Ok(cat_phys) = lhs.dtype().cat_physical() {
-        with_match_categorical_physical_type!(cat_phys, |$C| {
+        polars_core::with_match_categorical_physical_type!(cat_phys, |$C| {
             let lhs = lhs.cat::<$C>().unwrap();
             let rhs = rhs.cat::<$C>().unwrap();
             return Ok(get_merge_indicator(lhs.iter_str(), rhs.iter_str()));
@@ -1,3 +1,5 @@
+use std::sync::{Arc, LazyLock, RwLock};
+
 #[cfg(feature = "dtype-date")]
 use chrono::DateTime;
 use polars_core::prelude::arity::{binary_elementwise_values, try_binary_elementwise};
@@ -389,6 +391,134 @@ pub fn is_business_day(
     Ok(out.into_series())
 }
 
+/// A week mask together with a list of holidays, which determine the business days.
+///
+/// Calendars can be registered under a name with [`register_business_calendar`], after which
+/// business-day durations such as `"3bd[nyse]"` can refer to them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    week_mask: [bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: Vec<i32>, // Sorted and deduplicated, only contains business days of `week_mask`.
+}
+
+impl Default for BusinessCalendar {
+    /// Monday to Friday, without holidays.
+    fn default() -> Self {
+        Self {
+            week_mask: [true, true, true, true, true, false, false],
+            n_business_days_in_week_mask: 5,
+            holidays: vec![],
+        }
+    }
+}
+
+impl BusinessCalendar {
+    /// Create a new calendar.
+    ///
+    /// # Arguments
+    /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
+    /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
+    ///   days since the UNIX epoch.
+    pub fn try_new(week_mask: [bool; 7], holidays: &[i32]) -> PolarsResult<Self> {
+        if !week_mask.iter().any(|&x| x) {
+            polars_bail!(ComputeError:"`week_mask` must have at least one business day");
+        }
+        Ok(Self {
+            week_mask,
+            n_business_days_in_week_mask: week_mask.iter().filter(|&x| *x).count() as i32,
+            holidays: normalise_holidays(holidays, &week_mask),
+        })
+    }
+
+    pub fn week_mask(&self) -> [bool; 7] {
+        self.week_mask
+    }
+
+    /// The holidays that fall on a day of the week mask, sorted.
+    pub fn holidays(&self) -> &[i32] {
+        &self.holidays
+    }
+
+    pub fn is_business_day(&self, date: i32) -> bool {
+        self.week_mask[get_day_of_week(date)] && self.holidays.binary_search(&date).is_err()
+    }
+
+    /// Roll `date` forward to the first business day on or after it.
+    pub fn roll_forward(&self, date: i32) -> i32 {
+        self.roll(date, Roll::Forward).0
+    }
+
+    /// Roll `date` backward to the last business day on or before it.
+    pub fn roll_backward(&self, date: i32) -> i32 {
+        self.roll(date, Roll::Backward).0
+    }
+
+    fn roll(&self, date: i32, roll: Roll) -> (i32, usize) {
+        debug_assert!(roll != Roll::Raise);
+        roll_start_date(date, roll, &self.week_mask, &self.holidays)
+            .expect("only `Roll::Raise` can fail")
+    }
+
+    /// Add `n` business days to `date`.
+    ///
+    /// A `date` that isn't a business day first moves to the adjacent business day against the
+    /// direction of `n`, so that adding one business day to a Saturday results in the next Monday
+    /// and subtracting one results in the previous Friday.
+    pub fn add_business_days(&self, date: i32, n: i32) -> i32 {
+        if n == 0 {
+            return date;
+        }
+        let roll = if n > 0 { Roll::Backward } else { Roll::Forward };
+        let (date, day_of_week) = self.roll(date, roll);
+        add_business_days_impl(
+            date,
+            day_of_week,
+            n,
+            &self.week_mask,
+            self.n_business_days_in_week_mask,
+            &self.holidays,
+        )
+    }
+
+    /// Count the number of business days between `start` and `end`, excluding `end`.
+    pub fn business_day_count(&self, start: i32, end: i32) -> i32 {
+        business_day_count_impl(
+            start,
+            end,
+            &self.week_mask,
+            self.n_business_days_in_week_mask,
+            &self.holidays,
+        )
+    }
+}
+
+static BUSINESS_CALENDARS: LazyLock<RwLock<PlHashMap<PlSmallStr, Arc<BusinessCalendar>>>> =
+    LazyLock::new(Default::default);
+
+/// Register `calendar` under `name`, replacing any calendar previously registered under it.
+pub fn register_business_calendar(name: PlSmallStr, calendar: BusinessCalendar) {
+    BUSINESS_CALENDARS
+        .write()
+        .unwrap()
+        .insert(name, Arc::new(calendar));
+}
+
+/// Remove the calendar registered under `name`, returning whether there was one.
+pub fn unregister_business_calendar(name: &str) -> bool {
+    BUSINESS_CALENDARS.write().unwrap().remove(name).is_some()
+}
+
+/// Get the calendar registered under `name`.
+pub fn get_business_calendar(name: &str) -> PolarsResult<Arc<BusinessCalendar>> {
+    match BUSINESS_CALENDARS.read().unwrap().get(name) {
+        Some(calendar) => Ok(calendar.clone()),
+        None => {
+            polars_bail!(ComputeError: "no business calendar registered under the name '{}'", name)
+        },
+    }
+}
+
 fn roll_start_date(
     mut date: i32,
     roll: Roll,
@@ -5,10 +5,9 @@ use arrow::bitmap::MutableBitmap;
 use num_traits::{NumCast, Zero};
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
 
-use super::{linear_itp, nearest_itp};
+use super::spline::piecewise_itp;
+use super::{InterpolationMethod, linear_itp, nearest_itp};
 
 fn near_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -28,6 +27,14 @@ where
     }
 }
 
+fn previous_interp<T: Copy>(low: T, _high: T, steps: IdxSize, _steps_n: T, out: &mut Vec<T>) {
+    out.extend(std::iter::repeat_n(low, steps as usize - 1))
+}
+
+fn next_interp<T: Copy>(_low: T, high: T, steps: IdxSize, _steps_n: T, out: &mut Vec<T>) {
+    out.extend(std::iter::repeat_n(high, steps as usize - 1))
+}
+
 #[inline]
 fn signed_interp<T>(low: T, high: T, steps: IdxSize, steps_n: T, out: &mut Vec<T>)
 where
@@ -109,7 +116,8 @@ where
     }
 }
 
-fn interpolate_nearest(s: &Series) -> Series {
+/// Interpolation methods that only repeat existing values and thus keep the dtype.
+fn interpolate_step(s: &Series, method: InterpolationMethod) -> Series {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, _) | DataType::Enum(_, _) => s.clone(),
@@ -122,7 +130,14 @@ fn interpolate_nearest(s: &Series) -> Series {
             let s = s.to_physical_repr();
 
             macro_rules! dispatch {
-                ($ca:expr) => {{ interpolate_impl($ca, near_interp).into_series() }};
+                ($ca:expr) => {{
+                    match method {
+                        InterpolationMethod::Previous => interpolate_impl($ca, previous_interp),
+                        InterpolationMethod::Next => interpolate_impl($ca, next_interp),
+                        _ => interpolate_impl($ca, near_interp),
+                    }
+                    .into_series()
+                }};
             }
             let out = downcast_as_macro_arg_physical!(s, dispatch);
             match logical {
@@ -195,22 +210,47 @@ fn interpolate_linear(s: &Series) -> Series {
     }
 }
 
-fn linear_interp_signed<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Series {
-    interpolate_impl(ca, signed_interp::<T::Native>).into_series()
+fn interpolate_cubic(s: &Series, method: InterpolationMethod) -> Series {
+    let logical = s.dtype();
+    // The splines are computed in `f64` and temporal values on their physical representation.
+    let values = match logical {
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => s.cast(&DataType::Float64).unwrap(),
+        dt if dt.is_temporal() => s.to_physical_repr().cast(&DataType::Float64).unwrap(),
+        dt if dt.is_primitive_numeric() => s.cast(&DataType::Float64).unwrap(),
+        _ => return s.clone(),
+    };
+
+    let ca = values.f64().unwrap();
+    let x = (0..ca.len()).map(|i| i as f64).collect::<Vec<_>>();
+    let y = ca.iter().collect::<Vec<_>>();
+    let out = Float64Chunked::from_iter_options(
+        s.name().clone(),
+        piecewise_itp(&x, &y, method).into_iter(),
+    )
+    .into_series();
+    match logical {
+        DataType::Float32 => out.cast(logical).unwrap(),
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => out.cast(logical).unwrap(),
+        dt if dt.is_temporal() => out.cast(&dt.to_physical()).unwrap().cast(dt).unwrap(),
+        _ => out,
+    }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub enum InterpolationMethod {
-    Linear,
-    Nearest,
+fn linear_interp_signed<T: PolarsNumericType>(ca: &ChunkedArray<T>) -> Series {
+    interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
 pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
     match method {
         InterpolationMethod::Linear => interpolate_linear(s),
-        InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::Nearest
+        | InterpolationMethod::Previous
+        | InterpolationMethod::Next => interpolate_step(s, method),
+        InterpolationMethod::Cubic | InterpolationMethod::Akima | InterpolationMethod::Pchip => {
+            interpolate_cubic(s, method)
+        },
     }
 }
 
@@ -266,6 +306,81 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_previous_next() {
+        let ca = Int32Chunked::new(
+            "".into(),
+            &[None, Some(1), None, None, Some(4), None, Some(5), None],
+        );
+        let out = interpolate(&ca.clone().into_series(), InterpolationMethod::Previous);
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[
+                None,
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(4),
+                Some(4),
+                Some(5),
+                None
+            ]
+        );
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Next);
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[
+                None,
+                Some(1),
+                Some(4),
+                Some(4),
+                Some(4),
+                Some(5),
+                Some(5),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_cubic() {
+        let ca = Float64Chunked::new("".into(), &[Some(0.0), None, Some(1.0), None, Some(0.0)]);
+        let out = interpolate(&ca.clone().into_series(), InterpolationMethod::Cubic);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.0), Some(0.6875), Some(1.0), Some(0.6875), Some(0.0)]
+        );
+        for method in [InterpolationMethod::Akima, InterpolationMethod::Pchip] {
+            let out = interpolate(&ca.clone().into_series(), method);
+            assert_eq!(
+                Vec::from(out.f64().unwrap()),
+                &[Some(0.0), Some(0.75), Some(1.0), Some(0.75), Some(0.0)]
+            );
+        }
+
+        // PCHIP doesn't overshoot flat regions where the natural spline does.
+        let ca = UInt32Chunked::new(
+            "".into(),
+            &[Some(0), None, Some(0), None, Some(1), None, Some(1)],
+        );
+        let out = interpolate(&ca.clone().into_series(), InterpolationMethod::Pchip);
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[
+                Some(0.0),
+                Some(0.0),
+                Some(0.0),
+                Some(0.5),
+                Some(1.0),
+                Some(1.0),
+                Some(1.0)
+            ]
+        );
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Cubic);
+        let out = out.f64().unwrap();
+        assert!(out.get(1).unwrap() < 0.0 && out.get(5).unwrap() > 1.0);
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("".into(), &[Some(4), None, None, Some(1)]);
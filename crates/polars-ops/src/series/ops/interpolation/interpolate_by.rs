@@ -3,11 +3,12 @@ use std::ops::{Add, Div, Mul, Sub};
 use arrow::array::PrimitiveArray;
 use arrow::bitmap::MutableBitmap;
 use bytemuck::allocation::zeroed_vec;
-use num_traits::{NumCast, Zero};
+use num_traits::{NumCast, ToPrimitive, Zero};
 use polars_core::prelude::*;
 use polars_utils::slice::SliceAble;
 
-use super::linear_itp;
+use super::spline::piecewise_itp;
+use super::{InterpolationMethod, linear_itp};
 
 /// # Safety
 /// - `x` must be non-empty.
@@ -263,20 +264,79 @@ where
     }
 }
 
-pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResult<Column> {
+/// Interpolate with a method other than [`InterpolationMethod::Linear`], which needs the sorted
+/// x-coordinates of all values at once.
+fn interpolate_impl_by_piecewise<T, F>(
+    ca: &ChunkedArray<T>,
+    by: &ChunkedArray<F>,
+    by_is_sorted: bool,
+    method: InterpolationMethod,
+) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+    F: PolarsNumericType,
+{
+    if !ca.has_nulls() || ca.null_count() == ca.len() {
+        return Ok(ca.clone());
+    }
+
+    polars_ensure!(by.null_count() == 0, InvalidOperation: "null values in `by` column are not yet supported in 'interpolate_by' expression");
+    let sorting_indices = (!by_is_sorted).then(|| by.arg_sort(Default::default()));
+    let (by, ca) = match &sorting_indices {
+        Some(idx) => unsafe { (by.take_unchecked(idx), ca.take_unchecked(idx)) },
+        None => (by.clone(), ca.clone()),
+    };
+
+    // Use the offsets from the first (smallest) x-coordinate, so that large values such as
+    // nanosecond timestamps don't lose precision when converted to `f64`.
+    let first = by.get(0).unwrap();
+    let x = by
+        .into_no_null_iter()
+        .map(|v| (v - first).to_f64().unwrap())
+        .collect::<Vec<_>>();
+    let y = ca
+        .iter()
+        .map(|opt_v| opt_v.map(|v| v.to_f64().unwrap()))
+        .collect::<Vec<_>>();
+    let values = piecewise_itp(&x, &y, method)
+        .into_iter()
+        .map(|opt_v| opt_v.map(|v| NumCast::from(v).unwrap()));
+
+    let out = match &sorting_indices {
+        Some(idx) => {
+            let mut out = vec![None; ca.len()];
+            for (out_idx, v) in idx.into_no_null_iter().zip(values) {
+                out[out_idx as usize] = v;
+            }
+            ChunkedArray::from_iter_options(ca.name().clone(), out.into_iter())
+        },
+        None => ChunkedArray::from_iter_options(ca.name().clone(), values),
+    };
+    Ok(out)
+}
+
+pub fn interpolate_by(
+    s: &Column,
+    by: &Column,
+    by_is_sorted: bool,
+    method: InterpolationMethod,
+) -> PolarsResult<Column> {
     polars_ensure!(s.len() == by.len(), InvalidOperation: "`by` column must be the same length as Series ({}), got {}", s.len(), by.len());
 
     fn func<T, F>(
         ca: &ChunkedArray<T>,
         by: &ChunkedArray<F>,
         is_sorted: bool,
+        method: InterpolationMethod,
     ) -> PolarsResult<Column>
     where
         T: PolarsNumericType,
         F: PolarsNumericType,
         ChunkedArray<T>: IntoColumn,
     {
-        if is_sorted {
+        if method != InterpolationMethod::Linear {
+            interpolate_impl_by_piecewise(ca, by, is_sorted, method).map(|x| x.into_column())
+        } else if is_sorted {
             interpolate_impl_by_sorted(ca, by, |y_start, y_end, x, out| unsafe {
                 signed_interp_by_sorted(y_start, y_end, x, out)
             })
@@ -291,49 +351,56 @@ pub fn interpolate_by(s: &Column, by: &Column, by_is_sorted: bool) -> PolarsResu
 
     match (s.dtype(), by.dtype()) {
         (DataType::Float64, DataType::Float64) => {
-            func(s.f64().unwrap(), by.f64().unwrap(), by_is_sorted)
+            func(s.f64().unwrap(), by.f64().unwrap(), by_is_sorted, method)
         },
         (DataType::Float64, DataType::Float32) => {
-            func(s.f64().unwrap(), by.f32().unwrap(), by_is_sorted)
+            func(s.f64().unwrap(), by.f32().unwrap(), by_is_sorted, method)
         },
         (DataType::Float32, DataType::Float64) => {
-            func(s.f32().unwrap(), by.f64().unwrap(), by_is_sorted)
+            func(s.f32().unwrap(), by.f64().unwrap(), by_is_sorted, method)
         },
         (DataType::Float32, DataType::Float32) => {
-            func(s.f32().unwrap(), by.f32().unwrap(), by_is_sorted)
+            func(s.f32().unwrap(), by.f32().unwrap(), by_is_sorted, method)
         },
         (DataType::Float64, DataType::Int64) => {
-            func(s.f64().unwrap(), by.i64().unwrap(), by_is_sorted)
+            func(s.f64().unwrap(), by.i64().unwrap(), by_is_sorted, method)
         },
         (DataType::Float64, DataType::Int32) => {
-            func(s.f64().unwrap(), by.i32().unwrap(), by_is_sorted)
+            func(s.f64().unwrap(), by.i32().unwrap(), by_is_sorted, method)
         },
         (DataType::Float64, DataType::UInt64) => {
-            func(s.f64().unwrap(), by.u64().unwrap(), by_is_sorted)
+            func(s.f64().unwrap(), by.u64().unwrap(), by_is_sorted, method)
         },
         (DataType::Float64, DataType::UInt32) => {
-            func(s.f64().unwrap(), by.u32().unwrap(), by_is_sorted)
+            func(s.f64().unwrap(), by.u32().unwrap(), by_is_sorted, method)
         },
         (DataType::Float32, DataType::Int64) => {
-            func(s.f32().unwrap(), by.i64().unwrap(), by_is_sorted)
+            func(s.f32().unwrap(), by.i64().unwrap(), by_is_sorted, method)
         },
         (DataType::Float32, DataType::Int32) => {
-            func(s.f32().unwrap(), by.i32().unwrap(), by_is_sorted)
+            func(s.f32().unwrap(), by.i32().unwrap(), by_is_sorted, method)
         },
         (DataType::Float32, DataType::UInt64) => {
-            func(s.f32().unwrap(), by.u64().unwrap(), by_is_sorted)
+            func(s.f32().unwrap(), by.u64().unwrap(), by_is_sorted, method)
         },
         (DataType::Float32, DataType::UInt32) => {
-            func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted)
+            func(s.f32().unwrap(), by.u32().unwrap(), by_is_sorted, method)
         },
         #[cfg(feature = "dtype-date")]
-        (_, DataType::Date) => interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted),
+        (_, DataType::Date) => {
+            interpolate_by(s, &by.cast(&DataType::Int32).unwrap(), by_is_sorted, method)
+        },
         #[cfg(feature = "dtype-datetime")]
         (_, DataType::Datetime(_, _)) => {
-            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted)
+            interpolate_by(s, &by.cast(&DataType::Int64).unwrap(), by_is_sorted, method)
         },
         (DataType::UInt64 | DataType::UInt32 | DataType::Int64 | DataType::Int32, _) => {
-            interpolate_by(&s.cast(&DataType::Float64).unwrap(), by, by_is_sorted)
+            interpolate_by(
+                &s.cast(&DataType::Float64).unwrap(),
+                by,
+                by_is_sorted,
+                method,
+            )
         },
         _ => {
             polars_bail!(InvalidOperation: "expected series to be Float64, Float32, \
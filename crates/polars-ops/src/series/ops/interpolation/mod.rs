@@ -3,6 +3,30 @@ use std::ops::{Add, Div, Mul, Sub};
 pub mod interpolate;
 #[cfg(feature = "interpolate_by")]
 pub mod interpolate_by;
+mod spline;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum InterpolationMethod {
+    Linear,
+    Nearest,
+    /// Repeat the last non-null value.
+    Previous,
+    /// Repeat the next non-null value.
+    Next,
+    /// Natural cubic spline through all non-null values.
+    Cubic,
+    /// Akima spline, which is less prone to overshoot around outliers than `Cubic`.
+    Akima,
+    /// Piecewise cubic Hermite interpolation that preserves monotonicity.
+    Pchip,
+}
 
 fn linear_itp<T>(low: T, step: T, slope: T) -> T
 where
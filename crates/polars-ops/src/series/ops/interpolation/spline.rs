@@ -0,0 +1,165 @@
+use super::InterpolationMethod;
+
+/// Slopes of the segments between consecutive knots.
+fn secants(x: &[f64], y: &[f64]) -> Vec<f64> {
+    x.windows(2)
+        .zip(y.windows(2))
+        .map(|(x, y)| (y[1] - y[0]) / (x[1] - x[0]))
+        .collect()
+}
+
+/// Knot slopes of the natural cubic spline, i.e. the spline with zero second derivative at both
+/// ends.
+fn natural_cubic_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let h = x.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    let d = secants(x, y);
+
+    // Solve the tridiagonal system for the second derivatives with the Thomas algorithm.
+    let mut m2 = vec![0.0; n];
+    let mut c = vec![0.0; n];
+    let mut r = vec![0.0; n];
+    for i in 1..n - 1 {
+        let diag = 2.0 * (h[i - 1] + h[i]) - h[i - 1] * c[i - 1];
+        c[i] = h[i] / diag;
+        r[i] = (6.0 * (d[i] - d[i - 1]) - h[i - 1] * r[i - 1]) / diag;
+    }
+    for i in (1..n - 1).rev() {
+        m2[i] = r[i] - c[i] * m2[i + 1];
+    }
+
+    let mut slopes = (0..n - 1)
+        .map(|i| d[i] - h[i] * (2.0 * m2[i] + m2[i + 1]) / 6.0)
+        .collect::<Vec<_>>();
+    slopes.push(d[n - 2] + h[n - 2] * (m2[n - 2] + 2.0 * m2[n - 1]) / 6.0);
+    slopes
+}
+
+/// Knot slopes of the Akima spline, which only depend on the two neighbouring segments on
+/// either side and therefore don't oscillate around outliers.
+fn akima_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let d = secants(x, y);
+
+    // Extrapolate two extra segment slopes on both ends.
+    let mut ext = Vec::with_capacity(n + 3);
+    let d_left = 2.0 * d[0] - d[1];
+    ext.extend([2.0 * d_left - d[0], d_left]);
+    ext.extend_from_slice(&d);
+    let d_right = 2.0 * d[n - 2] - d[n - 3];
+    ext.extend([d_right, 2.0 * d_right - d[n - 2]]);
+
+    (0..n)
+        .map(|i| {
+            // The slopes of the segments around knot `i` are `ext[i + 1]` and `ext[i + 2]`.
+            let w_left = (ext[i + 3] - ext[i + 2]).abs();
+            let w_right = (ext[i + 1] - ext[i]).abs();
+            if w_left + w_right == 0.0 {
+                (ext[i + 1] + ext[i + 2]) / 2.0
+            } else {
+                (w_left * ext[i + 1] + w_right * ext[i + 2]) / (w_left + w_right)
+            }
+        })
+        .collect()
+}
+
+/// Knot slopes of the piecewise cubic Hermite interpolating polynomial (PCHIP), which preserves
+/// the monotonicity of the data.
+fn pchip_slopes(x: &[f64], y: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    let h = x.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    let d = secants(x, y);
+
+    // Unlike `f64::signum` this maps zero to zero.
+    let sign = |v: f64| (v > 0.0) as i8 - (v < 0.0) as i8;
+    let edge = |h0: f64, h1: f64, d0: f64, d1: f64| {
+        let m = ((2.0 * h0 + h1) * d0 - h0 * d1) / (h0 + h1);
+        if sign(m) != sign(d0) {
+            0.0
+        } else if sign(d0) != sign(d1) && m.abs() > 3.0 * d0.abs() {
+            3.0 * d0
+        } else {
+            m
+        }
+    };
+
+    let mut slopes = vec![edge(h[0], h[1], d[0], d[1])];
+    slopes.extend((1..n - 1).map(|i| {
+        if d[i - 1] * d[i] <= 0.0 {
+            0.0
+        } else {
+            // Weighted harmonic mean of the neighbouring secants.
+            let w1 = 2.0 * h[i] + h[i - 1];
+            let w2 = h[i] + 2.0 * h[i - 1];
+            (w1 + w2) / (w1 / d[i - 1] + w2 / d[i])
+        }
+    }));
+    slopes.push(edge(h[n - 2], h[n - 3], d[n - 2], d[n - 3]));
+    slopes
+}
+
+/// Evaluate the cubic Hermite polynomial through `(x0, y0)` and `(x1, y1)` with slopes `m0` and
+/// `m1` at `x`.
+fn hermite_itp(x0: f64, x1: f64, y0: f64, y1: f64, m0: f64, m1: f64, x: f64) -> f64 {
+    let h = x1 - x0;
+    let t = (x - x0) / h;
+    let t2 = t * t;
+    let t3 = t2 * t;
+    (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+        + (t3 - 2.0 * t2 + t) * h * m0
+        + (-2.0 * t3 + 3.0 * t2) * y1
+        + (t3 - t2) * h * m1
+}
+
+/// Fill the nulls between the first and last non-null value of `y`, where `x` holds the
+/// increasing x-coordinates of all values. Leading and trailing nulls remain null.
+///
+/// Unlike the kernels in `interpolate` this can use every non-null value, which the cubic
+/// methods need to compute their slopes.
+pub(super) fn piecewise_itp(
+    x: &[f64],
+    y: &[Option<f64>],
+    method: InterpolationMethod,
+) -> Vec<Option<f64>> {
+    debug_assert_eq!(x.len(), y.len());
+    let (knots, (kx, ky)): (Vec<usize>, (Vec<f64>, Vec<f64>)) = y
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (i, (x[i], v))))
+        .unzip();
+
+    let mut out = y.to_vec();
+    if knots.len() < 2 {
+        return out;
+    }
+
+    use InterpolationMethod as M;
+    // With too few knots the cubic methods fall back to lower order interpolation.
+    let slopes = match method {
+        M::Cubic if knots.len() >= 3 => Some(natural_cubic_slopes(&kx, &ky)),
+        M::Akima if knots.len() >= 3 => Some(akima_slopes(&kx, &ky)),
+        M::Pchip if knots.len() >= 3 => Some(pchip_slopes(&kx, &ky)),
+        _ => None,
+    };
+
+    for (k, w) in knots.windows(2).enumerate() {
+        let (x0, x1, y0, y1) = (kx[k], kx[k + 1], ky[k], ky[k + 1]);
+        for i in w[0] + 1..w[1] {
+            let v = match (method, &slopes) {
+                (_, Some(m)) => hermite_itp(x0, x1, y0, y1, m[k], m[k + 1], x[i]),
+                (M::Previous, _) => y0,
+                (M::Next, _) => y1,
+                (M::Nearest, _) => {
+                    if x1 - x[i] > x[i] - x0 {
+                        y0
+                    } else {
+                        y1
+                    }
+                },
+                _ => y0 + (x[i] - x0) * (y1 - y0) / (x1 - x0),
+            };
+            out[i] = Some(v);
+        }
+    }
+    out
+}
@@ -28,8 +28,8 @@ pub use crate::parquet::{
     metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
     page::{CompressedDataPage, DataPageHeader, Page},
     read::{
-        BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
-        get_column_iterator, read_metadata as _read_metadata,
+        BasicDecompressor, MutStreamingIterator, PageMetaData, PageReader, ReadColumnIterator,
+        State, decompress, get_column_iterator, read_metadata as _read_metadata,
     },
     schema::types::{
         GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
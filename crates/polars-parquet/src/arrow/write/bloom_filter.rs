@@ -0,0 +1,186 @@
+use arrow::array::*;
+use arrow::bitmap::MutableBitmap;
+use arrow::datatypes::ArrowDataType;
+use arrow::match_integer_type;
+use arrow::types::NativeType;
+use polars_error::{PolarsResult, polars_bail};
+
+use super::{BloomFilterOptions, ColumnWriteOptions, to_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+
+/// Builds the split-block bloom filters of an [`Array`], one per parquet leaf column.
+///
+/// Leaf columns whose [`FieldWriteOptions`](super::FieldWriteOptions) don't request a bloom
+/// filter result in `None`.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    column_options: &ColumnWriteOptions,
+) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let mut field_options = Vec::new();
+    column_options.to_leaves(&mut field_options);
+
+    if field_options.iter().all(|o| o.bloom_filter.is_none()) {
+        return Ok(vec![None; field_options.len()]);
+    }
+
+    let mut leaves = Vec::new();
+    to_leaves(array, &mut leaves);
+    assert_eq!(leaves.len(), field_options.len());
+
+    leaves
+        .iter()
+        .zip(field_options)
+        .map(|(leaf, field_options)| {
+            field_options
+                .bloom_filter
+                .map(|options| leaf_to_bloom_filter(leaf.as_ref(), options))
+                .transpose()
+        })
+        .collect()
+}
+
+fn leaf_to_bloom_filter(array: &dyn Array, options: BloomFilterOptions) -> PolarsResult<Vec<u8>> {
+    let ndv = options
+        .ndv
+        .unwrap_or((array.len() - array.null_count()) as u64);
+    let mut bitset = vec![0; optimal_num_bytes(ndv, options.fpp)];
+    hash_non_null_values(array, &mut |hash| insert(&mut bitset, hash))?;
+    Ok(bitset)
+}
+
+fn hash_primitive<T: NativeType, P: crate::parquet::types::NativeType>(
+    array: &dyn Array,
+    f: &mut impl FnMut(u64),
+    cast: impl Fn(T) -> P,
+) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .non_null_values_iter()
+        .for_each(|v| f(hash_native(cast(v))));
+}
+
+/// Calls `f` with the hash of every non-null value of `array`, hashed as the plain-encoded
+/// parquet physical value it is written as.
+fn hash_non_null_values(array: &dyn Array, f: &mut impl FnMut(u64)) -> PolarsResult<()> {
+    // casts below MUST match the casts done when writing the pages.
+    match array.dtype().to_logical_type() {
+        ArrowDataType::UInt8 => hash_primitive::<u8, i32>(array, f, |v| v as i32),
+        ArrowDataType::UInt16 => hash_primitive::<u16, i32>(array, f, |v| v as i32),
+        ArrowDataType::UInt32 => hash_primitive::<u32, i32>(array, f, |v| v as i32),
+        ArrowDataType::UInt64 => hash_primitive::<u64, i64>(array, f, |v| v as i64),
+        ArrowDataType::Int8 => hash_primitive::<i8, i32>(array, f, |v| v as i32),
+        ArrowDataType::Int16 => hash_primitive::<i16, i32>(array, f, |v| v as i32),
+        ArrowDataType::Int32 | ArrowDataType::Date32 | ArrowDataType::Time32(_) => {
+            hash_primitive::<i32, i32>(array, f, |v| v)
+        },
+        ArrowDataType::Int64
+        | ArrowDataType::Date64
+        | ArrowDataType::Time64(_)
+        | ArrowDataType::Timestamp(_, _)
+        | ArrowDataType::Duration(_) => hash_primitive::<i64, i64>(array, f, |v| v),
+        ArrowDataType::Float32 => hash_primitive::<f32, f32>(array, f, |v| v),
+        ArrowDataType::Float64 => hash_primitive::<f64, f64>(array, f, |v| v),
+        ArrowDataType::Utf8View | ArrowDataType::BinaryView => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryViewArray>()
+                .map_or_else(
+                    || {
+                        array
+                            .as_any()
+                            .downcast_ref::<Utf8ViewArray>()
+                            .unwrap()
+                            .to_binview()
+                    },
+                    |array| array.clone(),
+                );
+            array.non_null_values_iter().for_each(|v| f(hash_byte(v)));
+        },
+        ArrowDataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            array.non_null_values_iter().for_each(|v| f(hash_byte(v)));
+        },
+        ArrowDataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            array.non_null_values_iter().for_each(|v| f(hash_byte(v)));
+        },
+        ArrowDataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array.iter().flatten().for_each(|v| f(hash_byte(v)));
+        },
+        ArrowDataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+
+            // Only hash the dictionary values that are referenced by a valid key.
+            let values = array.values();
+            let mut used = MutableBitmap::from_len_zeroed(values.len());
+            array.keys_iter().flatten().for_each(|k| used.set(k, true));
+            let used = used.freeze();
+            let validity = match values.validity() {
+                Some(validity) => validity & &used,
+                None => used,
+            };
+
+            hash_non_null_values(values.with_validity(Some(validity)).as_ref(), f)?;
+        }),
+        dtype => polars_bail!(
+            InvalidOperation: "writing a bloom filter for parquet columns of type {:?} is not supported", dtype
+        ),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parquet::bloom_filter::is_in_set;
+    use crate::write::{ChildWriteOptions, Encoding, FieldWriteOptions};
+
+    fn leaf_options(bloom_filter: Option<BloomFilterOptions>) -> ColumnWriteOptions {
+        ColumnWriteOptions::default_with(ChildWriteOptions::Leaf(FieldWriteOptions {
+            encoding: Encoding::Plain,
+            bloom_filter,
+        }))
+    }
+
+    #[test]
+    fn disabled() {
+        let array = Int32Array::from_slice([1, 2, 3]);
+        let filters = array_to_bloom_filters(&array, &leaf_options(None)).unwrap();
+        assert_eq!(filters, vec![None]);
+    }
+
+    #[test]
+    fn primitive_and_nulls() {
+        let array = UInt32Array::from([Some(1), None, Some(u32::MAX)]);
+        let filters =
+            array_to_bloom_filters(&array, &leaf_options(Some(BloomFilterOptions::default())))
+                .unwrap();
+        let bitset = filters[0].as_ref().unwrap();
+
+        // UInt32 is written as INT32.
+        assert!(is_in_set(bitset, hash_native(1i32)));
+        assert!(is_in_set(bitset, hash_native(-1i32)));
+        assert!(!is_in_set(bitset, hash_native(2i32)));
+    }
+
+    #[test]
+    fn strings() {
+        let array = Utf8ViewArray::from_slice([Some("a"), None, Some("b")]);
+        let filters =
+            array_to_bloom_filters(&array, &leaf_options(Some(BloomFilterOptions::default())))
+                .unwrap();
+        let bitset = filters[0].as_ref().unwrap();
+
+        assert!(is_in_set(bitset, hash_byte("a")));
+        assert!(is_in_set(bitset, hash_byte("b")));
+        assert!(!is_in_set(bitset, hash_byte("c")));
+    }
+}
@@ -83,6 +83,18 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes a row group to the file, together with the (optional) bloom filter bitset of
+    /// each of its leaf columns.
+    pub fn write_with_bloom_filters(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(row_group, bloom_filters)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
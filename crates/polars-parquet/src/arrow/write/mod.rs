@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -124,6 +126,51 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    /// Write a split-block bloom filter for every column chunk of this field
+    pub bloom_filter: Option<BloomFilterOptions>,
+}
+
+/// The options to build a split-block bloom filter
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct BloomFilterOptions {
+    /// The false positive probability of the filter, in `(0, 1)`
+    pub fpp: f64,
+    /// The expected number of distinct values per column chunk. Defaults to the number of
+    /// non-null values if `None`.
+    pub ndv: Option<u64>,
+}
+
+impl BloomFilterOptions {
+    pub const DEFAULT_FPP: f64 = 0.05;
+
+    pub fn try_new(fpp: Option<f64>, ndv: Option<u64>) -> PolarsResult<Self> {
+        let fpp = fpp.unwrap_or(Self::DEFAULT_FPP);
+        polars_ensure!(
+            fpp > 0.0 && fpp < 1.0,
+            InvalidOperation: "bloom filter false positive probability must be in (0, 1), got {}", fpp
+        );
+        Ok(Self { fpp, ndv })
+    }
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            fpp: Self::DEFAULT_FPP,
+            ndv: None,
+        }
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl Hash for BloomFilterOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.fpp.to_bits().hash(state);
+        self.ndv.hash(state);
+    }
 }
 
 impl ColumnWriteOptions {
@@ -139,7 +186,10 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            bloom_filter: None,
+        }
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -157,11 +207,15 @@ pub struct StructFieldWriteOptions {
     pub children: Vec<ColumnWriteOptions>,
 }
 
+use std::hash::{Hash, Hasher};
+
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::array_to_bloom_filters;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 pub use row_group::{RowGroupIterator, row_group_iter};
 pub use schema::{schema_to_metadata_key, to_parquet_type};
 
@@ -4,8 +4,8 @@ mod read;
 mod split_block;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
-pub use split_block::{insert, is_in_set};
+pub use read::{deserialize, read};
+pub use split_block::{insert, is_in_set, optimal_num_bytes};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +68,13 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn num_bytes() {
+        assert_eq!(optimal_num_bytes(0, 0.05), 32);
+        assert_eq!(optimal_num_bytes(10, 0.05), 32);
+        // Matches the sizing of parquet-mr / arrow-rs for 1M distinct values.
+        assert_eq!(optimal_num_bytes(1_000_000, 0.05), 1024 * 1024);
+        assert_eq!(optimal_num_bytes(u64::MAX, 0.05), 128 * 1024 * 1024);
+    }
 }
@@ -3,6 +3,24 @@ const SALT: [u32; 8] = [
     1203114875, 1150766481, 2284105051, 2729912477, 1884591559, 770785867, 2667333959, 1550580529,
 ];
 
+/// The minimum size of a bitset in bytes, i.e. a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// The maximum size of a bitset in bytes.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a bitset that holds `ndv` distinct values with a false positive
+/// probability of (at most) `fpp`.
+///
+/// The size is rounded up to a power of two and clamped between a single block and 128MiB.
+/// Formula taken from https://github.com/apache/parquet-format/blob/master/BloomFilter.md
+pub fn optimal_num_bytes(ndv: u64, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
 fn hash_to_block_index(hash: u64, len: usize) -> usize {
     let number_of_blocks = len as u64 / 32;
     let low_hash = hash >> 32;
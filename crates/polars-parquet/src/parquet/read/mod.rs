@@ -3,6 +3,7 @@ mod compression;
 pub mod levels;
 mod metadata;
 mod page;
+pub mod page_index;
 #[cfg(feature = "async")]
 mod stream;
 
@@ -0,0 +1,53 @@
+use std::ops::Range;
+
+pub use polars_parquet_format::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocation};
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+
+use crate::parquet::error::ParquetResult;
+use crate::parquet::metadata::ColumnChunkMetadata;
+
+fn byte_range(offset: Option<i64>, length: Option<i32>) -> Option<Range<u64>> {
+    let offset = u64::try_from(offset?).ok()?;
+    let length = u64::try_from(length?).ok()?;
+    Some(offset..offset + length)
+}
+
+/// Returns the byte range of the [`ColumnIndex`] of a column chunk, if it has one.
+pub fn column_index_byte_range(column_chunk: &ColumnChunkMetadata) -> Option<Range<u64>> {
+    let column_chunk = column_chunk.column_chunk();
+    byte_range(
+        column_chunk.column_index_offset,
+        column_chunk.column_index_length,
+    )
+}
+
+/// Returns the byte range of the [`OffsetIndex`] of a column chunk, if it has one.
+pub fn offset_index_byte_range(column_chunk: &ColumnChunkMetadata) -> Option<Range<u64>> {
+    let column_chunk = column_chunk.column_chunk();
+    byte_range(
+        column_chunk.offset_index_offset,
+        column_chunk.offset_index_length,
+    )
+}
+
+/// Upper bound of the memory the thrift decoder is allowed to allocate for `num_bytes` of input.
+///
+/// Every list element is accounted for as at least a `usize`, while it can be encoded in a single
+/// byte, e.g. for `ColumnIndex::null_pages`.
+fn max_decoded_size(num_bytes: usize) -> usize {
+    num_bytes * 16 + 1024
+}
+
+/// Deserializes a [`ColumnIndex`] from `bytes`.
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    let max_size = max_decoded_size(bytes.len());
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(ColumnIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes an [`OffsetIndex`] from `bytes`.
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = max_decoded_size(bytes.len());
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
@@ -3,6 +3,7 @@ use std::io::Write;
 use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
 
+use super::bloom_filter::write_bloom_filter;
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
@@ -152,6 +153,24 @@ impl<W: Write> FileWriter<W> {
     ///
     /// This call is IO-bounded
     pub fn write<E>(&mut self, row_group: RowGroupIterColumns<'_, E>) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_bloom_filters(row_group, &[])
+    }
+
+    /// Writes a row group to the file, followed by the split-block bloom filters of its columns.
+    ///
+    /// `bloom_filters` holds an optional bitset per leaf column of the row group. Columns beyond
+    /// the length of `bloom_filters` are written without a bloom filter.
+    ///
+    /// This call is IO-bounded
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -160,7 +179,7 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             self.offset,
             self.schema.columns(),
@@ -168,11 +187,32 @@ impl<W: Write> FileWriter<W> {
             ordinal,
         )?;
         self.offset += size;
+        self.write_bloom_filters(&mut group, bloom_filters)?;
         self.row_groups.push(group);
         self.page_specs.push(specs);
         Ok(())
     }
 
+    fn write_bloom_filters(
+        &mut self,
+        group: &mut RowGroup,
+        bloom_filters: &[Option<Vec<u8>>],
+    ) -> ParquetResult<()> {
+        for (column, bitset) in group.columns.iter_mut().zip(bloom_filters) {
+            let Some(bitset) = bitset else {
+                continue;
+            };
+
+            let offset = self.offset;
+            self.offset += write_bloom_filter(&mut self.writer, bitset)?;
+
+            let metadata = column.meta_data.as_mut().unwrap();
+            metadata.bloom_filter_offset = Some(offset as i64);
+            metadata.bloom_filter_length = Some((self.offset - offset).try_into()?);
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
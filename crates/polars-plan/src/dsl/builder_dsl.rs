@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
@@ -82,6 +84,21 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        options: AvroScanOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro { options }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv(
@@ -215,6 +232,7 @@ impl DslBuilder {
         .into()
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn group_by<E: AsRef<[Expr]>>(
         self,
         keys: Vec<Expr>,
@@ -223,6 +241,7 @@ impl DslBuilder {
         maintain_order: bool,
         #[cfg(feature = "dynamic_group_by")] dynamic_options: Option<DynamicGroupOptions>,
         #[cfg(feature = "dynamic_group_by")] rolling_options: Option<RollingGroupOptions>,
+        #[cfg(feature = "dynamic_group_by")] session_options: Option<SessionGroupOptions>,
     ) -> Self {
         let aggs = aggs.as_ref().to_vec();
         let options = GroupbyOptions {
@@ -230,6 +249,8 @@ impl DslBuilder {
             dynamic: dynamic_options,
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
+            #[cfg(feature = "dynamic_group_by")]
+            session: session_options,
             slice: None,
         };
 
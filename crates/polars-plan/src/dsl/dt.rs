@@ -125,6 +125,28 @@ impl DateLikeNameSpace {
             .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::Quarter))
     }
 
+    /// Get the fiscal year of a Date/Datetime in the given fiscal calendar.
+    ///
+    /// Fiscal years are named after the calendar year in which they end.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_year(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalYear(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal quarter of a Date/Datetime in the given fiscal calendar.
+    ///
+    /// Quarters range from 1 to 4.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_quarter(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalQuarter(
+                calendar,
+            )))
+    }
+
     /// Extract the week from the underlying Date representation.
     /// Can be performed on Date and Datetime
     ///
@@ -224,6 +246,25 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Truncate the Datetime/Date range into the fiscal periods of the given fiscal calendar.
+    #[cfg(feature = "dtype-date")]
+    pub fn truncate_fiscal(self, every: Expr, calendar: FiscalCalendar) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::TemporalExpr(TemporalFunction::TruncateFiscal(calendar)),
+            every,
+        )
+    }
+
+    /// Assign a session id to every value of a sorted Datetime/Date or integer index column.
+    ///
+    /// A session ends after an inactivity of `gap`: a new session starts at every value that is at
+    /// least `gap` past the value before it. Combine with `over` to sessionize per key.
+    #[cfg(feature = "dynamic_group_by")]
+    pub fn session_id(self, gap: Duration) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::SessionId(gap)))
+    }
+
     /// Roll backward to the first day of the month.
     #[cfg(feature = "month_start")]
     pub fn month_start(self) -> Expr {
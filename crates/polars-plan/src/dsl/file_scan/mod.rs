@@ -4,6 +4,8 @@ use std::sync::Mutex;
 use deletion::DeletionFilesList;
 use polars_core::schema::iceberg::IcebergSchemaRef;
 use polars_core::utils::get_numeric_upcast_supertype_lossless;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroScanOptions;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
@@ -47,12 +49,18 @@ pub enum FileScanDsl {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    #[cfg(feature = "json")]
+    Json { options: JsonReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet { options: ParquetOptions },
 
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -77,6 +85,9 @@ pub enum FileScanIR {
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
 
+    #[cfg(feature = "json")]
+    Json { options: JsonReadOptions },
+
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
@@ -91,6 +102,9 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro { options: AvroScanOptions },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -112,10 +126,14 @@ impl FileScanIR {
             Self::Csv { .. } => ScanFlags::empty(),
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => ScanFlags::empty(),
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => ScanFlags::empty(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => ScanFlags::SPECIALIZED_PREDICATE_FILTER,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => ScanFlags::empty(),
+            #[cfg(feature = "json")]
+            Self::Json { .. } => ScanFlags::empty(),
             #[allow(unreachable_patterns)]
             _ => ScanFlags::empty(),
         }
@@ -127,6 +145,8 @@ impl FileScanIR {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => _has_row_index,
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => _has_row_index,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => false,
             #[allow(unreachable_patterns)]
@@ -140,10 +160,14 @@ impl FileScanIR {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
+            #[cfg(feature = "avro")]
+            Self::Avro { .. } => false,
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "json")]
+            Self::Json { .. } => false,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -314,6 +338,11 @@ mod _file_scan_eq_hash {
             options: &'a crate::prelude::NDJsonReadOptions,
         },
 
+        #[cfg(feature = "json")]
+        Json {
+            options: &'a crate::prelude::JsonReadOptions,
+        },
+
         #[cfg(feature = "parquet")]
         Parquet {
             options: &'a polars_io::prelude::ParquetOptions,
@@ -326,6 +355,11 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro {
+            options: &'a polars_io::avro::AvroScanOptions,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -351,6 +385,9 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { options } => FileScanEqHashWrap::NDJson { options },
 
+                #[cfg(feature = "json")]
+                FileScanIR::Json { options } => FileScanEqHashWrap::Json { options },
+
                 #[cfg(feature = "parquet")]
                 FileScanIR::Parquet { options, metadata } => FileScanEqHashWrap::Parquet {
                     options,
@@ -363,6 +400,9 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { options } => FileScanEqHashWrap::Avro { options },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
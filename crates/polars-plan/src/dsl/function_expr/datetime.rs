@@ -10,6 +10,10 @@ pub enum TemporalFunction {
     IsLeapYear,
     IsoYear,
     Quarter,
+    #[cfg(feature = "dtype-date")]
+    FiscalYear(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalQuarter(FiscalCalendar),
     Month,
     DaysInMonth,
     Week,
@@ -48,6 +52,10 @@ pub enum TemporalFunction {
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
     Truncate,
+    #[cfg(feature = "dtype-date")]
+    TruncateFiscal(FiscalCalendar),
+    #[cfg(feature = "dynamic_group_by")]
+    SessionId(Duration),
     #[cfg(feature = "offset_by")]
     OffsetBy,
     #[cfg(feature = "month_start")]
@@ -79,6 +87,10 @@ impl Display for TemporalFunction {
             IsLeapYear => "is_leap_year",
             IsoYear => "iso_year",
             Quarter => "quarter",
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(_) => "fiscal_year",
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(_) => "fiscal_quarter",
             Month => "month",
             DaysInMonth => "days_in_month",
             Week => "week",
@@ -117,6 +129,10 @@ impl Display for TemporalFunction {
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
             Truncate => "truncate",
+            #[cfg(feature = "dtype-date")]
+            TruncateFiscal(_) => "truncate_fiscal",
+            #[cfg(feature = "dynamic_group_by")]
+            SessionId(_) => "session_id",
             #[cfg(feature = "offset_by")]
             OffsetBy => "offset_by",
             #[cfg(feature = "month_start")]
@@ -23,6 +23,8 @@ pub enum ListFunction {
     Contains {
         nulls_equal: bool,
     },
+    #[cfg(feature = "list_index_of")]
+    IndexOf,
     #[cfg(feature = "list_drop_nulls")]
     DropNulls,
     #[cfg(feature = "list_sample")]
@@ -34,6 +36,8 @@ pub enum ListFunction {
     },
     Slice,
     Shift,
+    #[cfg(feature = "list_pad")]
+    PadEnd,
     Get(bool),
     #[cfg(feature = "list_gather")]
     Gather(bool),
@@ -67,10 +71,16 @@ pub enum ListFunction {
     #[cfg(feature = "list_any_all")]
     All,
     Join(bool),
+    #[cfg(feature = "list_flatten")]
+    Flatten(Option<usize>),
+    #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+    Reshape(Vec<usize>),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStruct),
+    #[cfg(feature = "list_zip")]
+    Zip,
 }
 
 impl Display for ListFunction {
@@ -81,6 +91,8 @@ impl Display for ListFunction {
             Concat => "concat",
             #[cfg(feature = "is_in")]
             Contains { nulls_equal: _ } => "contains",
+            #[cfg(feature = "list_index_of")]
+            IndexOf => "index_of",
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => "drop_nulls",
             #[cfg(feature = "list_sample")]
@@ -93,6 +105,8 @@ impl Display for ListFunction {
             },
             Slice => "slice",
             Shift => "shift",
+            #[cfg(feature = "list_pad")]
+            PadEnd => "pad_end",
             Get(_) => "get",
             #[cfg(feature = "list_gather")]
             Gather(_) => "gather",
@@ -129,10 +143,16 @@ impl Display for ListFunction {
             #[cfg(feature = "list_any_all")]
             All => "all",
             Join(_) => "join",
+            #[cfg(feature = "list_flatten")]
+            Flatten(_) => "flatten",
+            #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+            Reshape(_) => "reshape",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "list_zip")]
+            Zip => "zip",
         };
         write!(f, "list.{name}")
     }
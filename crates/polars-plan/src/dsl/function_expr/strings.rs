@@ -17,6 +17,8 @@ pub enum StringFunction {
         delimiter: PlSmallStr,
         ignore_nulls: bool,
     },
+    #[cfg(feature = "string_format")]
+    Format(FormatTemplate),
     #[cfg(feature = "regex")]
     Contains {
         literal: bool,
@@ -64,6 +66,18 @@ pub enum StringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    Hamming,
+    #[cfg(feature = "string_similarity")]
+    Jaccard {
+        ngram: usize,
+    },
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -83,6 +97,14 @@ pub enum StringFunction {
     Base64Encode,
     #[cfg(feature = "binary_encoding")]
     Base64Decode(bool),
+    #[cfg(feature = "string_url")]
+    ParseUrl(bool),
+    #[cfg(feature = "string_url")]
+    UrlQueryParam,
+    #[cfg(feature = "string_url")]
+    PercentEncode,
+    #[cfg(feature = "string_url")]
+    PercentDecode(bool),
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -96,9 +118,18 @@ pub enum StringFunction {
     },
     #[cfg(feature = "dtype-struct")]
     SplitN(usize),
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    SplitExactRegex {
+        n: usize,
+        inclusive: bool,
+    },
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    SplitNRegex(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataTypeExpr, StrptimeOptions),
     Split(bool),
+    #[cfg(feature = "regex")]
+    SplitRegex(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
     #[cfg(feature = "nightly")]
@@ -141,6 +172,8 @@ impl Display for StringFunction {
             ConcatHorizontal { .. } => "concat_horizontal",
             #[cfg(feature = "concat_str")]
             ConcatVertical { .. } => "concat_vertical",
+            #[cfg(feature = "string_format")]
+            Format(_) => "format",
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
@@ -167,6 +200,16 @@ impl Display for StringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_similarity")]
+            Jaccard { .. } => "jaccard",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -175,6 +218,14 @@ impl Display for StringFunction {
             Base64Encode => "base64_encode",
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
+            #[cfg(feature = "string_url")]
+            ParseUrl(_) => "parse_url",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam => "url_query_param",
+            #[cfg(feature = "string_url")]
+            PercentEncode => "percent_encode",
+            #[cfg(feature = "string_url")]
+            PercentDecode(_) => "percent_decode",
             Slice => "slice",
             StartsWith => "starts_with",
             StripChars => "strip_chars",
@@ -192,6 +243,16 @@ impl Display for StringFunction {
             },
             #[cfg(feature = "dtype-struct")]
             SplitN(_) => "splitn",
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitExactRegex { inclusive, .. } => {
+                if *inclusive {
+                    "split_exact_regex_inclusive"
+                } else {
+                    "split_exact_regex"
+                }
+            },
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitNRegex(_) => "splitn_regex",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
             Split(inclusive) => {
@@ -201,6 +262,14 @@ impl Display for StringFunction {
                     "split"
                 }
             },
+            #[cfg(feature = "regex")]
+            SplitRegex(inclusive) => {
+                if *inclusive {
+                    "split_regex_inclusive"
+                } else {
+                    "split_regex"
+                }
+            },
             #[cfg(feature = "nightly")]
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
@@ -46,6 +46,36 @@ pub fn format_str<E: AsRef<[Expr]>>(format: &str, args: E) -> PolarsResult<Expr>
     Ok(concat_str(exprs, "", false))
 }
 
+#[cfg(all(feature = "concat_str", feature = "strings", feature = "string_format"))]
+/// Format the results of an array of expressions using a format string in which placeholders
+/// can hold a format spec such as `{:>8.2f}` or `{:%Y-%m-%d}`.
+///
+/// Unlike [`format_str`], `{{` and `}}` are literal braces and an unmatched brace is an error.
+pub fn format_str_with_specs<E: AsRef<[Expr]>>(format: &str, args: E) -> PolarsResult<Expr> {
+    let template = FormatTemplate::parse(format)?;
+    polars_ensure!(
+        template.placeholders().count() == args.as_ref().len(),
+        ShapeMismatch: "number of placeholders should equal the number of arguments"
+    );
+    if !template.is_plain() {
+        return Ok(Expr::Function {
+            input: args.as_ref().to_vec(),
+            function: StringFunction::Format(template).into(),
+        });
+    }
+
+    let mut args = args.as_ref().iter().cloned();
+    let exprs: Vec<Expr> = template
+        .segments()
+        .iter()
+        .map(|segment| match segment {
+            FormatSegment::Literal(s) => lit(s.to_string()),
+            FormatSegment::Placeholder(_) => args.next().unwrap(),
+        })
+        .collect();
+    Ok(concat_str(exprs, "", false))
+}
+
 /// Concat lists entries.
 pub fn concat_list<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(s: E) -> PolarsResult<Expr> {
     let s: Vec<_> = s.as_ref().iter().map(|e| e.clone().into()).collect();
@@ -215,6 +215,18 @@ impl ListNameSpace {
             .map_ternary(FunctionExpr::ListExpr(ListFunction::Slice), offset, length)
     }
 
+    #[cfg(feature = "list_pad")]
+    /// Pad every sublist at the end with `fill_value` until it has `length` elements.
+    ///
+    /// Sublists that already have `length` or more elements are left as they are.
+    pub fn pad_end(self, length: Expr, fill_value: Expr) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::ListExpr(ListFunction::PadEnd),
+            length,
+            fill_value,
+        )
+    }
+
     /// Get the head of every sublist
     pub fn head(self, n: Expr) -> Expr {
         self.slice(lit(0), n)
@@ -232,6 +244,28 @@ impl ListNameSpace {
             .map_unary(FunctionExpr::ListExpr(ListFunction::ToArray(width)))
     }
 
+    #[cfg(feature = "list_flatten")]
+    /// Remove `depth` levels of nesting by concatenating the nested lists of every sublist.
+    ///
+    /// If `depth` is `None`, all nested `List` and `Array` levels are removed. Null nested lists
+    /// are skipped.
+    pub fn flatten(self, depth: Option<usize>) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::Flatten(depth)))
+    }
+
+    #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+    /// Reshape every sublist into a (nested) Array column of the given `dimensions`.
+    ///
+    /// Nested lists are flattened first, so every sublist must contain exactly as many values as
+    /// the product of the `dimensions`.
+    pub fn reshape(self, dimensions: &[usize]) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::Reshape(
+                dimensions.to_vec(),
+            )))
+    }
+
     #[cfg(feature = "list_to_struct")]
     #[allow(clippy::wrong_self_convention)]
     /// Convert this `List` to a `Series` of type `Struct`. The width will be determined according to
@@ -256,6 +290,16 @@ impl ListNameSpace {
         )
     }
 
+    #[cfg(feature = "list_index_of")]
+    /// Get the index of the first occurrence of `element` in every sublist, or null if it
+    /// does not occur.
+    pub fn index_of<E: Into<Expr>>(self, element: E) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ListExpr(ListFunction::IndexOf),
+            element.into(),
+        )
+    }
+
     #[cfg(feature = "list_count")]
     /// Count how often the value produced by ``element`` occurs.
     pub fn count_matches<E: Into<Expr>>(self, element: E) -> Expr {
@@ -297,6 +341,16 @@ impl ListNameSpace {
         self.set_operation(other.into(), SetOperation::SymmetricDifference)
     }
 
+    #[cfg(feature = "list_zip")]
+    /// Zip every sublist with the sublist of `other` at the same row into a list of structs
+    /// with the fields `field_0` and `field_1`.
+    ///
+    /// The output sublists are truncated to the length of the shorter input.
+    pub fn zip<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::Zip), other.into())
+    }
+
     pub fn eval<E: Into<Expr>>(self, other: E) -> Expr {
         Expr::Eval {
             expr: Arc::new(self.0),
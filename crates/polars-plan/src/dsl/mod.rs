@@ -1074,11 +1074,19 @@ impl Expr {
     }
 
     #[cfg(feature = "interpolate_by")]
-    /// Interpolate intermediate values.
+    /// Interpolate intermediate values linearly.
     /// Nulls at the beginning and end of the series remain null.
     /// The `by` column provides the x-coordinates for interpolation and must not contain nulls.
     pub fn interpolate_by(self, by: Expr) -> Expr {
-        self.map_binary(FunctionExpr::InterpolateBy, by)
+        self.interpolate_by_with_method(by, InterpolationMethod::Linear)
+    }
+
+    #[cfg(feature = "interpolate_by")]
+    /// Interpolate intermediate values with the given method.
+    /// Nulls at the beginning and end of the series remain null.
+    /// The `by` column provides the x-coordinates for interpolation and must not contain nulls.
+    pub fn interpolate_by_with_method(self, by: Expr, method: InterpolationMethod) -> Expr {
+        self.map_binary(FunctionExpr::InterpolateBy(method), by)
     }
 
     #[cfg(feature = "rolling_window")]
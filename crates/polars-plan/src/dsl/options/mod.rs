@@ -8,12 +8,14 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
-use polars_io::json::JsonWriterOptions;
+use polars_io::json::{JsonPath, JsonWriterOptions};
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "iejoin")]
@@ -24,6 +26,8 @@ use polars_ops::prelude::{JoinArgs, JoinType};
 use polars_time::DynamicGroupOptions;
 #[cfg(feature = "dynamic_group_by")]
 use polars_time::RollingGroupOptions;
+#[cfg(feature = "dynamic_group_by")]
+use polars_time::SessionGroupOptions;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
@@ -289,6 +293,8 @@ pub struct GroupbyOptions {
     pub dynamic: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     pub rolling: Option<RollingGroupOptions>,
+    #[cfg(feature = "dynamic_group_by")]
+    pub session: Option<SessionGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
 }
@@ -315,6 +321,17 @@ impl GroupbyOptions {
             false
         }
     }
+
+    pub(crate) fn is_session(&self) -> bool {
+        #[cfg(feature = "dynamic_group_by")]
+        {
+            self.session.is_some()
+        }
+        #[cfg(not(feature = "dynamic_group_by"))]
+        {
+            false
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -361,6 +378,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileType {
@@ -374,6 +393,8 @@ impl FileType {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::Json(_) => "jsonl",
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -436,3 +457,18 @@ pub struct NDJsonReadOptions {
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
 }
+
+/// Options for scanning (non-newline-delimited) JSON documents.
+///
+/// The rows of a document are the elements of the array at `json_path`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[cfg(feature = "json")]
+pub struct JsonReadOptions {
+    pub json_path: JsonPath,
+    pub infer_schema_length: Option<NonZeroUsize>,
+    pub ignore_errors: bool,
+    pub schema: Option<SchemaRef>,
+    pub schema_overwrite: Option<SchemaRef>,
+}
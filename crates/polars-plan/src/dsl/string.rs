@@ -144,6 +144,29 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Base64Decode(strict))
     }
 
+    #[cfg(feature = "string_url")]
+    /// Parse each string as a URL into a struct of its scheme, host, port, path, query
+    /// and fragment. Invalid URLs raise an error if `strict`, and become null otherwise.
+    pub fn parse_url(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::ParseUrl(strict))
+    }
+
+    #[cfg(feature = "string_url")]
+    /// Extract the decoded value of the query parameter `name` from each URL.
+    pub fn url_query_param(self, name: Expr) -> Expr {
+        self.0.map_binary(StringFunction::UrlQueryParam, name)
+    }
+
+    #[cfg(feature = "string_url")]
+    pub fn percent_encode(self) -> Expr {
+        self.0.map_unary(StringFunction::PercentEncode)
+    }
+
+    #[cfg(feature = "string_url")]
+    pub fn percent_decode(self, strict: bool) -> Expr {
+        self.0.map_unary(StringFunction::PercentDecode(strict))
+    }
+
     /// Extract a regex pattern from the a string value. If `group_index` is out of bounds, null is returned.
     pub fn extract(self, pat: Expr, group_index: usize) -> Expr {
         self.0.map_binary(StringFunction::Extract(group_index), pat)
@@ -356,6 +379,48 @@ impl StringNameSpace {
         self.0.map_binary(StringFunction::SplitN(n), by)
     }
 
+    #[cfg(feature = "regex")]
+    /// Split the string on the matches of a regex pattern. The resulting dtype is `List<String>`.
+    pub fn split_regex(self, pat: Expr) -> Expr {
+        self.0.map_binary(StringFunction::SplitRegex(false), pat)
+    }
+
+    #[cfg(feature = "regex")]
+    /// Split the string on the matches of a regex pattern and keep the matches.
+    /// The resulting dtype is `List<String>`.
+    pub fn split_regex_inclusive(self, pat: Expr) -> Expr {
+        self.0.map_binary(StringFunction::SplitRegex(true), pat)
+    }
+
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    /// Split exactly `n` times on the matches of a regex pattern. The resulting dtype is
+    /// [`DataType::Struct`].
+    pub fn split_exact_regex(self, pat: Expr, n: usize) -> Expr {
+        self.0.map_binary(
+            StringFunction::SplitExactRegex {
+                n,
+                inclusive: false,
+            },
+            pat,
+        )
+    }
+
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    /// Split exactly `n` times on the matches of a regex pattern and keep the matches.
+    /// The resulting dtype is [`DataType::Struct`].
+    pub fn split_exact_regex_inclusive(self, pat: Expr, n: usize) -> Expr {
+        self.0
+            .map_binary(StringFunction::SplitExactRegex { n, inclusive: true }, pat)
+    }
+
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    /// Split on the matches of a regex pattern, returning exactly `n` items. If there are more
+    /// possible splits, keeps the remainder of the string intact. The resulting dtype is
+    /// [`DataType::Struct`].
+    pub fn splitn_regex(self, pat: Expr, n: usize) -> Expr {
+        self.0.map_binary(StringFunction::SplitNRegex(n), pat)
+    }
+
     #[cfg(feature = "regex")]
     /// Replace values that match a regex `pat` with a `value`.
     pub fn replace(self, pat: Expr, value: Expr, literal: bool) -> Expr {
@@ -389,6 +454,39 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "string_similarity")]
+    /// Levenshtein distance to the strings of `other`, counted in characters.
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Levenshtein, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Damerau-Levenshtein distance to the strings of `other`, counted in
+    /// characters, where transposing two characters is a single edit.
+    pub fn damerau_levenshtein(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::DamerauLevenshtein, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaro-Winkler similarity to the strings of `other`, between 0 and 1.
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::JaroWinkler, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Hamming distance to the strings of `other`, counted in characters.
+    /// Null where the strings differ in length.
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::Hamming, other)
+    }
+
+    #[cfg(feature = "string_similarity")]
+    /// Jaccard similarity between the sets of character n-grams of each string
+    /// and of the strings of `other`.
+    pub fn jaccard(self, other: Expr, ngram: usize) -> Expr {
+        self.0.map_binary(StringFunction::Jaccard { ngram }, other)
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
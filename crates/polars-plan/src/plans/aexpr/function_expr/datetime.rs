@@ -20,6 +20,10 @@ pub enum IRTemporalFunction {
     IsLeapYear,
     IsoYear,
     Quarter,
+    #[cfg(feature = "dtype-date")]
+    FiscalYear(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalQuarter(FiscalCalendar),
     Month,
     DaysInMonth,
     Week,
@@ -58,6 +62,10 @@ pub enum IRTemporalFunction {
     ConvertTimeZone(TimeZone),
     TimeStamp(TimeUnit),
     Truncate,
+    #[cfg(feature = "dtype-date")]
+    TruncateFiscal(FiscalCalendar),
+    #[cfg(feature = "dynamic_group_by")]
+    SessionId(Duration),
     #[cfg(feature = "offset_by")]
     OffsetBy,
     #[cfg(feature = "month_start")]
@@ -85,6 +93,10 @@ impl IRTemporalFunction {
         match self {
             Millennium | Century | Year | IsoYear => mapper.with_dtype(DataType::Int32),
             OrdinalDay => mapper.with_dtype(DataType::Int16),
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(_) => mapper.with_dtype(DataType::Int32),
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(_) => mapper.with_dtype(DataType::Int8),
             Month | DaysInMonth | Quarter | Week | WeekDay | Day | Hour | Minute | Second => {
                 mapper.with_dtype(DataType::Int8)
             },
@@ -114,6 +126,10 @@ impl IRTemporalFunction {
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
             Truncate => mapper.with_same_dtype(),
+            #[cfg(feature = "dtype-date")]
+            TruncateFiscal(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "dynamic_group_by")]
+            SessionId(_) => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "offset_by")]
             OffsetBy => mapper.with_same_dtype(),
             #[cfg(feature = "month_start")]
@@ -183,6 +199,10 @@ impl IRTemporalFunction {
             | T::TotalNanoseconds => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
             T::ConvertTimeZone(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-date")]
+            T::FiscalYear(_) | T::FiscalQuarter(_) | T::TruncateFiscal(_) => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "month_start")]
             T::MonthStart => FunctionOptions::elementwise(),
             #[cfg(feature = "month_end")]
@@ -190,6 +210,8 @@ impl IRTemporalFunction {
             #[cfg(feature = "timezones")]
             T::BaseUtcOffset | T::DSTOffset => FunctionOptions::elementwise(),
             T::Truncate => FunctionOptions::elementwise(),
+            #[cfg(feature = "dynamic_group_by")]
+            T::SessionId(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "offset_by")]
             T::OffsetBy => FunctionOptions::elementwise(),
             T::Round => FunctionOptions::elementwise(),
@@ -216,6 +238,10 @@ impl Display for IRTemporalFunction {
             IsLeapYear => "is_leap_year",
             IsoYear => "iso_year",
             Quarter => "quarter",
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(_) => "fiscal_year",
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(_) => "fiscal_quarter",
             Month => "month",
             DaysInMonth => "days_in_month",
             Week => "week",
@@ -254,6 +280,10 @@ impl Display for IRTemporalFunction {
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
             Truncate => "truncate",
+            #[cfg(feature = "dtype-date")]
+            TruncateFiscal(_) => "truncate_fiscal",
+            #[cfg(feature = "dynamic_group_by")]
+            SessionId(_) => "session_id",
             #[cfg(feature = "offset_by")]
             OffsetBy => "offset_by",
             #[cfg(feature = "month_start")]
@@ -313,6 +343,18 @@ pub(super) fn quarter(s: &Column) -> PolarsResult<Column> {
         .quarter()
         .map(|ca| ca.into_column())
 }
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_year(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_year(calendar)
+        .map(|ca| ca.into_column())
+}
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_quarter(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .fiscal_quarter(calendar)
+        .map(|ca| ca.into_column())
+}
 pub(super) fn week(s: &Column) -> PolarsResult<Column> {
     s.as_materialized_series().week().map(|ca| ca.into_column())
 }
@@ -539,6 +581,40 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
     Ok(out)
 }
 
+#[cfg(feature = "dtype-date")]
+pub(super) fn truncate_fiscal(s: &[Column], calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    let time_series = &s[0];
+    let every = s[1].str()?;
+
+    let mut out = match time_series.dtype() {
+        DataType::Datetime(_, tz) => match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => time_series
+                .datetime()?
+                .truncate_fiscal(tz.parse::<Tz>().ok().as_ref(), every, calendar)?
+                .into_column(),
+            _ => time_series
+                .datetime()?
+                .truncate_fiscal(None, every, calendar)?
+                .into_column(),
+        },
+        DataType::Date => time_series
+            .date()?
+            .truncate_fiscal(None, every, calendar)?
+            .into_column(),
+        dt => polars_bail!(opq = truncate_fiscal, got = dt, expected = "date/datetime"),
+    };
+    out.set_sorted_flag(time_series.is_sorted_flag());
+    Ok(out)
+}
+
+#[cfg(feature = "dynamic_group_by")]
+pub(super) fn session_id(s: &Column, gap: Duration) -> PolarsResult<Column> {
+    s.as_materialized_series()
+        .session_id(gap)
+        .map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "offset_by")]
 pub(super) fn offset_by(s: &[Column]) -> PolarsResult<Column> {
     impl_offset_by(s[0].as_materialized_series(), s[1].as_materialized_series()).map(Column::from)
@@ -41,10 +41,10 @@ pub(super) fn interpolate(s: &Column, method: InterpolationMethod) -> PolarsResu
 }
 
 #[cfg(feature = "interpolate_by")]
-pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
+pub(super) fn interpolate_by(s: &[Column], method: InterpolationMethod) -> PolarsResult<Column> {
     let by = &s[1];
     let by_is_sorted = by.as_materialized_series().is_sorted(Default::default())?;
-    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
+    polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted, method)
 }
 
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
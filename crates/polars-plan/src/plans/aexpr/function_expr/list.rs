@@ -1,6 +1,8 @@
 use arrow::legacy::utils::CustomIterTools;
 use polars_core::utils::SuperTypeOptions;
 use polars_ops::chunked_array::list::*;
+#[cfg(feature = "list_zip")]
+use polars_utils::format_pl_smallstr;
 
 use super::*;
 use crate::{map, map_as_slice, wrap};
@@ -13,6 +15,8 @@ pub enum IRListFunction {
     Contains {
         nulls_equal: bool,
     },
+    #[cfg(feature = "list_index_of")]
+    IndexOf,
     #[cfg(feature = "list_drop_nulls")]
     DropNulls,
     #[cfg(feature = "list_sample")]
@@ -24,6 +28,8 @@ pub enum IRListFunction {
     },
     Slice,
     Shift,
+    #[cfg(feature = "list_pad")]
+    PadEnd,
     Get(bool),
     #[cfg(feature = "list_gather")]
     Gather(bool),
@@ -57,10 +63,16 @@ pub enum IRListFunction {
     #[cfg(feature = "list_any_all")]
     All,
     Join(bool),
+    #[cfg(feature = "list_flatten")]
+    Flatten(Option<usize>),
+    #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+    Reshape(Vec<usize>),
     #[cfg(feature = "dtype-array")]
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStruct),
+    #[cfg(feature = "list_zip")]
+    Zip,
 }
 
 impl IRListFunction {
@@ -70,12 +82,16 @@ impl IRListFunction {
             Concat => mapper.map_to_list_supertype(),
             #[cfg(feature = "is_in")]
             Contains { nulls_equal: _ } => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "list_index_of")]
+            IndexOf => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => mapper.with_same_dtype(),
             #[cfg(feature = "list_sample")]
             Sample { .. } => mapper.with_same_dtype(),
             Slice => mapper.with_same_dtype(),
             Shift => mapper.with_same_dtype(),
+            #[cfg(feature = "list_pad")]
+            PadEnd => mapper.with_same_dtype(),
             Get(_) => mapper.map_to_list_and_array_inner_dtype(),
             #[cfg(feature = "list_gather")]
             Gather(_) => mapper.with_same_dtype(),
@@ -124,6 +140,10 @@ impl IRListFunction {
             #[cfg(feature = "list_any_all")]
             All => mapper.with_dtype(DataType::Boolean),
             Join(_) => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "list_flatten")]
+            Flatten(depth) => mapper.try_map_dtype(|dt| list_flatten_dtype(dt, *depth)),
+            #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+            Reshape(dimensions) => mapper.try_map_dtype(|dt| list_reshape_dtype(dt, dimensions)),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => mapper.try_map_dtype(|dt| map_list_dtype_to_array_dtype(dt, *width)),
             NUnique => mapper.with_dtype(IDX_DTYPE),
@@ -161,6 +181,21 @@ impl IRListFunction {
                     ListToStruct::InferWidth { .. } => Ok(DataType::Unknown(UnknownKind::Any)),
                 }
             }),
+            #[cfg(feature = "list_zip")]
+            Zip => mapper.try_map_dtypes(|dtypes| {
+                let fields = dtypes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, dtype)| match dtype {
+                        DataType::List(inner) => Ok(Field::new(
+                            format_pl_smallstr!("field_{i}"),
+                            inner.as_ref().clone(),
+                        )),
+                        dt => polars_bail!(op = "list.zip", got = dt, expected = "List"),
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                Ok(DataType::List(Box::new(DataType::Struct(fields))))
+            }),
         }
     }
 
@@ -171,6 +206,12 @@ impl IRListFunction {
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             #[cfg(feature = "is_in")]
             L::Contains { nulls_equal: _ } => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_index_of")]
+            L::IndexOf => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_pad")]
+            L::PadEnd => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_zip")]
+            L::Zip => FunctionOptions::elementwise(),
             #[cfg(feature = "list_sample")]
             L::Sample { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "list_gather")]
@@ -209,6 +250,10 @@ impl IRListFunction {
             | L::NUnique => FunctionOptions::elementwise(),
             #[cfg(feature = "list_any_all")]
             L::Any | L::All => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_flatten")]
+            L::Flatten(_) => FunctionOptions::elementwise(),
+            #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+            L::Reshape(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-array")]
             L::ToArray(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "list_to_struct")]
@@ -236,6 +281,8 @@ impl Display for IRListFunction {
             Concat => "concat",
             #[cfg(feature = "is_in")]
             Contains { nulls_equal: _ } => "contains",
+            #[cfg(feature = "list_index_of")]
+            IndexOf => "index_of",
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => "drop_nulls",
             #[cfg(feature = "list_sample")]
@@ -248,6 +295,8 @@ impl Display for IRListFunction {
             },
             Slice => "slice",
             Shift => "shift",
+            #[cfg(feature = "list_pad")]
+            PadEnd => "pad_end",
             Get(_) => "get",
             #[cfg(feature = "list_gather")]
             Gather(_) => "gather",
@@ -284,10 +333,16 @@ impl Display for IRListFunction {
             #[cfg(feature = "list_any_all")]
             All => "all",
             Join(_) => "join",
+            #[cfg(feature = "list_flatten")]
+            Flatten(_) => "flatten",
+            #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+            Reshape(_) => "reshape",
             #[cfg(feature = "dtype-array")]
             ToArray(_) => "to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "list_zip")]
+            Zip => "zip",
         };
         write!(f, "list.{name}")
     }
@@ -300,6 +355,8 @@ impl From<IRListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Concat => wrap!(concat),
             #[cfg(feature = "is_in")]
             Contains { nulls_equal } => map_as_slice!(contains, nulls_equal),
+            #[cfg(feature = "list_index_of")]
+            IndexOf => map_as_slice!(index_of),
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => map!(drop_nulls),
             #[cfg(feature = "list_sample")]
@@ -317,6 +374,8 @@ impl From<IRListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             },
             Slice => wrap!(slice),
             Shift => map_as_slice!(shift),
+            #[cfg(feature = "list_pad")]
+            PadEnd => map_as_slice!(pad_end),
             Get(null_on_oob) => wrap!(get, null_on_oob),
             #[cfg(feature = "list_gather")]
             Gather(null_on_oob) => map_as_slice!(gather, null_on_oob),
@@ -346,11 +405,17 @@ impl From<IRListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "list_any_all")]
             All => map!(lst_all),
             Join(ignore_nulls) => map_as_slice!(join, ignore_nulls),
+            #[cfg(feature = "list_flatten")]
+            Flatten(depth) => map!(flatten, depth),
+            #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+            Reshape(dimensions) => map!(reshape, &dimensions),
             #[cfg(feature = "dtype-array")]
             ToArray(width) => map!(to_array, width),
             NUnique => map!(n_unique),
             #[cfg(feature = "list_to_struct")]
             ToStruct(args) => map!(to_struct, &args),
+            #[cfg(feature = "list_zip")]
+            Zip => map_as_slice!(zip),
         }
     }
 }
@@ -371,6 +436,15 @@ pub(super) fn contains(args: &mut [Column], nulls_equal: bool) -> PolarsResult<C
     Ok(ca.into_column())
 }
 
+#[cfg(feature = "list_index_of")]
+pub(super) fn index_of(args: &[Column]) -> PolarsResult<Column> {
+    let list = args[0].list()?;
+    let needle = &args[1];
+    Ok(list
+        .lst_index_of(needle.as_materialized_series())?
+        .into_column())
+}
+
 #[cfg(feature = "list_drop_nulls")]
 pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
     let list = s.list()?;
@@ -425,6 +499,18 @@ pub(super) fn shift(s: &[Column]) -> PolarsResult<Column> {
     list.lst_shift(periods).map(|ok| ok.into_column())
 }
 
+#[cfg(feature = "list_pad")]
+pub(super) fn pad_end(args: &[Column]) -> PolarsResult<Column> {
+    let list = args[0].list()?;
+    let length = &args[1];
+    let fill = &args[2];
+    list.lst_pad_end(
+        length.as_materialized_series(),
+        fill.as_materialized_series(),
+    )
+    .map(|ok| ok.into_column())
+}
+
 pub(super) fn slice(args: &mut [Column]) -> PolarsResult<Column> {
     let s = &args[0];
     let list_ca = s.list()?;
@@ -686,6 +772,16 @@ pub(super) fn join(s: &[Column], ignore_nulls: bool) -> PolarsResult<Column> {
     Ok(ca.lst_join(separator, ignore_nulls)?.into_column())
 }
 
+#[cfg(feature = "list_flatten")]
+pub(super) fn flatten(s: &Column, depth: Option<usize>) -> PolarsResult<Column> {
+    Ok(list_flatten(s.list()?, depth)?.into_column())
+}
+
+#[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+pub(super) fn reshape(s: &Column, dimensions: &[usize]) -> PolarsResult<Column> {
+    Ok(list_reshape(s.list()?, dimensions)?.into_column())
+}
+
 #[cfg(feature = "dtype-array")]
 pub(super) fn to_array(s: &Column, width: usize) -> PolarsResult<Column> {
     let array_dtype = map_list_dtype_to_array_dtype(s.dtype(), width)?;
@@ -719,3 +815,10 @@ pub(super) fn to_struct(s: &Column, args: &ListToStruct) -> PolarsResult<Column>
 pub(super) fn n_unique(s: &Column) -> PolarsResult<Column> {
     Ok(s.list()?.lst_n_unique()?.into_column())
 }
+
+#[cfg(feature = "list_zip")]
+pub(super) fn zip(args: &[Column]) -> PolarsResult<Column> {
+    let list = args[0].list()?;
+    let other = args[1].list()?;
+    Ok(list.lst_zip(other)?.into_column())
+}
@@ -274,7 +274,7 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "interpolate")]
     Interpolate(InterpolationMethod),
     #[cfg(feature = "interpolate_by")]
-    InterpolateBy,
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -469,7 +469,7 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {},
+            InterpolateBy(m) => m.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -836,7 +836,7 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => "interpolate_by",
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -1225,8 +1225,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map!(dispatch::interpolate, method)
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => {
-                map_as_slice!(dispatch::interpolate_by)
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
             },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -1528,7 +1528,7 @@ impl IRFunctionExpr {
             #[cfg(feature = "interpolate")]
             F::Interpolate(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate_by")]
-            F::InterpolateBy => FunctionOptions::length_preserving(),
+            F::InterpolateBy(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "log")]
             F::Log { .. } | F::Log1p | F::Exp => FunctionOptions::elementwise(),
             #[cfg(feature = "log")]
@@ -221,11 +221,16 @@ impl IRFunctionExpr {
             }),
             #[cfg(feature = "interpolate")]
             Interpolate(method) => match method {
-                InterpolationMethod::Linear => mapper.map_numeric_to_float_dtype(false),
-                InterpolationMethod::Nearest => mapper.with_same_dtype(),
+                InterpolationMethod::Linear
+                | InterpolationMethod::Cubic
+                | InterpolationMethod::Akima
+                | InterpolationMethod::Pchip => mapper.map_numeric_to_float_dtype(false),
+                InterpolationMethod::Nearest
+                | InterpolationMethod::Previous
+                | InterpolationMethod::Next => mapper.with_same_dtype(),
             },
             #[cfg(feature = "interpolate_by")]
-            InterpolateBy => mapper.map_numeric_to_float_dtype(true),
+            InterpolateBy(_) => mapper.map_numeric_to_float_dtype(true),
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
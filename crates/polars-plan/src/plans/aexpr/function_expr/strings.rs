@@ -28,6 +28,8 @@ pub enum IRStringFunction {
         delimiter: PlSmallStr,
         ignore_nulls: bool,
     },
+    #[cfg(feature = "string_format")]
+    Format(FormatTemplate),
     #[cfg(feature = "regex")]
     Contains {
         literal: bool,
@@ -75,6 +77,18 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "string_reverse")]
     Reverse,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein,
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein,
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    Hamming,
+    #[cfg(feature = "string_similarity")]
+    Jaccard {
+        ngram: usize,
+    },
     #[cfg(feature = "string_pad")]
     PadStart {
         fill_char: char,
@@ -94,6 +108,14 @@ pub enum IRStringFunction {
     Base64Encode,
     #[cfg(feature = "binary_encoding")]
     Base64Decode(bool),
+    #[cfg(feature = "string_url")]
+    ParseUrl(bool),
+    #[cfg(feature = "string_url")]
+    UrlQueryParam,
+    #[cfg(feature = "string_url")]
+    PercentEncode,
+    #[cfg(feature = "string_url")]
+    PercentDecode(bool),
     StartsWith,
     StripChars,
     StripCharsStart,
@@ -107,10 +129,19 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "dtype-struct")]
     SplitN(usize),
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    SplitExactRegex {
+        n: usize,
+        inclusive: bool,
+    },
+    #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+    SplitNRegex(usize),
     #[cfg(feature = "temporal")]
     // DataType can only be Date/Datetime/Time
     Strptime(DataType, StrptimeOptions),
     Split(bool),
+    #[cfg(feature = "regex")]
+    SplitRegex(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
     #[cfg(feature = "nightly")]
@@ -146,6 +177,8 @@ impl IRStringFunction {
         match self {
             #[cfg(feature = "concat_str")]
             ConcatVertical { .. } | ConcatHorizontal { .. } => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "string_format")]
+            Format(_) => mapper.with_dtype(DataType::String),
             #[cfg(feature = "regex")]
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatches(_) => mapper.with_dtype(DataType::UInt32),
@@ -170,6 +203,10 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein | DamerauLevenshtein | Hamming => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler | Jaccard { .. } => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -188,6 +225,8 @@ impl IRStringFunction {
                 _ => mapper.with_dtype(dtype.clone()),
             },
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "regex")]
+            SplitRegex(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-decimal")]
@@ -200,6 +239,14 @@ impl IRStringFunction {
             Base64Encode => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "string_url")]
+            ParseUrl(_) => mapper.with_dtype(DataType::Struct(
+                polars_ops::chunked_array::strings::url_fields(),
+            )),
+            #[cfg(feature = "string_url")]
+            UrlQueryParam | PercentEncode => mapper.with_same_dtype(),
+            #[cfg(feature = "string_url")]
+            PercentDecode(_) => mapper.with_dtype(DataType::Binary),
             Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
             | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
@@ -210,12 +257,24 @@ impl IRStringFunction {
                     .map(|i| Field::new(format_pl_smallstr!("field_{i}"), DataType::String))
                     .collect(),
             )),
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitExactRegex { n, .. } => mapper.with_dtype(DataType::Struct(
+                (0..n + 1)
+                    .map(|i| Field::new(format_pl_smallstr!("field_{i}"), DataType::String))
+                    .collect(),
+            )),
             #[cfg(feature = "dtype-struct")]
             SplitN(n) => mapper.with_dtype(DataType::Struct(
                 (0..*n)
                     .map(|i| Field::new(format_pl_smallstr!("field_{i}"), DataType::String))
                     .collect(),
             )),
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitNRegex(n) => mapper.with_dtype(DataType::Struct(
+                (0..*n)
+                    .map(|i| Field::new(format_pl_smallstr!("field_{i}"), DataType::String))
+                    .collect(),
+            )),
             #[cfg(feature = "find_many")]
             ContainsAny { .. } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "find_many")]
@@ -237,6 +296,8 @@ impl IRStringFunction {
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             #[cfg(feature = "concat_str")]
             S::ConcatVertical { .. } => FunctionOptions::aggregation(),
+            #[cfg(feature = "string_format")]
+            S::Format(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
             S::Contains { .. } => {
                 FunctionOptions::elementwise().with_supertyping(Default::default())
@@ -268,11 +329,21 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::Levenshtein
+            | S::DamerauLevenshtein
+            | S::JaroWinkler
+            | S::Hamming
+            | S::Jaccard { .. } => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, _) => FunctionOptions::elementwise_with_infer(),
             S::Split(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "regex")]
+            S::SplitRegex(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "nightly")]
             S::Titlecase => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-decimal")]
@@ -281,6 +352,12 @@ impl IRStringFunction {
             S::HexEncode | S::Base64Encode => FunctionOptions::elementwise(),
             #[cfg(feature = "binary_encoding")]
             S::HexDecode(_) | S::Base64Decode(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_url")]
+            S::ParseUrl(_) | S::PercentEncode | S::PercentDecode(_) => {
+                FunctionOptions::elementwise()
+            },
+            #[cfg(feature = "string_url")]
+            S::UrlQueryParam => FunctionOptions::elementwise().with_supertyping(Default::default()),
             S::Uppercase | S::Lowercase => FunctionOptions::elementwise(),
             S::StripChars
             | S::StripCharsStart
@@ -296,6 +373,8 @@ impl IRStringFunction {
             S::SplitExact { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-struct")]
             S::SplitN(_) => FunctionOptions::elementwise(),
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            S::SplitExactRegex { .. } | S::SplitNRegex(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "find_many")]
             S::ContainsAny { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "find_many")]
@@ -323,6 +402,8 @@ impl Display for IRStringFunction {
             ConcatHorizontal { .. } => "concat_horizontal",
             #[cfg(feature = "concat_str")]
             ConcatVertical { .. } => "concat_vertical",
+            #[cfg(feature = "string_format")]
+            Format(_) => "format",
             ExtractAll => "extract_all",
             #[cfg(feature = "extract_groups")]
             ExtractGroups { .. } => "extract_groups",
@@ -349,6 +430,16 @@ impl Display for IRStringFunction {
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            Hamming => "hamming",
+            #[cfg(feature = "string_similarity")]
+            Jaccard { .. } => "jaccard",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -357,6 +448,14 @@ impl Display for IRStringFunction {
             Base64Encode => "base64_encode",
             #[cfg(feature = "binary_encoding")]
             Base64Decode(_) => "base64_decode",
+            #[cfg(feature = "string_url")]
+            ParseUrl(_) => "parse_url",
+            #[cfg(feature = "string_url")]
+            UrlQueryParam => "url_query_param",
+            #[cfg(feature = "string_url")]
+            PercentEncode => "percent_encode",
+            #[cfg(feature = "string_url")]
+            PercentDecode(_) => "percent_decode",
             Slice => "slice",
             StartsWith => "starts_with",
             StripChars => "strip_chars",
@@ -374,6 +473,16 @@ impl Display for IRStringFunction {
             },
             #[cfg(feature = "dtype-struct")]
             SplitN(_) => "splitn",
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitExactRegex { inclusive, .. } => {
+                if *inclusive {
+                    "split_exact_regex_inclusive"
+                } else {
+                    "split_exact_regex"
+                }
+            },
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitNRegex(_) => "splitn_regex",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
             Split(inclusive) => {
@@ -383,6 +492,14 @@ impl Display for IRStringFunction {
                     "split"
                 }
             },
+            #[cfg(feature = "regex")]
+            SplitRegex(inclusive) => {
+                if *inclusive {
+                    "split_regex_inclusive"
+                } else {
+                    "split_regex"
+                }
+            },
             #[cfg(feature = "nightly")]
             Titlecase => "titlecase",
             #[cfg(feature = "dtype-decimal")]
@@ -451,11 +568,21 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
             #[cfg(feature = "dtype-struct")]
             SplitN(n) => map_as_slice!(strings::splitn, n),
+            #[cfg(feature = "regex")]
+            SplitRegex(inclusive) => map_as_slice!(strings::split_regex, inclusive),
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitExactRegex { n, inclusive } => {
+                map_as_slice!(strings::split_exact_regex, n, inclusive)
+            },
+            #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+            SplitNRegex(n) => map_as_slice!(strings::splitn_regex, n),
             #[cfg(feature = "concat_str")]
             ConcatVertical {
                 delimiter,
                 ignore_nulls,
             } => map!(strings::join, &delimiter, ignore_nulls),
+            #[cfg(feature = "string_format")]
+            Format(template) => map_as_slice!(strings::format, &template),
             #[cfg(feature = "concat_str")]
             ConcatHorizontal {
                 delimiter,
@@ -467,6 +594,16 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Normalize { form } => map!(strings::normalize, form.clone()),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein => map_as_slice!(strings::damerau_levenshtein),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_similarity")]
+            Hamming => map_as_slice!(strings::hamming),
+            #[cfg(feature = "string_similarity")]
+            Jaccard { ngram } => map_as_slice!(strings::jaccard, ngram),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -491,6 +628,14 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Encode => map!(strings::base64_encode),
             #[cfg(feature = "binary_encoding")]
             Base64Decode(strict) => map!(strings::base64_decode, strict),
+            #[cfg(feature = "string_url")]
+            ParseUrl(strict) => map!(strings::parse_url, strict),
+            #[cfg(feature = "string_url")]
+            UrlQueryParam => map_as_slice!(strings::url_query_param),
+            #[cfg(feature = "string_url")]
+            PercentEncode => map!(strings::percent_encode),
+            #[cfg(feature = "string_url")]
+            PercentDecode(strict) => map!(strings::percent_decode, strict),
             #[cfg(feature = "dtype-decimal")]
             ToDecimal(infer_len) => map!(strings::to_decimal, infer_len),
             #[cfg(feature = "extract_jsonpath")]
@@ -826,6 +971,31 @@ pub(super) fn split(s: &[Column], inclusive: bool) -> PolarsResult<Column> {
     }
 }
 
+#[cfg(feature = "regex")]
+pub(super) fn split_regex(s: &[Column], inclusive: bool) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+
+    Ok(ca.split_regex(pat, inclusive)?.into_column())
+}
+
+#[cfg(all(feature = "regex", feature = "dtype-struct"))]
+pub(super) fn split_exact_regex(s: &[Column], n: usize, inclusive: bool) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+
+    ca.split_exact_regex(pat, n, inclusive)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(all(feature = "regex", feature = "dtype-struct"))]
+pub(super) fn splitn_regex(s: &[Column], n: usize) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+
+    ca.splitn_regex(pat, n).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "dtype-date")]
 fn to_date(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     let ca = s.str()?;
@@ -937,6 +1107,23 @@ pub(super) fn concat_hor(
     Ok(polars_ops::chunked_array::hor_str_concat(&cas, delimiter, ignore_nulls)?.into_column())
 }
 
+#[cfg(feature = "string_format")]
+pub(super) fn format(s: &[Column], template: &FormatTemplate) -> PolarsResult<Column> {
+    // Temporal values with a strftime spec are formatted up front, as polars-ops cannot.
+    #[cfg(feature = "temporal")]
+    let s = &s
+        .iter()
+        .zip(template.placeholders())
+        .map(|(c, placeholder)| match placeholder {
+            FormatPlaceholder::Strftime(fmt) if c.dtype().is_temporal() => {
+                TemporalMethods::to_string(c.as_materialized_series(), fmt).map(Column::from)
+            },
+            _ => Ok(c.clone()),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(template.render(s)?.into_column())
+}
+
 impl From<IRStringFunction> for IRFunctionExpr {
     fn from(str: IRStringFunction) -> Self {
         IRFunctionExpr::StringExpr(str)
@@ -1128,6 +1315,46 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::levenshtein(ca, other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn damerau_levenshtein(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "damerau_levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::damerau_levenshtein(ca, other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::jaro_winkler(ca, other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::hamming(ca, other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaccard(s: &[Column], ngram: usize) -> PolarsResult<Column> {
+    _check_same_length(s, "jaccard")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::jaccard(ca, other, ngram)?.into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
@@ -1203,6 +1430,29 @@ pub(super) fn base64_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
     s.str()?.base64_decode(strict).map(|ca| ca.into_column())
 }
 
+#[cfg(feature = "string_url")]
+pub(super) fn parse_url(s: &Column, strict: bool) -> PolarsResult<Column> {
+    polars_ops::chunked_array::strings::parse_url(s.str()?, strict).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn url_query_param(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "url_query_param")?;
+    let ca = s[0].str()?;
+    let name = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::url_query_param(ca, name).into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn percent_encode(s: &Column) -> PolarsResult<Column> {
+    Ok(polars_ops::chunked_array::strings::percent_encode(s.str()?).into_column())
+}
+
+#[cfg(feature = "string_url")]
+pub(super) fn percent_decode(s: &Column, strict: bool) -> PolarsResult<Column> {
+    polars_ops::chunked_array::strings::percent_decode(s.str()?, strict).map(|ca| ca.into_column())
+}
+
 #[cfg(feature = "dtype-decimal")]
 pub(super) fn to_decimal(s: &Column, infer_len: usize) -> PolarsResult<Column> {
     let ca = s.str()?;
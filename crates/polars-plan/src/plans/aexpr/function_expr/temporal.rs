@@ -13,6 +13,10 @@ impl From<IRTemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Month => map!(datetime::month),
             DaysInMonth => map!(datetime::days_in_month),
             Quarter => map!(datetime::quarter),
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(calendar) => map!(datetime::fiscal_year, &calendar),
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(calendar) => map!(datetime::fiscal_quarter, &calendar),
             Week => map!(datetime::week),
             WeekDay => map!(datetime::weekday),
             #[cfg(feature = "dtype-duration")]
@@ -51,6 +55,10 @@ impl From<IRTemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Truncate => {
                 map_as_slice!(datetime::truncate)
             },
+            #[cfg(feature = "dtype-date")]
+            TruncateFiscal(calendar) => map_as_slice!(datetime::truncate_fiscal, &calendar),
+            #[cfg(feature = "dynamic_group_by")]
+            SessionId(gap) => map!(datetime::session_id, gap),
             #[cfg(feature = "offset_by")]
             OffsetBy => {
                 map_as_slice!(datetime::offset_by)
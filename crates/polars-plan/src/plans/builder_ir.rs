@@ -292,6 +292,10 @@ impl<'a> IRBuilder<'a> {
                     schema.with_column("_upper_boundary".into(), dtype.clone());
                 }
                 schema.with_column(name.clone(), dtype.clone());
+            } else if let Some(options) = options.session.as_ref() {
+                let name = &options.index_column;
+                let dtype = current_schema.get(name).unwrap();
+                schema.with_column(name.clone(), dtype.clone());
             }
         }
 
@@ -150,6 +150,8 @@ pub(super) fn convert_functions(
                 L::Concat => IL::Concat,
                 #[cfg(feature = "is_in")]
                 L::Contains { nulls_equal } => IL::Contains { nulls_equal },
+                #[cfg(feature = "list_index_of")]
+                L::IndexOf => IL::IndexOf,
                 #[cfg(feature = "list_drop_nulls")]
                 L::DropNulls => IL::DropNulls,
                 #[cfg(feature = "list_sample")]
@@ -166,6 +168,8 @@ pub(super) fn convert_functions(
                 },
                 L::Slice => IL::Slice,
                 L::Shift => IL::Shift,
+                #[cfg(feature = "list_pad")]
+                L::PadEnd => IL::PadEnd,
                 L::Get(v) => IL::Get(v),
                 #[cfg(feature = "list_gather")]
                 L::Gather(v) => IL::Gather(v),
@@ -196,10 +200,16 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "list_any_all")]
                 L::All => IL::All,
                 L::Join(v) => IL::Join(v),
+                #[cfg(feature = "list_flatten")]
+                L::Flatten(depth) => IL::Flatten(depth),
+                #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+                L::Reshape(dimensions) => IL::Reshape(dimensions),
                 #[cfg(feature = "dtype-array")]
                 L::ToArray(v) => IL::ToArray(v),
                 #[cfg(feature = "list_to_struct")]
                 L::ToStruct(list_to_struct_args) => IL::ToStruct(list_to_struct_args),
+                #[cfg(feature = "list_zip")]
+                L::Zip => IL::Zip,
             })
         },
         #[cfg(feature = "strings")]
@@ -222,6 +232,8 @@ pub(super) fn convert_functions(
                     delimiter,
                     ignore_nulls,
                 },
+                #[cfg(feature = "string_format")]
+                S::Format(template) => IS::Format(template),
                 #[cfg(feature = "regex")]
                 S::Contains { literal, strict } => IS::Contains { literal, strict },
                 S::CountMatches(v) => IS::CountMatches(v),
@@ -256,6 +268,16 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "string_similarity")]
+                S::Levenshtein => IS::Levenshtein,
+                #[cfg(feature = "string_similarity")]
+                S::DamerauLevenshtein => IS::DamerauLevenshtein,
+                #[cfg(feature = "string_similarity")]
+                S::JaroWinkler => IS::JaroWinkler,
+                #[cfg(feature = "string_similarity")]
+                S::Hamming => IS::Hamming,
+                #[cfg(feature = "string_similarity")]
+                S::Jaccard { ngram } => IS::Jaccard { ngram },
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -271,6 +293,14 @@ pub(super) fn convert_functions(
                 S::Base64Encode => IS::Base64Encode,
                 #[cfg(feature = "binary_encoding")]
                 S::Base64Decode(v) => IS::Base64Decode(v),
+                #[cfg(feature = "string_url")]
+                S::ParseUrl(v) => IS::ParseUrl(v),
+                #[cfg(feature = "string_url")]
+                S::UrlQueryParam => IS::UrlQueryParam,
+                #[cfg(feature = "string_url")]
+                S::PercentEncode => IS::PercentEncode,
+                #[cfg(feature = "string_url")]
+                S::PercentDecode(v) => IS::PercentDecode(v),
                 S::StartsWith => IS::StartsWith,
                 S::StripChars => IS::StripChars,
                 S::StripCharsStart => IS::StripCharsStart,
@@ -281,6 +311,10 @@ pub(super) fn convert_functions(
                 S::SplitExact { n, inclusive } => IS::SplitExact { n, inclusive },
                 #[cfg(feature = "dtype-struct")]
                 S::SplitN(v) => IS::SplitN(v),
+                #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+                S::SplitExactRegex { n, inclusive } => IS::SplitExactRegex { n, inclusive },
+                #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+                S::SplitNRegex(v) => IS::SplitNRegex(v),
                 #[cfg(feature = "temporal")]
                 S::Strptime(data_type, strptime_options) => {
                     let is_column_independent = is_column_independent_aexpr(e[0].node(), ctx.arena);
@@ -297,6 +331,8 @@ pub(super) fn convert_functions(
                     IS::Strptime(dtype, strptime_options)
                 },
                 S::Split(v) => IS::Split(v),
+                #[cfg(feature = "regex")]
+                S::SplitRegex(v) => IS::SplitRegex(v),
                 #[cfg(feature = "dtype-decimal")]
                 S::ToDecimal(v) => IS::ToDecimal(v),
                 #[cfg(feature = "nightly")]
@@ -362,6 +398,10 @@ pub(super) fn convert_functions(
                 T::IsLeapYear => IT::IsLeapYear,
                 T::IsoYear => IT::IsoYear,
                 T::Quarter => IT::Quarter,
+                #[cfg(feature = "dtype-date")]
+                T::FiscalYear(calendar) => IT::FiscalYear(calendar),
+                #[cfg(feature = "dtype-date")]
+                T::FiscalQuarter(calendar) => IT::FiscalQuarter(calendar),
                 T::Month => IT::Month,
                 T::DaysInMonth => IT::DaysInMonth,
                 T::Week => IT::Week,
@@ -400,6 +440,10 @@ pub(super) fn convert_functions(
                 T::ConvertTimeZone(time_zone) => IT::ConvertTimeZone(time_zone),
                 T::TimeStamp(time_unit) => IT::TimeStamp(time_unit),
                 T::Truncate => IT::Truncate,
+                #[cfg(feature = "dtype-date")]
+                T::TruncateFiscal(calendar) => IT::TruncateFiscal(calendar),
+                #[cfg(feature = "dynamic_group_by")]
+                T::SessionId(gap) => IT::SessionId(gap),
                 #[cfg(feature = "offset_by")]
                 T::OffsetBy => IT::OffsetBy,
                 #[cfg(feature = "month_start")]
@@ -825,7 +869,7 @@ pub(super) fn convert_functions(
         #[cfg(feature = "interpolate")]
         F::Interpolate(interpolation_method) => I::Interpolate(interpolation_method),
         #[cfg(feature = "interpolate_by")]
-        F::InterpolateBy => I::InterpolateBy,
+        F::InterpolateBy(method) => I::InterpolateBy(method),
         #[cfg(feature = "log")]
         F::Entropy { base, normalize } => I::Entropy { base, normalize },
         #[cfg(feature = "log")]
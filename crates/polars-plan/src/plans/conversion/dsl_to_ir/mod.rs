@@ -1120,6 +1120,13 @@ fn resolve_group_by(
                 output_schema.with_column("_upper_boundary".into(), dtype.clone());
             }
             output_schema.with_column(name.clone(), dtype.clone());
+        } else if let Some(options) = _options.session.as_ref() {
+            let name = options.index_column.clone();
+            let dtype = input_schema.try_get(name.as_str())?;
+            keys.push(col(name.clone()));
+            key_names.insert(name.clone());
+            pop_keys = true;
+            output_schema.with_column(name.clone(), dtype.clone());
         }
     }
     let keys_index_len = output_schema.len();
@@ -49,10 +49,16 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Ipc { .. } => {
                 sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { .. } => {
+                sources.expand_paths_with_hive_update(unified_scan_args, cloud_options)?
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
+            #[cfg(feature = "json")]
+            FileScanDsl::Json { .. } => sources.expand_paths(unified_scan_args, cloud_options)?,
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { .. } => {
                 // There are a lot of places that short-circuit if the paths is empty,
@@ -188,7 +194,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -314,6 +320,45 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+    use polars_io::avro::AvroReader;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(first.to_include_path_name())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let arrow_schema = AvroReader::new(std::io::Cursor::new(memslice)).arrow_schema()?;
+
+    Ok(FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(&arrow_schema), row_index)?,
+        Some(Either::Left(Arc::new(arrow_schema))),
+        (None, 0),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub fn csv_file_info(
     sources: &ScanSources,
@@ -504,6 +549,84 @@ pub fn ndjson_file_info(
     ))
 }
 
+#[cfg(feature = "json")]
+pub fn json_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    json_options: &JsonReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let owned = &mut vec![];
+
+    let mut schema = if let Some(schema) = json_options.schema.clone() {
+        schema
+    } else {
+        let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+
+        // Only the rows used for inference are converted to NDJSON.
+        let mut ndjson = vec![];
+        polars_io::json::json_rows_to_ndjson(
+            maybe_decompress_bytes(&memslice, owned)?,
+            &json_options.json_path,
+            json_options.infer_schema_length.map(|n| n.get()),
+            &mut ndjson,
+        )?;
+
+        if ndjson.is_empty() {
+            Default::default()
+        } else {
+            Arc::new(polars_io::ndjson::infer_schema(
+                &mut std::io::Cursor::new(ndjson),
+                json_options.infer_schema_length,
+            )?)
+        }
+    };
+
+    if let Some(overwriting_schema) = &json_options.schema_overwrite {
+        overwrite_schema(Arc::make_mut(&mut schema), overwriting_schema)?;
+    }
+
+    let mut reader_schema = schema.clone();
+
+    if row_index.is_some() {
+        (schema, reader_schema) = prepare_schemas(Arc::unwrap_or_clone(schema), row_index)?
+    }
+
+    Ok(FileInfo::new(
+        schema,
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    ))
+}
+
 // Add flags that influence metadata/schema here
 #[derive(Eq, Hash, PartialEq)]
 enum CachedSourceKey {
@@ -516,6 +639,9 @@ enum CachedSourceKey {
         schema: Option<SchemaRef>,
         schema_overwrite: Option<SchemaRef>,
     },
+    Avro {
+        first_path: PlPath,
+    },
 }
 
 #[derive(Default)]
@@ -577,6 +703,12 @@ impl SourcesToFileInfo {
                     },
                 )
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options } => (
+                scans::avro_file_info(sources, unified_scan_args.row_index.as_ref(), cloud_options)
+                    .map_err(|e| e.context(failed_here!(avro scan)))?,
+                FileScanIR::Avro { options },
+            ),
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 // TODO: This is a hack. We conditionally set `allow_missing_columns` to
@@ -608,6 +740,17 @@ impl SourcesToFileInfo {
                 .map_err(|e| e.context(failed_here!(ndjson scan)))?,
                 FileScanIR::NDJson { options },
             ),
+            #[cfg(feature = "json")]
+            FileScanDsl::Json { options } => (
+                scans::json_file_info(
+                    sources,
+                    unified_scan_args.row_index.as_ref(),
+                    &options,
+                    cloud_options,
+                )
+                .map_err(|e| e.context(failed_here!(json scan)))?,
+                FileScanIR::Json { options },
+            ),
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { dataset_object } => {
                 if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -688,6 +831,15 @@ impl SourcesToFileInfo {
                 let v = self.inner.get(&key);
                 (key, v)
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro { options: _ } => {
+                let key = CachedSourceKey::Avro {
+                    first_path: paths[0].clone(),
+                };
+
+                let v = self.inner.get(&key);
+                (key, v)
+            },
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { options } => {
                 let key = CachedSourceKey::CsvJson {
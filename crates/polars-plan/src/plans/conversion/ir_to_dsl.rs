@@ -320,6 +320,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::Concat => L::Concat,
                 #[cfg(feature = "is_in")]
                 IL::Contains { nulls_equal } => L::Contains { nulls_equal },
+                #[cfg(feature = "list_index_of")]
+                IL::IndexOf => L::IndexOf,
                 #[cfg(feature = "list_drop_nulls")]
                 IL::DropNulls => L::DropNulls,
                 #[cfg(feature = "list_sample")]
@@ -336,6 +338,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 },
                 IL::Slice => L::Slice,
                 IL::Shift => L::Shift,
+                #[cfg(feature = "list_pad")]
+                IL::PadEnd => L::PadEnd,
                 IL::Get(v) => L::Get(v),
                 #[cfg(feature = "list_gather")]
                 IL::Gather(v) => L::Gather(v),
@@ -366,10 +370,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 #[cfg(feature = "list_any_all")]
                 IL::All => L::All,
                 IL::Join(v) => L::Join(v),
+                #[cfg(feature = "list_flatten")]
+                IL::Flatten(depth) => L::Flatten(depth),
+                #[cfg(all(feature = "list_flatten", feature = "dtype-array"))]
+                IL::Reshape(dimensions) => L::Reshape(dimensions),
                 #[cfg(feature = "dtype-array")]
                 IL::ToArray(v) => L::ToArray(v),
                 #[cfg(feature = "list_to_struct")]
                 IL::ToStruct(list_to_struct_args) => L::ToStruct(list_to_struct_args),
+                #[cfg(feature = "list_zip")]
+                IL::Zip => L::Zip,
             })
         },
         #[cfg(feature = "strings")]
@@ -392,6 +402,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     delimiter,
                     ignore_nulls,
                 },
+                #[cfg(feature = "string_format")]
+                IB::Format(template) => B::Format(template),
                 #[cfg(feature = "regex")]
                 IB::Contains { literal, strict } => B::Contains { literal, strict },
                 IB::CountMatches(v) => B::CountMatches(v),
@@ -423,6 +435,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "string_similarity")]
+                IB::Levenshtein => B::Levenshtein,
+                #[cfg(feature = "string_similarity")]
+                IB::DamerauLevenshtein => B::DamerauLevenshtein,
+                #[cfg(feature = "string_similarity")]
+                IB::JaroWinkler => B::JaroWinkler,
+                #[cfg(feature = "string_similarity")]
+                IB::Hamming => B::Hamming,
+                #[cfg(feature = "string_similarity")]
+                IB::Jaccard { ngram } => B::Jaccard { ngram },
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
@@ -438,6 +460,14 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Base64Encode => B::Base64Encode,
                 #[cfg(feature = "binary_encoding")]
                 IB::Base64Decode(v) => B::Base64Decode(v),
+                #[cfg(feature = "string_url")]
+                IB::ParseUrl(v) => B::ParseUrl(v),
+                #[cfg(feature = "string_url")]
+                IB::UrlQueryParam => B::UrlQueryParam,
+                #[cfg(feature = "string_url")]
+                IB::PercentEncode => B::PercentEncode,
+                #[cfg(feature = "string_url")]
+                IB::PercentDecode(v) => B::PercentDecode(v),
                 IB::StartsWith => B::StartsWith,
                 IB::StripChars => B::StripChars,
                 IB::StripCharsStart => B::StripCharsStart,
@@ -448,11 +478,17 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::SplitExact { n, inclusive } => B::SplitExact { n, inclusive },
                 #[cfg(feature = "dtype-struct")]
                 IB::SplitN(n) => B::SplitN(n),
+                #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+                IB::SplitExactRegex { n, inclusive } => B::SplitExactRegex { n, inclusive },
+                #[cfg(all(feature = "regex", feature = "dtype-struct"))]
+                IB::SplitNRegex(n) => B::SplitNRegex(n),
                 #[cfg(feature = "temporal")]
                 IB::Strptime(dtype, strptime_options) => {
                     B::Strptime(dtype.into(), strptime_options)
                 },
                 IB::Split(v) => B::Split(v),
+                #[cfg(feature = "regex")]
+                IB::SplitRegex(v) => B::SplitRegex(v),
                 #[cfg(feature = "dtype-decimal")]
                 IB::ToDecimal(v) => B::ToDecimal(v),
                 #[cfg(feature = "nightly")]
@@ -517,6 +553,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::IsLeapYear => B::IsLeapYear,
                 IB::IsoYear => B::IsoYear,
                 IB::Quarter => B::Quarter,
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalYear(calendar) => B::FiscalYear(calendar),
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalQuarter(calendar) => B::FiscalQuarter(calendar),
                 IB::Month => B::Month,
                 IB::DaysInMonth => B::DaysInMonth,
                 IB::Week => B::Week,
@@ -555,6 +595,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::ConvertTimeZone(time_zone) => B::ConvertTimeZone(time_zone),
                 IB::TimeStamp(time_unit) => B::TimeStamp(time_unit),
                 IB::Truncate => B::Truncate,
+                #[cfg(feature = "dtype-date")]
+                IB::TruncateFiscal(calendar) => B::TruncateFiscal(calendar),
+                #[cfg(feature = "dynamic_group_by")]
+                IB::SessionId(gap) => B::SessionId(gap),
                 #[cfg(feature = "offset_by")]
                 IB::OffsetBy => B::OffsetBy,
                 #[cfg(feature = "month_start")]
@@ -894,7 +938,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "interpolate")]
         IF::Interpolate(m) => F::Interpolate(m),
         #[cfg(feature = "interpolate_by")]
-        IF::InterpolateBy => F::InterpolateBy,
+        IF::InterpolateBy(method) => F::InterpolateBy(method),
         #[cfg(feature = "log")]
         IF::Entropy { base, normalize } => F::Entropy { base, normalize },
         #[cfg(feature = "log")]
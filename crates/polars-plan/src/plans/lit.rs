@@ -568,7 +568,7 @@ impl Literal for ChronoDuration {
 impl Literal for Duration {
     fn lit(self) -> Expr {
         assert!(
-            self.months() == 0,
+            self.months() == 0 && self.business_days() == 0,
             "Cannot create literal duration that is not of fixed length; found {self}"
         );
         let ns = self.duration_ns();
@@ -167,6 +167,9 @@ impl OptimizationRule for ExpandDatasets {
                                     metadata: None,
                                 },
 
+                                #[cfg(feature = "avro")]
+                                FileScanDsl::Avro { options } => FileScanIR::Avro { options },
+
                                 #[cfg(feature = "parquet")]
                                 FileScanDsl::Parquet { options } => FileScanIR::Parquet {
                                     options,
@@ -176,6 +179,9 @@ impl OptimizationRule for ExpandDatasets {
                                 #[cfg(feature = "json")]
                                 FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                                #[cfg(feature = "json")]
+                                FileScanDsl::Json { options } => FileScanIR::Json { options },
+
                                 #[cfg(feature = "python")]
                                 FileScanDsl::PythonDataset { dataset_object } => {
                                     FileScanIR::PythonDataset {
@@ -371,6 +371,8 @@ impl PredicatePushDown<'_> {
                     FileScanIR::Parquet { .. } => {},
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => {},
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { .. } => {},
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
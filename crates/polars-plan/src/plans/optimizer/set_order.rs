@@ -144,6 +144,7 @@ pub(super) fn set_order_flags(
                     || *maintain_order
                     || options.is_rolling()
                     || options.is_dynamic()
+                    || options.is_session()
                 {
                     maintain_order_above = true;
                     continue;
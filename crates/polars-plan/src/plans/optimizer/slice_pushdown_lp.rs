@@ -227,12 +227,18 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScanIR::Ipc { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScanIR::Csv { .. } => true,
 
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { .. } => true,
 
+                #[cfg(feature = "json")]
+                FileScanIR::Json { .. } => true,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset { .. } => true,
 
@@ -154,6 +154,10 @@ pub fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - business
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::register_business_calendar))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::unregister_business_calendar))
+        .unwrap();
 
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
@@ -194,6 +198,8 @@ pub fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_str))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::format_str_with_specs))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::len)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cov)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_fold))
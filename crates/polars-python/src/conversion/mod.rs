@@ -821,9 +821,14 @@ impl<'py> FromPyObject<'py> for Wrap<InterpolationMethod> {
         let parsed = match &*(ob.extract::<PyBackedStr>()?) {
             "linear" => InterpolationMethod::Linear,
             "nearest" => InterpolationMethod::Nearest,
+            "previous" => InterpolationMethod::Previous,
+            "next" => InterpolationMethod::Next,
+            "cubic" => InterpolationMethod::Cubic,
+            "akima" => InterpolationMethod::Akima,
+            "pchip" => InterpolationMethod::Pchip,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "interpolation `method` must be one of {{'linear', 'nearest'}}, got {v}",
+                    "interpolation `method` must be one of {{'linear', 'nearest', 'previous', 'next', 'cubic', 'akima', 'pchip'}}, got {v}",
                 )));
             },
         };
@@ -908,6 +913,39 @@ impl<'py> FromPyObject<'py> for Wrap<ClosedWindow> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<FiscalPattern> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "monthly" => FiscalPattern::Monthly,
+            "4-4-5" => FiscalPattern::Weeks445,
+            "4-5-4" => FiscalPattern::Weeks454,
+            "5-4-4" => FiscalPattern::Weeks544,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`pattern` must be one of {{'monthly', '4-4-5', '4-5-4', '5-4-4'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Wrap<FiscalPattern> {
+    type Target = PyString;
+    type Output = Bound<'py, Self::Target>;
+    type Error = Infallible;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self.0 {
+            FiscalPattern::Monthly => "monthly",
+            FiscalPattern::Weeks445 => "4-4-5",
+            FiscalPattern::Weeks454 => "4-5-4",
+            FiscalPattern::Weeks544 => "5-4-4",
+        }
+        .into_pyobject(py)
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<RoundMode> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
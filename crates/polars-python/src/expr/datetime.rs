@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 
 use crate::PyExpr;
 use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 
 #[pymethods]
 impl PyExpr {
@@ -92,6 +93,28 @@ impl PyExpr {
         self.inner.clone().dt().truncate(every.inner).into()
     }
 
+    fn dt_truncate_fiscal(
+        &self,
+        every: Self,
+        start_month: u8,
+        pattern: Wrap<FiscalPattern>,
+        week_start: u8,
+    ) -> PyResult<Self> {
+        let calendar = FiscalCalendar::try_new(start_month, pattern.0, week_start)
+            .map_err(PyPolarsErr::from)?;
+        Ok(self
+            .inner
+            .clone()
+            .dt()
+            .truncate_fiscal(every.inner, calendar)
+            .into())
+    }
+
+    fn dt_session_id(&self, gap: &str) -> PyResult<Self> {
+        let gap = Duration::try_parse(gap).map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().dt().session_id(gap).into())
+    }
+
     fn dt_month_start(&self) -> Self {
         self.inner.clone().dt().month_start().into()
     }
@@ -172,6 +195,26 @@ impl PyExpr {
     fn dt_quarter(&self) -> Self {
         self.inner.clone().dt().quarter().into()
     }
+    fn dt_fiscal_year(
+        &self,
+        start_month: u8,
+        pattern: Wrap<FiscalPattern>,
+        week_start: u8,
+    ) -> PyResult<Self> {
+        let calendar = FiscalCalendar::try_new(start_month, pattern.0, week_start)
+            .map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().dt().fiscal_year(calendar).into())
+    }
+    fn dt_fiscal_quarter(
+        &self,
+        start_month: u8,
+        pattern: Wrap<FiscalPattern>,
+        week_start: u8,
+    ) -> PyResult<Self> {
+        let calendar = FiscalCalendar::try_new(start_month, pattern.0, week_start)
+            .map_err(PyPolarsErr::from)?;
+        Ok(self.inner.clone().dt().fiscal_quarter(calendar).into())
+    }
     fn dt_month(&self) -> Self {
         self.inner.clone().dt().month().into()
     }
@@ -711,8 +711,11 @@ impl PyExpr {
     fn interpolate(&self, method: Wrap<InterpolationMethod>) -> Self {
         self.inner.clone().interpolate(method.0).into()
     }
-    fn interpolate_by(&self, by: PyExpr) -> Self {
-        self.inner.clone().interpolate_by(by.inner).into()
+    fn interpolate_by(&self, by: PyExpr, method: Wrap<InterpolationMethod>) -> Self {
+        self.inner
+            .clone()
+            .interpolate_by_with_method(by.inner, method.0)
+            .into()
     }
 
     fn lower_bound(&self) -> Self {
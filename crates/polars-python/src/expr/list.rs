@@ -59,6 +59,12 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "list_flatten")]
+    #[pyo3(signature = (depth=None))]
+    fn list_flatten(&self, depth: Option<usize>) -> Self {
+        self.inner.clone().list().flatten(depth).into()
+    }
+
     fn list_get(&self, index: PyExpr, null_on_oob: bool) -> Self {
         self.inner
             .clone()
@@ -67,6 +73,11 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "list_index_of")]
+    fn list_index_of(&self, element: PyExpr) -> Self {
+        self.inner.clone().list().index_of(element.inner).into()
+    }
+
     fn list_join(&self, separator: PyExpr, ignore_nulls: bool) -> Self {
         self.inner
             .clone()
@@ -103,6 +114,20 @@ impl PyExpr {
         self.inner.clone().list().min().into()
     }
 
+    #[cfg(feature = "list_pad")]
+    fn list_pad_end(&self, length: PyExpr, fill_value: PyExpr) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .pad_end(length.inner, fill_value.inner)
+            .into()
+    }
+
+    #[cfg(feature = "list_flatten")]
+    fn list_reshape(&self, dimensions: Vec<usize>) -> Self {
+        self.inner.clone().list().reshape(&dimensions).into()
+    }
+
     fn list_reverse(&self) -> Self {
         self.inner.clone().list().reverse().into()
     }
@@ -260,4 +285,9 @@ impl PyExpr {
         }
         .into()
     }
+
+    #[cfg(feature = "list_zip")]
+    fn list_zip(&self, other: PyExpr) -> Self {
+        self.inner.clone().list().zip(other.inner).into()
+    }
 }
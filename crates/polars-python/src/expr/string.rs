@@ -165,6 +165,30 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_levenshtein(&self, other: Self) -> Self {
+        self.inner.clone().str().levenshtein(other.inner).into()
+    }
+
+    fn str_damerau_levenshtein(&self, other: Self) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .damerau_levenshtein(other.inner)
+            .into()
+    }
+
+    fn str_jaro_winkler(&self, other: Self) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    fn str_hamming(&self, other: Self) -> Self {
+        self.inner.clone().str().hamming(other.inner).into()
+    }
+
+    fn str_jaccard(&self, other: Self, ngram: usize) -> Self {
+        self.inner.clone().str().jaccard(other.inner, ngram).into()
+    }
+
     fn str_pad_start(&self, length: PyExpr, fill_char: char) -> Self {
         self.inner
             .clone()
@@ -229,6 +253,22 @@ impl PyExpr {
         self.inner.clone().str().base64_decode(strict).into()
     }
 
+    fn str_percent_encode(&self) -> Self {
+        self.inner.clone().str().percent_encode().into()
+    }
+
+    fn str_percent_decode(&self, strict: bool) -> Self {
+        self.inner.clone().str().percent_decode(strict).into()
+    }
+
+    fn str_parse_url(&self, strict: bool) -> Self {
+        self.inner.clone().str().parse_url(strict).into()
+    }
+
+    fn str_url_query_param(&self, name: Self) -> Self {
+        self.inner.clone().str().url_query_param(name.inner).into()
+    }
+
     #[pyo3(signature = (base, dtype=Some(Wrap(DataType::Int64)), strict=true))]
     fn str_to_integer(&self, base: Self, dtype: Option<Wrap<DataType>>, strict: bool) -> Self {
         self.inner
@@ -313,6 +353,31 @@ impl PyExpr {
         self.inner.clone().str().splitn(by.inner, n).into()
     }
 
+    #[cfg(feature = "regex")]
+    fn str_split_regex(&self, pat: Self, inclusive: bool) -> Self {
+        let str = self.inner.clone().str();
+        if inclusive {
+            str.split_regex_inclusive(pat.inner).into()
+        } else {
+            str.split_regex(pat.inner).into()
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    fn str_split_exact_regex(&self, pat: Self, n: usize, inclusive: bool) -> Self {
+        let str = self.inner.clone().str();
+        if inclusive {
+            str.split_exact_regex_inclusive(pat.inner, n).into()
+        } else {
+            str.split_exact_regex(pat.inner, n).into()
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    fn str_splitn_regex(&self, pat: Self, n: usize) -> Self {
+        self.inner.clone().str().splitn_regex(pat.inner, n).into()
+    }
+
     fn str_to_decimal(&self, infer_len: usize) -> Self {
         self.inner.clone().str().to_decimal(infer_len).into()
     }
@@ -1,7 +1,9 @@
 use polars::lazy::dsl;
+use polars_ops::series::BusinessCalendar;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::error::PyPolarsErr;
 
 #[pyfunction]
 pub fn business_day_count(
@@ -14,3 +16,19 @@ pub fn business_day_count(
     let end = end.inner;
     dsl::business_day_count(start, end, week_mask, holidays).into()
 }
+
+#[pyfunction]
+pub fn register_business_calendar(
+    name: &str,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> PyResult<()> {
+    let calendar = BusinessCalendar::try_new(week_mask, &holidays).map_err(PyPolarsErr::from)?;
+    polars_ops::series::register_business_calendar(name.into(), calendar);
+    Ok(())
+}
+
+#[pyfunction]
+pub fn unregister_business_calendar(name: &str) -> bool {
+    polars_ops::series::unregister_business_calendar(name)
+}
@@ -218,6 +218,13 @@ pub fn concat_str(s: Vec<PyExpr>, separator: &str, ignore_nulls: bool) -> PyExpr
     dsl::concat_str(s, separator, ignore_nulls).into()
 }
 
+#[pyfunction]
+pub fn format_str_with_specs(format: &str, args: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let args = args.to_exprs();
+    let expr = dsl::format_str_with_specs(format, args).map_err(PyPolarsErr::from)?;
+    Ok(expr.into())
+}
+
 #[pyfunction]
 pub fn len() -> PyExpr {
     dsl::len().into()
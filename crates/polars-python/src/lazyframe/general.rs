@@ -148,6 +148,83 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[staticmethod]
+    #[cfg(feature = "json")]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        source, sources, json_path, infer_schema_length, schema, schema_overrides, n_rows, rechunk,
+        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, retries,
+        file_cache_ttl
+    ))]
+    fn new_from_json(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        json_path: &str,
+        infer_schema_length: Option<usize>,
+        schema: Option<Wrap<Schema>>,
+        schema_overrides: Option<Wrap<Schema>>,
+        n_rows: Option<usize>,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        ignore_errors: bool,
+        include_file_paths: Option<String>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+    ) -> PyResult<Self> {
+        use cloud::credential_provider::PlCredentialProvider;
+        use polars::io::json::JsonPath;
+
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+        let json_path = JsonPath::parse(json_path).map_err(PyPolarsErr::from)?;
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        let mut r = LazyJsonReader::new_with_sources(sources);
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            cloud_options = cloud_options
+                .with_max_retries(retries)
+                .with_credential_provider(
+                    credential_provider.map(PlCredentialProvider::from_python_builder),
+                );
+
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+
+            r = r.with_cloud_options(Some(cloud_options));
+        };
+
+        let lf = r
+            .with_json_path(json_path)
+            .with_infer_schema_length(infer_schema_length.and_then(NonZeroUsize::new))
+            .with_n_rows(n_rows)
+            .with_rechunk(rechunk)
+            .with_schema(schema.map(|schema| Arc::new(schema.0)))
+            .with_schema_overwrite(schema_overrides.map(|x| Arc::new(x.0)))
+            .with_row_index(row_index)
+            .with_ignore_errors(ignore_errors)
+            .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .finish()
+            .map_err(PyPolarsErr::from)?;
+
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     #[cfg(feature = "csv")]
     #[pyo3(signature = (source, sources, separator, has_header, ignore_errors, skip_rows, skip_lines, n_rows, cache, overwrite_dtype,
@@ -413,6 +490,81 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "avro")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, n_rows, cache, rechunk, row_index, cloud_options, credential_provider,
+        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl,
+        include_file_paths
+    ))]
+    fn new_from_avro(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        hive_partitioning: Option<bool>,
+        hive_schema: Option<Wrap<Schema>>,
+        try_parse_hive_dates: bool,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        include_file_paths: Option<String>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            hive_start_idx: 0,
+            schema: hive_schema.map(|x| Arc::new(x.0)),
+            try_parse_dates: try_parse_hive_dates,
+        };
+
+        let mut args = ScanArgsAvro {
+            n_rows,
+            cache,
+            rechunk,
+            row_index,
+            cloud_options: None,
+            hive_options,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.into_owned()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_str();
+
+            let mut cloud_options =
+                parse_cloud_options(first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_avro_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     #[pyo3(signature = (
         dataset_object
@@ -835,6 +987,72 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (
+        target, compression, name, cloud_options, credential_provider, retries, sink_options
+    ))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: SinkTarget,
+        compression: Wrap<Option<polars::io::avro::AvroCompression>>,
+        name: String,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        sink_options: Wrap<SinkOptions>,
+    ) -> PyResult<PyLazyFrame> {
+        use polars::io::avro::{AvroCodec, AvroCompression};
+
+        let options = AvroWriterOptions {
+            compression: compression.0.map(|c| match c {
+                AvroCompression::Deflate => AvroCodec::Deflate,
+                AvroCompression::Snappy => AvroCodec::Snappy,
+            }),
+            name: name.into(),
+        };
+
+        #[cfg(feature = "cloud")]
+        let cloud_options = match target.base_path() {
+            None => None,
+            Some(base_path) => {
+                let cloud_options =
+                    parse_cloud_options(base_path.to_str(), cloud_options.unwrap_or_default())?;
+                Some(
+                    cloud_options
+                        .with_max_retries(retries)
+                        .with_credential_provider(
+                            credential_provider.map(polars::prelude::cloud::credential_provider::PlCredentialProvider::from_python_builder),
+                        ),
+                )
+            },
+        };
+
+        #[cfg(not(feature = "cloud"))]
+        let cloud_options = None;
+
+        py.enter_polars(|| {
+            let ldf = self.ldf.clone();
+            match target {
+                SinkTarget::File(target) => {
+                    ldf.sink_avro(target, options, cloud_options, sink_options.0)
+                },
+                SinkTarget::Partition(partition) => ldf.sink_avro_partitioned(
+                    Arc::new(partition.base_path.0),
+                    partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.variant,
+                    options,
+                    cloud_options,
+                    sink_options.0,
+                    partition.per_partition_sort_by,
+                    partition.finish_callback,
+                ),
+            }
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[cfg(feature = "csv")]
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
@@ -1078,6 +1296,29 @@ impl PyLazyFrame {
         Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
     }
 
+    fn group_by_session(
+        &mut self,
+        index_column: PyExpr,
+        gap: &str,
+        group_by: Vec<PyExpr>,
+    ) -> PyResult<PyLazyGroupBy> {
+        let group_by = group_by
+            .into_iter()
+            .map(|pyexpr| pyexpr.inner)
+            .collect::<Vec<_>>();
+        let ldf = self.ldf.clone();
+        let lazy_gb = ldf.group_by_session(
+            index_column.inner,
+            group_by,
+            SessionGroupOptions {
+                gap: Duration::try_parse(gap).map_err(PyPolarsErr::from)?,
+                ..Default::default()
+            },
+        );
+
+        Ok(PyLazyGroupBy { lgb: Some(lazy_gb) })
+    }
+
     fn with_context(&self, contexts: Vec<Self>) -> Self {
         let contexts = contexts.into_iter().map(|ldf| ldf.ldf).collect::<Vec<_>>();
         self.ldf.clone().with_context(contexts).into()
@@ -1596,12 +1837,23 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| {
+                let (fpp, ndv) = v.extract::<(Option<f64>, Option<u64>)>()?;
+                PyResult::Ok(
+                    polars_io::parquet::write::BloomFilterOptions::try_new(fpp, ndv)
+                        .map_err(PyPolarsErr::from)?,
+                )
+            })
+            .transpose()?;
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            bloom_filter,
         }))
     }
 }
@@ -6,7 +6,6 @@ use polars_core::series::IsSorted;
 #[cfg(feature = "string_normalize")]
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::prelude::RankMethod;
-use polars_ops::series::InterpolationMethod;
 #[cfg(feature = "search_sorted")]
 use polars_ops::series::SearchSortedSide;
 use polars_plan::plans::{
@@ -17,7 +16,7 @@ use polars_plan::prelude::{
     AExpr, GroupbyOptions, IRAggExpr, LiteralValue, Operator, WindowMapping, WindowType,
 };
 use polars_time::prelude::RollingGroupOptions;
-use polars_time::{Duration, DynamicGroupOptions};
+use polars_time::{Duration, DynamicGroupOptions, SessionGroupOptions};
 use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::PyNotImplementedError;
 use pyo3::prelude::*;
@@ -134,6 +133,7 @@ impl<'py> IntoPyObject<'py> for Wrap<InequalityOperator> {
 pub enum PyStringFunction {
     ConcatHorizontal,
     ConcatVertical,
+    Format,
     Contains,
     CountMatches,
     EndsWith,
@@ -168,6 +168,9 @@ pub enum PyStringFunction {
     SplitN,
     Strptime,
     Split,
+    SplitRegex,
+    SplitExactRegex,
+    SplitNRegex,
     ToDecimal,
     Titlecase,
     Uppercase,
@@ -176,6 +179,15 @@ pub enum PyStringFunction {
     ReplaceMany,
     EscapeRegex,
     Normalize,
+    Levenshtein,
+    DamerauLevenshtein,
+    JaroWinkler,
+    Hamming,
+    Jaccard,
+    ParseUrl,
+    UrlQueryParam,
+    PercentEncode,
+    PercentDecode,
 }
 
 #[pymethods]
@@ -263,6 +275,10 @@ pub enum PyTemporalFunction {
     ReplaceTimeZone,
     Combine,
     DatetimeFunction,
+    FiscalYear,
+    FiscalQuarter,
+    TruncateFiscal,
+    SessionId,
 }
 
 #[pymethods]
@@ -438,6 +454,8 @@ impl<'py> IntoPyObject<'py> for Wrap<Duration> {
             self.0.nanoseconds(),
             self.0.parsed_int,
             self.0.negative(),
+            self.0.business_days(),
+            self.0.calendar(),
         )
             .into_pyobject(py)
     }
@@ -518,6 +536,24 @@ impl PyDynamicGroupOptions {
     }
 }
 
+#[pyclass(name = "SessionGroupOptions")]
+pub struct PySessionGroupOptions {
+    inner: SessionGroupOptions,
+}
+
+#[pymethods]
+impl PySessionGroupOptions {
+    #[getter]
+    fn index_column(&self) -> &str {
+        self.inner.index_column.as_str()
+    }
+
+    #[getter]
+    fn gap(&self) -> Wrap<Duration> {
+        Wrap(self.inner.gap)
+    }
+}
+
 #[pyclass(name = "GroupbyOptions")]
 pub struct PyGroupbyOptions {
     inner: GroupbyOptions,
@@ -551,6 +587,14 @@ impl PyGroupbyOptions {
             .as_ref()
             .map(|f| PyRollingGroupOptions { inner: f.clone() })
     }
+
+    #[getter]
+    fn session(&self) -> Option<PySessionGroupOptions> {
+        self.inner
+            .session
+            .as_ref()
+            .map(|f| PySessionGroupOptions { inner: f.clone() })
+    }
 }
 
 pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
@@ -782,6 +826,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         ignore_nulls,
                     )
                         .into_py_any(py),
+                    IRStringFunction::Format(template) => {
+                        (PyStringFunction::Format, template.as_str()).into_py_any(py)
+                    },
                     #[cfg(feature = "regex")]
                     IRStringFunction::Contains { literal, strict } => {
                         (PyStringFunction::Contains, literal, strict).into_py_any(py)
@@ -857,6 +904,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::Base64Decode(strict) => {
                         (PyStringFunction::Base64Decode, strict).into_py_any(py)
                     },
+                    IRStringFunction::ParseUrl(strict) => {
+                        (PyStringFunction::ParseUrl, strict).into_py_any(py)
+                    },
+                    IRStringFunction::UrlQueryParam => {
+                        (PyStringFunction::UrlQueryParam,).into_py_any(py)
+                    },
+                    IRStringFunction::PercentEncode => {
+                        (PyStringFunction::PercentEncode,).into_py_any(py)
+                    },
+                    IRStringFunction::PercentDecode(strict) => {
+                        (PyStringFunction::PercentDecode, strict).into_py_any(py)
+                    },
                     IRStringFunction::StartsWith => (PyStringFunction::StartsWith,).into_py_any(py),
                     IRStringFunction::StripChars => (PyStringFunction::StripChars,).into_py_any(py),
                     IRStringFunction::StripCharsStart => {
@@ -875,6 +934,14 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         (PyStringFunction::SplitExact, n, inclusive).into_py_any(py)
                     },
                     IRStringFunction::SplitN(n) => (PyStringFunction::SplitN, n).into_py_any(py),
+                    #[cfg(feature = "regex")]
+                    IRStringFunction::SplitExactRegex { n, inclusive } => {
+                        (PyStringFunction::SplitExactRegex, n, inclusive).into_py_any(py)
+                    },
+                    #[cfg(feature = "regex")]
+                    IRStringFunction::SplitNRegex(n) => {
+                        (PyStringFunction::SplitNRegex, n).into_py_any(py)
+                    },
                     IRStringFunction::Strptime(_, options) => (
                         PyStringFunction::Strptime,
                         options.format.as_ref().map(|s| s.as_str()),
@@ -886,6 +953,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::Split(inclusive) => {
                         (PyStringFunction::Split, inclusive).into_py_any(py)
                     },
+                    #[cfg(feature = "regex")]
+                    IRStringFunction::SplitRegex(inclusive) => {
+                        (PyStringFunction::SplitRegex, inclusive).into_py_any(py)
+                    },
                     IRStringFunction::ToDecimal(inference_length) => {
                         (PyStringFunction::ToDecimal, inference_length).into_py_any(py)
                     },
@@ -913,6 +984,19 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::EscapeRegex => {
                         (PyStringFunction::EscapeRegex,).into_py_any(py)
                     },
+                    IRStringFunction::Levenshtein => {
+                        (PyStringFunction::Levenshtein,).into_py_any(py)
+                    },
+                    IRStringFunction::DamerauLevenshtein => {
+                        (PyStringFunction::DamerauLevenshtein,).into_py_any(py)
+                    },
+                    IRStringFunction::JaroWinkler => {
+                        (PyStringFunction::JaroWinkler,).into_py_any(py)
+                    },
+                    IRStringFunction::Hamming => (PyStringFunction::Hamming,).into_py_any(py),
+                    IRStringFunction::Jaccard { ngram } => {
+                        (PyStringFunction::Jaccard, ngram).into_py_any(py)
+                    },
                 },
                 IRFunctionExpr::StructExpr(fun) => match fun {
                     IRStructFunction::FieldByName(name) => {
@@ -947,6 +1031,20 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     },
                     IRTemporalFunction::IsoYear => (PyTemporalFunction::IsoYear,).into_py_any(py),
                     IRTemporalFunction::Quarter => (PyTemporalFunction::Quarter,).into_py_any(py),
+                    IRTemporalFunction::FiscalYear(calendar) => (
+                        PyTemporalFunction::FiscalYear,
+                        calendar.start_month(),
+                        Wrap(calendar.pattern()),
+                        calendar.week_start(),
+                    )
+                        .into_py_any(py),
+                    IRTemporalFunction::FiscalQuarter(calendar) => (
+                        PyTemporalFunction::FiscalQuarter,
+                        calendar.start_month(),
+                        Wrap(calendar.pattern()),
+                        calendar.week_start(),
+                    )
+                        .into_py_any(py),
                     IRTemporalFunction::Month => (PyTemporalFunction::Month,).into_py_any(py),
                     IRTemporalFunction::Week => (PyTemporalFunction::Week,).into_py_any(py),
                     IRTemporalFunction::WeekDay => (PyTemporalFunction::WeekDay,).into_py_any(py),
@@ -1013,6 +1111,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py_any(py)
                     },
                     IRTemporalFunction::Truncate => (PyTemporalFunction::Truncate,).into_py_any(py),
+                    IRTemporalFunction::TruncateFiscal(calendar) => (
+                        PyTemporalFunction::TruncateFiscal,
+                        calendar.start_month(),
+                        Wrap(calendar.pattern()),
+                        calendar.week_start(),
+                    )
+                        .into_py_any(py),
+                    IRTemporalFunction::SessionId(gap) => {
+                        (PyTemporalFunction::SessionId, Wrap(*gap)).into_py_any(py)
+                    },
                     IRTemporalFunction::OffsetBy => (PyTemporalFunction::OffsetBy,).into_py_any(py),
                     IRTemporalFunction::MonthStart => {
                         (PyTemporalFunction::MonthStart,).into_py_any(py)
@@ -1246,15 +1354,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     .into_py_any(py),
                 #[cfg(feature = "pct_change")]
                 IRFunctionExpr::PctChange => ("pct_change",).into_py_any(py),
-                IRFunctionExpr::Interpolate(method) => (
-                    "interpolate",
-                    match method {
-                        InterpolationMethod::Linear => "linear",
-                        InterpolationMethod::Nearest => "nearest",
-                    },
-                )
-                    .into_py_any(py),
-                IRFunctionExpr::InterpolateBy => ("interpolate_by",).into_py_any(py),
+                IRFunctionExpr::Interpolate(method) => {
+                    ("interpolate", Into::<&str>::into(method)).into_py_any(py)
+                },
+                IRFunctionExpr::InterpolateBy(method) => {
+                    ("interpolate_by", Into::<&str>::into(method)).into_py_any(py)
+                },
                 IRFunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).into_py_any(py)
                 },
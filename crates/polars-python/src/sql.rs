@@ -19,10 +19,10 @@ pub struct PySQLContext {
 )]
 impl PySQLContext {
     #[staticmethod]
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> PySQLContext {
+    pub fn new(max_recursive_cte_iterations: usize) -> PySQLContext {
         PySQLContext {
-            context: SQLContext::new(),
+            context: SQLContext::new()
+                .with_max_recursive_cte_iterations(max_recursive_cte_iterations),
         }
     }
 
@@ -4,21 +4,23 @@ use std::ops::Deref;
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
-use polars_ops::frame::JoinCoalesce;
+use polars_ops::frame::{JoinCoalesce, MaintainOrderJoin};
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    AssignmentTarget, BinaryOperator, CreateTable, Cte, Delete, Distinct, ExcludeSelectItem,
+    Expr as SQLExpr, FromTable, FunctionArg, GroupByExpr, GroupByWithModifier, Ident, Insert, Join,
+    JoinConstraint, JoinOperator, MergeAction, MergeClauseKind, MergeInsertExpr, MergeInsertKind,
+    ObjectName, ObjectType, Offset, OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr,
+    SetOperator, SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::functions::{GROUPING_COLUMN_PREFIX, has_sql_aggregate, is_sql_count};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
@@ -52,6 +54,9 @@ impl SelectModifiers {
     }
 }
 
+/// Default upper bound on the number of iterations a recursive CTE may run for.
+pub(crate) const DEFAULT_MAX_RECURSIVE_CTE_ITERATIONS: usize = 1000;
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -59,6 +64,7 @@ pub struct SQLContext {
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
+    pub(crate) max_recursive_cte_iterations: usize,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
@@ -75,6 +81,7 @@ impl Default for SQLContext {
             joined_aliases: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            max_recursive_cte_iterations: DEFAULT_MAX_RECURSIVE_CTE_ITERATIONS,
         }
     }
 }
@@ -177,6 +184,16 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations a recursive CTE (`WITH RECURSIVE`) may
+    /// run for before evaluation is aborted with an error (defaults to 1000).
+    ///
+    /// Note that recursive CTEs are evaluated eagerly while the query is planned,
+    /// that is, when calling [`SQLContext::execute`].
+    pub fn with_max_recursive_cte_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_recursive_cte_iterations = max_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -202,6 +219,9 @@ impl SQLContext {
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
             stmt @ Statement::Delete { .. } => self.execute_delete_from_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert_into_table(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update_table(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge_into_table(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type is not supported:\n{:?}", ast,
             ),
@@ -227,10 +247,12 @@ impl SQLContext {
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                })
             })
     }
 
@@ -553,6 +575,339 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO <tbl> [(cols)] VALUES ... | SELECT ...
+    fn execute_insert_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            or,
+            ignore,
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            after_columns,
+            on,
+            returning,
+            replace_into,
+            ..
+        }) = stmt
+        {
+            let error_message = match () {
+                _ if or.is_some() || *ignore || *replace_into => {
+                    Some("INSERT does not support conflict resolution clauses")
+                },
+                _ if partitioned.is_some() || !after_columns.is_empty() => {
+                    Some("INSERT does not support the PARTITION clause")
+                },
+                _ if on.is_some() => Some("INSERT does not support the ON CONFLICT clause"),
+                _ if returning.is_some() => Some("INSERT does not support the RETURNING clause"),
+                _ if source.is_none() => Some("INSERT requires a VALUES or SELECT source"),
+                _ => None,
+            };
+            if let Some(error_message) = error_message {
+                polars_bail!(SQLInterface: error_message);
+            }
+            let tbl_name = table_name.0.first().unwrap().value.as_str();
+            let Some(mut lf) = self.table_map.get(tbl_name).cloned() else {
+                polars_bail!(SQLInterface: "table '{}' does not exist", tbl_name);
+            };
+            let schema = self.get_frame_schema(&mut lf)?;
+            let mut src = self.execute_query(source.as_ref().unwrap())?;
+            let src_schema = self.get_frame_schema(&mut src)?;
+
+            // map the source columns (by position) onto the named/all target columns
+            let target_names: Vec<PlSmallStr> = if columns.is_empty() {
+                schema.iter_names().cloned().collect()
+            } else {
+                columns
+                    .iter()
+                    .map(|c| {
+                        let name = c.value.as_str();
+                        polars_ensure!(
+                            schema.contains(name),
+                            SQLInterface: "column '{}' does not exist in table '{}'", name, tbl_name
+                        );
+                        Ok(PlSmallStr::from_str(name))
+                    })
+                    .collect::<PolarsResult<_>>()?
+            };
+            polars_ensure!(
+                target_names.len() == src_schema.len(),
+                SQLInterface: "INSERT has {} target column(s) but the source provides {}",
+                target_names.len(), src_schema.len()
+            );
+            let exprs = schema
+                .iter()
+                .map(|(name, dtype)| {
+                    let value = match target_names.iter().position(|nm| nm == name) {
+                        Some(idx) => col(src_schema.get_at_index(idx).unwrap().0.clone()),
+                        None => lit(LiteralValue::untyped_null()),
+                    };
+                    value.strict_cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+            let src = src.select(exprs);
+
+            let lf = if *overwrite {
+                src
+            } else {
+                let opts = UnionArgs {
+                    parallel: true,
+                    ..Default::default()
+                };
+                polars_lazy::dsl::concat(vec![lf, src], opts)?
+            };
+            self.table_map.insert(tbl_name.to_string(), lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected INSERT")
+        }
+    }
+
+    // UPDATE <tbl> SET <col> = <expr>, ... [WHERE ...]
+    fn execute_update_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+            or,
+        } = stmt
+        {
+            let error_message = match () {
+                _ if !table.joins.is_empty() => Some("UPDATE does not support table JOINs"),
+                _ if from.is_some() => Some("UPDATE does not support the FROM clause"),
+                _ if returning.is_some() => Some("UPDATE does not support the RETURNING clause"),
+                _ if or.is_some() => Some("UPDATE does not support conflict resolution clauses"),
+                _ => None,
+            };
+            if let Some(error_message) = error_message {
+                polars_bail!(SQLInterface: error_message);
+            }
+            let TableFactor::Table { name, .. } = &table.relation else {
+                polars_bail!(SQLInterface: "UPDATE expects a table name; found {}", table.relation);
+            };
+            let tbl_name = name.0.first().unwrap().value.clone();
+            polars_ensure!(
+                self.table_map.contains_key(&tbl_name),
+                SQLInterface: "table '{}' does not exist", tbl_name
+            );
+            let (_, mut lf) = self.get_table(&table.relation)?;
+            let schema = self.get_frame_schema(&mut lf)?;
+
+            let predicate = selection
+                .as_ref()
+                .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
+                .transpose()?;
+            let updates = assignments
+                .iter()
+                .map(|assignment| {
+                    let (name, dtype) = resolve_assignment_target(&assignment.target, &schema)?;
+                    let value = parse_sql_expr(&assignment.value, self, Some(&schema))?
+                        .strict_cast(dtype.clone());
+                    Ok(match &predicate {
+                        Some(predicate) => when(predicate.clone())
+                            .then(value)
+                            .otherwise(col(name.clone())),
+                        None => value,
+                    }
+                    .alias(name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            let lf = lf.with_columns(updates);
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected UPDATE")
+        }
+    }
+
+    // MERGE INTO <tbl> USING <source> ON ... WHEN [NOT] MATCHED ... THEN ...
+    fn execute_merge_into_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        {
+            const TARGET_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_MERGE_TARGET_ROW");
+            const SOURCE_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_MERGE_SOURCE_ROW");
+
+            let TableFactor::Table { name, .. } = table else {
+                polars_bail!(SQLInterface: "MERGE expects a target table name; found {}", table);
+            };
+            let tbl_name = name.0.first().unwrap().value.clone();
+            polars_ensure!(
+                self.table_map.contains_key(&tbl_name),
+                SQLInterface: "table '{}' does not exist", tbl_name
+            );
+            let (l_name, mut lf) = self.get_table(table)?;
+            let (r_name, mut rf) = self.get_table(source)?;
+            let left_schema = self.get_frame_schema(&mut lf)?;
+            let right_schema = self.get_frame_schema(&mut rf)?;
+
+            // full join of target and source; the row indices identify the matches
+            let mut joined = self.process_join(
+                &TableInfo {
+                    frame: lf.with_row_index(TARGET_ROW, None),
+                    name: (&l_name).into(),
+                    schema: left_schema.clone(),
+                },
+                &TableInfo {
+                    frame: rf.with_row_index(SOURCE_ROW, None),
+                    name: (&r_name).into(),
+                    schema: right_schema.clone(),
+                },
+                &JoinConstraint::On((**on).clone()),
+                JoinType::Full,
+            )?;
+            self.track_joined_aliases(&mut joined, &r_name, &left_schema, &right_schema)?;
+            let joined_schema = self.get_frame_schema(&mut joined)?;
+
+            let has_target = col(TARGET_ROW).is_not_null();
+            let has_source = col(SOURCE_ROW).is_not_null();
+
+            // a target row may be modified by at most one source row; this is checked
+            // eagerly so that the error is raised by the MERGE statement itself
+            if clauses
+                .iter()
+                .any(|clause| clause.clause_kind == MergeClauseKind::Matched)
+            {
+                let has_multiple_matches = joined
+                    .clone()
+                    .filter(has_target.clone().and(has_source.clone()))
+                    .select([col(TARGET_ROW).n_unique().neq(len())])
+                    .collect()?;
+                polars_ensure!(
+                    has_multiple_matches.get_columns()[0].bool()?.get(0) != Some(true),
+                    SQLInterface: "MERGE cardinality violation; a row of table '{}' matched more than one source row", tbl_name
+                );
+            }
+
+            let mut taken = lit(false);
+            let mut keep_target = has_target.clone();
+            let mut insert_row = lit(false);
+            let mut values: PlHashMap<PlSmallStr, Vec<(Expr, Expr)>> = PlHashMap::new();
+
+            // each row is handled by the first clause that applies to it
+            for clause in clauses {
+                let mut applies = match clause.clause_kind {
+                    MergeClauseKind::Matched => has_target.clone().and(has_source.clone()),
+                    MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget => {
+                        has_target.clone().not()
+                    },
+                    MergeClauseKind::NotMatchedBySource => has_source.clone().not(),
+                };
+                if let Some(predicate) = &clause.predicate {
+                    let predicate = parse_sql_expr(predicate, self, Some(&joined_schema))?;
+                    applies = applies.and(predicate.fill_null(lit(false)));
+                }
+                let applies_here = applies.clone().and(taken.clone().not());
+                taken = taken.or(applies);
+
+                match &clause.action {
+                    MergeAction::Update { assignments } => {
+                        polars_ensure!(
+                            clause.clause_kind != MergeClauseKind::NotMatched
+                                && clause.clause_kind != MergeClauseKind::NotMatchedByTarget,
+                            SQLSyntax: "MERGE cannot UPDATE rows that are not matched by the target"
+                        );
+                        for assignment in assignments {
+                            let (name, _) =
+                                resolve_assignment_target(&assignment.target, &left_schema)?;
+                            let value =
+                                parse_sql_expr(&assignment.value, self, Some(&joined_schema))?;
+                            values
+                                .entry(name)
+                                .or_default()
+                                .push((applies_here.clone(), value));
+                        }
+                    },
+                    MergeAction::Delete => {
+                        polars_ensure!(
+                            clause.clause_kind != MergeClauseKind::NotMatched
+                                && clause.clause_kind != MergeClauseKind::NotMatchedByTarget,
+                            SQLSyntax: "MERGE cannot DELETE rows that are not matched by the target"
+                        );
+                        keep_target = keep_target.and(applies_here.not());
+                    },
+                    MergeAction::Insert(MergeInsertExpr { columns, kind }) => {
+                        let MergeInsertKind::Values(Values { rows, .. }) = kind else {
+                            polars_bail!(SQLInterface: "MERGE INSERT requires a VALUES clause");
+                        };
+                        polars_ensure!(
+                            rows.len() == 1,
+                            SQLSyntax: "MERGE INSERT expects a single VALUES row; found {}", rows.len()
+                        );
+                        let names: Vec<PlSmallStr> = if columns.is_empty() {
+                            left_schema.iter_names().cloned().collect()
+                        } else {
+                            columns
+                                .iter()
+                                .map(|c| PlSmallStr::from_str(c.value.as_str()))
+                                .collect()
+                        };
+                        polars_ensure!(
+                            names.len() == rows[0].len(),
+                            SQLSyntax: "MERGE INSERT has {} column(s) but {} value(s)",
+                            names.len(), rows[0].len()
+                        );
+                        let applies_here = applies_here.and(has_target.clone().not());
+                        for (name, value) in names.into_iter().zip(&rows[0]) {
+                            polars_ensure!(
+                                left_schema.contains(&name),
+                                SQLInterface: "column '{}' does not exist in table '{}'", name, tbl_name
+                            );
+                            let value = parse_sql_expr(value, self, Some(&joined_schema))?;
+                            values
+                                .entry(name)
+                                .or_default()
+                                .push((applies_here.clone(), value));
+                        }
+                        insert_row = insert_row.or(applies_here);
+                    },
+                }
+            }
+
+            let projection = left_schema
+                .iter()
+                .map(|(name, dtype)| {
+                    // inserted rows default to NULL in any columns that were not given values
+                    let original = when(has_target.clone())
+                        .then(col(name.clone()))
+                        .otherwise(lit(LiteralValue::untyped_null()));
+                    let value = values
+                        .remove(name)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .rev()
+                        .fold(original, |acc, (cond, value)| {
+                            when(cond).then(value).otherwise(acc)
+                        });
+                    value.strict_cast(dtype.clone()).alias(name.clone())
+                })
+                .collect::<Vec<_>>();
+
+            let lf = joined
+                .filter(keep_target.or(insert_row))
+                .sort_by_exprs(
+                    [col(TARGET_ROW), col(SOURCE_ROW)],
+                    SortMultipleOptions::default()
+                        .with_nulls_last(true)
+                        .with_maintain_order(true),
+                )
+                .select(projection);
+            self.table_map.insert(tbl_name, lf.clone());
+            Ok(lf)
+        } else {
+            polars_bail!(SQLInterface: "unexpected statement type; expected MERGE")
+        }
+    }
+
     // TRUNCATE <tbl>
     fn execute_truncate_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         if let Statement::Truncate {
@@ -594,24 +949,131 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = match &*cte.query.body {
+                    SetExpr::SetOperation {
+                        op: SetOperator::Union,
+                        set_quantifier,
+                        left,
+                        right,
+                    } if with.recursive && set_expr_references_table(right, &cte_name) => {
+                        self.register_ctes(&cte.query)?;
+                        let lf = self.execute_recursive_cte(cte, left, right, set_quantifier)?;
+                        self.process_limit_offset(lf, &cte.query.limit, &cte.query.offset)?
+                    },
+                    _ => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE of the form `<anchor> UNION [ALL] <recursive term>` by
+    /// iterating to a fixpoint; each iteration evaluates the recursive term against the
+    /// rows produced by the previous one, until no new rows are produced.
+    ///
+    /// As the number of iterations depends on the data, each of them is collected
+    /// eagerly; the result is registered as an in-memory table.
+    fn execute_recursive_cte(
+        &mut self,
+        cte: &Cte,
+        anchor: &SetExpr,
+        recursive_term: &SetExpr,
+        quantifier: &SetQuantifier,
+    ) -> PolarsResult<LazyFrame> {
+        let cte_name = &cte.alias.name.value;
+        let distinct = match quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'",
+                quantifier, cte_name
+            ),
+        };
+        let lf = self.process_query(anchor, &cte.query)?;
+        let lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+        let mut result = lf.collect()?;
+        if distinct {
+            result = result.unique_stable(None, UniqueKeepStrategy::First, None)?;
+        }
+        let schema = result.schema().clone();
+
+        let mut working_table = result.clone();
+        let mut iterations = 0;
+        while working_table.height() > 0 {
+            polars_ensure!(
+                iterations < self.max_recursive_cte_iterations,
+                SQLInterface: "recursive CTE '{}' did not terminate within {} iterations",
+                cte_name, self.max_recursive_cte_iterations
+            );
+            iterations += 1;
+
+            // The recursive term only sees the rows produced by the previous iteration.
+            self.register_cte(cte_name, working_table.lazy());
+            let mut lf = self.process_query(recursive_term, &cte.query)?;
+            let term_schema = self.get_frame_schema(&mut lf)?;
+            polars_ensure!(
+                term_schema.len() == schema.len(),
+                SQLInterface: "recursive CTE '{}' requires equal number of columns in each UNION term",
+                cte_name
+            );
+            // Align the recursive term with the names and types of the anchor.
+            let exprs: Vec<_> = term_schema
+                .iter_names()
+                .zip(schema.iter())
+                .map(|(term_name, (name, dtype))| {
+                    col(term_name.clone())
+                        .strict_cast(dtype.clone())
+                        .alias(name.clone())
+                })
+                .collect();
+            let new_rows = lf.select(exprs).collect()?;
+
+            working_table = if distinct {
+                // Keep only rows that have not been seen in any previous iteration.
+                let n_seen = result.height();
+                result = result.vstack(&new_rows)?.unique_stable(
+                    None,
+                    UniqueKeepStrategy::First,
+                    None,
+                )?;
+                result.slice(n_seen as i64, usize::MAX)
+            } else {
+                result.vstack_mut(&new_rows)?;
+                new_rows
+            };
+        }
+        result.as_single_chunk_par();
+        Ok(result.lazy())
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
         if !tbl_expr.joins.is_empty() {
+            // names of the relations that LATERAL joins are able to reference
+            let mut outer_names = vec![l_name.clone()];
             for join in &tbl_expr.joins {
+                if is_lateral_relation(&join.relation) {
+                    let join_type = lateral_join_type(&join.join_operator)?;
+                    let left_schema = self.get_frame_schema(&mut lf)?;
+                    let (r_name, right_schema);
+                    (r_name, right_schema, lf) = self.execute_lateral_join(
+                        lf,
+                        &left_schema,
+                        &join.relation,
+                        &outer_names,
+                        join_type,
+                    )?;
+                    self.track_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
+                    outer_names.push(r_name);
+                    continue;
+                }
                 let (r_name, mut rf) = self.get_table(&join.relation)?;
                 if r_name.is_empty() {
                     // Require non-empty to avoid duplicate column errors from nested self-joins.
@@ -678,30 +1140,365 @@ impl SQLContext {
                         polars_bail!(SQLInterface: "join type '{:?}' not currently supported", join_type)
                     },
                 };
+                self.track_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
+                outer_names.push(r_name);
+            }
+        };
+        Ok(lf)
+    }
 
-                // track join-aliased columns so we can resolve them later
-                let joined_schema = self.get_frame_schema(&mut lf)?;
+    /// Track join-aliased columns so we can resolve them later.
+    fn track_joined_aliases(
+        &mut self,
+        lf: &mut LazyFrame,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> PolarsResult<()> {
+        let joined_schema = self.get_frame_schema(lf)?;
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{name}:{r_name}");
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+        Ok(())
+    }
 
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.clone(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{name}:{r_name}");
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
+    /// Join a LATERAL relation (a correlated subquery, or an UNNEST of columns from
+    /// the preceding tables) onto the given frame, returning the relation name, the
+    /// schema of the columns it contributes, and the joined frame.
+    fn execute_lateral_join(
+        &mut self,
+        lf: LazyFrame,
+        left_schema: &Schema,
+        relation: &TableFactor,
+        outer_names: &[String],
+        join_type: JoinType,
+    ) -> PolarsResult<(String, Schema, LazyFrame)> {
+        match relation {
+            TableFactor::Derived {
+                subquery, alias, ..
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "derived tables must have aliases");
+                };
+                self.execute_lateral_subquery(
+                    lf,
+                    left_schema,
+                    subquery,
+                    alias,
+                    outer_names,
+                    join_type,
+                )
+            },
+            TableFactor::UNNEST {
+                alias,
+                array_exprs,
+                with_offset,
+                with_offset_alias,
+                with_ordinality,
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "UNNEST table must have an alias");
+                };
+                let mut column_names: Vec<Option<PlSmallStr>> = alias
+                    .columns
+                    .iter()
+                    .map(|c| Some(PlSmallStr::from_str(c.name.value.as_str())))
+                    .collect();
+                let ordinality = unnest_ordinality(
+                    *with_ordinality,
+                    *with_offset,
+                    with_offset_alias,
+                    &mut column_names,
+                    array_exprs.len(),
+                );
+                if column_names.len() != array_exprs.len() {
+                    let plural = if array_exprs.len() > 1 { "s" } else { "" };
+                    polars_bail!(
+                        SQLSyntax:
+                        "UNNEST table alias requires {} column name{}, found {}", array_exprs.len(), plural, column_names.len()
+                    );
+                }
+                let r_name = alias.name.value.clone();
+                let arrays = array_exprs
+                    .iter()
+                    .map(|e| parse_sql_expr(e, self, Some(left_schema)))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                // name the unnested columns, suffixing any that clash with existing ones
+                let mut right_schema = Schema::with_capacity(arrays.len() + 1);
+                let mut output_names = vec![];
+                for (expr, name) in arrays
+                    .iter()
+                    .zip(column_names.iter().flatten())
+                    .map(|(e, n)| (Some(e), n))
+                    .chain(ordinality.as_ref().map(|(n, _)| (None, n)))
+                {
+                    let dtype = match expr {
+                        Some(expr) => {
+                            let dtype = expr.to_field(left_schema)?.dtype;
+                            match dtype.inner_dtype() {
+                                Some(inner) => inner.clone(),
+                                None => polars_bail!(
+                                    SQLSyntax: "UNNEST expects list/array values; found {}", dtype
+                                ),
                             }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
+                        },
+                        None => DataType::Int64,
+                    };
+                    let output_name = if left_schema.contains(name) {
+                        format_pl_smallstr!("{name}:{r_name}")
+                    } else {
+                        name.clone()
+                    };
+                    right_schema.insert(name.clone(), dtype);
+                    output_names.push(output_name);
+                }
+
+                // explode the lists together; rows with empty/null lists are only
+                // retained (with null values) for a LEFT JOIN
+                const ROW_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_UNNEST_ROW");
+                const LIST_LEN: PlSmallStr = PlSmallStr::from_static("__POLARS_UNNEST_LEN");
+                let n_arrays = arrays.len();
+                let list_len = arrays[0].clone().list().len();
+                let mut lf = lf.with_columns(
+                    arrays
+                        .into_iter()
+                        .zip(&output_names)
+                        .map(|(e, name)| e.alias(name.clone()))
+                        .chain([list_len.alias(LIST_LEN)])
+                        .collect::<Vec<_>>(),
                 );
+                if join_type == JoinType::Inner {
+                    lf = lf.filter(col(LIST_LEN).gt(lit(0)));
+                }
+                let mut lf = lf
+                    .with_row_index(ROW_INDEX, None)
+                    .explode(cols(output_names[..n_arrays].iter().cloned()));
+                if let Some((_, start)) = ordinality {
+                    let position = repeat(true, len()).cum_count(false).over([col(ROW_INDEX)])
+                        + lit(start - 1);
+                    lf = lf.with_column(
+                        when(col(LIST_LEN).gt(lit(0)))
+                            .then(position.cast(DataType::Int64))
+                            .otherwise(lit(LiteralValue::untyped_null()).cast(DataType::Int64))
+                            .alias(output_names[n_arrays].clone()),
+                    );
+                }
+                let lf = lf.drop(cols([ROW_INDEX, LIST_LEN]));
+                self.table_map.insert(
+                    r_name.clone(),
+                    lf.clone()
+                        .select([cols(output_names.iter().cloned()).as_expr()]),
+                );
+                Ok((r_name, right_schema, lf))
+            },
+            _ => polars_bail!(SQLInterface: "LATERAL join is not supported for: {}", relation),
+        }
+    }
+
+    /// Decorrelate a LATERAL subquery: equality predicates between columns of the outer
+    /// tables and the subquery become join keys, and any LIMIT/OFFSET is applied per key
+    /// (which covers the common "top-N per group" pattern).
+    fn execute_lateral_subquery(
+        &mut self,
+        lf: LazyFrame,
+        left_schema: &Schema,
+        subquery: &Query,
+        alias: &TableAlias,
+        outer_names: &[String],
+        join_type: JoinType,
+    ) -> PolarsResult<(String, Schema, LazyFrame)> {
+        let SetExpr::Select(select) = &*subquery.body else {
+            polars_bail!(SQLInterface: "LATERAL subquery must be a simple SELECT; found {}", subquery.body)
+        };
+        let is_outer_ref = |e: &SQLExpr| {
+            matches!(e, SQLExpr::CompoundIdentifier(idents)
+                if idents.len() > 1 && outer_names.contains(&idents[0].value))
+        };
+
+        // split the correlated equality predicates out of the WHERE clause
+        let mut select = select.clone();
+        let mut predicates = vec![];
+        if let Some(selection) = select.selection.take() {
+            split_conjunction(selection, &mut predicates);
+        }
+        let (mut outer_keys, mut inner_keys, mut remaining) = (vec![], vec![], vec![]);
+        for predicate in predicates {
+            match &predicate {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: BinaryOperator::Eq,
+                    right,
+                } if is_outer_ref(left) != is_outer_ref(right) => {
+                    let (outer, inner) = if is_outer_ref(left) {
+                        (left, right)
+                    } else {
+                        (right, left)
+                    };
+                    outer_keys.push(parse_sql_expr(outer, self, Some(left_schema))?);
+                    inner_keys.push((**inner).clone());
+                },
+                _ => remaining.push(predicate),
+            }
+        }
+        select.selection = remaining.into_iter().reduce(|l, r| SQLExpr::BinaryOp {
+            left: Box::new(l),
+            op: BinaryOperator::And,
+            right: Box::new(r),
+        });
+
+        // project the inner side of the correlation keys (also grouping by them if the
+        // subquery is grouped or aggregates)
+        let has_aggregates = select.projection.iter().any(|item| match item {
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                has_sql_aggregate(expr, self)
+            },
+            _ => false,
+        });
+        // an aggregate without GROUP BY yields exactly one row for every outer row (so the
+        // outer rows without matches are kept, and their counts are 0 rather than NULL)
+        let is_ungrouped_aggregate = has_aggregates
+            && matches!(&select.group_by, GroupByExpr::Expressions(exprs, _) if exprs.is_empty());
+        let count_positions: Vec<usize> = select
+            .projection
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| match item {
+                SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. }
+                    if is_sql_count(expr, self) =>
+                {
+                    Some(i)
+                },
+                _ => None,
+            })
+            .collect();
+        let key_names: Vec<PlSmallStr> = (0..inner_keys.len())
+            .map(|i| format_pl_smallstr!("__POLARS_LATERAL_KEY_{i}"))
+            .collect();
+        for (expr, name) in inner_keys.iter().zip(&key_names) {
+            select.projection.push(SelectItem::ExprWithAlias {
+                expr: expr.clone(),
+                alias: Ident::new(name.as_str()),
+            });
+        }
+        if let GroupByExpr::Expressions(group_by_exprs, _) = &mut select.group_by {
+            if !group_by_exprs.is_empty() || has_aggregates {
+                group_by_exprs.extend(inner_keys);
             }
+        }
+        let key_cols: Vec<_> = key_names.iter().map(|name| col(name.clone())).collect();
+        let mut query = subquery.clone();
+        query.body = Box::new(SetExpr::Select(select));
+        let (limit, offset) = if key_names.is_empty() {
+            (None, None)
+        } else {
+            (query.limit.take(), query.offset.take())
         };
-        Ok(lf)
+
+        // the outer tables are not in scope for the remainder of the subquery
+        let hidden_aliases: Vec<_> = outer_names
+            .iter()
+            .filter_map(|name| self.table_aliases.borrow_mut().remove_entry(name))
+            .collect();
+        let rf = self.execute_query(&query);
+        self.table_aliases.borrow_mut().extend(hidden_aliases);
+        let mut rf = rf?;
+
+        let schema = self.get_frame_schema(&mut rf)?;
+        let n_cols = schema.len() - key_names.len();
+        if !alias.columns.is_empty() {
+            polars_ensure!(
+                alias.columns.len() == n_cols,
+                SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the LATERAL subquery ({})",
+                alias.columns.len(), alias.name.value, n_cols
+            );
+            let existing: Vec<_> = schema.iter_names().take(n_cols).cloned().collect();
+            let new: Vec<_> = alias.columns.iter().map(|c| c.name.value.clone()).collect();
+            rf = rf.rename(existing, new, true);
+        }
+        if limit.is_some() || offset.is_some() {
+            // the subquery output is already sorted, so number the rows within each key
+            let position = repeat(true, len()).cum_count(false).over(&key_cols);
+            let (offset, limit) = parse_limit_offset(&limit, &offset)?;
+            let offset = offset.unwrap_or(0);
+            let mut predicate = position.clone().gt(lit(offset));
+            if let Some(limit) = limit {
+                predicate = predicate.and(position.lt_eq(lit(offset + limit as i64)));
+            }
+            rf = rf.filter(predicate);
+        }
+
+        let r_name = alias.name.value.clone();
+        let right_schema = self.get_frame_schema(&mut rf)?;
+        let right_schema: Schema = right_schema
+            .iter()
+            .take(n_cols)
+            .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+            .collect();
+        self.table_map.insert(r_name.clone(), rf.clone());
+
+        let lf = if key_names.is_empty() && join_type == JoinType::Left {
+            // unlike a cross join, this retains the outer rows if the subquery is empty
+            const KEY: PlSmallStr = PlSmallStr::from_static("__POLARS_LATERAL_KEY");
+            lf.with_column(lit(true).alias(KEY))
+                .join_builder()
+                .with(rf.with_column(lit(true).alias(KEY)))
+                .on([col(KEY)])
+                .how(JoinType::Left)
+                .suffix(format!(":{r_name}"))
+                .coalesce(JoinCoalesce::CoalesceColumns)
+                .maintain_order(MaintainOrderJoin::LeftRight)
+                .finish()
+                .drop(cols([KEY]))
+        } else if key_names.is_empty() {
+            lf.cross_join(rf, Some(format_pl_smallstr!(":{}", r_name)))
+        } else {
+            let lf = lf
+                .join_builder()
+                .with(rf)
+                .left_on(outer_keys)
+                .right_on(key_cols)
+                .how(if is_ungrouped_aggregate {
+                    JoinType::Left
+                } else {
+                    join_type
+                })
+                .suffix(format!(":{r_name}"))
+                .coalesce(JoinCoalesce::KeepColumns)
+                .maintain_order(MaintainOrderJoin::LeftRight)
+                .finish()
+                .drop(cols(key_names));
+            if is_ungrouped_aggregate && !count_positions.is_empty() {
+                let counts: Vec<_> = count_positions
+                    .iter()
+                    .map(|&i| {
+                        let (name, dtype) = right_schema.get_at_index(i).unwrap();
+                        let output_name = if left_schema.contains(name) {
+                            format_pl_smallstr!("{name}:{r_name}")
+                        } else {
+                            name.clone()
+                        };
+                        col(output_name).fill_null(lit(0).cast(dtype.clone()))
+                    })
+                    .collect();
+                lf.with_columns(counts)
+            } else {
+                lf
+            }
+        };
+        Ok((r_name, right_schema, lf))
     }
 
     /// Execute the 'SELECT' part of the query.
@@ -711,9 +1508,25 @@ impl SQLContext {
         } else {
             // Note: implicit joins need more work to support properly,
             // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
+            let mut from = select_stmt.clone().from;
             if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+                // LATERAL relations following a comma are implicitly cross-joined
+                if from[1..]
+                    .iter()
+                    .all(|tbl| tbl.joins.is_empty() && is_lateral_relation(&tbl.relation))
+                {
+                    let laterals: Vec<_> = from
+                        .drain(1..)
+                        .map(|tbl| Join {
+                            relation: tbl.relation,
+                            global: false,
+                            join_operator: JoinOperator::CrossJoin,
+                        })
+                        .collect();
+                    from[0].joins.extend(laterals);
+                } else {
+                    polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+                }
             }
             self.execute_from_statement(from.first().unwrap())?
         };
@@ -734,32 +1547,18 @@ impl SQLContext {
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
-        match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+        let mut grouping_sets: Option<GroupingSets> = None;
+        let group_by_modifiers = match &select_stmt.group_by {
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values),
+            // including "ROLLUP(...)", "CUBE(...)" and "GROUPING SETS(...)" items
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
-                // translate the group expressions, allowing ordinal values
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| {
-                        self.expr_or_ordinal(
-                            e,
-                            &projections,
-                            None,
-                            Some(schema.deref()),
-                            "GROUP BY",
-                        )
-                    })
-                    .collect::<PolarsResult<_>>()?
+                (group_by_keys, grouping_sets) =
+                    self.resolve_group_by_keys(group_by_exprs, &projections, &schema)?;
+                modifiers
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
             GroupByExpr::All(modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
                 projections.iter().for_each(|expr| match expr {
                     // immediately match the most common cases (col|agg|len|lit, optionally aliased).
                     Expr::Agg(_) | Expr::Len | Expr::Literal(_) => (),
@@ -782,8 +1581,33 @@ impl SQLContext {
                         }
                     },
                 });
+                modifiers
             },
         };
+        // "GROUP BY ... WITH ROLLUP|CUBE" applies to all of the keys
+        if let [modifier] = group_by_modifiers.as_slice() {
+            let n_keys = group_by_keys.len();
+            let sets = match modifier {
+                GroupByWithModifier::Rollup if grouping_sets.is_none() => rollup_sets(n_keys),
+                GroupByWithModifier::Cube if grouping_sets.is_none() => cube_sets(n_keys),
+                _ => polars_bail!(SQLInterface: "GROUP BY does not support {} here", modifier),
+            };
+            grouping_sets = Some(sets_to_membership(&sets, n_keys));
+        } else if !group_by_modifiers.is_empty() {
+            polars_bail!(SQLInterface: "GROUP BY supports at most one of the CUBE, ROLLUP, or TOTALS modifiers")
+        }
+        // 'GROUPING()' needs the per-set indicator columns, even for a single grouping set
+        if grouping_sets.is_none()
+            && !group_by_keys.is_empty()
+            && projections.iter().any(|p| {
+                has_expr(
+                    p,
+                    |e| matches!(e, Expr::Column(name) if name.starts_with(GROUPING_COLUMN_PREFIX)),
+                )
+            })
+        {
+            grouping_sets = Some(vec![vec![true; group_by_keys.len()]]);
+        }
 
         lf = if group_by_keys.is_empty() {
             // The 'having' clause is only valid inside 'group by'
@@ -888,7 +1712,10 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf = match &grouping_sets {
+                Some(sets) => self.process_grouping_sets(lf, &group_by_keys, sets, &projections)?,
+                None => self.process_group_by(lf, &group_by_keys, &projections, None)?,
+            };
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
@@ -1129,7 +1956,10 @@ impl SQLContext {
                 subquery,
                 alias,
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
+                polars_ensure!(
+                    !(*lateral),
+                    SQLInterface: "LATERAL subquery must follow another table in the FROM clause"
+                );
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -1143,11 +1973,11 @@ impl SQLContext {
                 alias,
                 array_exprs,
                 with_offset,
-                with_offset_alias: _,
-                ..
+                with_offset_alias,
+                with_ordinality,
             } => {
                 if let Some(alias) = alias {
-                    let column_names: Vec<Option<PlSmallStr>> = alias
+                    let mut column_names: Vec<Option<PlSmallStr>> = alias
                         .columns
                         .iter()
                         .map(|c| {
@@ -1158,6 +1988,13 @@ impl SQLContext {
                             }
                         })
                         .collect();
+                    let ordinality = unnest_ordinality(
+                        *with_ordinality,
+                        *with_offset,
+                        with_offset_alias,
+                        &mut column_names,
+                        array_exprs.len(),
+                    );
 
                     let column_values: Vec<Series> = array_exprs
                         .iter()
@@ -1175,7 +2012,7 @@ impl SQLContext {
                             "UNNEST table alias requires {} column name{}, found {}", column_values.len(), plural, column_names.len()
                         );
                     }
-                    let column_series: Vec<Column> = column_values
+                    let mut column_series: Vec<Column> = column_values
                         .into_iter()
                         .zip(column_names)
                         .map(|(s, name)| {
@@ -1188,13 +2025,12 @@ impl SQLContext {
                         .map(Column::from)
                         .collect();
 
-                    let lf = DataFrame::new(column_series)?.lazy();
-
-                    if *with_offset {
-                        // TODO: support 'WITH ORDINALITY|OFFSET' modifier.
-                        //  (note that 'WITH OFFSET' is BigQuery-specific syntax, not PostgreSQL)
-                        polars_bail!(SQLInterface: "UNNEST tables do not (yet) support WITH ORDINALITY|OFFSET");
+                    if let Some((name, start)) = ordinality {
+                        let height = column_series.first().map_or(0, |c| c.len()) as i64;
+                        let values: Vec<i64> = (start..start + height).collect();
+                        column_series.push(Column::new(name, values));
                     }
+                    let lf = DataFrame::new(column_series)?.lazy();
                     let table_name = alias.name.value.clone();
                     self.table_map.insert(table_name.clone(), lf.clone());
                     Ok((table_name, lf))
@@ -1292,14 +2128,143 @@ impl SQLContext {
         ))
     }
 
+    /// Resolve the GROUP BY keys, expanding any "ROLLUP", "CUBE" and "GROUPING SETS" items
+    /// into grouping sets (marking which of the keys are in each set); these are `None`
+    /// for a plain GROUP BY, which has a single set with all of the keys.
+    fn resolve_group_by_keys(
+        &mut self,
+        group_by_exprs: &[SQLExpr],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Option<GroupingSets>)> {
+        let mut keys: Vec<Expr> = Vec::with_capacity(group_by_exprs.len());
+        let mut resolve_keys = |ctx: &mut Self, exprs: &[SQLExpr]| {
+            exprs
+                .iter()
+                .map(|e| {
+                    let key =
+                        ctx.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")?;
+                    Ok(match keys.iter().position(|k| *k == key) {
+                        Some(idx) => idx,
+                        None => {
+                            keys.push(key);
+                            keys.len() - 1
+                        },
+                    })
+                })
+                .collect::<PolarsResult<Vec<usize>>>()
+        };
+
+        // grouping sets (as key indices) of each item; the GROUP BY has their cross product
+        let mut has_grouping_sets = false;
+        let mut item_sets = Vec::with_capacity(group_by_exprs.len());
+        for e in group_by_exprs {
+            let (groups, sets) = match e {
+                SQLExpr::Rollup(groups) => (groups, rollup_sets(groups.len())),
+                SQLExpr::Cube(groups) => (groups, cube_sets(groups.len())),
+                SQLExpr::GroupingSets(sets) => {
+                    has_grouping_sets = true;
+                    item_sets.push(
+                        sets.iter()
+                            .map(|set| resolve_keys(self, set))
+                            .collect::<PolarsResult<Vec<_>>>()?,
+                    );
+                    continue;
+                },
+                e => {
+                    item_sets.push(vec![resolve_keys(self, std::slice::from_ref(e))?]);
+                    continue;
+                },
+            };
+            // ROLLUP and CUBE items may group multiple keys together, e.g. "ROLLUP((a, b), c)"
+            has_grouping_sets = true;
+            let groups = groups
+                .iter()
+                .map(|group| resolve_keys(self, group))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            item_sets.push(
+                sets.into_iter()
+                    .map(|set| set.into_iter().flat_map(|g| groups[g].clone()).collect())
+                    .collect(),
+            );
+        }
+        if !has_grouping_sets {
+            return Ok((keys, None));
+        }
+        let sets = item_sets
+            .into_iter()
+            .fold(vec![vec![]], |acc: Vec<Vec<usize>>, item| {
+                acc.iter()
+                    .flat_map(|set| {
+                        item.iter()
+                            .map(move |other| set.iter().chain(other).copied().collect())
+                    })
+                    .collect()
+            });
+        let n_keys = keys.len();
+        Ok((keys, Some(sets_to_membership(&sets, n_keys))))
+    }
+
+    /// Evaluate a GROUP BY with multiple grouping sets as the union of an aggregation per
+    /// set. Keys outside of a set are NULL in its rows; `GROUPING()` tells these apart from
+    /// NULL key values through a constant indicator column per key.
+    fn process_grouping_sets(
+        &mut self,
+        mut lf: LazyFrame,
+        group_by_keys: &[Expr],
+        grouping_sets: &[Vec<bool>],
+        projections: &[Expr],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let key_names = group_by_keys
+            .iter()
+            .map(|k| Ok(k.to_field(&schema)?.name))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let frames = grouping_sets
+            .iter()
+            .map(|in_set| {
+                let indicators = key_names
+                    .iter()
+                    .zip(in_set)
+                    .map(|(name, in_set)| {
+                        lit(!in_set as i32)
+                            .alias(format_pl_smallstr!("{GROUPING_COLUMN_PREFIX}{name}"))
+                    })
+                    .collect::<Vec<_>>();
+                self.process_group_by(
+                    lf.clone().with_columns(indicators),
+                    group_by_keys,
+                    projections,
+                    Some(in_set),
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let opts = UnionArgs {
+            parallel: true,
+            to_supertypes: true,
+            ..Default::default()
+        };
+        polars_lazy::dsl::concat(frames, opts)
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
         projections: &[Expr],
+        grouping_set: Option<&[bool]>,
     ) -> PolarsResult<LazyFrame> {
         let schema_before = self.get_frame_schema(&mut lf)?;
-        let group_by_keys_schema = expressions_to_schema(group_by_keys, &schema_before)?;
+        // 'GROUPING()' indicators are constant, so also grouping by them is a no-op
+        let grouping_indicators = schema_before
+            .iter_names()
+            .filter(|name| name.starts_with(GROUPING_COLUMN_PREFIX))
+            .map(|name| col(name.clone()))
+            .collect::<Vec<_>>();
+        let all_keys = [group_by_keys, &grouping_indicators].concat();
+        let group_by_keys_schema = expressions_to_schema(&all_keys, &schema_before)?;
 
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
@@ -1365,7 +2330,26 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        // keys that are not part of the grouping set are output as NULL
+        let mut aggregation_keys = Vec::with_capacity(all_keys.len());
+        let mut null_keys = vec![];
+        for (i, key) in group_by_keys.iter().enumerate() {
+            if grouping_set.is_none_or(|set| set[i]) {
+                aggregation_keys.push(key.clone());
+            } else {
+                let field = key.to_field(&schema_before)?;
+                null_keys.push(
+                    lit(LiteralValue::untyped_null())
+                        .cast(field.dtype)
+                        .alias(field.name),
+                );
+            }
+        }
+        aggregation_keys.extend(grouping_indicators);
+        let mut aggregated = lf.group_by(aggregation_keys).agg(&aggregation_projection);
+        if !null_keys.is_empty() {
+            aggregated = aggregated.with_columns(null_keys);
+        }
         let projection_schema = expressions_to_schema(projections, &schema_before)?;
 
         // A final projection to get the proper order and any deferred transforms/aliases.
@@ -1395,43 +2379,11 @@ impl SQLContext {
         limit: &Option<SQLExpr>,
         offset: &Option<Offset>,
     ) -> PolarsResult<LazyFrame> {
-        match (offset, limit) {
-            (
-                Some(Offset {
-                    value: SQLExpr::Value(SQLValue::Number(offset, _)),
-                    ..
-                }),
-                Some(SQLExpr::Value(SQLValue::Number(limit, _))),
-            ) => Ok(lf.slice(
-                offset
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
-                limit
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "LIMIT conversion error: {}", e))?,
-            )),
-            (
-                Some(Offset {
-                    value: SQLExpr::Value(SQLValue::Number(offset, _)),
-                    ..
-                }),
-                None,
-            ) => Ok(lf.slice(
-                offset
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
-                IdxSize::MAX,
-            )),
-            (None, Some(SQLExpr::Value(SQLValue::Number(limit, _)))) => Ok(lf.limit(
-                limit
-                    .parse()
-                    .map_err(|e| polars_err!(SQLInterface: "LIMIT conversion error: {}", e))?,
-            )),
-            (None, None) => Ok(lf),
-            _ => polars_bail!(
-                SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported",
-            ),
-        }
+        Ok(match parse_limit_offset(limit, offset)? {
+            (Some(offset), limit) => lf.slice(offset, limit.unwrap_or(IdxSize::MAX)),
+            (None, Some(limit)) => lf.limit(limit),
+            (None, None) => lf,
+        })
     }
 
     fn process_qualified_wildcard(
@@ -1721,3 +2673,191 @@ impl ExprSqlProjectionHeightBehavior {
         }
     }
 }
+
+/// Check whether a relation in a join is LATERAL; an UNNEST of anything other than
+/// literal arrays is implicitly lateral, as it references the preceding tables.
+fn is_lateral_relation(relation: &TableFactor) -> bool {
+    match relation {
+        TableFactor::Derived { lateral, .. } => *lateral,
+        TableFactor::UNNEST { array_exprs, .. } => {
+            array_exprs.iter().any(|e| !matches!(e, SQLExpr::Array(_)))
+        },
+        _ => false,
+    }
+}
+
+fn lateral_join_type(join_operator: &JoinOperator) -> PolarsResult<JoinType> {
+    let is_trivial = |constraint: &JoinConstraint| {
+        matches!(
+            constraint,
+            JoinConstraint::None | JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(true)))
+        )
+    };
+    Ok(match join_operator {
+        JoinOperator::CrossJoin => JoinType::Inner,
+        JoinOperator::Inner(constraint) if is_trivial(constraint) => JoinType::Inner,
+        JoinOperator::LeftOuter(constraint) if is_trivial(constraint) => JoinType::Left,
+        JoinOperator::Inner(_) | JoinOperator::LeftOuter(_) => polars_bail!(
+            SQLInterface: "LATERAL joins only support the 'ON TRUE' join condition"
+        ),
+        join_type => polars_bail!(
+            SQLInterface: "join type '{:?}' not supported for LATERAL joins", join_type
+        ),
+    })
+}
+
+/// Determine the name and starting value of the UNNEST ordinality column (if any);
+/// an explicit name is taken from the trailing table alias column.
+fn unnest_ordinality(
+    with_ordinality: bool,
+    with_offset: bool,
+    with_offset_alias: &Option<Ident>,
+    column_names: &mut Vec<Option<PlSmallStr>>,
+    n_arrays: usize,
+) -> Option<(PlSmallStr, i64)> {
+    // 'WITH ORDINALITY' is 1-based (PostgreSQL), 'WITH OFFSET' is 0-based (BigQuery)
+    let (default_name, start) = match (with_ordinality, with_offset) {
+        (true, _) => ("ordinality", 1),
+        (false, true) => ("offset", 0),
+        (false, false) => return None,
+    };
+    let alias_name = if column_names.len() > n_arrays {
+        column_names.pop().flatten()
+    } else {
+        None
+    };
+    let name = alias_name
+        .or_else(|| with_offset_alias.as_ref().map(|a| a.value.as_str().into()))
+        .unwrap_or_else(|| PlSmallStr::from_static(default_name));
+    Some((name, start))
+}
+
+/// Resolve the target column (and its dtype) of an UPDATE assignment.
+fn resolve_assignment_target(
+    target: &AssignmentTarget,
+    schema: &Schema,
+) -> PolarsResult<(PlSmallStr, DataType)> {
+    match target {
+        AssignmentTarget::ColumnName(ObjectName(idents)) => {
+            let name = idents.last().unwrap().value.as_str();
+            match schema.get_full(name) {
+                Some((_, name, dtype)) => Ok((name.clone(), dtype.clone())),
+                None => polars_bail!(SQLInterface: "cannot assign to unknown column '{}'", name),
+            }
+        },
+        AssignmentTarget::Tuple(_) => {
+            polars_bail!(SQLInterface: "tuple assignment is not supported; found {}", target)
+        },
+    }
+}
+
+/// Parse numeric LIMIT/OFFSET values, returning `(offset, limit)`.
+fn parse_limit_offset(
+    limit: &Option<SQLExpr>,
+    offset: &Option<Offset>,
+) -> PolarsResult<(Option<i64>, Option<IdxSize>)> {
+    let offset = match offset {
+        Some(Offset {
+            value: SQLExpr::Value(SQLValue::Number(offset, _)),
+            ..
+        }) => Some(
+            offset
+                .parse()
+                .map_err(|e| polars_err!(SQLInterface: "OFFSET conversion error: {}", e))?,
+        ),
+        None => None,
+        _ => polars_bail!(SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported"),
+    };
+    let limit = match limit {
+        Some(SQLExpr::Value(SQLValue::Number(limit, _))) => Some(
+            limit
+                .parse()
+                .map_err(|e| polars_err!(SQLInterface: "LIMIT conversion error: {}", e))?,
+        ),
+        None => None,
+        _ => polars_bail!(SQLSyntax: "non-numeric arguments for LIMIT/OFFSET are not supported"),
+    };
+    Ok((offset, limit))
+}
+
+/// Flatten a chain of 'AND' predicates into its individual terms.
+fn split_conjunction(expr: SQLExpr, predicates: &mut Vec<SQLExpr>) {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            split_conjunction(*left, predicates);
+            split_conjunction(*right, predicates);
+        },
+        SQLExpr::Nested(expr) => split_conjunction(*expr, predicates),
+        expr => predicates.push(expr),
+    }
+}
+
+/// Check whether the given set expression reads from the named table in any of its
+/// `FROM` clauses (including joins, derived tables and nested set operations).
+fn set_expr_references_table(expr: &SetExpr, name: &str) -> bool {
+    fn relation_references_table(relation: &TableFactor, name: &str) -> bool {
+        match relation {
+            TableFactor::Table { name: tbl, .. } => {
+                tbl.0.last().is_some_and(|ident| ident.value == name)
+            },
+            TableFactor::Derived { subquery, .. } => {
+                set_expr_references_table(&subquery.body, name)
+            },
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => tables_reference_table(std::slice::from_ref(table_with_joins), name),
+            _ => false,
+        }
+    }
+    fn tables_reference_table(tables: &[TableWithJoins], name: &str) -> bool {
+        tables.iter().any(|tbl| {
+            relation_references_table(&tbl.relation, name)
+                || tbl
+                    .joins
+                    .iter()
+                    .any(|join| relation_references_table(&join.relation, name))
+        })
+    }
+    match expr {
+        SetExpr::Select(select) => tables_reference_table(&select.from, name),
+        SetExpr::Query(query) => set_expr_references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_references_table(left, name) || set_expr_references_table(right, name)
+        },
+        _ => false,
+    }
+}
+
+/// Whether each of the GROUP BY keys is part of a grouping set, for every set.
+type GroupingSets = Vec<Vec<bool>>;
+
+/// Grouping sets of "ROLLUP(g1, ..., gn)", as indices into its groups.
+fn rollup_sets(n_groups: usize) -> Vec<Vec<usize>> {
+    (0..=n_groups).rev().map(|n| (0..n).collect()).collect()
+}
+
+/// Grouping sets of "CUBE(g1, ..., gn)", as indices into its groups.
+fn cube_sets(n_groups: usize) -> Vec<Vec<usize>> {
+    (0..1usize << n_groups)
+        .rev()
+        .map(|mask| {
+            (0..n_groups)
+                .filter(|g| mask & (1 << (n_groups - 1 - g)) != 0)
+                .collect()
+        })
+        .collect()
+}
+
+fn sets_to_membership(sets: &[Vec<usize>], n_keys: usize) -> GroupingSets {
+    sets.iter()
+        .map(|set| {
+            let mut in_set = vec![false; n_keys];
+            set.iter().for_each(|&k| in_set[k] = true);
+            in_set
+        })
+        .collect()
+}
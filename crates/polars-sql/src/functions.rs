@@ -2,26 +2,33 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, IdxSize, PolarsResult, QuantileMethod,
+    RollingOptionsFixedWindow, Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 use polars_ops::chunked_array::UnicodeForm;
-use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_ops::series::{RankMethod, RankOptions, RoundMode};
+use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, repeat, when};
 use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
 use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
 
+/// Prefix of the (per grouping set) constant columns that record if a GROUP BY key is
+/// part of the grouping set; these back the `GROUPING()` function.
+pub(crate) const GROUPING_COLUMN_PREFIX: &str = "__POLARS_GROUPING_";
+
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
     pub(crate) ctx: &'a mut SQLContext,
@@ -555,6 +562,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function.
+    /// Returns a bit mask of the given GROUP BY keys, with a bit set for each key that is
+    /// not part of the grouping set of the row (and so is aggregated over).
+    /// ```sql
+    /// SELECT GROUPING(column_1, column_2) FROM df GROUP BY ROLLUP(column_1, column_2);
+    /// ```
+    Grouping,
     /// SQL 'last' function.
     /// Returns the last element of the grouping.
     /// ```sql
@@ -611,6 +625,77 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT VARIANCE(column_1) FROM df;
     /// ```
     Variance,
+
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'cume_dist' window function.
+    /// Returns the fraction of partition rows that precede or are peers of the current row.
+    /// ```sql
+    /// SELECT CUME_DIST() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    CumeDist,
+    /// SQL 'dense_rank' window function.
+    /// Returns the rank of the current row within its partition, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'first_value' window function.
+    /// Returns the value of the expression at the first row of the window frame.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'lag' window function.
+    /// Returns the value of the expression `offset` rows before the current row
+    /// within its partition, or `default` if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'last_value' window function.
+    /// Returns the value of the expression at the last row of the window frame.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (
+    ///   PARTITION BY column_2 ORDER BY column_3
+    ///   ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+    /// ) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'lead' window function.
+    /// Returns the value of the expression `offset` rows after the current row
+    /// within its partition, or `default` if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1, 1, 0) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'ntile' window function.
+    /// Divides the partition into `n` buckets that are as equal in size as possible,
+    /// and returns the (1-indexed) bucket of the current row.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Ntile,
+    /// SQL 'percent_rank' window function.
+    /// Returns the relative rank of the current row: `(rank - 1) / (partition rows - 1)`.
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    PercentRank,
+    /// SQL 'rank' window function.
+    /// Returns the rank of the current row within its partition, with gaps.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' window function.
+    /// Returns the (1-indexed) number of the current row within its partition.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -745,18 +830,25 @@ impl PolarsSQLFunctions {
             "covar",
             "covar_pop",
             "covar_samp",
+            "cume_dist",
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -772,19 +864,23 @@ impl PolarsSQLFunctions {
             "quantile_disc",
             "min",
             "mod",
+            "ntile",
             "nullif",
             "octet_length",
+            "percent_rank",
             "pi",
             "pow",
             "power",
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -923,6 +1019,7 @@ impl PolarsSQLFunctions {
             "covar_pop" => Self::CovarPop,
             "covar" | "covar_samp" => Self::CovarSamp,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -933,6 +1030,20 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "cume_dist" => Self::CumeDist,
+            "dense_rank" => Self::DenseRank,
+            "first_value" => Self::FirstValue,
+            "lag" => Self::Lag,
+            "last_value" => Self::LastValue,
+            "lead" => Self::Lead,
+            "ntile" => Self::Ntile,
+            "percent_rank" => Self::PercentRank,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -963,6 +1074,81 @@ impl PolarsSQLFunctions {
             },
         })
     }
+
+    /// Whether the function aggregates its input (unless evaluated over a window).
+    fn is_aggregate(&self) -> bool {
+        use PolarsSQLFunctions::*;
+        matches!(
+            self,
+            Avg | Corr
+                | Count
+                | CovarPop
+                | CovarSamp
+                | First
+                | Last
+                | Max
+                | Median
+                | QuantileCont
+                | QuantileDisc
+                | Min
+                | StdDev
+                | Sum
+                | Variance
+                | ArrayAgg
+        )
+    }
+}
+
+/// Whether the SQL expression calls an aggregate function outside of a window.
+pub(crate) fn has_sql_aggregate(expr: &SQLExpr, ctx: &SQLContext) -> bool {
+    match expr {
+        SQLExpr::Function(function) => {
+            if function.over.is_none()
+                && PolarsSQLFunctions::try_from_sql(function, ctx).is_ok_and(|f| f.is_aggregate())
+            {
+                return true;
+            }
+            let FunctionArguments::List(list) = &function.args else {
+                return false;
+            };
+            list.args.iter().any(|arg| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(e))
+                | FunctionArg::Named {
+                    arg: FunctionArgExpr::Expr(e),
+                    ..
+                } => has_sql_aggregate(e, ctx),
+                _ => false,
+            })
+        },
+        SQLExpr::BinaryOp { left, right, .. } => {
+            has_sql_aggregate(left, ctx) || has_sql_aggregate(right, ctx)
+        },
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr) => has_sql_aggregate(expr, ctx),
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .iter()
+            .chain(else_result)
+            .map(|e| &**e)
+            .chain(conditions)
+            .chain(results)
+            .any(|e| has_sql_aggregate(e, ctx)),
+        _ => false,
+    }
+}
+
+/// Whether the SQL expression is a `COUNT` aggregate (which is 0, not NULL, for no rows).
+pub(crate) fn is_sql_count(expr: &SQLExpr, ctx: &SQLContext) -> bool {
+    matches!(expr, SQLExpr::Function(function)
+        if function.over.is_none()
+            && matches!(PolarsSQLFunctions::try_from_sql(function, ctx), Ok(PolarsSQLFunctions::Count)))
 }
 
 impl SQLFunctionVisitor<'_> {
@@ -982,6 +1168,25 @@ impl SQLFunctionVisitor<'_> {
             polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
         }
 
+        // aggregates with an explicit window frame are evaluated over the frame of each row
+        if let Some(WindowType::WindowSpec(WindowSpec {
+            window_frame: Some(_),
+            ..
+        })) = &function.over
+        {
+            let agg = match function_name {
+                Avg => Some(WindowFrameAgg::Mean),
+                Count => Some(WindowFrameAgg::Count),
+                Max => Some(WindowFrameAgg::Max),
+                Min => Some(WindowFrameAgg::Min),
+                Sum => Some(WindowFrameAgg::Sum),
+                _ => None,
+            };
+            if let Some(agg) = agg {
+                return self.visit_window_frame_agg(agg);
+            }
+        }
+
         match function_name {
             // ----
             // Bitwise functions
@@ -1434,6 +1639,7 @@ impl SQLFunctionVisitor<'_> {
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1494,6 +1700,20 @@ impl SQLFunctionVisitor<'_> {
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            CumeDist => self.visit_nullary_window(WindowContext::cume_dist),
+            DenseRank => self.visit_nullary_window(WindowContext::dense_rank),
+            FirstValue => self.visit_window_frame_agg(WindowFrameAgg::FirstValue),
+            Lag => self.visit_lag_lead(false),
+            LastValue => self.visit_window_frame_agg(WindowFrameAgg::LastValue),
+            Lead => self.visit_lag_lead(true),
+            Ntile => self.visit_ntile(),
+            PercentRank => self.visit_nullary_window(WindowContext::percent_rank),
+            Rank => self.visit_nullary_window(WindowContext::rank),
+            RowNumber => self.visit_nullary_window(WindowContext::row_number),
+
             // ----
             // Array functions
             // ----
@@ -1821,6 +2041,26 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let n_args = args.len();
+        polars_ensure!(
+            (1..32).contains(&n_args),
+            SQLSyntax: "GROUPING expects 1-31 arguments (found {})", n_args
+        );
+        let mut grouping = lit(0);
+        for (i, arg) in args.into_iter().enumerate() {
+            let FunctionArgExpr::Expr(sql_expr) = arg else {
+                return self.not_supported_error();
+            };
+            let key = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+            let name = key.meta().output_name()?;
+            let is_aggregated = col(format_pl_smallstr!("{GROUPING_COLUMN_PREFIX}{name}"));
+            grouping = grouping + is_aggregated * lit(1i32 << (n_args - 1 - i));
+        }
+        Ok(grouping.alias("grouping"))
+    }
+
     fn visit_count(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
         let count_expr = match (is_distinct, args.as_slice()) {
@@ -1900,6 +2140,227 @@ impl SQLFunctionVisitor<'_> {
         })
     }
 
+    fn visit_window_frame_agg(&mut self, agg: WindowFrameAgg) -> PolarsResult<Expr> {
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        let expr = match (is_distinct, args.as_slice(), agg) {
+            (false, [FunctionArgExpr::Expr(sql_expr)], _) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            // the row index is never null, so counting it counts the rows in the frame
+            (false, [FunctionArgExpr::Wildcard] | [], WindowFrameAgg::Count) => {
+                WindowContext::row_index()
+            },
+            _ => return self.not_supported_error(),
+        };
+        self.apply_window_function(|w| w.frame_agg(expr, agg))
+    }
+
+    fn visit_lag_lead(&mut self, lead: bool) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let mut exprs = vec![];
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => {
+                    exprs.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+                },
+                _ => return self.not_supported_error(),
+            }
+        }
+        let offset = match exprs.get(1) {
+            None => 1,
+            Some(Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n)))) => *n as i64,
+            Some(_) => {
+                polars_bail!(SQLSyntax: "invalid offset for {} ({})", self.func.name, args[1])
+            },
+        };
+        let n = lit(if lead { -offset } else { offset });
+        let expr = match exprs.as_slice() {
+            [e] | [e, _] => e.clone().shift(n),
+            [e, _, default] => e.clone().shift_and_fill(n, default.clone()),
+            _ => {
+                polars_bail!(SQLSyntax: "{} expects 1-3 arguments (found {})", self.func.name, args.len())
+            },
+        };
+        self.apply_window_function(|_| expr)
+    }
+
+    fn visit_ntile(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let n = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+                    Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n > 0 => {
+                        n as IdxSize
+                    },
+                    _ => {
+                        polars_bail!(SQLSyntax: "NTILE expects a positive integer number of buckets ({})", args[0])
+                    },
+                }
+            },
+            _ => {
+                polars_bail!(SQLSyntax: "NTILE expects 1 argument (found {})", args.len())
+            },
+        };
+        self.apply_window_function(|w| w.ntile(n))
+    }
+
+    fn visit_nullary_window(
+        &mut self,
+        f: impl FnOnce(&WindowContext) -> Expr,
+    ) -> PolarsResult<Expr> {
+        if !extract_args(self.func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        self.apply_window_function(f)
+    }
+
+    /// Evaluate a window function within each partition of the window spec, after
+    /// sorting the partition by the window ORDER BY. The function is built by `f`, which
+    /// gets access to the ORDER BY keys (to locate peer rows) and the window frame.
+    fn apply_window_function(
+        &mut self,
+        f: impl FnOnce(&WindowContext) -> Expr,
+    ) -> PolarsResult<Expr> {
+        let func = self.func;
+        let spec = match &func.over {
+            Some(WindowType::WindowSpec(spec)) => spec,
+            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
+                SQLInterface: "Named windows are not currently supported; found {:?}",
+                named_window
+            ),
+            None => polars_bail!(SQLSyntax: "{} requires an OVER clause", func.name),
+        };
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut order_keys = Vec::with_capacity(spec.order_by.len());
+        let mut sort_flags = Vec::with_capacity(spec.order_by.len());
+        for ob in &spec.order_by {
+            // note: as with ORDER BY, 'NULLS FIRST' is the default for DESC, 'NULLS LAST' otherwise
+            let descending = !ob.asc.unwrap_or(true);
+            order_keys.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+            sort_flags.push((descending, !ob.nulls_first.unwrap_or(descending)));
+        }
+        let order_by = match sort_flags.first() {
+            None => None,
+            Some(&(descending, nulls_last)) if sort_flags.iter().all(|f| *f == sort_flags[0]) => {
+                let sort_keys = order_keys
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| e.clone().alias(format!("_{i}")))
+                    .collect::<Vec<_>>();
+                let options = SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last)
+                    .with_maintain_order(true);
+                Some((sort_keys, options))
+            },
+            Some(_) => {
+                // `over` sorts with a single set of options, so replace each key by its
+                // (ascending, non-null) dense rank that respects the requested ordering
+                let sort_keys = order_keys
+                    .iter()
+                    .zip(&sort_flags)
+                    .enumerate()
+                    .map(|(i, (e, &(descending, nulls_last)))| {
+                        let rank_options = RankOptions {
+                            method: RankMethod::Dense,
+                            descending,
+                        };
+                        let null_rank = if nulls_last { IdxSize::MAX } else { 0 };
+                        e.clone()
+                            .rank(rank_options, None)
+                            .fill_null(lit(null_rank))
+                            .alias(format!("_{i}"))
+                    })
+                    .collect::<Vec<_>>();
+                Some((sort_keys, SortOptions::default().with_maintain_order(true)))
+            },
+        };
+
+        let frame = match &spec.window_frame {
+            Some(frame) => self.parse_window_frame(frame, !order_keys.is_empty())?,
+            // the default frame runs up to the last peer of the current row when the
+            // window is ordered, and spans the whole partition otherwise
+            None if !order_keys.is_empty() => WindowFrameBounds::RangeToCurrentRow,
+            None => WindowFrameBounds::Rows(None, None),
+        };
+        let expr = f(&WindowContext { order_keys, frame });
+
+        if partition_by.is_empty() && order_by.is_none() {
+            Ok(expr)
+        } else {
+            let partition_by = (!partition_by.is_empty()).then_some(partition_by);
+            expr.over_with_options(partition_by, order_by, Default::default())
+        }
+    }
+
+    fn parse_window_frame(
+        &mut self,
+        frame: &WindowFrame,
+        is_ordered: bool,
+    ) -> PolarsResult<WindowFrameBounds> {
+        let start = match &frame.start_bound {
+            WindowFrameBound::Following(None) => {
+                polars_bail!(SQLSyntax: "window frame cannot start at UNBOUNDED FOLLOWING")
+            },
+            bound => self.parse_window_frame_bound(bound)?,
+        };
+        let end = match &frame.end_bound {
+            Some(WindowFrameBound::Preceding(None)) => {
+                polars_bail!(SQLSyntax: "window frame cannot end at UNBOUNDED PRECEDING")
+            },
+            Some(bound) => self.parse_window_frame_bound(bound)?,
+            None => Some(0),
+        };
+        if let (Some(start), Some(end)) = (start, end) {
+            polars_ensure!(
+                start <= end,
+                SQLSyntax: "window frame cannot end before it starts ({})", frame.units
+            );
+        }
+        Ok(match frame.units {
+            WindowFrameUnits::Rows => WindowFrameBounds::Rows(start, end),
+            // without ORDER BY all rows of the partition are peers
+            WindowFrameUnits::Range if !is_ordered => WindowFrameBounds::Rows(None, None),
+            WindowFrameUnits::Range => match (start, end) {
+                (None, None) => WindowFrameBounds::Rows(None, None),
+                (None, Some(0)) => WindowFrameBounds::RangeToCurrentRow,
+                (Some(0), None) => WindowFrameBounds::RangeFromCurrentRow,
+                _ => polars_bail!(
+                    SQLInterface: "RANGE window frames are only supported with UNBOUNDED bounds on one side; use ROWS for offsets"
+                ),
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not currently supported")
+            },
+        })
+    }
+
+    /// Resolve a frame bound to a row offset from the current row (negative for
+    /// preceding rows); `None` denotes an unbounded side.
+    fn parse_window_frame_bound(&mut self, bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+        let (offset, sign) = match bound {
+            WindowFrameBound::CurrentRow => return Ok(Some(0)),
+            WindowFrameBound::Preceding(offset) => (offset, -1),
+            WindowFrameBound::Following(offset) => (offset, 1),
+        };
+        let Some(offset) = offset else {
+            return Ok(None);
+        };
+        match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => {
+                Ok(Some(sign * n as i64))
+            },
+            _ => polars_bail!(
+                SQLSyntax: "window frame offset must be a non-negative integer ({})", offset
+            ),
+        }
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1909,6 +2370,244 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// Aggregations that can be evaluated over an explicit window frame.
+#[derive(Clone, Copy)]
+enum WindowFrameAgg {
+    Count,
+    FirstValue,
+    LastValue,
+    Max,
+    Mean,
+    Min,
+    Sum,
+}
+
+/// Window frame, resolved against the rows of a sorted partition.
+#[derive(Clone, Copy)]
+enum WindowFrameBounds {
+    /// Frame start/end as row offsets from the current row (negative offsets
+    /// precede it); `None` denotes an unbounded side.
+    Rows(Option<i64>, Option<i64>),
+    /// `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`, which includes all
+    /// peers of the current row.
+    RangeToCurrentRow,
+    /// `RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING`, which includes all
+    /// peers of the current row.
+    RangeFromCurrentRow,
+}
+
+/// Builds window function expressions; these are evaluated within a single
+/// partition that has already been sorted by the window ORDER BY.
+struct WindowContext {
+    order_keys: Vec<Expr>,
+    frame: WindowFrameBounds,
+}
+
+impl WindowContext {
+    /// 0-indexed position of the row in the partition.
+    fn row_index() -> Expr {
+        repeat(true, len()).cum_count(false) - lit(1)
+    }
+
+    /// Number of partition rows after the current row.
+    fn rows_remaining() -> Expr {
+        len() - Self::row_index() - lit(1)
+    }
+
+    /// Whether the row is the first of its peers (rows with equal ORDER BY keys).
+    fn is_first_peer(&self) -> Expr {
+        self.order_keys
+            .iter()
+            .fold(Self::row_index().eq(lit(0)), |acc, k| {
+                acc.or(k.clone().neq_missing(k.clone().shift(lit(1))))
+            })
+    }
+
+    /// Whether the row is the last of its peers (rows with equal ORDER BY keys).
+    fn is_last_peer(&self) -> Expr {
+        self.order_keys
+            .iter()
+            .fold(Self::rows_remaining().eq(lit(0)), |acc, k| {
+                acc.or(k.clone().neq_missing(k.clone().shift(lit(-1))))
+            })
+    }
+
+    fn first_peer_index(&self) -> Expr {
+        when(self.is_first_peer())
+            .then(Self::row_index())
+            .otherwise(lit(LiteralValue::untyped_null()))
+            .fill_null_with_strategy(FillNullStrategy::Forward(None))
+    }
+
+    fn last_peer_index(&self) -> Expr {
+        when(self.is_last_peer())
+            .then(Self::row_index())
+            .otherwise(lit(LiteralValue::untyped_null()))
+            .fill_null_with_strategy(FillNullStrategy::Backward(None))
+    }
+
+    fn row_number(&self) -> Expr {
+        Self::row_index() + lit(1)
+    }
+
+    fn rank(&self) -> Expr {
+        self.first_peer_index() + lit(1)
+    }
+
+    fn dense_rank(&self) -> Expr {
+        self.is_first_peer().cum_sum(false).cast(IDX_DTYPE)
+    }
+
+    fn percent_rank(&self) -> Expr {
+        when(len().gt(lit(1)))
+            .then(
+                self.first_peer_index().cast(DataType::Float64)
+                    / (len() - lit(1)).cast(DataType::Float64),
+            )
+            .otherwise(lit(0.0))
+    }
+
+    fn cume_dist(&self) -> Expr {
+        (self.last_peer_index() + lit(1)).cast(DataType::Float64) / len().cast(DataType::Float64)
+    }
+
+    /// Bucket (1-indexed) of the row when splitting the partition into `n` buckets;
+    /// the first `len % n` buckets hold one row more than the others.
+    fn ntile(&self, n: IdxSize) -> Expr {
+        let size = len().floor_div(lit(n));
+        let remainder = len() % lit(n);
+        let large_rows = remainder.clone() * (size.clone() + lit(1));
+        let idx = Self::row_index();
+        let bucket = when(idx.clone().lt(large_rows.clone()))
+            .then(idx.clone().floor_div(size.clone() + lit(1)))
+            .otherwise(
+                remainder
+                    + (idx - large_rows)
+                        .floor_div(when(size.clone().eq(lit(0))).then(lit(1)).otherwise(size)),
+            );
+        (bucket + lit(1)).cast(IDX_DTYPE)
+    }
+
+    fn frame_agg(&self, expr: Expr, agg: WindowFrameAgg) -> Expr {
+        match self.frame {
+            WindowFrameBounds::Rows(start, end) => rows_frame_agg(expr, agg, start, end),
+            // evaluate the frame of the last (resp. first) peer, which spans all peers;
+            // the first (resp. last) value does not depend on the peers at all
+            WindowFrameBounds::RangeToCurrentRow => match agg {
+                WindowFrameAgg::FirstValue => expr.first(),
+                _ => rows_frame_agg(expr, agg, None, Some(0)).gather(self.last_peer_index()),
+            },
+            WindowFrameBounds::RangeFromCurrentRow => match agg {
+                WindowFrameAgg::LastValue => expr.last(),
+                _ => rows_frame_agg(expr, agg, Some(0), None).gather(self.first_peer_index()),
+            },
+        }
+    }
+}
+
+/// Aggregate `expr` over the frame `[row + start, row + end]` of each row of a partition.
+fn rows_frame_agg(expr: Expr, agg: WindowFrameAgg, start: Option<i64>, end: Option<i64>) -> Expr {
+    // `shift_rows(e, k)` puts the value of `k` rows ahead at the current row
+    let shift_rows = |e: Expr, k: i64| if k == 0 { e } else { e.shift(lit(-k)) };
+    let row_index = WindowContext::row_index;
+    let rows_remaining = WindowContext::rows_remaining;
+    let null = || lit(LiteralValue::untyped_null());
+    // frames of the first (resp. last) rows can lie entirely before (resp. after) the partition
+    let mask_empty_frame = |e: Expr| {
+        let mut is_empty = lit(false);
+        if let Some(end) = end.filter(|&end| end < 0) {
+            is_empty = is_empty.or(row_index().lt(lit(-end)));
+        }
+        if let Some(start) = start.filter(|&start| start > 0) {
+            is_empty = is_empty.or(rows_remaining().lt(lit(start)));
+        }
+        when(is_empty).then(null()).otherwise(e)
+    };
+
+    match agg {
+        WindowFrameAgg::Count => rows_frame_agg(
+            expr.is_not_null().cast(IDX_DTYPE),
+            WindowFrameAgg::Sum,
+            start,
+            end,
+        )
+        .fill_null(lit(0)),
+        WindowFrameAgg::Mean => {
+            rows_frame_agg(
+                expr.clone().cast(DataType::Float64),
+                WindowFrameAgg::Sum,
+                start,
+                end,
+            ) / rows_frame_agg(expr, WindowFrameAgg::Count, start, end).cast(DataType::Float64)
+        },
+        WindowFrameAgg::FirstValue => mask_empty_frame(match start {
+            None => expr.first(),
+            Some(start) if start < 0 => when(row_index().lt(lit(-start)))
+                .then(expr.clone().first())
+                .otherwise(shift_rows(expr, start)),
+            Some(start) => shift_rows(expr, start),
+        }),
+        WindowFrameAgg::LastValue => mask_empty_frame(match end {
+            None => expr.last(),
+            Some(end) if end > 0 => when(rows_remaining().lt(lit(end)))
+                .then(expr.clone().last())
+                .otherwise(shift_rows(expr, end)),
+            Some(end) => shift_rows(expr, end),
+        }),
+        WindowFrameAgg::Max | WindowFrameAgg::Min | WindowFrameAgg::Sum => {
+            let reduce = |e: Expr| match agg {
+                WindowFrameAgg::Max => e.max(),
+                WindowFrameAgg::Min => e.min(),
+                _ => e.sum(),
+            };
+            let cumulative = |e: Expr, reverse: bool| match agg {
+                WindowFrameAgg::Max => e.cum_max(reverse),
+                WindowFrameAgg::Min => e.cum_min(reverse),
+                _ => e.cum_sum(reverse),
+            };
+            let rolling = |e: Expr, options: RollingOptionsFixedWindow| match agg {
+                WindowFrameAgg::Max => e.rolling_max(options),
+                WindowFrameAgg::Min => e.rolling_min(options),
+                _ => e.rolling_sum(options),
+            };
+            // nulls are skipped by the aggregations, but cumulative ops leave them in
+            // place; fill them from the preceding (resp. following) rows of the frame
+            let prefix = cumulative(expr.clone(), false)
+                .fill_null_with_strategy(FillNullStrategy::Forward(None));
+            let suffix = cumulative(expr.clone(), true)
+                .fill_null_with_strategy(FillNullStrategy::Backward(None));
+            // frames that run up to the end of the partition
+            let to_end = |start: Option<i64>| match start {
+                None => reduce(expr.clone()),
+                Some(start) if start < 0 => when(row_index().lt(lit(-start)))
+                    .then(reduce(expr.clone()))
+                    .otherwise(shift_rows(suffix.clone(), start)),
+                Some(start) => shift_rows(suffix.clone(), start),
+            };
+            // aggregates of frames ending at `row + end` are shifted from the row that
+            // frame ends at; frames of the last rows are truncated at the partition end
+            let (windowed, from_start) = match (start, end) {
+                (_, None) => return to_end(start),
+                (None, Some(end)) => (shift_rows(prefix, end), None),
+                (Some(start), Some(end)) => {
+                    let options = RollingOptionsFixedWindow {
+                        window_size: (end - start + 1) as usize,
+                        min_periods: 1,
+                        ..Default::default()
+                    };
+                    (shift_rows(rolling(expr.clone(), options), end), Some(start))
+                },
+            };
+            match end {
+                Some(end) if end > 0 => when(rows_remaining().lt(lit(end)))
+                    .then(to_end(from_start))
+                    .otherwise(windowed),
+                _ => windowed,
+            }
+        },
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
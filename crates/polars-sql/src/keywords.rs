@@ -19,10 +19,12 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::ARRAY,
         keywords::AS,
         keywords::ASC,
+        keywords::BETWEEN,
         keywords::BOOLEAN,
         keywords::BY,
         keywords::CASE,
         keywords::CREATE,
+        keywords::CURRENT,
         keywords::DATE,
         keywords::DATETIME,
         keywords::DESC,
@@ -32,18 +34,23 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::EXCEPT,
         keywords::EXCLUDE,
         keywords::FLOAT,
+        keywords::FOLLOWING,
         keywords::FROM,
         keywords::FULL,
         keywords::GROUP,
         keywords::HAVING,
         keywords::IN,
         keywords::INNER,
+        keywords::INSERT,
         keywords::INT,
         keywords::INTERSECT,
         keywords::INTERVAL,
+        keywords::INTO,
         keywords::JOIN,
         keywords::LEFT,
         keywords::LIMIT,
+        keywords::MATCHED,
+        keywords::MERGE,
         keywords::NOT,
         keywords::NULL,
         keywords::OFFSET,
@@ -51,20 +58,29 @@ pub fn all_keywords() -> Vec<&'static str> {
         keywords::OR,
         keywords::ORDER,
         keywords::OUTER,
+        keywords::OVER,
+        keywords::PARTITION,
+        keywords::PRECEDING,
+        keywords::RANGE,
         keywords::REGEXP,
         keywords::RENAME,
         keywords::REPLACE,
         keywords::RIGHT,
         keywords::RLIKE,
+        keywords::ROW,
+        keywords::ROWS,
         keywords::SELECT,
         keywords::SEMI,
+        keywords::SET,
         keywords::SHOW,
         keywords::TABLE,
         keywords::TABLES,
         keywords::THEN,
         keywords::TIME,
         keywords::TRUNCATE,
+        keywords::UNBOUNDED,
         keywords::UNION,
+        keywords::UPDATE,
         keywords::USING,
         keywords::VARCHAR,
         keywords::WHEN,
@@ -0,0 +1,277 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "id" => [1, 2, 3, 4, 5, 6, 7],
+      "grp" => ["a", "b", "a", "b", "a", "a", "b"],
+      "score" => [Some(10), Some(20), Some(30), Some(20), None, Some(30), Some(50)],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn execute(select: &str) -> DataFrame {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    let query = format!("SELECT id, {select} FROM df ORDER BY id");
+    ctx.execute(&query).unwrap().collect().unwrap()
+}
+
+fn column_values<T: PolarsNumericType>(df: &DataFrame, name: &str) -> Vec<Option<T::Native>> {
+    let s = df
+        .column(name)
+        .unwrap()
+        .cast(&T::get_static_dtype())
+        .unwrap();
+    s.as_materialized_series()
+        .unpack::<T>()
+        .unwrap()
+        .into_iter()
+        .collect()
+}
+
+fn assert_close(actual: Vec<Option<f64>>, expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.into_iter().zip(expected) {
+        assert!((a.unwrap() - e).abs() < 1e-12, "{a:?} != {e}");
+    }
+}
+
+#[test]
+fn test_ranking_functions() {
+    let df = execute(
+        r#"
+        ROW_NUMBER() OVER (PARTITION BY grp ORDER BY score DESC, id) AS row_num,
+        RANK() OVER (PARTITION BY grp ORDER BY score) AS rnk,
+        DENSE_RANK() OVER (PARTITION BY grp ORDER BY score) AS dense_rnk,
+        PERCENT_RANK() OVER (ORDER BY score) AS pct_rank,
+        CUME_DIST() OVER (ORDER BY score) AS cume
+        "#,
+    );
+    // grp "a": ids 1, 3, 5, 6 with scores 10, 30, NULL, 30 (nulls sort last by default)
+    // grp "b": ids 2, 4, 7 with scores 20, 20, 50
+    assert_eq!(
+        column_values::<Int64Type>(&df, "row_num"),
+        [4, 2, 2, 3, 1, 3, 1].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "rnk"),
+        [1, 1, 2, 1, 4, 2, 3].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "dense_rnk"),
+        [1, 1, 2, 1, 3, 2, 2].map(Some)
+    );
+    // overall order: 10, 20, 20, 30, 30, 50, NULL
+    assert_close(
+        column_values::<Float64Type>(&df, "pct_rank"),
+        &[
+            0.0,
+            1.0 / 6.0,
+            3.0 / 6.0,
+            1.0 / 6.0,
+            1.0,
+            3.0 / 6.0,
+            5.0 / 6.0,
+        ],
+    );
+    assert_close(
+        column_values::<Float64Type>(&df, "cume"),
+        &[
+            1.0 / 7.0,
+            3.0 / 7.0,
+            5.0 / 7.0,
+            3.0 / 7.0,
+            1.0,
+            5.0 / 7.0,
+            6.0 / 7.0,
+        ],
+    );
+}
+
+#[test]
+fn test_ranking_mixed_sort_order() {
+    let df = execute("ROW_NUMBER() OVER (ORDER BY grp DESC, score ASC NULLS FIRST) AS row_num");
+    // "b": 20 (id 2), 20 (id 4), 50 (id 7); "a": NULL (id 5), 10 (id 1), 30 (id 3), 30 (id 6)
+    assert_eq!(
+        column_values::<Int64Type>(&df, "row_num"),
+        [5, 1, 6, 2, 4, 7, 3].map(Some)
+    );
+}
+
+#[test]
+fn test_ntile() {
+    let df = execute(
+        r#"
+        NTILE(3) OVER (ORDER BY id) AS tile3,
+        NTILE(2) OVER (PARTITION BY grp ORDER BY id) AS tile2,
+        NTILE(10) OVER (PARTITION BY grp ORDER BY id) AS tile10
+        "#,
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "tile3"),
+        [1, 1, 1, 2, 2, 3, 3].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "tile2"),
+        [1, 1, 1, 1, 2, 2, 2].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "tile10"),
+        [1, 1, 2, 2, 3, 4, 3].map(Some)
+    );
+}
+
+#[test]
+fn test_lag_lead() {
+    let df = execute(
+        r#"
+        LAG(score) OVER (PARTITION BY grp ORDER BY id) AS prev,
+        LAG(score, 2, -1) OVER (PARTITION BY grp ORDER BY id) AS prev2,
+        LEAD(id) OVER (ORDER BY id DESC) AS next_desc,
+        LEAD(score, 1, 0) OVER (PARTITION BY grp ORDER BY id) AS next
+        "#,
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "prev"),
+        [None, None, Some(10), Some(20), Some(30), None, Some(20)]
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "prev2"),
+        [
+            Some(-1),
+            Some(-1),
+            Some(-1),
+            Some(-1),
+            Some(10),
+            Some(30),
+            Some(20)
+        ]
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "next_desc"),
+        [None, Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "next"),
+        [
+            Some(30),
+            Some(20),
+            None,
+            Some(50),
+            Some(30),
+            Some(0),
+            Some(0)
+        ]
+    );
+}
+
+#[test]
+fn test_first_last_value() {
+    let df = execute(
+        r#"
+        FIRST_VALUE(id) OVER (PARTITION BY grp ORDER BY score) AS first_id,
+        LAST_VALUE(id) OVER (PARTITION BY grp ORDER BY score) AS last_peer_id,
+        LAST_VALUE(id) OVER (
+            PARTITION BY grp ORDER BY score
+            ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+        ) AS last_id,
+        FIRST_VALUE(id) OVER (ORDER BY id ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS lag_frame
+        "#,
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "first_id"),
+        [1, 2, 1, 2, 1, 1, 2].map(Some)
+    );
+    // the default frame ends at the last peer of the current row
+    assert_eq!(
+        column_values::<Int64Type>(&df, "last_peer_id"),
+        [1, 4, 6, 4, 5, 6, 7].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "last_id"),
+        [5, 7, 5, 7, 5, 5, 7].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "lag_frame"),
+        [None, Some(1), Some(1), Some(2), Some(3), Some(4), Some(5)]
+    );
+}
+
+#[test]
+fn test_aggregate_window_frames() {
+    let df = execute(
+        r#"
+        SUM(score) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS sum_centered,
+        SUM(score) OVER (PARTITION BY grp ORDER BY id ROWS UNBOUNDED PRECEDING) AS sum_running,
+        MAX(score) OVER (ORDER BY id ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS max_rest,
+        MIN(score) OVER (ORDER BY id ROWS BETWEEN 1 FOLLOWING AND 2 FOLLOWING) AS min_next,
+        COUNT(score) OVER (ORDER BY id ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS cnt,
+        COUNT(*) OVER (ORDER BY id ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS cnt_rows,
+        AVG(score) OVER (PARTITION BY grp ORDER BY score RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS avg_range
+        "#,
+    );
+    // scores by id: 10, 20, 30, 20, NULL, 30, 50
+    assert_eq!(
+        column_values::<Int64Type>(&df, "sum_centered"),
+        [30, 60, 70, 50, 50, 80, 80].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "sum_running"),
+        [
+            Some(10),
+            Some(20),
+            Some(40),
+            Some(40),
+            Some(40),
+            Some(70),
+            Some(90)
+        ]
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "max_rest"),
+        [50, 50, 50, 50, 50, 50, 50].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "min_next"),
+        [
+            Some(20),
+            Some(20),
+            Some(20),
+            Some(30),
+            Some(30),
+            Some(50),
+            None
+        ]
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "cnt"),
+        [1, 2, 3, 3, 2, 2, 2].map(Some)
+    );
+    assert_eq!(
+        column_values::<Int64Type>(&df, "cnt_rows"),
+        [1, 2, 3, 3, 3, 3, 3].map(Some)
+    );
+    // grp "a" sorted: 10, 30, 30, NULL; grp "b" sorted: 20, 20, 50
+    assert_close(
+        column_values::<Float64Type>(&df, "avg_range"),
+        &[10.0, 20.0, 70.0 / 3.0, 20.0, 70.0 / 3.0, 70.0 / 3.0, 30.0],
+    );
+}
+
+#[test]
+fn test_window_function_errors() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    for query in [
+        "SELECT ROW_NUMBER() FROM df",
+        "SELECT NTILE(0) OVER (ORDER BY id) FROM df",
+        "SELECT SUM(score) OVER (ORDER BY id ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        "SELECT SUM(score) OVER (ORDER BY id RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df",
+        "SELECT SUM(score) OVER (ORDER BY id GROUPS UNBOUNDED PRECEDING) FROM df",
+    ] {
+        assert!(ctx.execute(query).is_err(), "expected error for: {query}");
+    }
+}
@@ -601,3 +601,548 @@ fn test_compound_invalid_3() {
     let sql = "SELECT * FROM df1 INNER JOIN df2 ON df1.a = df2.a AND b";
     let _ = ctx.execute(sql).unwrap();
 }
+
+fn prepare_grouping_sets_context() -> SQLContext {
+    let df = df! {
+        "region" => ["N", "N", "S", "S", "S"],
+        "product" => [Some("x"), Some("y"), Some("x"), Some("x"), None],
+        "sales" => [1i64, 2, 3, 4, 5],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", df.lazy());
+    ctx
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let mut ctx = prepare_grouping_sets_context();
+    let expected = df! {
+        "region" => [Some("N"), Some("N"), Some("S"), Some("S"), Some("N"), Some("S"), None],
+        "product" => [Some("x"), Some("y"), Some("x"), None, None, None, None],
+        "total" => [1i64, 2, 7, 5, 3, 12, 15],
+        "g" => [0i32, 0, 0, 0, 1, 1, 3],
+    }
+    .unwrap();
+
+    for sql in [
+        r#"
+        SELECT region, product, SUM(sales) AS total, GROUPING(region, product) AS g
+        FROM sales
+        GROUP BY ROLLUP(region, product)
+        ORDER BY g, region, product
+        "#,
+        r#"
+        SELECT region, product, SUM(sales) AS total, GROUPING(region, product) AS g
+        FROM sales
+        GROUP BY region, product WITH ROLLUP
+        ORDER BY g, region, product
+        "#,
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert!(
+            actual.equals_missing(&expected),
+            "expected = {expected:?}\nactual={actual:?}"
+        );
+    }
+}
+
+#[test]
+fn test_group_by_grouping_sets() {
+    let mut ctx = prepare_grouping_sets_context();
+    let sql = r#"
+        SELECT
+            region,
+            product,
+            SUM(sales) AS total,
+            GROUPING(region) AS g_region,
+            GROUPING(product) AS g_product
+        FROM sales
+        GROUP BY GROUPING SETS ((region), (product), ())
+        ORDER BY g_region, g_product, region, product
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => [Some("N"), Some("S"), None, None, None, None],
+        "product" => [None, None, Some("x"), Some("y"), None, None],
+        "total" => [3i64, 12, 8, 2, 5, 15],
+        "g_region" => [0i32, 0, 1, 1, 1, 1],
+        "g_product" => [1i32, 1, 0, 0, 0, 1],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_group_by_cube() {
+    let mut ctx = prepare_grouping_sets_context();
+    let sql = r#"
+        SELECT region, product, COUNT(*) AS n, GROUPING(region, product) AS g
+        FROM sales
+        GROUP BY CUBE(region, product)
+        ORDER BY g, region, product
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => [
+            Some("N"), Some("N"), Some("S"), Some("S"), Some("N"), Some("S"),
+            None, None, None, None,
+        ],
+        "product" => [
+            Some("x"), Some("y"), Some("x"), None, None, None,
+            Some("x"), Some("y"), None, None,
+        ],
+        "n" => [1u32, 1, 2, 1, 2, 3, 3, 1, 1, 5],
+        "g" => [0i32, 0, 0, 0, 1, 1, 2, 2, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // a plain key combined with a rollup only aggregates over the rolled-up keys
+    let sql = r#"
+        SELECT region, product, SUM(sales) AS total
+        FROM sales
+        GROUP BY region, ROLLUP(product)
+        ORDER BY region, product NULLS FIRST
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 6);
+    assert_eq!(
+        actual.column("total").unwrap().i64().unwrap().get(0),
+        Some(3)
+    );
+}
+
+#[test]
+fn test_recursive_cte_sequence() {
+    let mut ctx = SQLContext::new();
+    let sql = r#"
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < 5
+        )
+        SELECT n FROM seq ORDER BY n
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "n" => [1i32, 2, 3, 4, 5] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let mut ctx = SQLContext::new();
+    let employees = df! {
+        "id" => [1, 2, 3, 4, 5, 6],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(4), Some(3)],
+        "name" => ["ceo", "cto", "cfo", "dev_lead", "dev", "accountant"],
+    }
+    .unwrap();
+    ctx.register("employees", employees.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE reports AS (
+            SELECT id, name, 0 AS depth FROM employees WHERE id = 2
+            UNION ALL
+            SELECT e.id, e.name, r.depth + 1
+            FROM employees e
+            INNER JOIN reports r ON e.manager_id = r.id
+        )
+        SELECT name, depth FROM reports ORDER BY depth
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["cto", "dev_lead", "dev"],
+        "depth" => [0i32, 1, 2],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_recursive_cte_union_distinct() {
+    let mut ctx = SQLContext::new();
+    let edges = df! {
+        "src" => [1, 2, 3, 3],
+        "dst" => [2, 3, 1, 4],
+    }
+    .unwrap();
+    ctx.register("edges", edges.lazy());
+
+    // the graph contains a cycle; UNION (distinct) discards rows that were
+    // already produced, so evaluation reaches a fixpoint
+    let sql = r#"
+        WITH RECURSIVE reachable(node) AS (
+            SELECT 1
+            UNION
+            SELECT edges.dst FROM edges JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! { "node" => [1i32, 2, 3, 4] }.unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // the same query with UNION ALL never terminates, and is caught by the guard
+    let mut ctx = ctx.with_max_recursive_cte_iterations(10);
+    let sql = sql.replace("UNION\n", "UNION ALL\n");
+    let err = ctx.execute(&sql).err().unwrap();
+    assert!(
+        err.to_string()
+            .contains("did not terminate within 10 iterations")
+    );
+}
+
+fn prepare_lateral_context() -> SQLContext {
+    let mut ctx = SQLContext::new();
+    let customers = df! {
+        "id" => [1, 2, 3],
+        "name" => ["ann", "bob", "cat"],
+        "tags" => [
+            Series::new("".into(), ["x", "y"]),
+            Series::new("".into(), ["z"]),
+            Series::new_empty("".into(), &DataType::String),
+        ],
+    }
+    .unwrap();
+    let orders = df! {
+        "id" => [10, 11, 12, 13, 14, 15],
+        "customer_id" => [1, 1, 1, 2, 2, 4],
+        "amount" => [5, 30, 20, 7, 9, 99],
+    }
+    .unwrap();
+    ctx.register("customers", customers.lazy());
+    ctx.register("orders", orders.lazy());
+    ctx
+}
+
+#[test]
+fn test_unnest_with_ordinality() {
+    let mut ctx = SQLContext::new();
+    let sql = r#"
+        SELECT * FROM UNNEST(ARRAY[30, 20, 10], ARRAY['a', 'b', 'c'])
+        WITH ORDINALITY AS t(n, s, pos)
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "n" => [30i64, 20, 10],
+        "s" => ["a", "b", "c"],
+        "pos" => [1i64, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_unnest_lateral() {
+    let mut ctx = prepare_lateral_context();
+
+    // implicitly lateral, with rows for empty lists removed
+    for sql in [
+        "SELECT c.name, t.tag, t.pos FROM customers c CROSS JOIN UNNEST(c.tags) WITH ORDINALITY AS t(tag, pos)",
+        "SELECT c.name, t.tag, t.pos FROM customers c, UNNEST(c.tags) WITH ORDINALITY AS t(tag, pos)",
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        let expected = df! {
+            "name" => ["ann", "ann", "bob"],
+            "tag" => ["x", "y", "z"],
+            "pos" => [1i64, 2, 1],
+        }
+        .unwrap();
+        assert!(
+            actual.equals(&expected),
+            "expected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    // a LEFT JOIN retains rows with empty lists
+    let sql = r#"
+        SELECT name, tag, ordinality
+        FROM customers c LEFT JOIN UNNEST(c.tags) WITH ORDINALITY AS t(tag) ON TRUE
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["ann", "ann", "bob", "cat"],
+        "tag" => [Some("x"), Some("y"), Some("z"), None],
+        "ordinality" => [Some(1i64), Some(2), Some(1), None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+fn test_lateral_top_n_per_group() {
+    let mut ctx = prepare_lateral_context();
+    let sql = r#"
+        SELECT c.name, o2.id AS order_id, o2.amount
+        FROM customers c
+        CROSS JOIN LATERAL (
+            SELECT o.id, o.amount FROM orders o
+            WHERE o.customer_id = c.id AND o.amount > 5
+            ORDER BY o.amount DESC
+            LIMIT 2
+        ) AS o2
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["ann", "ann", "bob", "bob"],
+        "order_id" => [11, 12, 14, 13],
+        "amount" => [30, 20, 9, 7],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // LEFT JOIN LATERAL keeps customers without matching orders (and supports OFFSET)
+    let sql = r#"
+        SELECT c.name, t.amount
+        FROM customers c
+        LEFT JOIN LATERAL (
+            SELECT amount FROM orders
+            WHERE c.id = orders.customer_id
+            ORDER BY amount
+            LIMIT 1 OFFSET 1
+        ) AS t ON TRUE
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["ann", "bob", "cat"],
+        "amount" => [Some(20), Some(9), None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // aggregates are computed per correlation key, and without GROUP BY they yield a row
+    // for every outer row (with a count of 0 if there are no matches)
+    for (join, on) in [("CROSS JOIN LATERAL", ""), ("LEFT JOIN LATERAL", "ON TRUE")] {
+        let sql = format!(
+            r#"
+            SELECT c.name, t.total, t.n
+            FROM customers c
+            {join} (
+                SELECT SUM(o.amount) AS total, COUNT(*) AS n FROM orders o
+                WHERE o.customer_id = c.id
+            ) AS t {on}
+        "#
+        );
+        let actual = ctx.execute(&sql).unwrap().collect().unwrap();
+        let expected = df! {
+            "name" => ["ann", "bob", "cat"],
+            "total" => [Some(55), Some(16), None],
+            "n" => [3 as IdxSize, 2, 0],
+        }
+        .unwrap();
+        assert!(
+            actual.equals_missing(&expected),
+            "expected = {expected:?}\nactual={actual:?}"
+        );
+    }
+
+    // an uncorrelated LEFT JOIN LATERAL keeps all rows if the subquery is empty
+    let sql = r#"
+        SELECT c.name, t.amount
+        FROM customers c
+        LEFT JOIN LATERAL (SELECT amount FROM orders WHERE amount > 100) AS t ON TRUE
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "name" => ["ann", "bob", "cat"],
+        "amount" => [None::<i32>, None, None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // correlated references are only supported as equality predicates
+    let sql = r#"
+        SELECT * FROM customers c
+        CROSS JOIN LATERAL (SELECT id FROM orders o WHERE o.amount > c.id) AS t
+    "#;
+    assert!(ctx.execute(sql).is_err());
+}
+
+fn prepare_dml_context() -> SQLContext {
+    let mut ctx = SQLContext::new();
+    let inventory = df! {
+        "id" => [1, 2, 3],
+        "item" => ["apple", "pear", "plum"],
+        "qty" => [10, 0, 5],
+    }
+    .unwrap();
+    ctx.register("inventory", inventory.lazy());
+    ctx
+}
+
+fn execute_and_collect(ctx: &mut SQLContext, sql: &str) -> DataFrame {
+    ctx.execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_insert_into() {
+    let mut ctx = prepare_dml_context();
+    execute_and_collect(
+        &mut ctx,
+        "INSERT INTO inventory VALUES (4, 'kiwi', 7), (5, 'lime', NULL)",
+    );
+    execute_and_collect(
+        &mut ctx,
+        "INSERT INTO inventory (item, id) SELECT item || '!', id + 10 FROM inventory WHERE qty = 0",
+    );
+
+    let actual = execute_and_collect(&mut ctx, "SELECT * FROM inventory");
+    let expected = df! {
+        "id" => [1, 2, 3, 4, 5, 12],
+        "item" => ["apple", "pear", "plum", "kiwi", "lime", "pear!"],
+        "qty" => [Some(10), Some(0), Some(5), Some(7), None, None],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    for sql in [
+        "INSERT INTO inventory VALUES (6, 'fig')",
+        "INSERT INTO inventory (id, colour) VALUES (6, 'red')",
+        "INSERT INTO missing VALUES (1)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "expected error for: {sql}");
+    }
+}
+
+#[test]
+fn test_update() {
+    let mut ctx = prepare_dml_context();
+    execute_and_collect(
+        &mut ctx,
+        "UPDATE inventory SET qty = qty + 1, item = UPPER(item) WHERE qty < 10",
+    );
+    let actual = execute_and_collect(&mut ctx, "SELECT * FROM inventory");
+    let expected = df! {
+        "id" => [1, 2, 3],
+        "item" => ["apple", "PEAR", "PLUM"],
+        "qty" => [10, 1, 6],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // assignments all see the original values
+    execute_and_collect(
+        &mut ctx,
+        "UPDATE inventory AS inv SET id = inv.qty, qty = inv.id",
+    );
+    let actual = execute_and_collect(&mut ctx, "SELECT id, qty FROM inventory");
+    let expected = df! {
+        "id" => [10, 1, 6],
+        "qty" => [1, 2, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+    assert!(ctx.execute("UPDATE inventory SET colour = 'red'").is_err());
+}
+
+#[test]
+fn test_merge_into() {
+    let mut ctx = prepare_dml_context();
+    let updates = df! {
+        "id" => [2, 3, 4, 5],
+        "item" => ["pear", "plum", "kiwi", "lime"],
+        "qty" => [8, 0, 3, 0],
+    }
+    .unwrap();
+    ctx.register("updates", updates.lazy());
+
+    execute_and_collect(
+        &mut ctx,
+        r#"
+        MERGE INTO inventory AS tgt
+        USING updates AS src
+        ON tgt.id = src.id
+        WHEN MATCHED AND src.qty = 0 THEN DELETE
+        WHEN MATCHED THEN UPDATE SET qty = tgt.qty + src.qty
+        WHEN NOT MATCHED AND src.qty > 0 THEN INSERT (id, item, qty) VALUES (src.id, src.item, src.qty)
+        "#,
+    );
+    let actual = execute_and_collect(&mut ctx, "SELECT * FROM inventory");
+    let expected = df! {
+        "id" => [1, 2, 4],
+        "item" => ["apple", "pear", "kiwi"],
+        "qty" => [10, 8, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // rows of the target without a match in the source
+    execute_and_collect(
+        &mut ctx,
+        r#"
+        MERGE INTO inventory USING updates ON inventory.id = updates.id
+        WHEN NOT MATCHED BY SOURCE THEN UPDATE SET qty = 0
+        "#,
+    );
+    let actual = execute_and_collect(&mut ctx, "SELECT id, qty FROM inventory");
+    let expected = df! {
+        "id" => [1, 2, 4],
+        "qty" => [0, 8, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // a target row must not be matched by several source rows
+    let duplicates = df! {
+        "id" => [2, 2],
+        "qty" => [1, 2],
+    }
+    .unwrap();
+    ctx.register("duplicates", duplicates.lazy());
+    let err = ctx
+        .execute(
+            r#"
+            MERGE INTO inventory USING duplicates ON inventory.id = duplicates.id
+            WHEN MATCHED THEN UPDATE SET qty = duplicates.qty
+            "#,
+        )
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("cardinality violation"), "{err}");
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use polars_core::schema::Schema;
+use polars_expr::prelude::PhysicalExpr;
+use polars_time::{DynamicGroupByState, DynamicGroupOptions};
+
+use super::compute_node_prelude::*;
+use crate::morsel::SourceToken;
+
+/// A `group_by_dynamic` on a sorted index column without additional keys.
+///
+/// Rows are buffered until the index column passes the upper bound of their windows, at which
+/// point those windows are aggregated and sent. Only the rows of windows that are still open are
+/// kept in memory.
+pub struct DynamicGroupByNode {
+    state: DynamicGroupByState,
+    aggs: Vec<Arc<dyn PhysicalExpr>>,
+    buffer: DataFrame,
+    seq: MorselSeq,
+}
+
+impl DynamicGroupByNode {
+    pub fn new(
+        input_schema: &Schema,
+        options: DynamicGroupOptions,
+        aggs: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> PolarsResult<Self> {
+        let index_dtype = input_schema.try_get(&options.index_column)?;
+        Ok(Self {
+            state: DynamicGroupByState::try_new(options, index_dtype)?,
+            aggs,
+            buffer: DataFrame::empty_with_schema(input_schema),
+            seq: MorselSeq::default(),
+        })
+    }
+
+    /// Aggregates the windows that are closed and drops the rows that are no longer needed.
+    fn flush(&mut self, is_last: bool) -> PolarsResult<Option<DataFrame>> {
+        let (time_key, mut columns, groups, n_done) = self.state.update(&self.buffer, is_last)?;
+
+        let out = if groups.is_empty() {
+            None
+        } else {
+            self.buffer.as_single_chunk_par();
+            let state = ExecutionState::new();
+            columns.push(time_key);
+            for agg in &self.aggs {
+                columns.push(
+                    agg.evaluate_on_groups(&self.buffer, &groups, &state)?
+                        .finalize(),
+                );
+            }
+            Some(DataFrame::new(columns)?)
+        };
+
+        self.buffer = self.buffer.slice(n_done as i64, usize::MAX);
+        Ok(out)
+    }
+}
+
+impl ComputeNode for DynamicGroupByNode {
+    fn name(&self) -> &str {
+        "dynamic-group-by"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if send[0] == PortState::Done {
+            recv[0] = PortState::Done;
+            self.buffer = self.buffer.clear();
+        } else if recv[0] == PortState::Done {
+            // The windows that are still open are closed by the end of the input.
+            if self.buffer.height() > 0 {
+                send[0] = PortState::Ready;
+            } else {
+                send[0] = PortState::Done;
+            }
+        } else {
+            recv.swap_with_slice(send);
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert_eq!(recv_ports.len(), 1);
+        assert_eq!(send_ports.len(), 1);
+
+        let recv = recv_ports[0].take();
+        let mut send = send_ports[0].take().unwrap().serial();
+
+        match recv {
+            None => {
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    if let Some(df) = self.flush(true)? {
+                        _ = send
+                            .send(Morsel::new(df, self.seq.successor(), SourceToken::new()))
+                            .await;
+                    }
+                    Ok(())
+                }));
+            },
+
+            Some(recv) => {
+                let mut recv = recv.serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(mut m) = recv.recv().await {
+                        self.seq = m.seq();
+                        if m.df().height() == 0 {
+                            continue;
+                        }
+
+                        self.buffer.vstack_mut_owned(std::mem::take(m.df_mut()))?;
+                        let Some(df) = self.flush(false)? else {
+                            continue;
+                        };
+                        *m.df_mut() = df;
+
+                        if send.send(m).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+        }
+    }
+}
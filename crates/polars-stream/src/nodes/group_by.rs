@@ -1,7 +1,10 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use parking_lot::Mutex;
 use polars_core::POOL;
-use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
+use polars_core::prelude::{Column, IntoColumn, PlHashMap, PlHashSet, PlRandomState};
+use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_expr::groups::Grouper;
@@ -22,6 +25,9 @@ use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    CAN_SPILL_FRAMES, SpillDir, get_memory_budget, read_spilled_frame, spill_frame,
+};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
@@ -47,6 +53,11 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // The estimated size of cold_morsels, and the files per partition the
+    // cold morsels were spilled to once they exceeded the memory budget.
+    cold_morsels_size: usize,
+    spilled_per_p: Vec<Vec<PathBuf>>,
 }
 
 impl LocalGroupBySinkState {
@@ -70,7 +81,66 @@ impl LocalGroupBySinkState {
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            cold_morsels_size: 0,
+            spilled_per_p: vec![Vec::new(); num_partitions],
+        }
+    }
+
+    /// Writes the cold morsels to disk, one IPC file per partition, each
+    /// containing the key columns, the morsel sequence id and the columns
+    /// used for reductions.
+    fn spill_cold_morsels(
+        &mut self,
+        key_schema: &Schema,
+        uniq_grouped_reduction_cols: &[PlSmallStr],
+        spill_dir: &Mutex<Option<Arc<SpillDir>>>,
+    ) -> PolarsResult<()> {
+        let dir = SpillDir::get_or_create(spill_dir, "GroupByNode")?;
+        let num_partitions = self.sketch_per_p.len();
+        let cold_morsels = core::mem::take(&mut self.cold_morsels);
+        for p in 0..num_partitions {
+            let mut parts = Vec::new();
+            for (i, (seq, keys, df)) in cold_morsels.iter().enumerate() {
+                let p_morsel_idxs_start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let p_morsel_idxs_stop =
+                    self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs =
+                    &self.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+                if p_morsel_idxs.is_empty() {
+                    continue;
+                }
+
+                unsafe {
+                    let mut columns = keys
+                        .gather_unchecked(p_morsel_idxs)
+                        .to_df(key_schema)
+                        .take_columns();
+                    columns.push(Column::new_scalar(
+                        PlSmallStr::EMPTY,
+                        Scalar::from(*seq),
+                        p_morsel_idxs.len(),
+                    ));
+                    for name in uniq_grouped_reduction_cols {
+                        let c = df.column(name).unwrap();
+                        columns.push(c.take_slice_unchecked(p_morsel_idxs));
+                    }
+                    parts.push(columns);
+                }
+            }
+
+            if !parts.is_empty() {
+                self.spilled_per_p[p].push(spill_frame(&dir, parts)?);
+            }
+        }
+
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
         }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.cold_morsels_size = 0;
+        Ok(())
     }
 
     fn flush_evictions(&mut self, partitioner: &HashPartitioner) {
@@ -102,6 +172,7 @@ impl LocalGroupBySinkState {
 }
 
 struct GroupBySinkState {
+    key_schema: Arc<Schema>,
     key_selectors: Vec<StreamExpr>,
     grouper: Box<dyn Grouper>,
     uniq_grouped_reduction_cols: Vec<PlSmallStr>,
@@ -110,6 +181,11 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+
+    // The cold morsels of each local are spilled once their estimated size
+    // exceeds this budget.
+    memory_budget_per_local: usize,
+    spill_dir: Mutex<Option<Arc<SpillDir>>>,
 }
 
 impl GroupBySinkState {
@@ -121,11 +197,14 @@ impl GroupBySinkState {
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let key_schema = &*self.key_schema;
             let key_selectors = &self.key_selectors;
             let uniq_grouped_reduction_cols = &self.uniq_grouped_reduction_cols;
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let memory_budget_per_local = self.memory_budget_per_local;
+            let spill_dir = &self.spill_dir;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -191,10 +270,20 @@ impl GroupBySinkState {
                             local
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+                            local.cold_morsels_size +=
+                                cold_df.estimated_size() + cold_keys.estimated_size();
                             local.cold_morsels.push((seq, cold_keys, cold_df));
                         }
                     }
 
+                    if local.cold_morsels_size > memory_budget_per_local {
+                        local.spill_cold_morsels(
+                            key_schema,
+                            uniq_grouped_reduction_cols,
+                            spill_dir,
+                        )?;
+                    }
+
                     // If we have too many evicted rows, flush them.
                     if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
                         local.flush_evictions(&partitioner);
@@ -205,8 +294,9 @@ impl GroupBySinkState {
         }
     }
 
-    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
-        // Finalize pre-aggregations.
+    /// Adds the evicted and remaining hot groups of each local to its
+    /// pre-aggregates.
+    fn finalize_locals(&mut self) {
         POOL.install(|| {
             self.locals
                 .as_mut_slice()
@@ -221,7 +311,190 @@ impl GroupBySinkState {
                     l.add_pre_agg(hot_keys, hot_reductions, &self.partitioner);
                 });
         });
+    }
+
+    fn num_partitions(&self) -> usize {
+        self.locals[0].sketch_per_p.len()
+    }
+
+    fn has_spilled(&self) -> bool {
+        self.locals
+            .iter()
+            .any(|l| l.spilled_per_p.iter().any(|files| !files.is_empty()))
+    }
+
+    /// Allocates the grouper and reductions for partition p, based on the
+    /// cardinality estimate of the partition.
+    fn new_partition(&self, p: usize) -> GroupByPartition {
+        let mut sketch = CardinalitySketch::new();
+        for l in &self.locals {
+            sketch.combine(&l.sketch_per_p[p]);
+        }
+
+        let est_num_groups = sketch.estimate() * 5 / 4;
+        let mut grouper = self.grouper.new_empty();
+        let mut grouped_reductions = self
+            .grouped_reductions
+            .iter()
+            .map(|gr| gr.new_empty())
+            .collect_vec();
+        grouper.reserve(est_num_groups);
+        for r in &mut grouped_reductions {
+            r.reserve(est_num_groups);
+        }
+        GroupByPartition {
+            grouper,
+            grouped_reductions,
+        }
+    }
+
+    /// Inserts the rows of partition p of the cold morsels of local l.
+    fn insert_morsels(
+        &self,
+        out: &mut GroupByPartition,
+        l: &LocalGroupBySinkState,
+        l_morsels: &[(u64, HashKeys, DataFrame)],
+        p: usize,
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let num_partitions = self.num_partitions();
+        for (i, morsel) in l_morsels.iter().enumerate() {
+            let (seq_id, keys, cols) = morsel;
+            unsafe {
+                let p_morsel_idxs_start = l.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let p_morsel_idxs_stop = l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs =
+                    &l.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+
+                group_idxs.clear();
+                out.grouper
+                    .insert_keys_subset(keys, p_morsel_idxs, Some(group_idxs));
+                for (c, r) in self
+                    .grouped_reduction_cols
+                    .iter()
+                    .zip(&mut out.grouped_reductions)
+                {
+                    let values = cols.column(c.as_str()).unwrap();
+                    r.resize(out.grouper.num_groups());
+                    r.update_groups_subset(values, p_morsel_idxs, group_idxs, *seq_id)?;
+                }
+            }
+        }
+        Ok(())
+    }
 
+    /// Inserts the spilled morsels of partition p of local l, one file at a
+    /// time.
+    fn insert_spilled(
+        &self,
+        out: &mut GroupByPartition,
+        l: &LocalGroupBySinkState,
+        p: usize,
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let key_schema = &*self.key_schema;
+        let mut subset = Vec::new();
+        for path in &l.spilled_per_p[p] {
+            let mut columns = read_spilled_frame(path)?;
+            let values = columns.split_off(key_schema.len() + 1);
+            let seqs = columns.pop().unwrap();
+            let keys = columns
+                .into_iter()
+                .zip(key_schema.iter_names())
+                .map(|(c, name)| c.with_name(name.clone()))
+                .collect();
+            let keys = DataFrame::new_with_height(seqs.len(), keys)?;
+            let keys = HashKeys::from_df(&keys, self.random_state, true, false);
+            let values: PlHashMap<_, _> = self
+                .uniq_grouped_reduction_cols
+                .iter()
+                .zip(values)
+                .collect();
+
+            // Update the reductions per run of rows from the same morsel.
+            let seqs = seqs.as_materialized_series().u64()?.clone();
+            let seqs = seqs.cont_slice()?;
+            let mut start = 0;
+            while start < seqs.len() {
+                let seq_id = seqs[start];
+                let mut stop = start + 1;
+                while stop < seqs.len() && seqs[stop] == seq_id {
+                    stop += 1;
+                }
+                subset.clear();
+                subset.extend(start as IdxSize..stop as IdxSize);
+
+                unsafe {
+                    group_idxs.clear();
+                    out.grouper
+                        .insert_keys_subset(&keys, &subset, Some(group_idxs));
+                    for (c, r) in self
+                        .grouped_reduction_cols
+                        .iter()
+                        .zip(&mut out.grouped_reductions)
+                    {
+                        r.resize(out.grouper.num_groups());
+                        r.update_groups_subset(&values[&c], &subset, group_idxs, seq_id)?;
+                    }
+                }
+                start = stop;
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts the pre-aggregates of partition p of local l.
+    fn insert_pre_aggs(
+        &self,
+        out: &mut GroupByPartition,
+        l: &LocalGroupBySinkState,
+        l_pre_aggs: &[(HashKeys, Vec<Box<dyn GroupedReduction>>)],
+        p: usize,
+        group_idxs: &mut Vec<IdxSize>,
+    ) -> PolarsResult<()> {
+        let num_partitions = self.num_partitions();
+        for (i, key_pre_aggs) in l_pre_aggs.iter().enumerate() {
+            let (keys, pre_aggs) = key_pre_aggs;
+            unsafe {
+                let p_pre_agg_idxs_start = l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
+                let p_pre_agg_idxs_stop =
+                    l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_pre_agg_idxs =
+                    &l.pre_agg_idxs_values_per_p[p][p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
+
+                group_idxs.clear();
+                out.grouper
+                    .insert_keys_subset(keys, p_pre_agg_idxs, Some(group_idxs));
+                for (pre_agg, r) in pre_aggs.iter().zip(&mut out.grouped_reductions) {
+                    r.resize(out.grouper.num_groups());
+                    r.combine_subset(&**pre_agg, p_pre_agg_idxs, group_idxs)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Combines partition p of all locals, reading its spilled morsels back
+    /// from disk. The state of the locals is left intact for the other
+    /// partitions.
+    fn combine_partition(&self, p: usize) -> PolarsResult<GroupByPartition> {
+        let mut out = self.new_partition(p);
+        let mut group_idxs = Vec::new();
+        for l in &self.locals {
+            self.insert_morsels(&mut out, l, &l.cold_morsels, p, &mut group_idxs)?;
+        }
+        for l in &self.locals {
+            self.insert_spilled(&mut out, l, p, &mut group_idxs)?;
+        }
+        for l in &self.locals {
+            self.insert_pre_aggs(&mut out, l, &l.pre_aggs, p, &mut group_idxs)?;
+        }
+        Ok(out)
+    }
+
+    /// Combines all partitions in parallel, dropping the state of the locals
+    /// as it is processed.
+    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
         // To reduce maximum memory usage we want to drop the morsels
         // as soon as they're processed, so we move into Arcs. The drops might
         // also be expensive, so instead of directly dropping we put that on
@@ -241,13 +514,10 @@ impl GroupBySinkState {
             B(B),
         }
         let (drop_q_send, drop_q_recv) = async_channel::bounded(self.locals.len());
-        let num_partitions = self.locals[0].sketch_per_p.len();
+        let num_partitions = self.num_partitions();
         let output_per_partition: SparseInitVec<GroupByPartition> =
             SparseInitVec::with_capacity(num_partitions);
-        let locals = &self.locals;
-        let grouper_template = &self.grouper;
-        let grouped_reductions_template = &self.grouped_reductions;
-        let grouped_reduction_cols = &self.grouped_reduction_cols;
+        let this = &*self;
 
         async_executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
@@ -266,62 +536,18 @@ impl GroupBySinkState {
                     let morsels_per_local = Arc::unwrap_or_clone(arc_morsels_per_local);
                     let pre_aggs_per_local = Arc::unwrap_or_clone(arc_pre_aggs_per_local);
 
-                    // Compute cardinality estimate and total amount of
-                    // payload for this partition.
-                    let mut sketch = CardinalitySketch::new();
-                    for l in locals {
-                        sketch.combine(&l.sketch_per_p[p]);
-                    }
-
-                    // Allocate grouper and reductions.
-                    let est_num_groups = sketch.estimate() * 5 / 4;
-                    let mut p_grouper = grouper_template.new_empty();
-                    let mut p_reductions = grouped_reductions_template
-                        .iter()
-                        .map(|gr| gr.new_empty())
-                        .collect_vec();
-                    p_grouper.reserve(est_num_groups);
-                    for r in &mut p_reductions {
-                        r.reserve(est_num_groups);
-                    }
+                    let mut out = this.new_partition(p);
 
                     // Insert morsels.
                     let mut skip_drop_attempt = false;
                     let mut group_idxs = Vec::new();
-                    for (l, l_morsels) in locals.iter().zip(morsels_per_local) {
+                    for (l, l_morsels) in this.locals.iter().zip(morsels_per_local) {
                         // Try to help with dropping.
                         if !skip_drop_attempt {
                             drop(drop_q_recv.try_recv());
                         }
 
-                        for (i, morsel) in l_morsels.iter().enumerate() {
-                            let (seq_id, keys, cols) = morsel;
-                            unsafe {
-                                let p_morsel_idxs_start =
-                                    l.morsel_idxs_offsets_per_p[i * num_partitions + p];
-                                let p_morsel_idxs_stop =
-                                    l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
-                                let p_morsel_idxs = &l.morsel_idxs_values_per_p[p]
-                                    [p_morsel_idxs_start..p_morsel_idxs_stop];
-
-                                group_idxs.clear();
-                                p_grouper.insert_keys_subset(
-                                    keys,
-                                    p_morsel_idxs,
-                                    Some(&mut group_idxs),
-                                );
-                                for (c, r) in grouped_reduction_cols.iter().zip(&mut p_reductions) {
-                                    let values = cols.column(c.as_str()).unwrap();
-                                    r.resize(p_grouper.num_groups());
-                                    r.update_groups_subset(
-                                        values,
-                                        p_morsel_idxs,
-                                        &group_idxs,
-                                        *seq_id,
-                                    )?;
-                                }
-                            }
-                        }
+                        this.insert_morsels(&mut out, l, &l_morsels, p, &mut group_idxs)?;
 
                         if let Some(l) = Arc::into_inner(l_morsels) {
                             // If we're the last thread to process this set of morsels we're probably
@@ -335,34 +561,13 @@ impl GroupBySinkState {
                     }
 
                     // Insert pre-aggregates.
-                    for (l, l_pre_aggs) in locals.iter().zip(pre_aggs_per_local) {
+                    for (l, l_pre_aggs) in this.locals.iter().zip(pre_aggs_per_local) {
                         // Try to help with dropping.
                         if !skip_drop_attempt {
                             drop(drop_q_recv.try_recv());
                         }
 
-                        for (i, key_pre_aggs) in l_pre_aggs.iter().enumerate() {
-                            let (keys, pre_aggs) = key_pre_aggs;
-                            unsafe {
-                                let p_pre_agg_idxs_start =
-                                    l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
-                                let p_pre_agg_idxs_stop =
-                                    l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
-                                let p_pre_agg_idxs = &l.pre_agg_idxs_values_per_p[p]
-                                    [p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
-
-                                group_idxs.clear();
-                                p_grouper.insert_keys_subset(
-                                    keys,
-                                    p_pre_agg_idxs,
-                                    Some(&mut group_idxs),
-                                );
-                                for (pre_agg, r) in pre_aggs.iter().zip(&mut p_reductions) {
-                                    r.resize(p_grouper.num_groups());
-                                    r.combine_subset(&**pre_agg, p_pre_agg_idxs, &group_idxs)?;
-                                }
-                            }
-                        }
+                        this.insert_pre_aggs(&mut out, l, &l_pre_aggs, p, &mut group_idxs)?;
 
                         if let Some(l) = Arc::into_inner(l_pre_aggs) {
                             // If we're the last thread to process this set of morsels we're probably
@@ -381,16 +586,7 @@ impl GroupBySinkState {
                         drop(to_drop);
                     }
 
-                    output_per_partition
-                        .try_set(
-                            p,
-                            GroupByPartition {
-                                grouper: p_grouper,
-                                grouped_reductions: p_reductions,
-                            },
-                        )
-                        .ok()
-                        .unwrap();
+                    output_per_partition.try_set(p, out).ok().unwrap();
 
                     PolarsResult::Ok(())
                 }));
@@ -443,9 +639,57 @@ impl GroupByPartition {
     }
 }
 
+/// Emits the result of a group-by which spilled to disk. To bound memory
+/// usage the partitions are combined and emitted one at a time.
+struct SpilledSourceState {
+    sink: GroupBySinkState,
+    next_partition: usize,
+    source: Option<InMemorySourceNode>,
+    seq_offset: MorselSeq,
+}
+
+impl SpilledSourceState {
+    /// Updates the source of the active partition, moving on to the next
+    /// partition once it is exhausted. Returns false once all partitions are
+    /// emitted.
+    fn update_state(
+        &mut self,
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+        key_schema: &Schema,
+        output_schema: &Schema,
+    ) -> PolarsResult<bool> {
+        loop {
+            if let Some(src) = &mut self.source {
+                src.update_state(&mut [], send, state)?;
+                if send[0] != PortState::Done {
+                    return Ok(true);
+                }
+                self.source = None;
+            }
+
+            if self.next_partition == self.sink.num_partitions() {
+                return Ok(false);
+            }
+            let df = self
+                .sink
+                .combine_partition(self.next_partition)?
+                .into_df(key_schema, output_schema)?;
+            self.next_partition += 1;
+            if df.height() > 0 {
+                // The source never uses more sequence ids than rows.
+                let height = df.height() as u64;
+                self.source = Some(InMemorySourceNode::new(Arc::new(df), self.seq_offset));
+                self.seq_offset = self.seq_offset.offset_by_u64(height);
+            }
+        }
+    }
+}
+
 enum GroupByState {
     Sink(GroupBySinkState),
     Source(InMemorySourceNode),
+    SpilledSource(SpilledSourceState),
     Done,
 }
 
@@ -466,7 +710,7 @@ impl GroupByNode {
         output_schema: Arc<Schema>,
         random_state: PlRandomState,
         num_pipelines: usize,
-    ) -> Self {
+    ) -> PolarsResult<Self> {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
             .unwrap_or(DEFAULT_HOT_TABLE_SIZE);
@@ -489,8 +733,14 @@ impl GroupByNode {
             })
             .collect();
         let partitioner = HashPartitioner::new(num_partitions, 0);
-        Self {
+        let memory_budget_per_local = if CAN_SPILL_FRAMES {
+            get_memory_budget("POLARS_GROUP_BY_MEMORY_BUDGET")? / num_pipelines
+        } else {
+            usize::MAX
+        };
+        Ok(Self {
             state: GroupByState::Sink(GroupBySinkState {
+                key_schema: key_schema.clone(),
                 key_selectors,
                 grouped_reductions,
                 grouper,
@@ -499,10 +749,12 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                memory_budget_per_local,
+                spill_dir: Mutex::new(None),
             }),
             key_schema,
             output_schema,
-        }
+        })
     }
 }
 
@@ -532,17 +784,30 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
-                let dfs = POOL.install(|| {
-                    partitions
-                        .into_par_iter()
-                        .map(|p| p.into_df(&self.key_schema, &self.output_schema))
-                        .collect::<Result<Vec<_>, _>>()
-                })?;
-
-                let df = accumulate_dataframes_vertical_unchecked(dfs);
-                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
-                self.state = GroupByState::Source(source);
+                sink.finalize_locals();
+                if sink.has_spilled() {
+                    let mut source = SpilledSourceState {
+                        sink,
+                        next_partition: 0,
+                        source: None,
+                        seq_offset: MorselSeq::new(0),
+                    };
+                    if source.update_state(send, state, &self.key_schema, &self.output_schema)? {
+                        self.state = GroupByState::SpilledSource(source);
+                    }
+                } else {
+                    let partitions = sink.combine_locals()?;
+                    let dfs = POOL.install(|| {
+                        partitions
+                            .into_par_iter()
+                            .map(|p| p.into_df(&self.key_schema, &self.output_schema))
+                            .collect::<Result<Vec<_>, _>>()
+                    })?;
+
+                    let df = accumulate_dataframes_vertical_unchecked(dfs);
+                    let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                    self.state = GroupByState::Source(source);
+                }
             },
             // Defer to source node implementation.
             GroupByState::Source(src) => {
@@ -551,6 +816,11 @@ impl ComputeNode for GroupByNode {
                     self.state = GroupByState::Done;
                 }
             },
+            GroupByState::SpilledSource(src) => {
+                if !src.update_state(send, state, &self.key_schema, &self.output_schema)? {
+                    self.state = GroupByState::Done;
+                }
+            },
             // Nothing to change.
             GroupByState::Done | GroupByState::Sink(_) => {},
         }
@@ -561,7 +831,7 @@ impl ComputeNode for GroupByNode {
                 send[0] = PortState::Blocked;
                 recv[0] = PortState::Ready;
             },
-            GroupByState::Source(..) => {
+            GroupByState::Source(..) | GroupByState::SpilledSource(..) => {
                 recv[0] = PortState::Done;
                 send[0] = PortState::Ready;
             },
@@ -596,6 +866,11 @@ impl ComputeNode for GroupByNode {
                 assert!(recv_ports[0].is_none());
                 source.spawn(scope, &mut [], send_ports, state, join_handles);
             },
+            GroupByState::SpilledSource(source) => {
+                assert!(recv_ports[0].is_none());
+                let source = source.source.as_mut().unwrap();
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
             GroupByState::Done => unreachable!(),
         }
     }
@@ -81,6 +81,17 @@ pub fn get_create_new_fn(
                 Ok(sink)
             }) as _
         },
+        #[cfg(feature = "avro")]
+        FileType::Avro(avro_writer_options) => Arc::new(move |input_schema, target| {
+            let sink = Box::new(super::avro::AvroSinkNode::new(
+                input_schema,
+                target,
+                sink_options.clone(),
+                &avro_writer_options,
+                cloud_options.clone(),
+            )?) as Box<dyn SinkNode + Send>;
+            Ok(sink)
+        }) as _,
         #[cfg(feature = "csv")]
         FileType::Csv(csv_writer_options) => Arc::new(move |input_schema, target| {
             let sink = Box::new(super::csv::CsvSinkNode::new(
@@ -96,7 +107,8 @@ pub fn get_create_new_fn(
             feature = "csv",
             feature = "parquet",
             feature = "json",
-            feature = "ipc"
+            feature = "ipc",
+            feature = "avro"
         )))]
         _ => {
             panic!("activate source feature")
@@ -0,0 +1,464 @@
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::datatypes::ArrowSchema;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::avro_schema::read::block_iterator;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::read::{BlockLocation, deserialize, infer_schema, read_block_locations};
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::PlSmallStr;
+use polars_core::schema::{Schema, SchemaExt, SchemaRef};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::avro::read_avro_metadata;
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    FileReader, FileReaderCallbacks, Projection,
+};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder;
+
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = AvroFileReader {
+                scan_source: source,
+                cloud_options,
+                verbose: config::verbose(),
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<Arc<InitializedState>>,
+}
+
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: FileMetadata,
+    arrow_schema: ArrowSchema,
+    file_schema: SchemaRef,
+    /// Offset of the first block in `memslice`.
+    data_start: usize,
+    blocks: Vec<BlockLocation>,
+    n_rows_in_file: IdxSize,
+}
+
+/// A range of consecutive blocks that is decoded into morsels by a single decoder task.
+struct Batch {
+    blocks: Range<usize>,
+    /// Rows to keep, relative to the first row of the first block.
+    slice: Range<usize>,
+    /// Row index value of the first row in `slice`.
+    row_index_offset: IdxSize,
+    morsel_seq_base: u64,
+}
+
+fn get_max_morsel_size() -> usize {
+    std::env::var("POLARS_STREAMING_AVRO_SOURCE_MAX_MORSEL_SIZE")
+        .map_or_else(
+            |_| get_ideal_morsel_size(),
+            |v| {
+                v.parse::<usize>().expect(
+                    "POLARS_STREAMING_AVRO_SOURCE_MAX_MORSEL_SIZE does not contain valid size",
+                )
+            },
+        )
+        .max(1)
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution does not download Avro.
+        if let ScanSourceRef::Path(addr) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(addr.to_str())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let (metadata, data_start) = read_avro_metadata(&memslice)?;
+        let arrow_schema = infer_schema(&metadata.record)?;
+        let file_schema = Arc::new(Schema::from_arrow_schema(&arrow_schema));
+        let blocks = read_block_locations(&memslice[data_start..], metadata.marker)?;
+
+        let n_rows: usize = blocks.iter().map(|block| block.num_rows).sum();
+        let n_rows_in_file = IdxSize::try_from(n_rows)
+            .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows))?;
+
+        self.init_data = Some(Arc::new(InitializedState {
+            memslice,
+            metadata,
+            arrow_schema,
+            file_schema,
+            data_start,
+            blocks,
+            n_rows_in_file,
+        }));
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+        let state = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let n_rows_in_file = usize::try_from(state.n_rows_in_file).unwrap();
+
+        let normalized_pre_slice = pre_slice_arg
+            .clone()
+            .map(|pre_slice| pre_slice.restrict_to_bounds(n_rows_in_file));
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(state.n_rows_in_file);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx.try_send(calc_row_position_after_slice(
+                state.n_rows_in_file,
+                normalized_pre_slice.clone(),
+            ));
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(state.file_schema.clone());
+        }
+
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..n_rows_in_file, Range::<usize>::from);
+
+        // Columns are decoded in file order and re-ordered to the projected order afterwards.
+        let projection: Vec<bool> = state
+            .file_schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+        let projected_names: Vec<PlSmallStr> = projected_schema
+            .iter_names()
+            .filter(|name| state.file_schema.contains(name))
+            .cloned()
+            .collect();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                projected_names.len(),
+                state.file_schema.len(),
+                pre_slice_arg,
+                normalized_pre_slice
+            )
+        }
+
+        let max_morsel_size = get_max_morsel_size();
+        let batches: Arc<[Batch]> = split_into_batches(
+            &state.blocks,
+            slice,
+            row_index.as_ref().map_or(0, |ri| ri.offset),
+            get_ideal_morsel_size(),
+            max_morsel_size,
+        )
+        .into();
+
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+        let next_batch_idx = Arc::new(AtomicUsize::new(0));
+        let projection: Arc<[bool]> = projection.into();
+        let projected_names: Arc<[PlSmallStr]> = projected_names.into();
+
+        // Decoder tasks.
+        //
+        // Every task repeatedly claims the next batch of blocks, decodes it and splits the result
+        // into morsels. As batches are claimed in order, the morsels that each task sends are in
+        // order as well.
+        let decoder_handles = morsel_senders
+            .into_iter()
+            .map(|mut morsel_tx| {
+                let state = state.clone();
+                let batches = batches.clone();
+                let next_batch_idx = next_batch_idx.clone();
+                let projection = projection.clone();
+                let projected_names = projected_names.clone();
+                let row_index = row_index.clone();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                    let source_token = SourceToken::new();
+
+                    loop {
+                        let batch_idx = next_batch_idx.fetch_add(1, Ordering::Relaxed);
+                        let Some(batch) = batches.get(batch_idx) else {
+                            break;
+                        };
+
+                        let mut df = if projected_names.is_empty() {
+                            DataFrame::empty_with_height(batch.slice.len())
+                        } else {
+                            let df = decode_blocks(&state, batch.blocks.clone(), &projection)?;
+                            df.slice(batch.slice.start as i64, batch.slice.len())
+                                .select(projected_names.iter().cloned())?
+                        };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            df = df.with_row_index(name.clone(), Some(batch.row_index_offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(batch.morsel_seq_base + i as u64);
+                            let morsel = Morsel::new(morsel_df, seq, source_token.clone());
+
+                            if morsel_tx.send_morsel(morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// Groups the blocks that overlap with `slice` into batches of at least `batch_size` rows.
+fn split_into_batches(
+    blocks: &[BlockLocation],
+    slice: Range<usize>,
+    row_index_offset: IdxSize,
+    batch_size: usize,
+    max_morsel_size: usize,
+) -> Vec<Batch> {
+    let mut batches = Vec::new();
+    let mut morsel_seq_base = 0;
+
+    let mut block_idx = 0;
+    let mut row_position = 0;
+
+    while block_idx < blocks.len() && row_position < slice.end {
+        let num_rows = blocks[block_idx].num_rows;
+
+        // Skip over blocks before the slice.
+        if row_position + num_rows <= slice.start {
+            row_position += num_rows;
+            block_idx += 1;
+            continue;
+        }
+
+        let batch_start_block = block_idx;
+        let batch_start_row = row_position;
+
+        while block_idx < blocks.len()
+            && row_position < slice.end
+            && row_position - batch_start_row < batch_size
+        {
+            row_position += blocks[block_idx].num_rows;
+            block_idx += 1;
+        }
+
+        let start = slice.start.max(batch_start_row) - batch_start_row;
+        let end = slice.end.min(row_position) - batch_start_row;
+
+        batches.push(Batch {
+            blocks: batch_start_block..block_idx,
+            slice: start..end,
+            row_index_offset: row_index_offset + (batch_start_row + start) as IdxSize,
+            morsel_seq_base,
+        });
+
+        morsel_seq_base += (end - start).div_ceil(max_morsel_size) as u64;
+    }
+
+    batches
+}
+
+/// Decodes the (projected) columns of a range of blocks.
+fn decode_blocks(
+    state: &InitializedState,
+    blocks: Range<usize>,
+    projection: &[bool],
+) -> PolarsResult<DataFrame> {
+    let data = &state.memslice[state.data_start..];
+
+    let record_batches = state.blocks[blocks]
+        .iter()
+        .map(|location| {
+            let mut iter = block_iterator(
+                &data[location.offset..],
+                state.metadata.compression,
+                state.metadata.marker,
+            );
+            let block = iter
+                .next()?
+                .ok_or_else(|| polars_err!(oos = "missing block - corrupt avro file"))?;
+
+            deserialize(
+                block,
+                &state.arrow_schema,
+                &state.metadata.record.fields,
+                projection,
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(accumulate_dataframes_vertical_unchecked(
+        record_batches.into_iter().map(DataFrame::from),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocks(num_rows: &[usize]) -> Vec<BlockLocation> {
+        num_rows
+            .iter()
+            .map(|&num_rows| BlockLocation {
+                offset: 0,
+                num_rows,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split_into_batches() {
+        let blocks = blocks(&[3, 3, 3, 3]);
+
+        let batches = split_into_batches(&blocks, 0..12, 0, 5, 100);
+        assert_eq!(
+            batches.iter().map(|b| b.blocks.clone()).collect::<Vec<_>>(),
+            [0..2, 2..4]
+        );
+        assert_eq!(
+            batches.iter().map(|b| b.slice.clone()).collect::<Vec<_>>(),
+            [0..6, 0..6]
+        );
+
+        let batches = split_into_batches(&blocks, 4..8, 10, 5, 2);
+        assert_eq!(
+            batches
+                .iter()
+                .map(|b| (
+                    b.blocks.clone(),
+                    b.slice.clone(),
+                    b.row_index_offset,
+                    b.morsel_seq_base
+                ))
+                .collect::<Vec<_>>(),
+            [(1..3, 1..5, 14, 0)]
+        );
+
+        assert!(split_into_batches(&blocks, 12..12, 0, 5, 100).is_empty());
+    }
+}
@@ -27,13 +27,47 @@ impl FileReaderBuilder for Arc<polars_plan::dsl::NDJsonReadOptions> {
         _scan_source_idx: usize,
     ) -> Box<dyn FileReader> {
         let scan_source = source;
-        let options = self.clone();
         let verbose = config::verbose();
 
         let reader = NDJsonFileReader {
             scan_source,
             cloud_options,
-            options,
+            ignore_errors: self.ignore_errors,
+            json_path: None,
+            cached_bytes: None,
+            verbose,
+        };
+
+        Box::new(reader) as Box<dyn FileReader>
+    }
+}
+
+#[cfg(feature = "json")]
+impl FileReaderBuilder for Arc<polars_plan::dsl::JsonReadOptions> {
+    fn reader_name(&self) -> &str {
+        "json"
+    }
+
+    fn reader_capabilities(&self) -> ReaderCapabilities {
+        use ReaderCapabilities as RC;
+
+        RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+    }
+
+    fn build_file_reader(
+        &self,
+        source: ScanSource,
+        cloud_options: Option<Arc<CloudOptions>>,
+        _scan_source_idx: usize,
+    ) -> Box<dyn FileReader> {
+        let scan_source = source;
+        let verbose = config::verbose();
+
+        let reader = NDJsonFileReader {
+            scan_source,
+            cloud_options,
+            ignore_errors: self.ignore_errors,
+            json_path: Some(self.json_path.clone()),
             cached_bytes: None,
             verbose,
         };
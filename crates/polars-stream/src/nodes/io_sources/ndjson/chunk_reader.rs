@@ -2,7 +2,6 @@ use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::ndjson;
 use polars_io::prelude::parse_ndjson;
-use polars_plan::dsl::NDJsonReadOptions;
 
 use crate::nodes::compute_node_prelude::*;
 
@@ -14,15 +13,12 @@ pub(super) struct ChunkReader {
 }
 
 impl ChunkReader {
-    pub(super) fn try_new(
-        options: &NDJsonReadOptions,
-        projected_schema: &SchemaRef,
-    ) -> PolarsResult<Self> {
+    pub(super) fn try_new(ignore_errors: bool, projected_schema: &SchemaRef) -> PolarsResult<Self> {
         let projected_schema = projected_schema.clone();
 
         Ok(Self {
             projected_schema,
-            ignore_errors: options.ignore_errors,
+            ignore_errors,
         })
     }
 
@@ -0,0 +1,99 @@
+use polars_core::config;
+use polars_error::PolarsResult;
+use polars_io::json::{JsonPath, json_rows, write_ndjson_row};
+use polars_utils::mmap::MemSlice;
+
+use super::line_batch_processor::LineBatch;
+use crate::async_primitives::distributor_channel;
+
+/// Distributes the rows of a (non-newline-delimited) JSON document to the LineBatchProcessors.
+///
+/// The rows are converted to NDJSON one batch of `chunk_size` bytes at a time, so at most a few
+/// batches are in memory next to the document. Rows are visited front to back, so this cannot be
+/// used for negative slices.
+pub(super) struct JsonRowDistributor {
+    pub(super) document: MemSlice,
+    pub(super) json_path: JsonPath,
+    pub(super) chunk_size: usize,
+    pub(super) n_rows_to_skip: usize,
+    pub(super) line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
+}
+
+impl JsonRowDistributor {
+    /// Returns the number of rows skipped (i.e. were not sent to LineBatchProcessors).
+    pub(super) async fn run(self) -> PolarsResult<usize> {
+        let JsonRowDistributor {
+            document,
+            json_path,
+            chunk_size,
+            n_rows_to_skip,
+            mut line_batch_distribute_tx,
+        } = self;
+
+        let verbose = config::verbose();
+
+        if verbose {
+            eprintln!(
+                "[NDJSON JsonRowDistributor]: \
+                document.len(): {}, \
+                json_path: {}, \
+                chunk_size: {}, \
+                n_rows_to_skip: {}",
+                document.len(),
+                json_path,
+                chunk_size,
+                n_rows_to_skip,
+            )
+        }
+
+        let mut rows = json_rows(&document, &json_path)?;
+
+        // Skipped rows are never converted.
+        let mut n_rows_skipped = 0;
+        while n_rows_skipped < n_rows_to_skip && rows.next().transpose()?.is_some() {
+            n_rows_skipped += 1;
+        }
+
+        let mut batch = Vec::with_capacity(chunk_size);
+        let mut chunk_idx = 0;
+
+        loop {
+            let row = rows.next().transpose()?;
+
+            if let Some(row) = row {
+                write_ndjson_row(row, &mut batch);
+                if batch.len() < chunk_size {
+                    continue;
+                }
+            } else if batch.is_empty() {
+                break;
+            }
+
+            let owned_bytes =
+                MemSlice::from_vec(std::mem::replace(&mut batch, Vec::with_capacity(chunk_size)));
+            // Safety: The LineBatch holds `owned_bytes` for as long as `bytes` is used.
+            let bytes: &'static [u8] = unsafe { std::mem::transmute(owned_bytes.as_ref()) };
+
+            if line_batch_distribute_tx
+                .send(LineBatch {
+                    bytes,
+                    owned_bytes: Some(owned_bytes),
+                    chunk_idx,
+                })
+                .await
+                .is_err()
+                || row.is_none()
+            {
+                break;
+            }
+
+            chunk_idx += 1;
+        }
+
+        if verbose {
+            eprintln!("[NDJSON JsonRowDistributor]: returning");
+        }
+
+        Ok(n_rows_skipped)
+    }
+}
@@ -107,6 +107,7 @@ impl LineBatchDistributor {
                     && line_batch_distribute_tx
                         .send(LineBatch {
                             bytes: full_chunk,
+                            owned_bytes: None,
                             chunk_idx,
                         })
                         .await
@@ -59,7 +59,12 @@ impl LineBatchProcessor {
 
         let mut n_rows_processed: usize = 0;
 
-        while let Ok(LineBatch { bytes, chunk_idx }) = line_batch_rx.recv().await {
+        while let Ok(LineBatch {
+            bytes,
+            owned_bytes: _owned_bytes,
+            chunk_idx,
+        }) = line_batch_rx.recv().await
+        {
             let df = chunk_reader.read_chunk(bytes)?;
 
             n_rows_processed = n_rows_processed.saturating_add(df.height());
@@ -78,6 +83,7 @@ impl LineBatchProcessor {
 
             while let Ok(LineBatch {
                 bytes,
+                owned_bytes: _owned_bytes,
                 chunk_idx: _,
             }) = line_batch_rx.recv().await
             {
@@ -97,6 +103,9 @@ impl LineBatchProcessor {
 pub(super) struct LineBatch {
     /// Safety: This is sent between 2 places that both hold a reference to the underlying MemSlice.
     pub(super) bytes: &'static [u8],
+    /// Holds the underlying MemSlice of `bytes` if it is not the global bytes, e.g. for rows
+    /// converted from a JSON document.
+    pub(super) owned_bytes: Option<MemSlice>,
     pub(super) chunk_idx: usize,
 }
 
@@ -11,9 +11,10 @@ use negative_slice_pass::MorselStreamReverser;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
+use polars_io::json::{JsonPath, json_rows_to_ndjson};
 use polars_io::prelude::estimate_n_lines_in_file;
 use polars_io::utils::compression::maybe_decompress_bytes;
-use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
+use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
 use polars_utils::mmap::MemSlice;
@@ -32,6 +33,7 @@ use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::{MorselSeq, TaskPriority};
 mod chunk_reader;
+mod json_row_distributor;
 mod line_batch_distributor;
 mod line_batch_processor;
 mod negative_slice_pass;
@@ -42,7 +44,10 @@ pub struct NDJsonFileReader {
     scan_source: ScanSource,
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
-    options: Arc<NDJsonReadOptions>,
+    ignore_errors: bool,
+    /// Set if the source is a JSON document rather than NDJSON. Its rows are then converted to
+    /// NDJSON one batch at a time while reading.
+    json_path: Option<JsonPath>,
     verbose: bool,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     cached_bytes: Option<MemSlice>,
@@ -80,10 +85,30 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
+        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
+
         // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
         // Ideally we have a streaming download/decompression.
         let global_bytes = self.get_bytes_maybe_decompress()?;
 
+        // The rows of a JSON document are converted to NDJSON while reading. A negative slice is
+        // read back to front, so there all rows are converted up front.
+        let (global_bytes, json_path) = match self.json_path.clone() {
+            Some(json_path) if is_negative_slice => {
+                let mut out = Vec::with_capacity(global_bytes.len());
+                let n_rows = json_rows_to_ndjson(&global_bytes, &json_path, None, &mut out)?;
+
+                if verbose {
+                    eprintln!(
+                        "[NDJsonFileReader]: converted {n_rows} rows at {json_path} to NDJSON"
+                    );
+                }
+
+                (MemSlice::from_vec(out), None)
+            },
+            json_path => (global_bytes, json_path),
+        };
+
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
         //
@@ -97,8 +122,6 @@ impl FileReader for NDJsonFileReader {
             _ = tx.try_send(schema.clone())
         }
 
-        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
-
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -134,7 +157,8 @@ impl FileReader for NDJsonFileReader {
 
         let chunk_size: usize = {
             let n_bytes_to_split = if let Some(x) = global_slice.as_ref() {
-                if needs_total_row_count {
+                // The line estimate is meaningless on a JSON document.
+                if needs_total_row_count || json_path.is_some() {
                     global_bytes.len()
                 } else {
                     // There may be early stopping, try to heuristically use a smaller chunk size to stop faster.
@@ -342,17 +366,30 @@ impl FileReader for NDJsonFileReader {
             })
             .collect::<Vec<_>>();
 
-        let line_batch_distributor_task_handle = AbortOnDropHandle::new(spawn(
-            TaskPriority::Low,
-            line_batch_distributor::LineBatchDistributor {
-                global_bytes,
-                chunk_size,
-                n_rows_to_skip,
-                reverse: is_negative_slice,
-                line_batch_distribute_tx,
-            }
-            .run(),
-        ));
+        let line_batch_distributor_task_handle = AbortOnDropHandle::new(match json_path {
+            Some(json_path) => spawn(
+                TaskPriority::Low,
+                json_row_distributor::JsonRowDistributor {
+                    document: global_bytes,
+                    json_path,
+                    chunk_size,
+                    n_rows_to_skip,
+                    line_batch_distribute_tx,
+                }
+                .run(),
+            ),
+            None => spawn(
+                TaskPriority::Low,
+                line_batch_distributor::LineBatchDistributor {
+                    global_bytes,
+                    chunk_size,
+                    n_rows_to_skip,
+                    reverse: is_negative_slice,
+                    line_batch_distribute_tx,
+                }
+                .run(),
+            ),
+        });
 
         let finishing_handle = spawn(TaskPriority::Low, async move {
             // Number of rows skipped by the line batch distributor.
@@ -436,7 +473,7 @@ impl FileReader for NDJsonFileReader {
 
 impl NDJsonFileReader {
     fn try_init_chunk_reader(&self, schema: &SchemaRef) -> PolarsResult<ChunkReader> {
-        ChunkReader::try_new(&self.options, schema)
+        ChunkReader::try_new(self.ignore_errors, schema)
     }
 
     fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
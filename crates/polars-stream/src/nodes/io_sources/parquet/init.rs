@@ -123,6 +123,7 @@ impl ParquetReadImpl {
                 &metadata,
                 projected_arrow_fields.clone(),
                 row_index,
+                &byte_source,
                 verbose,
             )
             .await?;
@@ -131,6 +132,7 @@ impl ParquetReadImpl {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
                 predicate,
+                use_page_index: use_statistics,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
@@ -138,6 +140,8 @@ impl ParquetReadImpl {
                 row_group_slice,
                 row_group_mask,
                 row_offset,
+                page_indexes: None,
+                prunable_columns: Default::default(),
             };
 
             while let Some(prefetch) = row_group_data_fetcher.next().await {
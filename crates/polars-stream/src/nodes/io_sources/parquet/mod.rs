@@ -31,6 +31,7 @@ use crate::utils::task_handles_ext;
 pub mod builder;
 mod init;
 mod metadata_utils;
+mod page_index;
 mod projection;
 mod row_group_data_fetch;
 mod row_group_decode;
@@ -0,0 +1,342 @@
+use std::ops::Range;
+
+use polars_core::prelude::{ArrowField, DataType, InitHashMaps, PlHashMap};
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::PolarsResult;
+use polars_io::predicates::{ColumnPredicates, SpecializedColumnPredicate};
+use polars_io::prelude::{FileMetadata, page_index_row_mask};
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::page_index::{
+    ColumnIndex, OffsetIndex, column_index_byte_range, deserialize_column_index,
+    deserialize_offset_index, offset_index_byte_range,
+};
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
+use polars_utils::mmap::MemSlice;
+
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// The page indexes that were fetched for a row group, by leaf column index.
+#[derive(Default)]
+pub(super) struct RowGroupPageIndex {
+    column_indexes: PlHashMap<usize, ColumnIndex>,
+    offset_indexes: PlHashMap<usize, OffsetIndex>,
+}
+
+impl RowGroupPageIndex {
+    pub(super) fn offset_index(&self, idx: usize) -> Option<&OffsetIndex> {
+        self.offset_indexes.get(&idx)
+    }
+}
+
+/// Returns the projected columns that have a column predicate that can be checked against the
+/// page index, as `(arrow field, predicate, leaf column index)`.
+fn page_index_predicate_columns<'a>(
+    row_group_metadata: &'a RowGroupMetadata,
+    projection: &'a [ArrowFieldProjection],
+    column_predicates: &'a ColumnPredicates,
+) -> impl Iterator<Item = (&'a ArrowField, &'a SpecializedColumnPredicate, usize)> + 'a {
+    projection.iter().filter_map(|projection| {
+        // Casts can change the order of values, so these are not checked.
+        let ArrowFieldProjection::Plain(arrow_field) = projection else {
+            return None;
+        };
+
+        let Some((_, Some(specialized))) = column_predicates.predicates.get(&arrow_field.name)
+        else {
+            return None;
+        };
+
+        let Some(&[idx]) = row_group_metadata.columns_idxs_under_root_iter(&arrow_field.name)
+        else {
+            return None;
+        };
+
+        Some((arrow_field, specialized, idx))
+    })
+}
+
+/// Fetches the page indexes of `row_groups` with a single `get_ranges` call.
+///
+/// Column indexes are fetched for the columns with a column predicate. If `prunable_columns` is
+/// given, the offset indexes of those columns are fetched as well, so that their pages without
+/// any rows of the page index mask don't need to be fetched.
+pub(super) async fn fetch_page_indexes(
+    metadata: &FileMetadata,
+    row_groups: impl Iterator<Item = usize>,
+    projection: &[ArrowFieldProjection],
+    column_predicates: &ColumnPredicates,
+    prunable_columns: &[usize],
+    byte_source: &DynByteSource,
+) -> PolarsResult<PlHashMap<usize, RowGroupPageIndex>> {
+    let to_usize = |r: Range<u64>| r.start as usize..r.end as usize;
+
+    // (row group index, leaf column index, column index byte range, offset index byte range)
+    let mut lookups = Vec::new();
+
+    for rg_idx in row_groups {
+        let row_group_metadata = &metadata.row_groups[rg_idx];
+        let n_lookups = lookups.len();
+
+        for (_, _, idx) in
+            page_index_predicate_columns(row_group_metadata, projection, column_predicates)
+        {
+            let column_chunk = &row_group_metadata.parquet_columns()[idx];
+
+            if let (Some(column_index_range), Some(offset_index_range)) = (
+                column_index_byte_range(column_chunk),
+                offset_index_byte_range(column_chunk),
+            ) {
+                lookups.push((
+                    rg_idx,
+                    idx,
+                    Some(to_usize(column_index_range)),
+                    to_usize(offset_index_range),
+                ));
+            }
+        }
+
+        // Without a column index no pages can be pruned.
+        if lookups.len() == n_lookups {
+            continue;
+        }
+
+        for &i in prunable_columns {
+            let Some(&[idx]) =
+                row_group_metadata.columns_idxs_under_root_iter(&projection[i].arrow_field().name)
+            else {
+                continue;
+            };
+
+            if let Some(offset_index_range) =
+                offset_index_byte_range(&row_group_metadata.parquet_columns()[idx])
+            {
+                lookups.push((rg_idx, idx, None, to_usize(offset_index_range)));
+            }
+        }
+    }
+
+    let mut page_indexes = PlHashMap::<usize, RowGroupPageIndex>::new();
+
+    if lookups.is_empty() {
+        return Ok(page_indexes);
+    }
+
+    let mut ranges = lookups
+        .iter()
+        .flat_map(|(_, _, ci, oi)| ci.iter().cloned().chain([oi.clone()]))
+        .collect::<Vec<_>>();
+
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    for (rg_idx, idx, column_index_range, offset_index_range) in lookups {
+        let page_index = page_indexes.entry(rg_idx).or_default();
+
+        if let Some(column_index_range) = column_index_range {
+            page_index.column_indexes.insert(
+                idx,
+                deserialize_column_index(&bytes_map[&column_index_range.start])?,
+            );
+        }
+
+        page_index.offset_indexes.insert(
+            idx,
+            deserialize_offset_index(&bytes_map[&offset_index_range.start])?,
+        );
+    }
+
+    Ok(page_indexes)
+}
+
+/// Calculates the mask of the rows of a row group that lie in pages which may satisfy the column
+/// predicates, according to the page index (ColumnIndex / OffsetIndex) of the file.
+///
+/// Returns `None` if no column has a page index that can be used.
+pub(super) fn calculate_page_index_row_mask(
+    row_group_metadata: &RowGroupMetadata,
+    projection: &[ArrowFieldProjection],
+    column_predicates: &ColumnPredicates,
+    page_index: &RowGroupPageIndex,
+) -> Option<Bitmap> {
+    let num_rows = row_group_metadata.num_rows();
+
+    let mut mask: Option<Bitmap> = None;
+
+    for (arrow_field, specialized, idx) in
+        page_index_predicate_columns(row_group_metadata, projection, column_predicates)
+    {
+        let (Some(column_index), Some(offset_index)) = (
+            page_index.column_indexes.get(&idx),
+            page_index.offset_indexes.get(&idx),
+        ) else {
+            continue;
+        };
+
+        let Some(column_mask) = page_index_row_mask(
+            specialized,
+            &DataType::from_arrow_field(arrow_field),
+            row_group_metadata.parquet_columns()[idx].physical_type(),
+            column_index,
+            offset_index,
+            num_rows,
+        ) else {
+            continue;
+        };
+
+        // All column predicates need to hold.
+        mask = Some(match mask {
+            None => column_mask,
+            Some(mask) => &mask & &column_mask,
+        });
+    }
+
+    mask
+}
+
+/// The byte ranges of a column chunk that are needed to decode the rows of a page index mask.
+pub(super) struct PrunedPageRanges {
+    /// The bytes before the first data page (i.e. the dictionary page), followed by the ranges of
+    /// the data pages that contain rows of the mask.
+    pub(super) ranges: Vec<Range<usize>>,
+    /// The rows of the row group that lie in the kept data pages.
+    pub(super) rows: Bitmap,
+}
+
+/// A column chunk of which only the data pages with rows of the page index mask were fetched.
+pub(super) struct PrunedColumnChunk {
+    /// The fetched ranges of [`PrunedPageRanges`], concatenated.
+    pub(super) bytes: MemSlice,
+    /// The rows of the row group that lie in the kept data pages.
+    pub(super) rows: Bitmap,
+}
+
+/// Returns the byte ranges of the data pages of a non-nested column chunk that contain rows of
+/// `mask`.
+///
+/// Returns `None` if all pages are needed or the offset index cannot be used.
+pub(super) fn pruned_page_ranges(
+    column_chunk: &ColumnChunkMetadata,
+    offset_index: &OffsetIndex,
+    mask: &Bitmap,
+) -> Option<PrunedPageRanges> {
+    // Every value must be a row, i.e. the column cannot be repeated.
+    if usize::try_from(column_chunk.num_values()).ok()? != mask.len() {
+        return None;
+    }
+
+    let byte_range = column_chunk.byte_range();
+    prune_pages(
+        byte_range.start as usize..byte_range.end as usize,
+        offset_index,
+        mask,
+    )
+}
+
+fn prune_pages(
+    column_range: Range<usize>,
+    offset_index: &OffsetIndex,
+    mask: &Bitmap,
+) -> Option<PrunedPageRanges> {
+    let num_rows = mask.len();
+
+    let locations = &offset_index.page_locations;
+    let first_page_offset = usize::try_from(locations.first()?.offset).ok()?;
+
+    if !column_range.contains(&first_page_offset) {
+        return None;
+    }
+
+    let mut ranges = Vec::with_capacity(locations.len() + 1);
+    if column_range.start < first_page_offset {
+        ranges.push(column_range.start..first_page_offset);
+    }
+
+    let mut rows = MutableBitmap::with_capacity(num_rows);
+    let mut prev_end = first_page_offset;
+    let mut pruned = false;
+
+    for (i, page) in locations.iter().enumerate() {
+        let start = usize::try_from(page.first_row_index).ok()?;
+        let end = match locations.get(i + 1) {
+            None => num_rows,
+            Some(next) => usize::try_from(next.first_row_index).ok()?,
+        };
+
+        let offset = usize::try_from(page.offset).ok()?;
+        let page_end = offset.checked_add(usize::try_from(page.compressed_page_size).ok()?)?;
+
+        if start != rows.len() || end < start || end > num_rows {
+            return None;
+        }
+        if offset < prev_end || page_end > column_range.end {
+            return None;
+        }
+        prev_end = page_end;
+
+        let keep = mask.clone().sliced(start, end - start).set_bits() > 0;
+        rows.extend_constant(end - start, keep);
+
+        if !keep {
+            pruned = true;
+            continue;
+        }
+
+        match ranges.last_mut() {
+            Some(last) if last.end == offset => last.end = page_end,
+            _ => ranges.push(offset..page_end),
+        }
+    }
+
+    if rows.len() != num_rows || !pruned {
+        return None;
+    }
+
+    Some(PrunedPageRanges {
+        ranges,
+        rows: rows.freeze(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_parquet::parquet::read::page_index::PageLocation;
+
+    use super::*;
+
+    #[test]
+    fn test_prune_pages() {
+        // A dictionary page at 4..10, followed by 4 data pages of 3 rows each.
+        let offset_index = OffsetIndex {
+            page_locations: [(10, 5, 0), (15, 5, 3), (20, 5, 6), (25, 5, 9)]
+                .into_iter()
+                .map(
+                    |(offset, compressed_page_size, first_row_index)| PageLocation {
+                        offset,
+                        compressed_page_size,
+                        first_row_index,
+                    },
+                )
+                .collect(),
+            unencoded_byte_array_data_bytes: None,
+        };
+
+        let mask = |rows: &[usize]| Bitmap::from_iter((0..12).map(|i| rows.contains(&i)));
+
+        let pruned = prune_pages(4..30, &offset_index, &mask(&[1, 9, 10])).unwrap();
+        assert_eq!(pruned.ranges, vec![4..15, 25..30]);
+        assert_eq!(
+            pruned.rows,
+            Bitmap::from_iter((0..12).map(|i| !(3..9).contains(&i)))
+        );
+
+        let pruned = prune_pages(10..30, &offset_index, &mask(&[4, 7])).unwrap();
+        assert_eq!(pruned.ranges, vec![15..25]);
+        assert_eq!(pruned.rows.set_bits(), 6);
+
+        // All pages are needed.
+        assert!(prune_pages(4..30, &offset_index, &mask(&[0, 3, 6, 9])).is_none());
+        // The pages don't lie in the column chunk.
+        assert!(prune_pages(4..20, &offset_index, &mask(&[1])).is_none());
+        // The page rows don't match the number of rows.
+        assert!(prune_pages(4..30, &offset_index, &Bitmap::new_zeroed(8)).is_none());
+    }
+}
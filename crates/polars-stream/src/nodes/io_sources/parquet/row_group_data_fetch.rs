@@ -1,17 +1,21 @@
 use std::ops::Range;
 use std::sync::Arc;
 
-use polars_core::prelude::PlHashMap;
+use polars_core::prelude::{InitHashMaps, PlHashMap};
 use polars_core::series::IsSorted;
 use polars_core::utils::arrow::bitmap::Bitmap;
 use polars_error::PolarsResult;
-use polars_io::predicates::ScanIOPredicate;
+use polars_io::predicates::{ColumnPredicates, ScanIOPredicate};
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
 use polars_parquet::read::RowGroupMetadata;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
+use crate::nodes::io_sources::parquet::page_index::{
+    PrunedColumnChunk, PrunedPageRanges, RowGroupPageIndex, calculate_page_index_row_mask,
+    fetch_page_indexes, pruned_page_ranges,
+};
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::utils::task_handles_ext;
 
@@ -22,13 +26,18 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Rows that lie in pages which may satisfy the predicate according to the page index.
+    pub(super) page_index_mask: Option<Bitmap>,
+    /// Column chunks of which only the pages with rows of `page_index_mask` were fetched, by the
+    /// start of their byte range.
+    pub(super) pruned_column_chunks: PlHashMap<usize, PrunedColumnChunk>,
 }
 
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Arc<[ArrowFieldProjection]>,
     pub(super) is_full_projection: bool,
-    #[allow(unused)] // TODO: Fix!
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) use_page_index: bool,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -38,6 +47,13 @@ pub(super) struct RowGroupDataFetcher {
     pub(super) row_group_mask: Option<Bitmap>,
 
     pub(super) row_offset: usize,
+
+    /// Page indexes of the remaining row groups. These are fetched together for the first row
+    /// group that uses the page index.
+    pub(super) page_indexes: Option<PlHashMap<usize, RowGroupPageIndex>>,
+    /// Indices into `projection` of the columns whose pages without rows of the page index mask
+    /// are not fetched.
+    pub(super) prunable_columns: Arc<[usize]>,
 }
 
 impl RowGroupDataFetcher {
@@ -82,11 +98,62 @@ impl RowGroupDataFetcher {
             let projection = self.projection.clone();
             let is_full_projection = self.is_full_projection;
             let memory_prefetch_func = self.memory_prefetch_func;
+            let column_predicates = self
+                .predicate
+                .as_ref()
+                .filter(|_| self.use_page_index && slice.is_none())
+                .map(|p| p.column_predicates.clone());
+
+            let page_index = match &column_predicates {
+                Some(column_predicates) => {
+                    if let Err(err) = self.fetch_page_indexes(idx, column_predicates).await {
+                        return Some(Err(err));
+                    }
+                    self.page_indexes.as_mut().unwrap().remove(&idx)
+                },
+                None => None,
+            };
+            let prunable_columns = self.prunable_columns.clone();
             let io_runtime = polars_io::pl_async::get_runtime();
 
             let handle = io_runtime.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
-                let fetched_bytes =
+
+                let page_index_mask = match (&column_predicates, &page_index) {
+                    (Some(column_predicates), Some(page_index)) => calculate_page_index_row_mask(
+                        row_group_metadata,
+                        &projection,
+                        column_predicates,
+                        page_index,
+                    ),
+                    _ => None,
+                };
+
+                // Columns that are decoded with the page index mask only need the pages that
+                // contain rows of the mask.
+                let pruned_page_ranges = match (&page_index, &page_index_mask) {
+                    (Some(page_index), Some(mask)) => prunable_columns
+                        .iter()
+                        .filter_map(|&i| {
+                            let &[idx] = row_group_metadata
+                                .columns_idxs_under_root_iter(&projection[i].arrow_field().name)?
+                            else {
+                                return None;
+                            };
+                            let column_chunk = &row_group_metadata.parquet_columns()[idx];
+                            let pruned = pruned_page_ranges(
+                                column_chunk,
+                                page_index.offset_index(idx)?,
+                                mask,
+                            )?;
+
+                            Some((column_chunk.byte_range().start as usize, pruned))
+                        })
+                        .collect::<PlHashMap<_, _>>(),
+                    _ => PlHashMap::new(),
+                };
+
+                let (fetched_bytes, pruned_column_chunks) =
                     if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
                         // Skip byte range calculation for `no_prefetch`.
                         if memory_prefetch_func as usize
@@ -113,42 +180,35 @@ impl RowGroupDataFetcher {
                         // file that can be sliced directly, so we can skip the byte-range
                         // calculations and HashMap allocation.
                         let mem_slice = mem_slice.0.clone();
-                        FetchedBytes::MemSlice {
-                            offset: 0,
-                            mem_slice,
-                        }
+                        (
+                            FetchedBytes::MemSlice {
+                                offset: 0,
+                                mem_slice,
+                            },
+                            PlHashMap::new(),
+                        )
                     } else if !is_full_projection {
-                        let mut ranges = get_row_group_byte_ranges_for_projection(
+                        let ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
                             &mut projection.iter().map(|x| &x.arrow_field().name),
                         )
                         .collect::<Vec<_>>();
 
-                        let n_ranges = ranges.len();
-
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
-
-                        assert_eq!(bytes_map.len(), n_ranges);
-
-                        FetchedBytes::BytesMap(bytes_map)
+                        fetch_ranges(current_byte_source.as_ref(), ranges, pruned_page_ranges)
+                            .await?
                     } else {
                         // We still prefer `get_ranges()` over a single `get_range()` for downloading
                         // the entire row group, as it can have less memory-copying. A single `get_range()`
                         // would naively concatenate the memory blocks of the entire row group, while
                         // `get_ranges()` can skip concatenation since the downloaded blocks are
                         // aligned to the columns.
-                        let mut ranges = row_group_metadata
+                        let ranges = row_group_metadata
                             .byte_ranges_iter()
                             .map(|x| x.start as usize..x.end as usize)
                             .collect::<Vec<_>>();
 
-                        let n_ranges = ranges.len();
-
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
-
-                        assert_eq!(bytes_map.len(), n_ranges);
-
-                        FetchedBytes::BytesMap(bytes_map)
+                        fetch_ranges(current_byte_source.as_ref(), ranges, pruned_page_ranges)
+                            .await?
                     };
 
                 PolarsResult::Ok(RowGroupData {
@@ -158,6 +218,8 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_index_mask,
+                    pruned_column_chunks,
                 })
             });
 
@@ -167,6 +229,98 @@ impl RowGroupDataFetcher {
 
         None
     }
+
+    /// Fetches the page indexes of row group `idx` and of all remaining row groups that are not
+    /// skipped, in a single round trip. Does nothing if they were already fetched.
+    async fn fetch_page_indexes(
+        &mut self,
+        idx: usize,
+        column_predicates: &ColumnPredicates,
+    ) -> PolarsResult<()> {
+        if self.page_indexes.is_some() {
+            return Ok(());
+        }
+
+        // The pages are pruned when fetching from a byte source that does not hold the entire
+        // file. Live columns are decoded without the page index mask if the predicate is
+        // evaluated during decoding, so all their pages are needed.
+        if !matches!(self.byte_source.as_ref(), DynByteSource::MemSlice(_)) {
+            let live_columns = &self.predicate.as_ref().unwrap().live_columns;
+
+            self.prunable_columns = self
+                .projection
+                .iter()
+                .enumerate()
+                .filter(|(_, projection)| {
+                    !projection.arrow_field().dtype().is_nested()
+                        && !live_columns.contains(projection.output_name())
+                })
+                .map(|(i, _)| i)
+                .collect();
+        }
+
+        let row_group_mask = self.row_group_mask.as_ref();
+        let remaining_row_groups = self
+            .row_group_slice
+            .clone()
+            .enumerate()
+            .filter(|(i, _)| !row_group_mask.is_some_and(|mask| mask.get_bit(*i)))
+            .map(|(_, rg_idx)| rg_idx);
+
+        self.page_indexes = Some(
+            fetch_page_indexes(
+                &self.metadata,
+                std::iter::once(idx).chain(remaining_row_groups),
+                &self.projection,
+                column_predicates,
+                &self.prunable_columns,
+                &self.byte_source,
+            )
+            .await?,
+        );
+
+        Ok(())
+    }
+}
+
+/// Fetches the column chunks at `ranges`. Of the column chunks in `pruned_page_ranges`, only the
+/// given pages are fetched.
+async fn fetch_ranges(
+    byte_source: &DynByteSource,
+    mut ranges: Vec<Range<usize>>,
+    pruned_page_ranges: PlHashMap<usize, PrunedPageRanges>,
+) -> PolarsResult<(FetchedBytes, PlHashMap<usize, PrunedColumnChunk>)> {
+    ranges.retain(|range| !pruned_page_ranges.contains_key(&range.start));
+    ranges.extend(
+        pruned_page_ranges
+            .values()
+            .flat_map(|pruned| pruned.ranges.iter().cloned()),
+    );
+
+    let n_ranges = ranges.len();
+
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    assert_eq!(bytes_map.len(), n_ranges);
+
+    let pruned_column_chunks = pruned_page_ranges
+        .into_iter()
+        .map(|(column_start, PrunedPageRanges { ranges, rows })| {
+            let bytes = if let [range] = ranges.as_slice() {
+                bytes_map[&range.start].clone()
+            } else {
+                let mut bytes = Vec::with_capacity(ranges.iter().map(|r| r.len()).sum());
+                for range in ranges.iter() {
+                    bytes.extend_from_slice(&bytes_map[&range.start]);
+                }
+                MemSlice::from_vec(bytes)
+            };
+
+            (column_start, PrunedColumnChunk { bytes, rows })
+        })
+        .collect();
+
+    Ok((FetchedBytes::BytesMap(bytes_map), pruned_column_chunks))
 }
 
 pub(super) enum FetchedBytes {
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
 use polars_core::series::Series;
+use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
 use polars_io::RowIndex;
@@ -13,7 +14,9 @@ use polars_io::predicates::{
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
 use polars_io::prelude::_internal::calc_prefilter_cost;
 use polars_io::prelude::try_set_sorted_flag;
-use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
+use polars_parquet::read::{
+    ColumnChunkMetadata, Filter, PageMetaData, ParquetType, PredicateFilter, PrimitiveLogicalType,
+};
 use polars_utils::IdxSize;
 use polars_utils::enum_unit_vec::EnumUnitVec;
 use polars_utils::pl_str::PlSmallStr;
@@ -48,6 +51,11 @@ impl RowGroupDecoder {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
+        // The page index mask is only used if it allows skipping rows.
+        row_group_data
+            .page_index_mask
+            .take_if(|mask| row_group_data.slice.is_some() || mask.unset_bits() == 0);
+
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
             && !self.predicate_field_indices.is_empty()
@@ -75,19 +83,30 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
-        if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
-            out_columns.push(s);
-        }
+        let mut row_index =
+            self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())?;
+
+        // Rows in pages that cannot match the predicate are skipped during decoding.
+        let filter = match row_group_data.page_index_mask.clone() {
+            Some(mask) => {
+                if let Some(c) = row_index.as_mut() {
+                    *c = c.filter(&BooleanChunked::from_bitmap(
+                        PlSmallStr::EMPTY,
+                        mask.clone(),
+                    ))?;
+                }
+                Filter::Mask(mask)
+            },
+            None => Filter::Range(slice_range.clone()),
+        };
 
-        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        let projection_height = filter.num_rows(slice_range.len());
+
+        out_columns.extend(row_index);
 
-        let projection_height = slice_range.len();
+        let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         out_columns.extend(decoded_cols);
 
@@ -233,7 +252,7 @@ impl RowGroupDecoder {
 fn decode_column(
     arrow_field: &ArrowField,
     row_group_data: &RowGroupData,
-    filter: Option<polars_parquet::read::Filter>,
+    mut filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
     let Some(iter) = row_group_data
@@ -250,32 +269,30 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
+    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
+    // Nested columns cannot be decoded with a mask, so they are filtered after decoding.
+    let post_filter_mask = match &filter {
+        Some(Filter::Mask(mask)) if arrow_field.dtype.is_nested() => {
+            let mask = mask.clone();
+            filter = None;
+            Some(mask)
+        },
+        _ => None,
+    };
 
-    let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
+    let (array, pred_true_mask) =
+        deserialize_column_chunks(arrow_field, row_group_data, iter, filter)?;
 
-    let (array, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+    let mut series = Series::try_from((arrow_field, array))?;
 
-    if !skip_num_rows_check {
-        assert_eq!(array.len(), expected_num_rows);
+    if let Some(mask) = post_filter_mask {
+        series = series.filter(&BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask))?;
     }
 
-    let mut series = Series::try_from((arrow_field, array))?;
+    if !skip_num_rows_check {
+        assert_eq!(series.len(), expected_num_rows);
+    }
 
     if let Some(col_idxs) = row_group_data
         .row_group_metadata
@@ -291,6 +308,67 @@ fn decode_column(
     Ok((series.into_column(), pred_true_mask))
 }
 
+/// Deserializes the column chunks of `arrow_field`.
+///
+/// Only the pages with rows of the page index mask were fetched of pruned column chunks. These are
+/// decoded with the filter restricted to the rows of those pages.
+fn deserialize_column_chunks<'a>(
+    arrow_field: &ArrowField,
+    row_group_data: &RowGroupData,
+    column_chunks: impl Iterator<Item = &'a ColumnChunkMetadata>,
+    filter: Option<Filter>,
+) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
+    let column_chunks = column_chunks.collect::<Vec<_>>();
+
+    if let [col_md] = column_chunks.as_slice()
+        && let Some(pruned) = row_group_data
+            .pruned_column_chunks
+            .get(&(col_md.byte_range().start as usize))
+    {
+        // Pages are only pruned for columns that are decoded with (a subset of) the page index
+        // mask.
+        let Some(Filter::Mask(mask)) = filter else {
+            unreachable!()
+        };
+        assert_eq!(mask.len(), pruned.rows.len());
+
+        let mask = mask
+            .iter()
+            .zip(pruned.rows.iter())
+            .filter_map(|(keep, in_page)| in_page.then_some(keep))
+            .collect::<Bitmap>();
+
+        let mut page_meta = PageMetaData::from(*col_md);
+        page_meta.num_values = pruned.rows.set_bits() as i64;
+
+        return polars_io::prelude::_internal::to_deserializer_with_page_meta(
+            vec![(page_meta, pruned.bytes.clone())],
+            arrow_field.clone(),
+            Some(Filter::Mask(mask)),
+        );
+    }
+
+    let columns_to_deserialize = column_chunks
+        .into_iter()
+        .map(|col_md| {
+            let byte_range = col_md.byte_range();
+
+            (
+                col_md,
+                row_group_data
+                    .fetched_bytes
+                    .get_range(byte_range.start as usize..byte_range.end as usize),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    polars_io::prelude::_internal::to_deserializer(
+        columns_to_deserialize,
+        arrow_field.clone(),
+        filter,
+    )
+}
+
 /// Filters columns, in parallel depending number of rows / columns.
 async fn filter_cols(
     cols: Vec<Column>,
@@ -351,9 +429,10 @@ fn decode_column_in_filter(
     use_column_predicates: bool,
     column_predicates: &ColumnPredicates,
     row_group_data: &RowGroupData,
+    page_index_mask: Option<&Bitmap>,
     projection_height: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
-    let mut filter = None;
+    let mut filter = page_index_mask.map(|mask| Filter::Mask(mask.clone()));
     let mut constant = None;
     if use_column_predicates {
         if let Some((column_predicate, specialized)) =
@@ -406,13 +485,6 @@ impl RowGroupDecoder {
             self.row_index.is_some() as usize + self.predicate_field_indices.len(),
         );
 
-        if let Some(s) = self.materialize_row_index(
-            row_group_data.as_ref(),
-            0..row_group_data.row_group_metadata.num_rows(),
-        )? {
-            live_columns.push(s);
-        }
-
         let scan_predicate = self.predicate.as_ref().unwrap();
 
         let use_column_predicates = self.allow_column_predicates
@@ -427,6 +499,29 @@ impl RowGroupDecoder {
                     matches!(pt.logical_type, Some(PrimitiveLogicalType::Float16))
                 });
 
+        // Column predicates already skip what they can while decoding, so the page index mask is
+        // only used to decode the live columns when evaluating the full predicate.
+        let page_index_mask = row_group_data
+            .page_index_mask
+            .clone()
+            .filter(|_| !use_column_predicates);
+        let live_height = page_index_mask
+            .as_ref()
+            .map_or(projection_height, |mask| mask.set_bits());
+
+        if let Some(mut s) = self.materialize_row_index(
+            row_group_data.as_ref(),
+            0..row_group_data.row_group_metadata.num_rows(),
+        )? {
+            if let Some(mask) = page_index_mask.as_ref() {
+                s = s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    mask.clone(),
+                ))?;
+            }
+            live_columns.push(s);
+        }
+
         let cols_per_thread = (self
             .predicate_field_indices
             .len()
@@ -436,6 +531,7 @@ impl RowGroupDecoder {
             let predicate_field_indices = self.predicate_field_indices.clone();
             let projected_arrow_fields = self.projected_arrow_fields.clone();
             let row_group_data = row_group_data.clone();
+            let page_index_mask = page_index_mask.clone();
 
             parallelize_first_to_local(
                 (0..self.predicate_field_indices.len())
@@ -445,6 +541,7 @@ impl RowGroupDecoder {
                         let predicate_field_indices = predicate_field_indices.clone();
                         let projected_arrow_fields = projected_arrow_fields.clone();
                         let column_predicates = scan_predicate.column_predicates.clone();
+                        let page_index_mask = page_index_mask.clone();
 
                         async move {
                             (offset
@@ -467,7 +564,8 @@ impl RowGroupDecoder {
                                         use_column_predicates,
                                         column_predicates.as_ref(),
                                         row_group_data.as_ref(),
-                                        projection_height,
+                                        page_index_mask.as_ref(),
+                                        live_height,
                                     )?;
 
                                     let col = projection.apply_transform(col)?;
@@ -517,9 +615,7 @@ impl RowGroupDecoder {
                 (DataFrame::new(live_columns).unwrap(), mask)
             }
         } else {
-            let mut live_df = unsafe {
-                DataFrame::new_no_checks(row_group_data.row_group_metadata.num_rows(), live_columns)
-            };
+            let mut live_df = unsafe { DataFrame::new_no_checks(live_height, live_columns) };
 
             let mask = scan_predicate.predicate.evaluate_io(&live_df)?;
             let mask = mask.bool().unwrap();
@@ -552,11 +648,16 @@ impl RowGroupDecoder {
 
         mask.rechunk_mut();
         let mask_bitmap = mask.downcast_as_array();
-        let mask_bitmap = match mask_bitmap.validity() {
+        let mut mask_bitmap = match mask_bitmap.validity() {
             None => mask_bitmap.values().clone(),
             Some(v) => mask_bitmap.values() & v,
         };
 
+        if let Some(page_index_mask) = page_index_mask.as_ref() {
+            mask_bitmap = expand_mask(page_index_mask, &mask_bitmap);
+            mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask_bitmap.clone());
+        }
+
         assert_eq!(mask_bitmap.len(), projection_height);
 
         let prefilter_cost = calc_prefilter_cost(&mask_bitmap);
@@ -624,6 +725,20 @@ impl RowGroupDecoder {
     }
 }
 
+/// Scatters `mask`, which has a bit for every set bit of `selection`, back to the length of
+/// `selection`.
+fn expand_mask(selection: &Bitmap, mask: &Bitmap) -> Bitmap {
+    assert_eq!(selection.set_bits(), mask.len());
+
+    let mut out = MutableBitmap::from_len_zeroed(selection.len());
+    for (i, keep) in selection.true_idx_iter().zip(mask.iter()) {
+        if keep {
+            out.set(i, true);
+        }
+    }
+    out.freeze()
+}
+
 fn decode_column_prefiltered(
     arrow_field: &ArrowField,
     row_group_data: &RowGroupData,
@@ -644,29 +759,13 @@ fn decode_column_prefiltered(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
-
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
-
     let prefilter = !arrow_field.dtype.is_nested();
 
     let deserialize_filter =
         prefilter.then(|| polars_parquet::read::Filter::Mask(mask_bitmap.clone()));
 
-    let (array, _) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        deserialize_filter,
-    )?;
+    let (array, _) =
+        deserialize_column_chunks(arrow_field, row_group_data, iter, deserialize_filter)?;
 
     let mut series = Series::try_from((arrow_field, array))?;
 
@@ -1,12 +1,14 @@
 use std::ops::Range;
 
 use arrow::array::{MutablePrimitiveArray, PrimitiveArray};
-use arrow::bitmap::Bitmap;
+use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::pushable::Pushable;
 use polars_core::prelude::*;
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::FileMetadata;
+use polars_io::prelude::{FileMetadata, bloom_filter_hashes, bloom_filter_may_contain_any};
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::deserialize as deserialize_bloom_filter;
 use polars_parquet::read::RowGroupMetadata;
 use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
 use polars_utils::format_pl_smallstr;
@@ -74,15 +76,15 @@ impl StatisticsColumns {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     row_group_slice: Range<usize>,
     use_statistics: bool,
     predicate: Option<&ScanIOPredicate>,
     metadata: &Arc<FileMetadata>,
     projected_arrow_fields: Arc<[ArrowFieldProjection]>,
-    // This is mut so that the offset is updated to the position of the first
-    // row group.
-    mut row_index: Option<RowIndex>,
+    row_index: Option<RowIndex>,
+    byte_source: &DynByteSource,
     verbose: bool,
 ) -> PolarsResult<Option<Bitmap>> {
     if !use_statistics {
@@ -93,6 +95,43 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
         return Ok(None);
     };
 
+    let statistics_skip_mask = calculate_row_group_statistics_skip_mask(
+        row_group_slice.clone(),
+        predicate,
+        metadata,
+        projected_arrow_fields.clone(),
+        row_index,
+        verbose,
+    )
+    .await?;
+
+    let bloom_filter_skip_mask = calculate_row_group_bloom_filter_skip_mask(
+        row_group_slice,
+        predicate,
+        metadata,
+        &projected_arrow_fields,
+        byte_source,
+        statistics_skip_mask.as_ref(),
+        verbose,
+    )
+    .await?;
+
+    Ok(match (statistics_skip_mask, bloom_filter_skip_mask) {
+        (Some(l), Some(r)) => Some(&l | &r),
+        (l, r) => l.or(r),
+    })
+}
+
+async fn calculate_row_group_statistics_skip_mask(
+    row_group_slice: Range<usize>,
+    predicate: &ScanIOPredicate,
+    metadata: &Arc<FileMetadata>,
+    projected_arrow_fields: Arc<[ArrowFieldProjection]>,
+    // This is mut so that the offset is updated to the position of the first
+    // row group.
+    mut row_index: Option<RowIndex>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
     let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
         return Ok(None);
     };
@@ -169,6 +208,122 @@ pub(super) async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
+/// Skips the row groups for which a bloom filter proves that an `Equal` / `EqualOneOf` column
+/// predicate cannot hold. Row groups that are already skipped in `skip_mask` are not checked.
+async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    predicate: &ScanIOPredicate,
+    metadata: &FileMetadata,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    skip_mask: Option<&Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    let row_groups_slice = &metadata.row_groups[row_group_slice];
+
+    let Some(first_row_group) = row_groups_slice.first() else {
+        return Ok(None);
+    };
+
+    // (leaf column index, hashes of which at least one has to be in the bloom filter)
+    let mut lookups = Vec::new();
+
+    for projection in projected_arrow_fields.iter() {
+        // Casts can change the physical representation, so these are not checked.
+        let ArrowFieldProjection::Plain(arrow_field) = projection else {
+            continue;
+        };
+
+        let Some((_, Some(specialized))) = predicate
+            .column_predicates
+            .predicates
+            .get(&arrow_field.name)
+        else {
+            continue;
+        };
+
+        let Some(&[idx]) = first_row_group.columns_idxs_under_root_iter(&arrow_field.name) else {
+            continue;
+        };
+
+        let physical_type = first_row_group.parquet_columns()[idx].physical_type();
+        let dtype = DataType::from_arrow_field(arrow_field);
+
+        if let Some(hashes) = bloom_filter_hashes(specialized, &dtype, physical_type) {
+            lookups.push((idx, hashes));
+        }
+    }
+
+    if lookups.is_empty() {
+        return Ok(None);
+    }
+
+    let bloom_filter_range = |rg: &RowGroupMetadata, idx: usize| {
+        let md = rg.parquet_columns()[idx].metadata();
+        let offset = usize::try_from(md.bloom_filter_offset?).ok()?;
+        let length = usize::try_from(md.bloom_filter_length?).ok()?;
+        Some(offset..offset + length)
+    };
+
+    let mut ranges = Vec::new();
+
+    for (i, rg) in row_groups_slice.iter().enumerate() {
+        if skip_mask.is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        ranges.extend(
+            lookups
+                .iter()
+                .filter_map(|(idx, _)| bloom_filter_range(rg, *idx)),
+        );
+    }
+
+    if ranges.is_empty() {
+        return Ok(None);
+    }
+
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut bitset = Vec::new();
+    let mut bloom_filter_skip_mask = MutableBitmap::with_capacity(row_groups_slice.len());
+
+    for (i, rg) in row_groups_slice.iter().enumerate() {
+        let mut skip = false;
+
+        if !skip_mask.is_some_and(|m| m.get_bit(i)) {
+            for (idx, hashes) in lookups.iter() {
+                let Some(range) = bloom_filter_range(rg, *idx) else {
+                    continue;
+                };
+
+                deserialize_bloom_filter(bytes_map.get(&range.start).unwrap(), &mut bitset)?;
+
+                // An empty bitset means the bloom filter is not supported.
+                if !bitset.is_empty() && !bloom_filter_may_contain_any(&bitset, hashes) {
+                    skip = true;
+                    break;
+                }
+            }
+        }
+
+        bloom_filter_skip_mask.push(skip);
+    }
+
+    let bloom_filter_skip_mask = bloom_filter_skip_mask.freeze();
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            skipping {} / {} row groups",
+            bloom_filter_skip_mask.set_bits(),
+            row_groups_slice.len(),
+        );
+    }
+
+    Ok(Some(bloom_filter_skip_mask))
+}
+
 fn load_parquet_column_statistics(
     row_groups: &[RowGroupMetadata],
     projection: &ArrowFieldProjection,
@@ -0,0 +1,427 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_error::polars_bail;
+use polars_ops::frame::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy, JoinArgs, JoinType};
+use polars_ops::series::SeriesMethods;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+struct AsOfJoinParams {
+    left_on: PlSmallStr,
+    right_on: PlSmallStr,
+    options: AsOfOptions,
+    suffix: Option<PlSmallStr>,
+    coalesce: bool,
+}
+
+impl AsOfJoinParams {
+    fn has_by(&self) -> bool {
+        self.options.left_by.is_some()
+    }
+
+    /// With groups we always check sortedness, as unlike the in-memory join
+    /// we need the keys to be sorted globally, not only within each group.
+    /// The planner only uses this node for groups if it knows the keys to be
+    /// sorted, so this guards against incorrect sortedness hints.
+    fn must_check_sortedness(&self) -> bool {
+        self.options.check_sortedness || self.has_by()
+    }
+
+    fn unsorted_err(&self) -> PolarsResult<()> {
+        if self.has_by() {
+            polars_bail!(
+                InvalidOperation: "the streaming asof join with 'by' groups requires the 'on' key to be sorted globally, not only within each group"
+            );
+        }
+        polars_bail!(
+            InvalidOperation: "argument in operation 'asof_join' is not sorted, please sort the 'expr/series/column' first"
+        )
+    }
+
+    /// Joins a left morsel with all right rows which can possibly match it
+    /// using the in-memory as-of join.
+    fn join(&self, left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+        let left_key = left.column(&self.left_on)?.as_materialized_series();
+        let right_key = right.column(&self.right_on)?.as_materialized_series();
+        let tolerance = self.options.tolerance.clone().map(|t| t.into_value());
+        match (&self.options.left_by, &self.options.right_by) {
+            (Some(left_by), Some(right_by)) => left._join_asof_by(
+                right,
+                left_key,
+                right_key,
+                left_by.clone(),
+                right_by.clone(),
+                self.options.strategy,
+                tolerance,
+                self.suffix.clone(),
+                None,
+                self.coalesce,
+                self.options.allow_eq,
+                false,
+            ),
+            _ => left._join_asof(
+                right,
+                left_key,
+                right_key,
+                self.options.strategy,
+                tolerance,
+                self.suffix.clone(),
+                None,
+                self.coalesce,
+                self.options.allow_eq,
+                false,
+            ),
+        }
+    }
+}
+
+/// The number of rows in the sorted `key` which are less than `value`.
+fn num_lt(key: &Series, value: &Series) -> PolarsResult<usize> {
+    Ok(key.lt(value)?.num_trues())
+}
+
+/// The number of rows in the sorted `key` which are less than or equal to `value`.
+fn num_le(key: &Series, value: &Series) -> PolarsResult<usize> {
+    Ok(key.lt_eq(value)?.num_trues())
+}
+
+/// Whether the key of a new morsel is sorted and does not precede the largest
+/// key seen before it.
+fn is_sorted_after(key: &Series, prev_max: Option<&Series>) -> PolarsResult<bool> {
+    let mut sorted = key.to_physical_repr().is_sorted(SortOptions::default())?;
+    if let Some(prev_max) = prev_max.filter(|_| sorted) {
+        let min = key.min_reduce()?;
+        sorted = min.is_null() || !min.into_series(PlSmallStr::EMPTY).lt(prev_max)?.all();
+    }
+    Ok(sorted)
+}
+
+/// Returns the maximum non-null key as a unit-length series, if any.
+fn max_key(key: &Series) -> PolarsResult<Option<Series>> {
+    let max = key.max_reduce()?;
+    Ok((!max.is_null()).then(|| max.into_series(key.name().clone())))
+}
+
+struct AsOfJoinState {
+    seq: MorselSeq,
+    /// Left morsels which were received but not yet joined.
+    left_unprocessed: VecDeque<DataFrame>,
+    /// The largest left key joined so far, used to check sortedness.
+    left_max: Option<Series>,
+    /// The right rows (without null keys) which can still match future left
+    /// rows, sorted by key.
+    right_buffer: DataFrame,
+    right_done: bool,
+}
+
+impl AsOfJoinState {
+    fn right_key(&self, params: &AsOfJoinParams) -> PolarsResult<&Series> {
+        Ok(self
+            .right_buffer
+            .column(&params.right_on)?
+            .as_materialized_series())
+    }
+
+    fn push_right(&mut self, mut df: DataFrame, params: &AsOfJoinParams) -> PolarsResult<()> {
+        // Null keys never match, so we don't have to keep them around.
+        let key = df.column(&params.right_on)?;
+        if key.has_nulls() {
+            let mask = key.is_not_null();
+            df = df.filter(&mask)?;
+        }
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        if params.must_check_sortedness() {
+            let prev_max = (self.right_buffer.height() > 0)
+                .then(|| self.right_key(params).map(|key| key.tail(Some(1))))
+                .transpose()?;
+            let key = df.column(&params.right_on)?.as_materialized_series();
+            if !is_sorted_after(key, prev_max.as_ref())? {
+                params.unsorted_err()?;
+            }
+        }
+
+        self.right_buffer.vstack_mut_owned(df)?;
+        Ok(())
+    }
+
+    /// Whether we have seen enough of the right side to join left rows with
+    /// keys up to `left_max`.
+    fn can_join(&self, left_max: Option<&Series>, params: &AsOfJoinParams) -> PolarsResult<bool> {
+        let Some(left_max) = left_max else {
+            return Ok(true);
+        };
+        if self.right_done {
+            return Ok(true);
+        }
+
+        let key = self.right_key(params)?;
+        let n = key.len();
+        Ok(match params.options.strategy {
+            // We need all right rows up to and including the first one past
+            // the largest left key.
+            AsofStrategy::Backward => num_le(key, left_max)? < n,
+            AsofStrategy::Forward if !params.has_by() => num_le(key, left_max)? < n,
+            // The nearest strategy prefers the last of equal keys, so we also
+            // need all right rows equal to the first one past the largest key.
+            AsofStrategy::Nearest if !params.has_by() => {
+                let idx = num_le(key, left_max)?;
+                idx < n && num_le(key, &key.slice(idx as i64, 1))? < n
+            },
+            // With groups the next right row in a group can be arbitrarily far
+            // ahead, unless it would be out of tolerance anyway.
+            AsofStrategy::Forward | AsofStrategy::Nearest => match &params.options.tolerance {
+                Some(tolerance) => {
+                    let tolerance = tolerance.clone().into_series(PlSmallStr::EMPTY);
+                    let limit = (left_max.to_physical_repr().as_ref() + &tolerance)?;
+                    num_le(&key.to_physical_repr(), &limit)? < n
+                },
+                None => false,
+            },
+        })
+    }
+
+    /// Drops the right rows which cannot match any left row with a key of at
+    /// least `left_max`.
+    fn prune(&mut self, left_max: &Series, params: &AsOfJoinParams) -> PolarsResult<()> {
+        let num_stale = num_lt(self.right_key(params)?, left_max)?;
+        if num_stale == 0 {
+            return Ok(());
+        }
+
+        let (stale, fresh) = self.right_buffer.split_at(num_stale as i64);
+        self.right_buffer = match params.options.strategy {
+            AsofStrategy::Forward => fresh,
+            // Keep the last stale row (per group), as it is the backward match
+            // for left rows preceding the first fresh row.
+            AsofStrategy::Backward | AsofStrategy::Nearest => {
+                let mut carry = match &params.options.right_by {
+                    Some(right_by) => stale.unique_impl(
+                        true,
+                        Some(right_by.clone()),
+                        UniqueKeepStrategy::Last,
+                        None,
+                    )?,
+                    None => stale.tail(Some(1)),
+                };
+                carry.vstack_mut_owned(fresh)?;
+                carry
+            },
+        };
+        Ok(())
+    }
+}
+
+/// Requests the port to stop producing morsels and collects the morsels that
+/// were already produced.
+async fn drain_port(port: &mut Receiver<Morsel>) -> Vec<DataFrame> {
+    let mut dfs = Vec::new();
+    if let Ok(morsel) = port.recv().await {
+        morsel.source_token().stop();
+        dfs.push(morsel.into_df());
+        while let Ok(morsel) = port.recv().await {
+            dfs.push(morsel.into_df());
+        }
+    }
+    dfs
+}
+
+/// An as-of join of two inputs sorted by their key. Both inputs are consumed
+/// in order, only buffering those right rows which can still match upcoming
+/// left rows.
+pub struct AsOfJoinNode {
+    params: AsOfJoinParams,
+    state: AsOfJoinState,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_on: PlSmallStr,
+        right_on: PlSmallStr,
+        args: &JoinArgs,
+    ) -> Self {
+        let JoinType::AsOf(options) = &args.how else {
+            unreachable!()
+        };
+        Self {
+            params: AsOfJoinParams {
+                left_on,
+                right_on,
+                options: options.as_ref().clone(),
+                suffix: args.suffix.clone(),
+                coalesce: args.should_coalesce(),
+            },
+            state: AsOfJoinState {
+                seq: MorselSeq::default(),
+                left_unprocessed: VecDeque::new(),
+                left_max: None,
+                right_buffer: DataFrame::empty_with_schema(&right_input_schema),
+                right_done: false,
+            },
+        }
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        let left_done = recv[0] == PortState::Done && self.state.left_unprocessed.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            self.state.right_buffer = self.state.right_buffer.clear();
+            return Ok(());
+        }
+
+        self.state.right_done = recv[1] == PortState::Done;
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.state.left_unprocessed.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        let send = send_ports[0].take().unwrap().parallel();
+        let mut left = recv_ports[0].take().map(|p| p.serial());
+        let mut right = recv_ports[1].take().map(|p| p.serial());
+        let (mut distributor, dist_recv) =
+            distributor_channel(send.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let params = &self.params;
+        let state = &mut self.state;
+
+        // A single task decides which right rows each left morsel needs.
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            let source_token = SourceToken::new();
+
+            while !source_token.stop_requested() {
+                let left_df = match state.left_unprocessed.pop_front() {
+                    Some(df) => df,
+                    None => {
+                        let Some(port) = &mut left else { break };
+                        let Ok(morsel) = port.recv().await else {
+                            left = None;
+                            break;
+                        };
+                        morsel.into_df()
+                    },
+                };
+                if left_df.height() == 0 {
+                    continue;
+                }
+
+                let left_key = left_df.column(&params.left_on)?.as_materialized_series();
+                let left_max = max_key(left_key)?;
+                if params.must_check_sortedness()
+                    && !is_sorted_after(left_key, state.left_max.as_ref())?
+                {
+                    params.unsorted_err()?;
+                }
+
+                while !state.can_join(left_max.as_ref(), params)? {
+                    let Some(port) = &mut right else { break };
+                    match port.recv().await {
+                        Ok(morsel) => state.push_right(morsel.into_df(), params)?,
+                        Err(_) => right = None,
+                    }
+                }
+                if !state.can_join(left_max.as_ref(), params)? {
+                    // Wait for more right rows in the next phase.
+                    state.left_unprocessed.push_front(left_df);
+                    break;
+                }
+
+                if state.right_buffer.should_rechunk() {
+                    state.right_buffer.rechunk_mut();
+                }
+                let morsel = Morsel::new(left_df, state.seq, source_token.clone());
+                state.seq = state.seq.successor();
+                if distributor
+                    .send((morsel, state.right_buffer.clone()))
+                    .await
+                    .is_err()
+                {
+                    return Ok(());
+                }
+
+                if let Some(left_max) = left_max {
+                    state.prune(&left_max, params)?;
+                    state.left_max = Some(left_max);
+                }
+            }
+
+            // Buffer whatever our inputs already produced before the phase ends.
+            if let Some(port) = &mut left {
+                state.left_unprocessed.extend(drain_port(port).await);
+            }
+            if let Some(port) = &mut right {
+                for df in drain_port(port).await {
+                    state.push_right(df, params)?;
+                }
+            }
+            Ok(())
+        }));
+
+        join_handles.extend(dist_recv.into_iter().zip(send).map(|(mut recv, mut send)| {
+            scope.spawn_task(TaskPriority::High, async move {
+                while let Ok((morsel, right_df)) = recv.recv().await {
+                    let morsel = morsel.try_map(|left_df| params.join(&left_df, &right_df))?;
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(())
+            })
+        }));
+    }
+}
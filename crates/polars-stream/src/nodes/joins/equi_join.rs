@@ -1,9 +1,11 @@
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
@@ -31,9 +33,14 @@ use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{
+    CAN_SPILL_FRAMES, SpillDir, get_memory_budget, read_spilled_frame, spill_frame,
+};
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
+    left_input_schema: Arc<Schema>,
+    right_input_schema: Arc<Schema>,
     preserve_order_build: bool,
     preserve_order_probe: bool,
     left_key_schema: Arc<Schema>,
@@ -45,8 +52,12 @@ struct EquiJoinParams {
     right_payload_select: Vec<Option<PlSmallStr>>,
     left_payload_schema: Arc<Schema>,
     right_payload_schema: Arc<Schema>,
+    unique_key_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    // The build morsels of each local builder are spilled once their
+    // estimated size exceeds this budget.
+    memory_budget_per_local: usize,
 }
 
 impl EquiJoinParams {
@@ -182,12 +193,11 @@ async fn select_keys(
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
-    Ok(HashKeys::from_df(
-        &keys,
-        params.random_state,
-        params.args.nulls_equal,
-        false,
-    ))
+    Ok(hash_keys(&keys, params))
+}
+
+fn hash_keys(keys: &DataFrame, params: &EquiJoinParams) -> HashKeys {
+    HashKeys::from_df(keys, params.random_state, params.args.nulls_equal, false)
 }
 
 fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
@@ -389,6 +399,7 @@ impl SampleState {
                             local_builder,
                             partitioner.clone(),
                             params,
+                            &build_state.spill_dir,
                             state,
                         ),
                     ));
@@ -421,11 +432,69 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // The estimated size of morsels, and the files per partition the morsels
+    // were spilled to once they exceeded the memory budget.
+    morsels_size: usize,
+    spilled_per_p: Vec<Vec<PathBuf>>,
+}
+
+impl LocalBuilder {
+    /// Writes the morsels to disk, one IPC file per partition, each containing
+    /// the key columns followed by the payload.
+    fn spill(
+        &mut self,
+        key_schema: &Schema,
+        spill_dir: &Mutex<Option<Arc<SpillDir>>>,
+    ) -> PolarsResult<()> {
+        let dir = SpillDir::get_or_create(spill_dir, "EquiJoinNode")?;
+        let num_partitions = self.sketch_per_p.len();
+        let morsels = core::mem::take(&mut self.morsels);
+        for p in 0..num_partitions {
+            let mut parts = Vec::new();
+            for (i, (_seq, payload, keys)) in morsels.iter().enumerate() {
+                let p_morsel_idxs_start = self.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                let p_morsel_idxs_stop =
+                    self.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                let p_morsel_idxs =
+                    &self.morsel_idxs_values_per_p[p][p_morsel_idxs_start..p_morsel_idxs_stop];
+                if p_morsel_idxs.is_empty() {
+                    continue;
+                }
+
+                unsafe {
+                    let mut columns = keys
+                        .gather_unchecked(p_morsel_idxs)
+                        .to_df(key_schema)
+                        .take_columns();
+                    columns.extend(
+                        payload
+                            .take_slice_unchecked_impl(p_morsel_idxs, false)
+                            .take_columns(),
+                    );
+                    parts.push(columns);
+                }
+            }
+
+            if !parts.is_empty() {
+                self.spilled_per_p[p].push(spill_frame(&dir, parts)?);
+            }
+        }
+
+        for idxs in &mut self.morsel_idxs_values_per_p {
+            idxs.clear();
+        }
+        self.morsel_idxs_offsets_per_p.clear();
+        self.morsel_idxs_offsets_per_p.resize(num_partitions, 0);
+        self.morsels_size = 0;
+        Ok(())
+    }
 }
 
 struct BuildState {
     local_builders: Vec<LocalBuilder>,
     sampled_probe_morsels: BufferedStream,
+    spill_dir: Mutex<Option<Arc<SpillDir>>>,
 }
 
 impl BuildState {
@@ -440,11 +509,14 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                morsels_size: 0,
+                spilled_per_p: vec![Vec::new(); num_partitions],
             })
             .collect();
         Self {
             local_builders,
             sampled_probe_morsels,
+            spill_dir: Mutex::new(None),
         }
     }
 
@@ -453,6 +525,7 @@ impl BuildState {
         local: &mut LocalBuilder,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
+        spill_dir: &Mutex<Option<Arc<SpillDir>>>,
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
@@ -488,11 +561,59 @@ impl BuildState {
             local
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
+            local.morsels_size += payload.estimated_size() + hash_keys.estimated_size();
             local.morsels.push((morsel.seq(), payload, hash_keys));
+
+            if local.morsels_size > params.memory_budget_per_local {
+                local.spill(&params.unique_key_schema, spill_dir)?;
+            }
         }
         Ok(())
     }
 
+    fn has_spilled(&self) -> bool {
+        self.local_builders
+            .iter()
+            .any(|l| l.spilled_per_p.iter().any(|files| !files.is_empty()))
+    }
+
+    /// Spills the remaining build morsels so the join can be done out-of-core,
+    /// one partition at a time, after the probe side is spilled as well.
+    fn finalize_spilled(&mut self, params: &EquiJoinParams) -> PolarsResult<ProbeSpillState> {
+        let key_schema = &*params.unique_key_schema;
+        let spill_dir = &self.spill_dir;
+        POOL.install(|| {
+            self.local_builders
+                .par_iter_mut()
+                .with_max_len(1)
+                .try_for_each(|l| l.spill(key_schema, spill_dir))
+        })?;
+
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let mut build_files_per_p = vec![Vec::new(); num_partitions];
+        for l in &mut self.local_builders {
+            for (files, l_files) in build_files_per_p.iter_mut().zip(&mut l.spilled_per_p) {
+                files.append(l_files);
+            }
+        }
+        if config::verbose() {
+            eprintln!("[EquiJoinNode]: build side exceeded memory budget, joining out-of-core");
+        }
+
+        Ok(ProbeSpillState {
+            locals: (0..self.local_builders.len())
+                .map(|_| LocalProbeSpiller {
+                    buffered_per_p: vec![Vec::new(); num_partitions],
+                    buffered_size: 0,
+                    spilled_per_p: vec![Vec::new(); num_partitions],
+                })
+                .collect(),
+            build_files_per_p,
+            sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
+            spill_dir: self.spill_dir.lock().clone().unwrap(),
+        })
+    }
+
     fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
         let track_unmatchable = params.emit_unmatched_build();
         let payload_schema = if params.left_is_build.unwrap() {
@@ -1001,6 +1122,50 @@ impl ProbeState {
         Ok(max_seq)
     }
 
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_port: Option<RecvPort<'_>>,
+        send_port: SendPort<'_>,
+        params: &'env EquiJoinParams,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let senders = send_port.parallel();
+        let receivers = self
+            .sampled_probe_morsels
+            .reinsert(state.num_pipelines, recv_port, scope, join_handles)
+            .unwrap();
+
+        let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+        let probe_tasks = receivers
+            .into_iter()
+            .zip(senders)
+            .map(|(recv, send)| {
+                scope.spawn_task(
+                    TaskPriority::High,
+                    ProbeState::partition_and_probe(
+                        recv,
+                        send,
+                        &self.table_per_partition,
+                        &self.unordered_morsel_seq,
+                        partitioner.clone(),
+                        params,
+                        state,
+                    ),
+                )
+            })
+            .collect_vec();
+
+        let max_seq_sent = &mut self.max_seq_sent;
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            for probe_task in probe_tasks {
+                *max_seq_sent = (*max_seq_sent).max(probe_task.await?);
+            }
+            Ok(())
+        }));
+    }
+
     fn ordered_unmatched(&mut self, params: &EquiJoinParams) -> DataFrame {
         // TODO: parallelize this operator.
 
@@ -1154,10 +1319,332 @@ impl EmitUnmatchedState {
     }
 }
 
+struct LocalProbeSpiller {
+    // The partitioned probe morsels not yet spilled, and the files per
+    // partition they were spilled to.
+    buffered_per_p: Vec<Vec<Vec<Column>>>,
+    buffered_size: usize,
+    spilled_per_p: Vec<Vec<PathBuf>>,
+}
+
+impl LocalProbeSpiller {
+    fn flush(&mut self, dir: &SpillDir) -> PolarsResult<()> {
+        for (buffered, files) in self.buffered_per_p.iter_mut().zip(&mut self.spilled_per_p) {
+            if !buffered.is_empty() {
+                files.push(spill_frame(dir, buffered.drain(..))?);
+            }
+        }
+        self.buffered_size = 0;
+        Ok(())
+    }
+}
+
+/// The build side did not fit in memory, so we partition the probe side and
+/// spill it to disk as well.
+struct ProbeSpillState {
+    locals: Vec<LocalProbeSpiller>,
+    build_files_per_p: Vec<Vec<PathBuf>>,
+    sampled_probe_morsels: BufferedStream,
+    spill_dir: Arc<SpillDir>,
+}
+
+impl ProbeSpillState {
+    async fn partition_and_spill(
+        mut recv: Receiver<Morsel>,
+        local: &mut LocalProbeSpiller,
+        partitioner: HashPartitioner,
+        params: &EquiJoinParams,
+        dir: &SpillDir,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let key_selectors = if params.left_is_build.unwrap() {
+            &params.right_key_selectors
+        } else {
+            &params.left_key_selectors
+        };
+        let emit_unmatched = params.emit_unmatched_probe();
+        let mut idxs_per_p = vec![Vec::new(); partitioner.num_partitions()];
+
+        while let Ok(morsel) = recv.recv().await {
+            let mut df = morsel.into_df();
+            if df.height() == 0 {
+                continue;
+            }
+
+            let hash_keys =
+                select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+            df.rechunk_mut();
+            for idxs in &mut idxs_per_p {
+                idxs.clear();
+            }
+            hash_keys.gen_idxs_per_partition(
+                &partitioner,
+                &mut idxs_per_p,
+                &mut [],
+                emit_unmatched,
+            );
+
+            for (idxs, buffered) in idxs_per_p.iter().zip(&mut local.buffered_per_p) {
+                if !idxs.is_empty() {
+                    let part = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+                    local.buffered_size += part.estimated_size();
+                    buffered.push(part.take_columns());
+                }
+            }
+
+            if local.buffered_size > params.memory_budget_per_local {
+                local.flush(dir)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(
+        &mut self,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<SpilledJoinState> {
+        // Spill any sampled probe morsels which haven't been processed yet.
+        if !self.sampled_probe_morsels.is_empty() {
+            let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+            let dir = &*self.spill_dir;
+            crate::async_executor::task_scope(|scope| {
+                let mut join_handles = Vec::new();
+                let receivers = self
+                    .sampled_probe_morsels
+                    .reinsert(state.num_pipelines, None, scope, &mut join_handles)
+                    .unwrap();
+
+                for (local, recv) in self.locals.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        ProbeSpillState::partition_and_spill(
+                            recv,
+                            local,
+                            partitioner.clone(),
+                            params,
+                            dir,
+                            state,
+                        ),
+                    ));
+                }
+
+                polars_io::pl_async::get_runtime().block_on(async move {
+                    for handle in join_handles {
+                        handle.await?;
+                    }
+                    PolarsResult::Ok(())
+                })
+            })?;
+        }
+
+        let dir = &*self.spill_dir;
+        POOL.install(|| {
+            self.locals
+                .par_iter_mut()
+                .with_max_len(1)
+                .try_for_each(|l| l.flush(dir))
+        })?;
+
+        let num_partitions = self.build_files_per_p.len();
+        let mut probe_files_per_p = vec![Vec::new(); num_partitions];
+        for l in &mut self.locals {
+            for (files, l_files) in probe_files_per_p.iter_mut().zip(&mut l.spilled_per_p) {
+                files.append(l_files);
+            }
+        }
+
+        Ok(SpilledJoinState {
+            build_files_per_p: core::mem::take(&mut self.build_files_per_p),
+            probe_files_per_p,
+            active_partition_idx: 0,
+            probe_state: None,
+            emit_state: None,
+            morsel_seq: MorselSeq::default(),
+            _spill_dir: self.spill_dir.clone(),
+        })
+    }
+}
+
+/// Joins the spilled build and probe sides one partition at a time.
+struct SpilledJoinState {
+    build_files_per_p: Vec<Vec<PathBuf>>,
+    probe_files_per_p: Vec<Vec<PathBuf>>,
+    active_partition_idx: usize,
+    // Probes the active partition, one spilled probe file at a time.
+    probe_state: Option<ProbeState>,
+    // Emits the unmatched build rows of the active partition.
+    emit_state: Option<EmitUnmatchedState>,
+    morsel_seq: MorselSeq,
+    _spill_dir: Arc<SpillDir>,
+}
+
+impl SpilledJoinState {
+    /// Moves on to the next unit of work if the current one is done. Returns
+    /// false once all partitions are joined.
+    fn advance(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+        num_pipelines: usize,
+    ) -> PolarsResult<bool> {
+        loop {
+            if let Some(emit_state) = &self.emit_state {
+                if emit_state.active_partition_idx < emit_state.partitions.len() {
+                    return Ok(true);
+                }
+                self.morsel_seq = emit_state.morsel_seq;
+                self.emit_state = None;
+                self.active_partition_idx += 1;
+                continue;
+            }
+
+            let p = self.active_partition_idx;
+            if let Some(probe_state) = &mut self.probe_state {
+                if !probe_state.sampled_probe_morsels.is_empty() {
+                    return Ok(true);
+                }
+
+                // The unordered morsel sequence counts in steps of one.
+                self.morsel_seq =
+                    MorselSeq::new(probe_state.unordered_morsel_seq.load(Ordering::Relaxed));
+                if let Some(path) = self.probe_files_per_p[p].pop() {
+                    let morsels = load_spilled_probe_morsels(&path, params, num_pipelines)?;
+                    probe_state.sampled_probe_morsels =
+                        BufferedStream::new(morsels, MorselSeq::default());
+                    continue;
+                }
+
+                let mut tables = core::mem::take(&mut probe_state.table_per_partition);
+                self.probe_state = None;
+                if params.emit_unmatched_build() {
+                    self.emit_state = Some(EmitUnmatchedState {
+                        partitions: vec![tables.swap_remove(p)],
+                        active_partition_idx: 0,
+                        offset_in_active_p: 0,
+                        morsel_seq: self.morsel_seq,
+                    });
+                } else {
+                    self.active_partition_idx += 1;
+                }
+                continue;
+            }
+
+            if p >= self.build_files_per_p.len() {
+                return Ok(false);
+            }
+            if self.probe_files_per_p[p].is_empty() && !params.emit_unmatched_build() {
+                // Nothing to join for this partition.
+                self.active_partition_idx += 1;
+                continue;
+            }
+
+            self.probe_state = Some(ProbeState {
+                table_per_partition: self.load_probe_tables(p, params, table)?,
+                max_seq_sent: MorselSeq::default(),
+                sampled_probe_morsels: BufferedStream::default(),
+                unordered_morsel_seq: AtomicU64::new(self.morsel_seq.to_u64() / 2),
+            });
+        }
+    }
+
+    /// Builds the probe table for partition p from its spilled build files,
+    /// the tables of the other partitions are left empty.
+    fn load_probe_tables(
+        &mut self,
+        p: usize,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<Vec<ProbeTable>> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let payload_schema = if params.left_is_build.unwrap() {
+            &params.left_payload_schema
+        } else {
+            &params.right_payload_schema
+        };
+        let key_schema = &params.unique_key_schema;
+
+        let mut p_table = table.new_empty();
+        let mut p_payload = DataFrameBuilder::new(payload_schema.clone());
+        let mut subset = Vec::new();
+        for path in self.build_files_per_p[p].drain(..) {
+            let mut columns = read_spilled_frame(&path)?;
+            let height = columns[0].len();
+            let payload = columns.split_off(key_schema.len());
+            let keys = rename_columns(columns, key_schema);
+            let payload = rename_columns(payload, payload_schema);
+            let keys = hash_keys(&DataFrame::new_with_height(height, keys)?, params);
+            subset.clear();
+            subset.extend(0..height as IdxSize);
+            unsafe { p_table.insert_keys_subset(&keys, &subset, track_unmatchable) };
+            p_payload.subslice_extend(
+                &DataFrame::new_with_height(height, payload)?,
+                0,
+                height,
+                ShareStrategy::Never,
+            );
+        }
+
+        let mut tables = (0..self.build_files_per_p.len())
+            .map(|_| ProbeTable {
+                hash_table: table.new_empty(),
+                payload: DataFrame::empty_with_schema(payload_schema),
+                seq_ids: Vec::new(),
+            })
+            .collect_vec();
+        tables[p] = ProbeTable {
+            hash_table: p_table,
+            payload: p_payload.freeze(),
+            seq_ids: Vec::new(),
+        };
+        Ok(tables)
+    }
+}
+
+fn rename_columns(columns: Vec<Column>, schema: &Schema) -> Vec<Column> {
+    columns
+        .into_iter()
+        .zip(schema.iter_names())
+        .map(|(c, name)| c.with_name(name.clone()))
+        .collect()
+}
+
+/// Loads a spilled file of probe morsels, split into morsels of the ideal size.
+fn load_spilled_probe_morsels(
+    path: &std::path::Path,
+    params: &EquiJoinParams,
+    num_pipelines: usize,
+) -> PolarsResult<Vec<Morsel>> {
+    let probe_schema = if params.left_is_build.unwrap() {
+        &params.right_input_schema
+    } else {
+        &params.left_input_schema
+    };
+    let columns = read_spilled_frame(path)?;
+    let height = columns[0].len();
+    let df = DataFrame::new_with_height(height, rename_columns(columns, probe_schema))?;
+
+    let morsel_size = get_ideal_morsel_size()
+        .min(height.div_ceil(num_pipelines))
+        .max(1);
+    Ok((0..height)
+        .step_by(morsel_size)
+        .map(|offset| {
+            Morsel::new(
+                df.slice(offset as i64, morsel_size),
+                MorselSeq::default(),
+                SourceToken::new(),
+            )
+        })
+        .collect())
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
     Probe(ProbeState),
+    ProbeSpill(ProbeSpillState),
+    SpilledJoin(SpilledJoinState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
     Done,
@@ -1230,10 +1717,20 @@ impl EquiJoinNode {
         let left_payload_schema = Arc::new(select_schema(&left_input_schema, &left_payload_select));
         let right_payload_schema =
             Arc::new(select_schema(&right_input_schema, &right_payload_select));
+
+        // Only unordered joins can be done out-of-core, one partition at a time.
+        let memory_budget_per_local =
+            if CAN_SPILL_FRAMES && args.maintain_order == MaintainOrderJoin::None {
+                get_memory_budget("POLARS_JOIN_MEMORY_BUDGET")? / num_pipelines
+            } else {
+                usize::MAX
+            };
         Ok(Self {
             state,
             params: EquiJoinParams {
                 left_is_build,
+                left_input_schema,
+                right_input_schema,
                 preserve_order_build,
                 preserve_order_probe,
                 left_key_schema,
@@ -1244,8 +1741,10 @@ impl EquiJoinNode {
                 right_payload_select,
                 left_payload_schema,
                 right_payload_schema,
+                unique_key_schema: unique_key_schema.clone(),
                 args,
                 random_state: PlRandomState::default(),
+                memory_budget_per_local,
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1289,12 +1788,32 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
-                let probe_state = if self.params.preserve_order_build {
-                    build_state.finalize_ordered(&self.params, &*self.table)
+                if build_state.has_spilled() {
+                    let spill_state = build_state.finalize_spilled(&self.params)?;
+                    self.state = EquiJoinState::ProbeSpill(spill_state);
                 } else {
-                    build_state.finalize_unordered(&self.params, &*self.table)
-                };
-                self.state = EquiJoinState::Probe(probe_state);
+                    let probe_state = if self.params.preserve_order_build {
+                        build_state.finalize_ordered(&self.params, &*self.table)
+                    } else {
+                        build_state.finalize_unordered(&self.params, &*self.table)
+                    };
+                    self.state = EquiJoinState::Probe(probe_state);
+                }
+            }
+        }
+
+        // If we are spilling the probe side and it is done, start joining the
+        // spilled partitions.
+        if let EquiJoinState::ProbeSpill(spill_state) = &mut self.state {
+            if recv[probe_idx] == PortState::Done {
+                let join_state = spill_state.finalize(&self.params, state)?;
+                self.state = EquiJoinState::SpilledJoin(join_state);
+            }
+        }
+
+        if let EquiJoinState::SpilledJoin(join_state) = &mut self.state {
+            if !join_state.advance(&self.params, &*self.table, state.num_pipelines)? {
+                self.state = EquiJoinState::Done;
             }
         }
 
@@ -1372,7 +1891,14 @@ impl ComputeNode for EquiJoinNode {
                 }
                 recv[build_idx] = PortState::Done;
             },
-            EquiJoinState::EmitUnmatchedBuild(_) => {
+            EquiJoinState::ProbeSpill(_) => {
+                send[0] = PortState::Blocked;
+                recv[build_idx] = PortState::Done;
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Ready;
+                }
+            },
+            EquiJoinState::SpilledJoin(_) | EquiJoinState::EmitUnmatchedBuild(_) => {
                 send[0] = PortState::Ready;
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
@@ -1397,7 +1923,9 @@ impl ComputeNode for EquiJoinNode {
     fn is_memory_intensive_pipeline_blocker(&self) -> bool {
         matches!(
             self.state,
-            EquiJoinState::Sample { .. } | EquiJoinState::Build { .. }
+            EquiJoinState::Sample { .. }
+                | EquiJoinState::Build { .. }
+                | EquiJoinState::ProbeSpill(_)
         )
     }
 
@@ -1472,6 +2000,7 @@ impl ComputeNode for EquiJoinNode {
                             local_builder,
                             partitioner.clone(),
                             &self.params,
+                            &build_state.spill_dir,
                             state,
                         ),
                     ));
@@ -1479,8 +2008,19 @@ impl ComputeNode for EquiJoinNode {
             },
             EquiJoinState::Probe(probe_state) => {
                 assert!(recv_ports[build_idx].is_none());
-                let senders = send_ports[0].take().unwrap().parallel();
-                let receivers = probe_state
+                probe_state.spawn(
+                    scope,
+                    recv_ports[probe_idx].take(),
+                    send_ports[0].take().unwrap(),
+                    &self.params,
+                    state,
+                    join_handles,
+                );
+            },
+            EquiJoinState::ProbeSpill(spill_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[build_idx].is_none());
+                let receivers = spill_state
                     .sampled_probe_morsels
                     .reinsert(
                         state.num_pipelines,
@@ -1491,32 +2031,40 @@ impl ComputeNode for EquiJoinNode {
                     .unwrap();
 
                 let partitioner = HashPartitioner::new(state.num_pipelines, 0);
-                let probe_tasks = receivers
-                    .into_iter()
-                    .zip(senders)
-                    .map(|(recv, send)| {
-                        scope.spawn_task(
-                            TaskPriority::High,
-                            ProbeState::partition_and_probe(
-                                recv,
-                                send,
-                                &probe_state.table_per_partition,
-                                &probe_state.unordered_morsel_seq,
-                                partitioner.clone(),
-                                &self.params,
-                                state,
-                            ),
-                        )
-                    })
-                    .collect_vec();
-
-                let max_seq_sent = &mut probe_state.max_seq_sent;
-                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
-                    for probe_task in probe_tasks {
-                        *max_seq_sent = (*max_seq_sent).max(probe_task.await?);
-                    }
-                    Ok(())
-                }));
+                let dir = &*spill_state.spill_dir;
+                for (local, recv) in spill_state.locals.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        ProbeSpillState::partition_and_spill(
+                            recv,
+                            local,
+                            partitioner.clone(),
+                            &self.params,
+                            dir,
+                            state,
+                        ),
+                    ));
+                }
+            },
+            EquiJoinState::SpilledJoin(join_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                if let Some(emit_state) = &mut join_state.emit_state {
+                    let send = send_ports[0].take().unwrap().serial();
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::Low,
+                        emit_state.emit_unmatched(send, &self.params, state.num_pipelines),
+                    ));
+                } else {
+                    join_state.probe_state.as_mut().unwrap().spawn(
+                        scope,
+                        None,
+                        send_ports[0].take().unwrap(),
+                        &self.params,
+                        state,
+                        join_handles,
+                    );
+                }
             },
             EquiJoinState::EmitUnmatchedBuild(emit_state) => {
                 assert!(recv_ports[build_idx].is_none());
@@ -0,0 +1,518 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::{POOL, config};
+use polars_ops::frame::{IEJoinOptions, InequalityOperator, JoinArgs, iejoin};
+use polars_utils::itertools::Itertools;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use crate::async_primitives::connector::{Receiver, Sender};
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+use crate::utils::spill::{
+    CAN_SPILL_FRAMES, SpillDir, get_memory_budget, load_spilled_frame, spill_frame,
+};
+
+struct IEJoinParams {
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    right_input_schema: Arc<Schema>,
+    options: IEJoinOptions,
+    suffix: Option<PlSmallStr>,
+    // The build morsels of each local builder are spilled once their
+    // estimated size exceeds this budget.
+    memory_budget_per_local: usize,
+}
+
+impl IEJoinParams {
+    fn operators(&self) -> impl Iterator<Item = InequalityOperator> {
+        std::iter::once(self.options.operator1).chain(self.options.operator2)
+    }
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    state: &ExecutionState,
+) -> PolarsResult<DataFrame> {
+    let mut key_columns = Vec::new();
+    for selector in key_selectors {
+        key_columns.push(selector.evaluate(df, state).await?.into_column());
+    }
+    DataFrame::new_with_broadcast_len(key_columns, df.height())
+}
+
+fn key_series(keys: &DataFrame) -> Vec<Series> {
+    keys.get_columns()
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect()
+}
+
+/// The (physical) bounds of the non-null values of each key, or `None` if a
+/// key has no non-null values, in which case no row can match.
+type KeyBounds = Vec<(AnyValue<'static>, AnyValue<'static>)>;
+
+fn key_bounds(keys: &DataFrame) -> PolarsResult<Option<KeyBounds>> {
+    let mut bounds = Vec::with_capacity(keys.width());
+    for key in keys.get_columns() {
+        let key = key.to_physical_repr();
+        let min = key.min_reduce()?.into_value();
+        if min.is_null() {
+            return Ok(None);
+        }
+        bounds.push((min, key.max_reduce()?.into_value()));
+    }
+    Ok(Some(bounds))
+}
+
+/// Whether any row within the left bounds can be joined with any row within
+/// the right bounds.
+fn may_match(left: &KeyBounds, right: &KeyBounds, params: &IEJoinParams) -> bool {
+    params
+        .operators()
+        .zip(left.iter().zip(right))
+        .all(|(op, ((l_min, l_max), (r_min, r_max)))| match op {
+            InequalityOperator::Lt => l_min < r_max,
+            InequalityOperator::LtEq => l_min <= r_max,
+            InequalityOperator::Gt => l_max > r_min,
+            InequalityOperator::GtEq => l_max >= r_min,
+        })
+}
+
+/// A range of the build side, sorted by its first key, which is either kept
+/// in memory or spilled to disk.
+struct BuildChunk {
+    bounds: KeyBounds,
+    data: BuildChunkData,
+}
+
+enum BuildChunkData {
+    InMemory { payload: DataFrame, keys: DataFrame },
+    // The key columns followed by the payload, named by position.
+    Spilled { path: PathBuf, height: usize },
+}
+
+impl BuildChunk {
+    /// Returns the payload and keys of this chunk, the chunk with index `idx`.
+    /// Spilled chunks are read back from disk, unless they are still cached.
+    fn load(
+        &self,
+        idx: usize,
+        cache: &mut LoadedChunks,
+        params: &IEJoinParams,
+    ) -> PolarsResult<(Cow<'_, DataFrame>, Vec<Series>)> {
+        match &self.data {
+            BuildChunkData::InMemory { payload, keys } => {
+                Ok((Cow::Borrowed(payload), key_series(keys)))
+            },
+            BuildChunkData::Spilled { path, height } => {
+                if let Some((payload, keys)) = cache.get(idx) {
+                    return Ok((Cow::Owned(payload), keys));
+                }
+
+                let mut columns = load_spilled_frame(path)?;
+                let payload = columns
+                    .split_off(params.right_key_selectors.len())
+                    .into_iter()
+                    .zip(params.right_input_schema.iter_names())
+                    .map(|(c, name)| c.with_name(name.clone()))
+                    .collect();
+                let keys: Vec<Series> = columns
+                    .into_iter()
+                    .map(Column::take_materialized_series)
+                    .collect();
+                let payload = unsafe { DataFrame::new_no_checks(*height, payload) };
+                cache.insert(idx, payload.clone(), keys.clone());
+                Ok((Cow::Owned(payload), keys))
+            },
+        }
+    }
+}
+
+/// The spilled chunks a probe task loaded most recently, kept within the
+/// memory budget so that consecutive morsels matching the same chunks don't
+/// read them from disk again.
+struct LoadedChunks {
+    // The index, payload, keys and estimated size of each chunk, least
+    // recently used first.
+    chunks: VecDeque<(usize, DataFrame, Vec<Series>, usize)>,
+    size: usize,
+    budget: usize,
+}
+
+impl LoadedChunks {
+    fn new(budget: usize) -> Self {
+        Self {
+            chunks: VecDeque::new(),
+            size: 0,
+            budget,
+        }
+    }
+
+    fn get(&mut self, idx: usize) -> Option<(DataFrame, Vec<Series>)> {
+        let pos = self.chunks.iter().position(|c| c.0 == idx)?;
+        let chunk = self.chunks.remove(pos).unwrap();
+        let out = (chunk.1.clone(), chunk.2.clone());
+        self.chunks.push_back(chunk);
+        Some(out)
+    }
+
+    fn insert(&mut self, idx: usize, payload: DataFrame, keys: Vec<Series>) {
+        let size =
+            payload.estimated_size() + keys.iter().map(|k| k.estimated_size()).sum::<usize>();
+        self.size += size;
+        self.chunks.push_back((idx, payload, keys, size));
+        while self.size > self.budget {
+            let (_, _, _, size) = self.chunks.pop_front().unwrap();
+            self.size -= size;
+        }
+    }
+}
+
+#[derive(Default)]
+struct LocalBuilder {
+    payloads: Vec<DataFrame>,
+    keys: Vec<DataFrame>,
+    size: usize,
+    chunks: Vec<BuildChunk>,
+}
+
+impl LocalBuilder {
+    /// Sorts the buffered morsels by their first key and splits them into
+    /// chunks, spilling the chunks to `dir` if given.
+    fn flush(&mut self, dir: Option<&SpillDir>) -> PolarsResult<()> {
+        if self.payloads.is_empty() {
+            return Ok(());
+        }
+
+        let payload = accumulate_dataframes_vertical_unchecked(self.payloads.drain(..));
+        let keys = accumulate_dataframes_vertical_unchecked(self.keys.drain(..));
+        self.size = 0;
+
+        let sort_idx = keys[0].arg_sort(SortOptions::default().with_multithreaded(false));
+        let (payload, keys) = unsafe {
+            (
+                payload.take_unchecked(&sort_idx),
+                keys.take_unchecked(&sort_idx),
+            )
+        };
+
+        let chunk_size = get_ideal_morsel_size();
+        for offset in (0..payload.height()).step_by(chunk_size) {
+            let chunk_payload = payload.slice(offset as i64, chunk_size);
+            let chunk_keys = keys.slice(offset as i64, chunk_size);
+            let bounds = key_bounds(&chunk_keys)?.unwrap();
+            let data = match dir {
+                None => BuildChunkData::InMemory {
+                    payload: chunk_payload,
+                    keys: chunk_keys,
+                },
+                Some(dir) => {
+                    let height = chunk_payload.height();
+                    let mut columns = chunk_keys.take_columns();
+                    columns.extend(chunk_payload.take_columns());
+                    BuildChunkData::Spilled {
+                        path: spill_frame(dir, [columns])?,
+                        height,
+                    }
+                },
+            };
+            self.chunks.push(BuildChunk { bounds, data });
+        }
+        Ok(())
+    }
+}
+
+struct BuildState {
+    local_builders: Vec<LocalBuilder>,
+    spill_dir: Mutex<Option<Arc<SpillDir>>>,
+}
+
+impl BuildState {
+    async fn sink(
+        mut recv: Receiver<Morsel>,
+        local: &mut LocalBuilder,
+        spill_dir: &Mutex<Option<Arc<SpillDir>>>,
+        params: &IEJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            let mut df = morsel.into_df();
+            let mut keys = select_keys(
+                &df,
+                &params.right_key_selectors,
+                &state.in_memory_exec_state,
+            )
+            .await?;
+
+            // Rows with a null key never match, so we don't have to keep them.
+            if keys.get_columns().iter().any(|k| k.has_nulls()) {
+                let mut mask = keys[0].is_not_null();
+                for key in &keys.get_columns()[1..] {
+                    mask = &mask & &key.is_not_null();
+                }
+                df = df.filter(&mask)?;
+                keys = keys.filter(&mask)?;
+            }
+            if df.height() == 0 {
+                continue;
+            }
+
+            local.size += df.estimated_size() + keys.estimated_size();
+            local.payloads.push(df);
+            local.keys.push(keys);
+            if local.size > params.memory_budget_per_local {
+                let dir = SpillDir::get_or_create(spill_dir, "IEJoinNode")?;
+                local.flush(Some(&dir))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> PolarsResult<ProbeState> {
+        POOL.install(|| {
+            self.local_builders
+                .par_iter_mut()
+                .try_for_each(|l| l.flush(None))
+        })?;
+        let chunks = self
+            .local_builders
+            .iter_mut()
+            .flat_map(|l| l.chunks.drain(..))
+            .collect_vec();
+
+        let spill_dir = self.spill_dir.lock().take();
+        if spill_dir.is_some() && config::verbose() {
+            eprintln!("[IEJoinNode]: build side exceeded memory budget, joining out-of-core");
+        }
+        Ok(ProbeState {
+            chunks,
+            _spill_dir: spill_dir,
+        })
+    }
+}
+
+struct ProbeState {
+    chunks: Vec<BuildChunk>,
+    // Removes the spilled chunks once we're done.
+    _spill_dir: Option<Arc<SpillDir>>,
+}
+
+impl ProbeState {
+    async fn probe(
+        mut recv: Receiver<Morsel>,
+        mut send: Sender<Morsel>,
+        chunks: &[BuildChunk],
+        params: &IEJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let mut loaded = LoadedChunks::new(params.memory_budget_per_local);
+        while let Ok(morsel) = recv.recv().await {
+            let (df, seq, source_token, consume_token) = morsel.into_inner();
+            if df.height() == 0 {
+                continue;
+            }
+
+            let keys =
+                select_keys(&df, &params.left_key_selectors, &state.in_memory_exec_state).await?;
+            let Some(bounds) = key_bounds(&keys)? else {
+                continue;
+            };
+            let keys = key_series(&keys);
+
+            for (idx, chunk) in chunks.iter().enumerate() {
+                if !may_match(&bounds, &chunk.bounds, params) {
+                    continue;
+                }
+
+                let (build_payload, build_keys) = chunk.load(idx, &mut loaded, params)?;
+                let out = iejoin(
+                    &df,
+                    &build_payload,
+                    keys.clone(),
+                    build_keys,
+                    &params.options,
+                    params.suffix.clone(),
+                    None,
+                )?;
+                if out.height() == 0 {
+                    continue;
+                }
+
+                if send
+                    .send(Morsel::new(out, seq, source_token.clone()))
+                    .await
+                    .is_err()
+                {
+                    return Ok(());
+                }
+            }
+            drop(consume_token);
+        }
+        Ok(())
+    }
+}
+
+enum IEJoinState {
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+/// An inequality join which buffers the right input in chunks sorted by the
+/// first key, spilling them to disk if they exceed the memory budget, and
+/// joins each morsel of the left input with the chunks whose key ranges it
+/// can match.
+pub struct IEJoinNode {
+    state: IEJoinState,
+    params: IEJoinParams,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        args: &JoinArgs,
+        options: IEJoinOptions,
+        num_pipelines: usize,
+    ) -> PolarsResult<Self> {
+        let memory_budget_per_local = if CAN_SPILL_FRAMES {
+            get_memory_budget("POLARS_JOIN_MEMORY_BUDGET")? / num_pipelines
+        } else {
+            usize::MAX
+        };
+        let local_builders = (0..num_pipelines)
+            .map(|_| LocalBuilder::default())
+            .collect();
+
+        Ok(Self {
+            state: IEJoinState::Build(BuildState {
+                local_builders,
+                spill_dir: Mutex::new(None),
+            }),
+            params: IEJoinParams {
+                left_key_selectors,
+                right_key_selectors,
+                right_input_schema,
+                options,
+                suffix: args.suffix.clone(),
+                memory_budget_per_local,
+            },
+        })
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "ie-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // If we are building and the build input is done, transition to probing.
+        if let IEJoinState::Build(build_state) = &mut self.state {
+            if recv[1] == PortState::Done {
+                let probe_state = build_state.finalize()?;
+                self.state = if probe_state.chunks.is_empty() {
+                    IEJoinState::Done
+                } else {
+                    IEJoinState::Probe(probe_state)
+                };
+            }
+        }
+
+        // If we are probing and the probe input is done, we're done.
+        if let IEJoinState::Probe(_) = &self.state {
+            if recv[0] == PortState::Done {
+                self.state = IEJoinState::Done;
+            }
+        }
+
+        match &self.state {
+            IEJoinState::Build(_) => {
+                send[0] = PortState::Blocked;
+                if recv[1] != PortState::Done {
+                    recv[1] = PortState::Ready;
+                }
+                if recv[0] != PortState::Done {
+                    recv[0] = PortState::Blocked;
+                }
+            },
+            IEJoinState::Probe(_) => {
+                core::mem::swap(&mut send[0], &mut recv[0]);
+                recv[1] = PortState::Done;
+            },
+            IEJoinState::Done => {
+                send[0] = PortState::Done;
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, IEJoinState::Build(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        match &mut self.state {
+            IEJoinState::Build(build_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[0].is_none());
+                let receivers = recv_ports[1].take().unwrap().parallel();
+
+                let spill_dir = &build_state.spill_dir;
+                for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        BuildState::sink(recv, local_builder, spill_dir, &self.params, state),
+                    ));
+                }
+            },
+            IEJoinState::Probe(probe_state) => {
+                assert!(recv_ports[1].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let senders = send_ports[0].take().unwrap().parallel();
+
+                for (recv, send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        ProbeState::probe(recv, send, &probe_state.chunks, &self.params, state),
+                    ));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
@@ -12,8 +12,12 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::RecvPort;
 
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
 pub mod cross_join;
 pub mod equi_join;
+#[cfg(feature = "iejoin")]
+pub mod ie_join;
 pub mod in_memory;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
@@ -1,3 +1,5 @@
+#[cfg(feature = "dynamic_group_by")]
+pub mod dynamic_group_by;
 pub mod dynamic_slice;
 pub mod filter;
 pub mod group_by;
@@ -20,6 +22,7 @@ pub mod rle;
 pub mod rle_id;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
 pub mod streaming_slice;
 pub mod top_k;
 pub mod with_row_index;
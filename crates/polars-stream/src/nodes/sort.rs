@@ -0,0 +1,696 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use arrow::array::{BinaryViewArray, MutableBinaryViewArray};
+use parking_lot::Mutex;
+use polars_core::config;
+use polars_core::prelude::row_encode::{
+    _get_rows_encoded, _get_rows_encoded_unordered, get_row_encoding_context,
+};
+use polars_core::prelude::*;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_ops::frame::_merge_sorted_dfs;
+use polars_row::{RowEncodingContext, RowEncodingOptions};
+use polars_utils::itertools::Itertools;
+
+use super::compute_node_prelude::*;
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::spill::{SpillDir, get_memory_budget};
+
+/// The amount of memory (in bytes) the sort node may buffer in total before
+/// it starts spilling sorted runs to disk.
+pub fn get_sort_memory_budget() -> PolarsResult<usize> {
+    get_memory_budget("POLARS_SORT_MEMORY_BUDGET")
+}
+
+/// A sorted run consisting of a row-encoded sort key column (with a
+/// tiebreaker on the input order appended) and a row-encoded payload column.
+enum SortedRun {
+    InMemory(DataFrame),
+    Spilled {
+        path: PathBuf,
+        num_blocks: usize,
+        // Keeps the directory alive until this run is consumed.
+        _dir: Arc<SpillDir>,
+    },
+}
+
+impl SortedRun {
+    fn spill(run: DataFrame, dir: Arc<SpillDir>) -> PolarsResult<Self> {
+        let path = dir.new_file_path("bin");
+        let mut writer = BufWriter::new(File::create(&path)?);
+        let blocks = split_into_blocks(run);
+        for block in &blocks {
+            write_block(&mut writer, block)?;
+        }
+        writer.flush()?;
+        Ok(Self::Spilled {
+            path,
+            num_blocks: blocks.len(),
+            _dir: dir,
+        })
+    }
+
+    fn into_cursor(self) -> PolarsResult<RunCursor> {
+        let source = match self {
+            Self::InMemory(df) => RunSource::InMemory(split_into_blocks(df).into()),
+            Self::Spilled {
+                path,
+                num_blocks,
+                _dir,
+            } => RunSource::Spilled {
+                reader: BufReader::new(File::open(&path)?),
+                blocks_left: num_blocks,
+                _dir,
+            },
+        };
+        Ok(RunCursor {
+            current: DataFrame::empty(),
+            source,
+        })
+    }
+}
+
+fn split_into_blocks(df: DataFrame) -> Vec<DataFrame> {
+    let block_size = get_ideal_morsel_size().max(1);
+    (0..df.height())
+        .step_by(block_size)
+        .map(|offset| {
+            let mut block = df.slice(offset as i64, block_size);
+            block.as_single_chunk();
+            block
+        })
+        .collect()
+}
+
+fn key_array(run: &DataFrame) -> &BinaryViewArray {
+    run[0].binary().unwrap().downcast_as_array()
+}
+
+/// Writes a block of a sorted run as its row count and byte length, followed
+/// by the length-prefixed key and payload of every row.
+fn write_block(writer: &mut impl Write, block: &DataFrame) -> PolarsResult<()> {
+    let keys = key_array(block);
+    let payloads = block[1].binary().unwrap().downcast_as_array();
+
+    let mut body = Vec::with_capacity(keys.total_bytes_len() + payloads.total_bytes_len());
+    for (key, payload) in keys.values_iter().zip(payloads.values_iter()) {
+        body.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        body.extend_from_slice(key);
+        body.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        body.extend_from_slice(payload);
+    }
+
+    writer.write_all(&(block.height() as u64).to_le_bytes())?;
+    writer.write_all(&(body.len() as u64).to_le_bytes())?;
+    writer.write_all(&body)?;
+    Ok(())
+}
+
+fn read_block(reader: &mut impl Read) -> PolarsResult<DataFrame> {
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header)?;
+    let num_rows = u64::from_le_bytes(header[..8].try_into().unwrap()) as usize;
+    let body_len = u64::from_le_bytes(header[8..].try_into().unwrap()) as usize;
+    let mut body = vec![0u8; body_len];
+    reader.read_exact(&mut body)?;
+
+    let mut keys = MutableBinaryViewArray::<[u8]>::with_capacity(num_rows);
+    let mut payloads = MutableBinaryViewArray::<[u8]>::with_capacity(num_rows);
+    let mut rest = body.as_slice();
+    for _ in 0..num_rows {
+        keys.push_value(take_length_prefixed(&mut rest));
+        payloads.push_value(take_length_prefixed(&mut rest));
+    }
+
+    Ok(run_frame(keys, payloads))
+}
+
+fn take_length_prefixed<'a>(rest: &mut &'a [u8]) -> &'a [u8] {
+    let (len, tail) = rest.split_at(size_of::<u32>());
+    let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+    let (value, tail) = tail.split_at(len);
+    *rest = tail;
+    value
+}
+
+fn run_frame(
+    keys: MutableBinaryViewArray<[u8]>,
+    payloads: MutableBinaryViewArray<[u8]>,
+) -> DataFrame {
+    let height = keys.len();
+    let keys = BinaryChunked::with_chunk(PlSmallStr::from_static("key"), keys.freeze());
+    let payloads = BinaryChunked::with_chunk(PlSmallStr::from_static("payload"), payloads.freeze());
+    unsafe { DataFrame::new_no_checks(height, vec![keys.into_column(), payloads.into_column()]) }
+}
+
+enum RunSource {
+    InMemory(VecDeque<DataFrame>),
+    Spilled {
+        reader: BufReader<File>,
+        blocks_left: usize,
+        _dir: Arc<SpillDir>,
+    },
+}
+
+/// The not yet merged remainder of a sorted run.
+struct RunCursor {
+    current: DataFrame,
+    source: RunSource,
+}
+
+impl RunCursor {
+    /// Makes sure the current block is non-empty, returns false if the run is
+    /// exhausted.
+    fn load(&mut self) -> PolarsResult<bool> {
+        while self.current.height() == 0 {
+            self.current = match &mut self.source {
+                RunSource::InMemory(blocks) => match blocks.pop_front() {
+                    Some(block) => block,
+                    None => return Ok(false),
+                },
+                RunSource::Spilled {
+                    reader,
+                    blocks_left,
+                    ..
+                } => {
+                    if *blocks_left == 0 {
+                        return Ok(false);
+                    }
+                    *blocks_left -= 1;
+                    read_block(reader)?
+                },
+            };
+        }
+        Ok(true)
+    }
+}
+
+/// Find the prefixes of the runs which can be merged without looking at any
+/// more data: everything up to the smallest of the last keys of the currently
+/// loaded blocks.
+///
+/// This returns `None` once all runs are exhausted.
+fn find_mergeable(cursors: &mut Vec<RunCursor>) -> PolarsResult<Option<Vec<DataFrame>>> {
+    let mut i = 0;
+    while i < cursors.len() {
+        if cursors[i].load()? {
+            i += 1;
+        } else {
+            cursors.swap_remove(i);
+        }
+    }
+
+    let Some(cutoff) = cursors
+        .iter()
+        .map(|c| {
+            let keys = key_array(&c.current);
+            keys.value(keys.len() - 1)
+        })
+        .min()
+        .map(<[u8]>::to_vec)
+    else {
+        return Ok(None);
+    };
+
+    let mut mergeable = Vec::with_capacity(cursors.len());
+    for cursor in cursors.iter_mut() {
+        let keys = key_array(&cursor.current);
+        let (mut lo, mut hi) = (0, keys.len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if keys.value(mid) <= cutoff.as_slice() {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        if lo > 0 {
+            let (prefix, rest) = cursor.current.split_at(lo as i64);
+            mergeable.push(prefix);
+            cursor.current = rest;
+        }
+    }
+    Ok(Some(mergeable))
+}
+
+fn merge_runs(mut runs: Vec<DataFrame>) -> PolarsResult<DataFrame> {
+    while runs.len() > 1 {
+        runs = runs
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => _merge_sorted_dfs(
+                    left,
+                    right,
+                    left[0].as_materialized_series(),
+                    right[0].as_materialized_series(),
+                    false,
+                ),
+                [single] => Ok(single.clone()),
+                _ => unreachable!(),
+            })
+            .collect::<PolarsResult<_>>()?;
+    }
+    Ok(runs.pop().unwrap())
+}
+
+/// Everything needed to turn buffered morsels into sorted runs and back.
+struct RowCodec {
+    schema: SchemaRef,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    payload_opts: Vec<RowEncodingOptions>,
+    payload_ctxts: Vec<Option<RowEncodingContext>>,
+    payload_arrow_dtypes: Vec<ArrowDataType>,
+}
+
+impl RowCodec {
+    fn new(schema: SchemaRef, descending: Vec<bool>, nulls_last: Vec<bool>) -> Self {
+        let payload_opts = vec![RowEncodingOptions::new_unsorted(); schema.len()];
+        let payload_ctxts = schema.iter_values().map(get_row_encoding_context).collect();
+        let payload_arrow_dtypes = schema
+            .iter_values()
+            .map(|dt| dt.to_physical().to_arrow(CompatLevel::newest()))
+            .collect();
+        Self {
+            schema,
+            descending,
+            nulls_last,
+            payload_opts,
+            payload_ctxts,
+            payload_arrow_dtypes,
+        }
+    }
+
+    /// Row-encodes the buffered morsels and sorts them into a single run.
+    ///
+    /// The morsel sequence id and the row index within the morsel are appended
+    /// to the encoded sort key, making the order of the run (and of merging
+    /// runs) stable with respect to the input.
+    fn encode_run(&self, morsels: Vec<BufferedMorsel>) -> PolarsResult<DataFrame> {
+        let num_rows = morsels.iter().map(|m| m.df.height()).sum();
+        let mut keys = MutableBinaryViewArray::<[u8]>::with_capacity(num_rows);
+        let mut payloads = MutableBinaryViewArray::<[u8]>::with_capacity(num_rows);
+        let mut key_buf = Vec::new();
+        for morsel in morsels {
+            let key_rows = _get_rows_encoded(&morsel.keys, &self.descending, &self.nulls_last)?;
+            let payload_rows = _get_rows_encoded_unordered(morsel.df.get_columns())?;
+            let seq = morsel.seq.to_u64().to_be_bytes();
+            for (idx, (key, payload)) in key_rows.iter().zip(payload_rows.iter()).enumerate() {
+                key_buf.clear();
+                key_buf.extend_from_slice(key);
+                key_buf.extend_from_slice(&seq);
+                key_buf.extend_from_slice(&(idx as u64).to_be_bytes());
+                keys.push_value(&key_buf);
+                payloads.push_value(payload);
+            }
+        }
+
+        let run = run_frame(keys, payloads);
+        let idx = run[0]
+            .binary()
+            .unwrap()
+            .arg_sort(SortOptions::default().with_multithreaded(false));
+        Ok(unsafe { run.take_unchecked(&idx) })
+    }
+
+    fn decode_payload(&self, run: &DataFrame) -> DataFrame {
+        let payloads = run[1].binary().unwrap();
+        let mut rows: Vec<&[u8]> = payloads.into_no_null_iter().collect();
+        let arrays = unsafe {
+            polars_row::decode::decode_rows(
+                &mut rows,
+                &self.payload_opts,
+                &self.payload_ctxts,
+                &self.payload_arrow_dtypes,
+            )
+        };
+        let columns = self
+            .schema
+            .iter()
+            .zip(arrays)
+            .map(|((name, dtype), array)| unsafe {
+                Series::from_chunks_and_dtype_unchecked(name.clone(), vec![array], dtype)
+                    .into_column()
+            })
+            .collect();
+        unsafe { DataFrame::new_no_checks(run.height(), columns) }
+    }
+}
+
+struct BufferedMorsel {
+    seq: MorselSeq,
+    df: DataFrame,
+    keys: Vec<Column>,
+}
+
+#[derive(Default)]
+struct LocalSortBuffer {
+    morsels: Vec<BufferedMorsel>,
+    estimated_size: usize,
+    num_rows: usize,
+    spilled_runs: Vec<SortedRun>,
+}
+
+impl LocalSortBuffer {
+    fn spill(&mut self, codec: &RowCodec, dir: &Mutex<Option<Arc<SpillDir>>>) -> PolarsResult<()> {
+        let run = codec.encode_run(std::mem::take(&mut self.morsels))?;
+        self.estimated_size = 0;
+
+        let dir = SpillDir::get_or_create(dir, "SortNode")?;
+        self.spilled_runs.push(SortedRun::spill(run, dir)?);
+        Ok(())
+    }
+}
+
+struct MergeState {
+    cursors: Vec<RunCursor>,
+    seq: MorselSeq,
+    // The slice which still needs to be applied to the merged output.
+    to_skip: usize,
+    remaining: usize,
+}
+
+impl MergeState {
+    fn is_done(&self) -> bool {
+        self.cursors.is_empty() || self.remaining == 0
+    }
+}
+
+enum SortState {
+    Sink {
+        buffers: Vec<LocalSortBuffer>,
+        spill_dir: Mutex<Option<Arc<SpillDir>>>,
+    },
+    Merge(MergeState),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// Sorts its input, spilling sorted runs to disk once the buffered data
+/// exceeds the memory budget and k-way merging them afterwards.
+pub struct SortNode {
+    input_schema: SchemaRef,
+    key_selectors: Vec<StreamExpr>,
+    slice: Option<(i64, usize)>,
+    sort_options: SortMultipleOptions,
+    codec: RowCodec,
+    memory_budget: usize,
+    state: SortState,
+}
+
+impl SortNode {
+    pub fn new(
+        input_schema: SchemaRef,
+        key_selectors: Vec<StreamExpr>,
+        slice: Option<(i64, usize)>,
+        mut sort_options: SortMultipleOptions,
+        memory_budget: usize,
+    ) -> Self {
+        let num_keys = key_selectors.len();
+        if sort_options.descending.len() == 1 {
+            sort_options.descending = vec![sort_options.descending[0]; num_keys];
+        }
+        if sort_options.nulls_last.len() == 1 {
+            sort_options.nulls_last = vec![sort_options.nulls_last[0]; num_keys];
+        }
+
+        let codec = RowCodec::new(
+            input_schema.clone(),
+            sort_options.descending.clone(),
+            sort_options.nulls_last.clone(),
+        );
+        Self {
+            input_schema,
+            key_selectors,
+            slice,
+            sort_options,
+            codec,
+            memory_budget,
+            state: SortState::Sink {
+                buffers: Vec::new(),
+                spill_dir: Mutex::new(None),
+            },
+        }
+    }
+
+    fn finalize_sink(&mut self, buffers: Vec<LocalSortBuffer>) -> PolarsResult<SortState> {
+        let num_rows: usize = buffers.iter().map(|b| b.num_rows).sum();
+
+        if buffers.iter().all(|b| b.spilled_runs.is_empty()) {
+            // Everything fits in memory, sort it in one go.
+            let mut morsels = buffers.into_iter().flat_map(|b| b.morsels).collect_vec();
+            morsels.sort_by_key(|m| m.seq);
+
+            let df = if morsels.is_empty() {
+                DataFrame::empty_with_schema(&self.input_schema)
+            } else {
+                let mut keys = morsels[0].keys.clone();
+                for morsel in &morsels[1..] {
+                    for (key, other) in keys.iter_mut().zip(&morsel.keys) {
+                        key.append(other)?;
+                    }
+                }
+                let df =
+                    accumulate_dataframes_vertical_unchecked(morsels.into_iter().map(|m| m.df));
+                df.sort_impl(keys, self.sort_options.clone(), self.slice)?
+            };
+            return Ok(SortState::Source(InMemorySourceNode::new(
+                Arc::new(df),
+                MorselSeq::default(),
+            )));
+        }
+
+        let (to_skip, remaining) = match self.slice {
+            Some((offset, len)) => slice_offsets(offset, len, num_rows),
+            None => (0, num_rows),
+        };
+        if remaining == 0 {
+            return Ok(SortState::Source(InMemorySourceNode::new(
+                Arc::new(DataFrame::empty_with_schema(&self.input_schema)),
+                MorselSeq::default(),
+            )));
+        }
+
+        let mut cursors = Vec::new();
+        for mut buffer in buffers {
+            if !buffer.morsels.is_empty() {
+                let run = self.codec.encode_run(std::mem::take(&mut buffer.morsels))?;
+                cursors.push(SortedRun::InMemory(run).into_cursor()?);
+            }
+            for run in buffer.spilled_runs {
+                cursors.push(run.into_cursor()?);
+            }
+        }
+        if config::verbose() {
+            eprintln!("[SortNode]: merging {} sorted runs", cursors.len());
+        }
+
+        Ok(SortState::Merge(MergeState {
+            cursors,
+            seq: MorselSeq::default(),
+            to_skip,
+            remaining,
+        }))
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if let SortState::Sink { buffers, .. } = &mut self.state {
+            if buffers.is_empty() {
+                buffers.resize_with(state.num_pipelines, Default::default);
+            }
+        }
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to being a source.
+            SortState::Sink { buffers, .. } if recv[0] == PortState::Done => {
+                let buffers = std::mem::take(buffers);
+                self.state = self.finalize_sink(buffers)?;
+            },
+            SortState::Merge(merge) if merge.is_done() => {
+                self.state = SortState::Done;
+            },
+            // Nothing to change.
+            _ => {},
+        }
+
+        // Communicate our state.
+        match &mut self.state {
+            SortState::Sink { .. } => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Merge(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Source(src) => {
+                recv[0] = PortState::Done;
+                src.update_state(&mut [], send, state)?;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let codec = &self.codec;
+        match &mut self.state {
+            SortState::Sink { buffers, spill_dir } => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                let local_budget = self.memory_budget / buffers.len();
+
+                for (mut recv, buffer) in receivers.into_iter().zip(buffers.iter_mut()) {
+                    let key_selectors = &*self.key_selectors;
+                    let spill_dir = &*spill_dir;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok(morsel) = recv.recv().await {
+                            let seq = morsel.seq();
+                            let df = morsel.into_df();
+                            let height = df.height();
+                            let mut keys = Vec::with_capacity(key_selectors.len());
+                            for selector in key_selectors {
+                                let key =
+                                    selector.evaluate(&df, &state.in_memory_exec_state).await?;
+                                keys.push(if key.len() == 1 && height != 1 {
+                                    key.new_from_index(0, height)
+                                } else {
+                                    key
+                                });
+                            }
+
+                            buffer.estimated_size += df.estimated_size();
+                            buffer.num_rows += height;
+                            buffer.morsels.push(BufferedMorsel { seq, df, keys });
+                            if buffer.estimated_size > local_budget {
+                                buffer.spill(codec, spill_dir)?;
+                            }
+                        }
+
+                        Ok(())
+                    }));
+                }
+            },
+
+            SortState::Merge(merge) => {
+                assert!(recv_ports[0].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+                let (mut distributor, dist_recv) =
+                    distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    let source_token = SourceToken::new();
+                    while merge.remaining > 0 {
+                        let Some(mergeable) = find_mergeable(&mut merge.cursors)? else {
+                            break;
+                        };
+
+                        let height: usize = mergeable.iter().map(|df| df.height()).sum();
+                        let skip = merge.to_skip.min(height);
+                        merge.to_skip -= skip;
+                        let len = (height - skip).min(merge.remaining);
+                        merge.remaining -= len;
+                        if len == 0 {
+                            continue;
+                        }
+
+                        let seq = merge.seq;
+                        merge.seq = seq.successor();
+                        let slice = (skip, len);
+                        if distributor
+                            .send((mergeable, slice, seq, source_token.clone()))
+                            .await
+                            .is_err()
+                        {
+                            return Ok(());
+                        }
+
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }));
+
+                // Merging and decoding is expensive, so it is split over several tasks.
+                join_handles.extend(dist_recv.into_iter().zip(senders).map(
+                    |(mut recv, mut send)| {
+                        let ideal_morsel_size = get_ideal_morsel_size().max(1);
+                        scope.spawn_task(TaskPriority::High, async move {
+                            while let Ok((mergeable, (skip, len), seq, source_token)) =
+                                recv.recv().await
+                            {
+                                let merged = merge_runs(mergeable)?.slice(skip as i64, len);
+                                let df = codec.decode_payload(&merged);
+                                for offset in (0..df.height()).step_by(ideal_morsel_size) {
+                                    // MorselSeq have to be monotonely non-decreasing so we can
+                                    // pass the same sequence token several times.
+                                    let morsel = Morsel::new(
+                                        df.slice(offset as i64, ideal_morsel_size),
+                                        seq,
+                                        source_token.clone(),
+                                    );
+                                    if send.send(morsel).await.is_err() {
+                                        return Ok(());
+                                    }
+                                }
+                            }
+
+                            Ok(())
+                        })
+                    },
+                ));
+            },
+
+            SortState::Source(src) => {
+                assert!(recv_ports[0].is_none());
+                src.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -38,6 +38,8 @@ impl NodeStyle {
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "iejoin")]
+            K::IEJoin { .. } => Self::MemoryIntensive,
             _ => Self::Generic,
         }
     }
@@ -261,6 +263,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -285,6 +289,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -436,6 +442,21 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            options,
+            aggs,
+        } => (
+            format!(
+                "dynamic-group-by\\nindex: {}\\nevery: {}\\nperiod: {}\\naggs:\\n{}",
+                options.index_column,
+                options.every,
+                options.period,
+                fmt_exprs_to_label(aggs, expr_arena, FormatExprStyle::Select)
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::InMemoryJoin {
             input_left,
             input_right,
@@ -508,6 +529,51 @@ fn visualize_plan_rec(
             input_right,
             args: _,
         } => ("cross-join".to_string(), &[*input_left, *input_right][..]),
+        #[cfg(feature = "asof_join")]
+        PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args: _,
+        } => {
+            let mut out = "asof-join".to_string();
+            let mut f = EscapeLabel(&mut out);
+
+            write!(f, "\nleft_on: {left_on}\nright_on: {right_on}").unwrap();
+
+            (out, &[*input_left, *input_right][..])
+        },
+        #[cfg(feature = "iejoin")]
+        PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args: _,
+            options,
+        } => {
+            let mut label = "ie-join".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\noperators: {}",
+                escape_graphviz(&format!("{:?}, {:?}", options.operator1, options.operator2))
+            )
+            .unwrap();
+            (label, &[*input_left, *input_right][..])
+        },
         #[cfg(feature = "merge_sorted")]
         PhysNodeKind::MergeSorted {
             input_left,
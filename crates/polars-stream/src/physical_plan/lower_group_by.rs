@@ -394,6 +394,39 @@ fn try_lower_elementwise_scalar_agg_expr(
     }
 }
 
+/// Lowers a `group_by_dynamic` without additional keys to a streaming node, which aggregates
+/// the windows as soon as the index column has passed their upper bound.
+#[cfg(feature = "dynamic_group_by")]
+fn try_build_streaming_dynamic_group_by(
+    input: PhysStream,
+    keys: &[ExprIR],
+    aggs: &[ExprIR],
+    output_schema: Arc<Schema>,
+    options: &GroupbyOptions,
+    apply: Option<&PlanCallback<DataFrame, DataFrame>>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> Option<PhysStream> {
+    let dynamic_options = options.dynamic.as_ref()?;
+    if !keys.is_empty() || apply.is_some() {
+        return None; // TODO
+    }
+
+    let group_by_node = phys_sm.insert(PhysNode::new(
+        output_schema,
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            options: dynamic_options.clone(),
+            aggs: aggs.to_vec(),
+        },
+    ));
+    let out = PhysStream::first(group_by_node);
+    Some(if let Some((offset, len)) = options.slice {
+        build_slice_stream(out, offset, len, phys_sm)
+    } else {
+        out
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn try_build_streaming_group_by(
     input: PhysStream,
@@ -412,7 +445,7 @@ fn try_build_streaming_group_by(
     }
 
     #[cfg(feature = "dynamic_group_by")]
-    if options.dynamic.is_some() || options.rolling.is_some() {
+    if options.dynamic.is_some() || options.rolling.is_some() || options.session.is_some() {
         return None; // TODO
     }
 
@@ -536,6 +569,19 @@ pub fn build_group_by_stream(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> PolarsResult<PhysStream> {
+    #[cfg(feature = "dynamic_group_by")]
+    if let Some(stream) = try_build_streaming_dynamic_group_by(
+        input,
+        keys,
+        aggs,
+        output_schema.clone(),
+        &options,
+        apply.as_ref(),
+        phys_sm,
+    ) {
+        return Ok(stream);
+    }
+
     let streaming = try_build_streaming_group_by(
         input,
         keys,
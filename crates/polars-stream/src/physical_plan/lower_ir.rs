@@ -10,6 +10,8 @@ use polars_error::{PolarsResult, polars_bail};
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_plan::constants::get_literal_name;
+#[cfg(feature = "iejoin")]
+use polars_plan::dsl::JoinTypeOptionsIR;
 use polars_plan::dsl::default_values::DefaultFieldValues;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
@@ -116,6 +118,103 @@ fn build_filter_stream(
     )
 }
 
+/// Whether the output of `node` is known to be sorted ascending (with nulls
+/// first) by the column `key` at planning time.
+#[cfg(feature = "asof_join")]
+fn is_sorted_by_key(
+    mut node: Node,
+    key: &str,
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> bool {
+    use polars_core::series::IsSorted;
+    use polars_plan::plans::IRFunctionExpr;
+
+    let is_key =
+        |e: &ExprIR| matches!(expr_arena.get(e.node()), AExpr::Column(name) if name == key);
+    loop {
+        match ir_arena.get(node) {
+            IR::SimpleProjection { input, .. }
+            | IR::Filter { input, .. }
+            | IR::Slice { input, .. } => node = *input,
+            IR::Sort {
+                by_column,
+                sort_options,
+                ..
+            } => {
+                return by_column.first().is_some_and(is_key)
+                    && sort_options.descending.first() == Some(&false)
+                    && sort_options.nulls_last.first() == Some(&false);
+            },
+            IR::DataFrameScan { df, .. } => {
+                return df
+                    .column(key)
+                    .is_ok_and(|c| c.is_sorted_flag() == IsSorted::Ascending);
+            },
+            IR::Select {
+                input, expr: exprs, ..
+            }
+            | IR::HStack { input, exprs, .. } => {
+                let key_expr = exprs.iter().find(|e| e.output_name() == key);
+                match key_expr.map(|e| expr_arena.get(e.node())) {
+                    // Columns which are not among the expressions of a `HStack`
+                    // pass through unchanged.
+                    None if matches!(ir_arena.get(node), IR::HStack { .. }) => node = *input,
+                    Some(AExpr::Column(name)) if name == key => node = *input,
+                    Some(AExpr::Function {
+                        function: IRFunctionExpr::SetSortedFlag(IsSorted::Ascending),
+                        ..
+                    }) => return true,
+                    _ => return false,
+                }
+            },
+            _ => return false,
+        }
+    }
+}
+
+/// Returns the key columns of an asof join if it can be executed by the
+/// streaming asof join node, which only supports plain column keys.
+///
+/// With `by` groups the in-memory join only needs the keys to be sorted within
+/// each group, whereas the streaming node needs them to be sorted globally. We
+/// only use the streaming node for those if that is known at planning time.
+#[cfg(feature = "asof_join")]
+fn streaming_asof_join_keys(
+    args: &polars_ops::frame::JoinArgs,
+    input_left: Node,
+    input_right: Node,
+    left_on: &[ExprIR],
+    right_on: &[ExprIR],
+    ir_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<(
+    polars_utils::pl_str::PlSmallStr,
+    polars_utils::pl_str::PlSmallStr,
+)> {
+    let polars_ops::frame::JoinType::AsOf(options) = &args.how else {
+        return None;
+    };
+    if args.validation.needs_checks() || options.left_by.is_some() != options.right_by.is_some() {
+        return None;
+    }
+    let column_key = |on: &[ExprIR]| match on {
+        [key] => match expr_arena.get(key.node()) {
+            AExpr::Column(name) if name == key.output_name() => Some(name.clone()),
+            _ => None,
+        },
+        _ => None,
+    };
+    let (left_key, right_key) = (column_key(left_on)?, column_key(right_on)?);
+    if options.left_by.is_some()
+        && !(is_sorted_by_key(input_left, &left_key, ir_arena, expr_arena)
+            && is_sorted_by_key(input_right, &right_key, ir_arena, expr_arena))
+    {
+        return None;
+    }
+    Some((left_key, right_key))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct StreamingLowerIRContext {
     pub prepare_visualization: bool,
@@ -578,6 +677,17 @@ pub fn lower_ir(
                         Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
                     },
 
+                    #[cfg(feature = "json")]
+                    FileScanIR::Json { options } => {
+                        Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
+                    },
+
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro { options: _ } => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder)
+                            as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object: _,
@@ -906,7 +1016,42 @@ pub fn lower_ir(
             let options = options.options.clone();
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
-            if (args.how.is_equi() || args.how.is_semi_anti()) && !args.validation.needs_checks() {
+
+            #[cfg(feature = "asof_join")]
+            if let Some((left_key, right_key)) = streaming_asof_join_keys(
+                &args,
+                input_left,
+                input_right,
+                &left_on,
+                &right_on,
+                ir_arena,
+                expr_arena,
+            ) {
+                let node = phys_sm.insert(PhysNode::new(
+                    output_schema,
+                    PhysNodeKind::AsOfJoin {
+                        input_left: phys_left,
+                        input_right: phys_right,
+                        left_on: left_key,
+                        right_on: right_key,
+                        args: args.clone(),
+                    },
+                ));
+                let mut stream = PhysStream::first(node);
+                if let Some((offset, len)) = args.slice {
+                    stream = build_slice_stream(stream, offset, len, phys_sm);
+                }
+                return Ok(stream);
+            }
+
+            #[cfg(feature = "iejoin")]
+            let is_ie_join = matches!(options, Some(JoinTypeOptionsIR::IEJoin(_)));
+            #[cfg(not(feature = "iejoin"))]
+            let is_ie_join = false;
+
+            if (args.how.is_equi() || args.how.is_semi_anti() || is_ie_join)
+                && !args.validation.needs_checks()
+            {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
                 // nodes since the lowering code does not see we access any non-literal expressions.
@@ -940,6 +1085,26 @@ pub fn lower_ir(
                 trans_left_on.drain(left_on.len()..);
                 trans_right_on.drain(right_on.len()..);
 
+                #[cfg(feature = "iejoin")]
+                if let Some(JoinTypeOptionsIR::IEJoin(ie_options)) = options {
+                    let node = phys_sm.insert(PhysNode::new(
+                        output_schema,
+                        PhysNodeKind::IEJoin {
+                            input_left: trans_input_left,
+                            input_right: trans_input_right,
+                            left_on: trans_left_on,
+                            right_on: trans_right_on,
+                            args: args.clone(),
+                            options: ie_options,
+                        },
+                    ));
+                    let mut stream = PhysStream::first(node);
+                    if let Some((offset, len)) = args.slice {
+                        stream = build_slice_stream(stream, offset, len, phys_sm);
+                    }
+                    return Ok(stream);
+                }
+
                 let node = if args.how.is_equi() {
                     phys_sm.insert(PhysNode::new(
                         output_schema,
@@ -12,7 +12,7 @@ use polars_expr::state::ExecutionState;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
 use polars_plan::dsl::{JoinOptionsIR, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
-use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
+use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR, is_elementwise_rec};
 use polars_plan::prelude::{FileType, FunctionFlags};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::format_pl_smallstr;
@@ -305,6 +305,17 @@ fn to_graph_rec<'a>(
                     )?),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        input_schema,
+                        target.clone(),
+                        sink_options,
+                        avro_writer_options,
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(feature = "csv")]
                 FileType::Csv(csv_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::csv::CsvSinkNode::new(
@@ -320,7 +331,8 @@ fn to_graph_rec<'a>(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -457,34 +469,60 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: *slice,
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                None,
-            )?);
 
-            let input_key = to_graph_rec(input.node, ctx)?;
-            ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
-                    input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
-                ),
-                [(input_key, input.port)],
-            )
+            // Sort keys are evaluated per morsel and all columns are row-encoded
+            // when spilling, otherwise we fall back to the in-memory engine.
+            let can_stream = !input_schema.is_empty()
+                && input_schema.iter_values().all(|dt| !dt.contains_objects())
+                && by_column
+                    .iter()
+                    .all(|e| is_elementwise_rec(e.node(), ctx.expr_arena));
+            if can_stream {
+                let key_selectors = by_column
+                    .iter()
+                    .map(|e| create_stream_expr(e, ctx, &input_schema))
+                    .try_collect_vec()?;
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::sort::SortNode::new(
+                        input_schema,
+                        key_selectors,
+                        *slice,
+                        sort_options.clone(),
+                        nodes::sort::get_sort_memory_budget()?,
+                    ),
+                    [(input_key, input.port)],
+                )
+            } else {
+                let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                let mut lp_arena = Arena::default();
+                let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                let sort_node = lp_arena.add(IR::Sort {
+                    input: df_node,
+                    by_column: by_column.clone(),
+                    slice: *slice,
+                    sort_options: sort_options.clone(),
+                });
+                let executor = Mutex::new(create_physical_plan(
+                    sort_node,
+                    &mut lp_arena,
+                    ctx.expr_arena,
+                    None,
+                )?);
+
+                let input_key = to_graph_rec(input.node, ctx)?;
+                ctx.graph.add_node(
+                    nodes::in_memory_map::InMemoryMapNode::new(
+                        input_schema,
+                        Arc::new(move |df| {
+                            lmdf.set_materialized_dataframe(df);
+                            let mut state = ExecutionState::new();
+                            executor.lock().execute(&mut state)
+                        }),
+                    ),
+                    [(input_key, input.port)],
+                )
+            }
         },
 
         TopK {
@@ -701,7 +739,37 @@ fn to_graph_rec<'a>(
                     node.output_schema.clone(),
                     PlRandomState::default(),
                     ctx.num_pipelines,
-                ),
+                )?,
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "dynamic_group_by")]
+        DynamicGroupBy {
+            input,
+            options,
+            aggs,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let aggs = aggs
+                .iter()
+                .map(|agg| {
+                    create_physical_expr(
+                        agg,
+                        Context::Aggregation,
+                        ctx.expr_arena,
+                        &input_schema,
+                        &mut ctx.expr_conversion_state,
+                    )
+                })
+                .try_collect_vec()?;
+            ctx.graph.add_node(
+                nodes::dynamic_group_by::DynamicGroupByNode::new(
+                    &input_schema,
+                    options.clone(),
+                    aggs,
+                )?,
                 [(input_key, input.port)],
             )
         },
@@ -890,6 +958,103 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_schema =
+                compute_output_schema(&left_input_schema, left_on, ctx.expr_arena)?;
+            let right_key_schema =
+                compute_output_schema(&right_input_schema, right_on, ctx.expr_arena)?;
+            polars_ensure!(
+                left_on.len() == right_on.len() &&
+                left_on.iter().zip(right_on.iter()).all(|(l, r)| {
+                    let l_dtype = left_key_schema.get(l.output_name()).unwrap();
+                    let r_dtype = right_key_schema.get(r.output_name()).unwrap();
+                    l_dtype == r_dtype
+                }),
+                SchemaMismatch: "join received different key types on left and right side"
+            );
+
+            // The keys may select the same column more than once.
+            let left_key_selectors = left_on
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let e = e.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&e, ctx, &left_input_schema)
+                })
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .enumerate()
+                .map(|(i, e)| {
+                    let e = e.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&e, ctx, &right_input_schema)
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::joins::ie_join::IEJoinNode::new(
+                    right_input_schema,
+                    left_key_selectors,
+                    right_key_selectors,
+                    args,
+                    options.clone(),
+                    ctx.num_pipelines,
+                )?,
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let mut args = args.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            if let polars_ops::frame::JoinType::AsOf(options) = &mut args.how {
+                polars_mem_engine::resolve_asof_tolerance(
+                    options,
+                    left_input_schema.try_get(left_on)?,
+                )?;
+            }
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    right_input_schema,
+                    left_on.clone(),
+                    right_on.clone(),
+                    &args,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         #[cfg(feature = "merge_sorted")]
         MergeSorted {
             input_left,
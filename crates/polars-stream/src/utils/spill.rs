@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::Mutex;
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_io::path_utils::POLARS_TEMP_DIR_BASE_PATH;
+
+const DEFAULT_MEMORY_BUDGET: usize = 4 << 30;
+
+/// Reads a memory budget (in bytes) from the given environment variable,
+/// falling back to the default budget if it is not set.
+pub fn get_memory_budget(env_var: &str) -> PolarsResult<usize> {
+    match std::env::var(env_var) {
+        Ok(budget) => budget.parse().map_err(|_| {
+            polars_err!(
+                InvalidOperation: "invalid value for {env_var}: '{budget}', expected a size in bytes"
+            )
+        }),
+        Err(_) => Ok(DEFAULT_MEMORY_BUDGET),
+    }
+}
+
+/// A directory in which a node spills its state, removed when dropped.
+pub struct SpillDir {
+    path: PathBuf,
+    next_file_idx: AtomicU64,
+}
+
+impl SpillDir {
+    pub fn new(node_name: &str) -> PolarsResult<Self> {
+        let path = POLARS_TEMP_DIR_BASE_PATH
+            .join(format!("{node_name}-spill"))
+            .join(format!(
+                "{}-{:016x}",
+                std::process::id(),
+                rand::random::<u64>()
+            ));
+        std::fs::create_dir_all(&path)?;
+        if config::verbose() {
+            eprintln!("[{node_name}]: spilling to {}", path.display());
+        }
+        Ok(Self {
+            path,
+            next_file_idx: AtomicU64::new(0),
+        })
+    }
+
+    /// Returns the spill directory in `slot`, creating it on first use.
+    pub fn get_or_create(
+        slot: &Mutex<Option<Arc<SpillDir>>>,
+        node_name: &str,
+    ) -> PolarsResult<Arc<SpillDir>> {
+        let mut slot = slot.lock();
+        Ok(match &*slot {
+            Some(dir) => dir.clone(),
+            None => slot.insert(Arc::new(SpillDir::new(node_name)?)).clone(),
+        })
+    }
+
+    pub fn new_file_path(&self, extension: &str) -> PathBuf {
+        let idx = self.next_file_idx.fetch_add(1, Ordering::Relaxed);
+        self.path.join(format!("{idx}.{extension}"))
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Whether this build is able to spill frames, which requires IPC support.
+pub const CAN_SPILL_FRAMES: bool = cfg!(feature = "ipc");
+
+/// Writes the given parts, each a list of equal-length columns with the same
+/// dtypes, as a single frame to a new IPC file in the spill directory. The
+/// columns are renamed positionally, so parts with arbitrary (even duplicate)
+/// names can be spilled and given their names back after
+/// [`read_spilled_frame`].
+#[cfg(feature = "ipc")]
+pub fn spill_frame(
+    dir: &SpillDir,
+    parts: impl IntoIterator<Item = Vec<Column>>,
+) -> PolarsResult<PathBuf> {
+    use std::io::BufWriter;
+
+    use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+    use polars_io::SerWriter;
+    use polars_io::ipc::IpcWriter;
+
+    let dfs = parts.into_iter().map(|columns| {
+        let height = columns.first().map_or(0, |c| c.len());
+        let columns = columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| c.with_name(polars_utils::format_pl_smallstr!("{i}")))
+            .collect();
+        unsafe { DataFrame::new_no_checks(height, columns) }
+    });
+    let mut df = accumulate_dataframes_vertical_unchecked(dfs);
+
+    let path = dir.new_file_path("ipc");
+    let file = std::fs::File::create(&path)?;
+    IpcWriter::new(BufWriter::new(file)).finish(&mut df)?;
+    Ok(path)
+}
+
+#[cfg(not(feature = "ipc"))]
+pub fn spill_frame(
+    _dir: &SpillDir,
+    _parts: impl IntoIterator<Item = Vec<Column>>,
+) -> PolarsResult<PathBuf> {
+    polars_bail!(ComputeError: "spilling frames to disk requires the 'ipc' feature")
+}
+
+/// Reads back (and removes) a frame written by [`spill_frame`] as a single
+/// chunk, with the columns still named by position.
+pub fn read_spilled_frame(path: &std::path::Path) -> PolarsResult<Vec<Column>> {
+    let columns = load_spilled_frame(path)?;
+    std::fs::remove_file(path)?;
+    Ok(columns)
+}
+
+/// Like [`read_spilled_frame`], but leaves the file in place so it can be
+/// read again.
+#[cfg(feature = "ipc")]
+pub fn load_spilled_frame(path: &std::path::Path) -> PolarsResult<Vec<Column>> {
+    use polars_io::SerReader;
+    use polars_io::ipc::IpcReader;
+
+    let mut df = IpcReader::new(std::fs::File::open(path)?).finish()?;
+    df.rechunk_mut();
+    Ok(df.take_columns())
+}
+
+#[cfg(not(feature = "ipc"))]
+pub fn load_spilled_frame(_path: &std::path::Path) -> PolarsResult<Vec<Column>> {
+    polars_bail!(ComputeError: "spilling frames to disk requires the 'ipc' feature")
+}
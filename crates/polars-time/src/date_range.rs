@@ -110,6 +110,26 @@ pub(crate) fn datetime_range_i64(
         ComputeError: "`interval` must be positive"
     );
 
+    // Business-day ranges start on the first business day, which is then part of the range
+    // regardless of `closed` if it lies after `start`.
+    let mut closed = closed;
+    let start = if interval.business_days() > 0 {
+        let rolled = interval.roll_forward_to_business_day(start, time_zone, time_unit)?;
+        if rolled > start {
+            closed = match closed {
+                ClosedWindow::Right => ClosedWindow::Both,
+                ClosedWindow::None => ClosedWindow::Left,
+                closed => closed,
+            };
+        }
+        rolled
+    } else {
+        start
+    };
+    if start > end {
+        return Ok(Vec::new());
+    }
+
     let duration = match time_unit {
         TimeUnit::Nanoseconds => interval.duration_ns(),
         TimeUnit::Microseconds => interval.duration_us(),
@@ -151,20 +171,20 @@ pub(crate) fn datetime_range_i64(
         ClosedWindow::Both | ClosedWindow::Left => 0,
         ClosedWindow::Right | ClosedWindow::None => 1,
     };
-    let mut t = offset_fn(&(interval * i), start, time_zone)?;
+    let mut t = offset_fn(&interval.try_mul(i)?, start, time_zone)?;
     i += 1;
     match closed {
         ClosedWindow::Both | ClosedWindow::Right => {
             while t <= end {
                 ts.push(t);
-                t = offset_fn(&(interval * i), start, time_zone)?;
+                t = offset_fn(&interval.try_mul(i)?, start, time_zone)?;
                 i += 1;
             }
         },
         ClosedWindow::Left | ClosedWindow::None => {
             while t < end {
                 ts.push(t);
-                t = offset_fn(&(interval * i), start, time_zone)?;
+                t = offset_fn(&interval.try_mul(i)?, start, time_zone)?;
                 i += 1;
             }
         },
@@ -74,6 +74,25 @@ impl Default for RollingGroupOptions {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct SessionGroupOptions {
+    /// Time or index column.
+    pub index_column: PlSmallStr,
+    /// Period of inactivity after which a session ends.
+    pub gap: Duration,
+}
+
+impl Default for SessionGroupOptions {
+    fn default() -> Self {
+        Self {
+            index_column: "".into(),
+            gap: Duration::new(1),
+        }
+    }
+}
+
 fn check_sortedness_slice(v: &[i64]) -> PolarsResult<()> {
     polars_ensure!(v.is_sorted_ascending(), ComputeError: "input data is not sorted");
     Ok(())
@@ -94,6 +113,12 @@ pub trait PolarsTemporalGroupby {
         group_by: Option<GroupsSlice>,
         options: &DynamicGroupOptions,
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)>;
+
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, GroupPositions)>;
 }
 
 impl PolarsTemporalGroupby for DataFrame {
@@ -112,6 +137,14 @@ impl PolarsTemporalGroupby for DataFrame {
     ) -> PolarsResult<(Column, Vec<Column>, GroupPositions)> {
         Wrap(self).group_by_dynamic(group_by, options)
     }
+
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, GroupPositions)> {
+        Wrap(self).group_by_session(group_by, options)
+    }
 }
 
 impl Wrap<&DataFrame> {
@@ -260,6 +293,30 @@ impl Wrap<&DataFrame> {
         self.impl_group_by_dynamic(dt, group_by, options, tu, time_type)
     }
 
+    /// Returns: time_keys, groupsproxy.
+    fn group_by_session(
+        &self,
+        group_by: Option<GroupsSlice>,
+        options: &SessionGroupOptions,
+    ) -> PolarsResult<(Column, GroupPositions)> {
+        let mut time = self.0.column(&options.index_column)?.rechunk();
+        let sorted = group_by.is_none();
+        let groups = session_groups(&time, group_by, options.gap, "group_by_session")?;
+        let groups = GroupsType::Slice {
+            groups,
+            rolling: false,
+        };
+
+        if sorted {
+            time.set_sorted_flag(IsSorted::Ascending);
+        }
+        let mut time_key = unsafe { time.agg_first(&groups) };
+        if sorted {
+            time_key.set_sorted_flag(IsSorted::Ascending);
+        }
+        Ok((time_key, groups.into_sliceable()))
+    }
+
     fn impl_group_by_dynamic(
         &self,
         mut dt: Column,
@@ -382,43 +439,17 @@ impl Wrap<&DataFrame> {
         // note that if 'group_by' is none we can be sure that the index column, the lower column and the
         // upper column remain/are sorted
 
-        let dt = unsafe { dt.clone().into_series().agg_first(&groups) };
-        let mut dt = dt.datetime().unwrap().physical().clone();
-
-        let lower =
-            lower_bound.map(|lower| Int64Chunked::new_vec(PlSmallStr::from_static(LB_NAME), lower));
-        let upper =
-            upper_bound.map(|upper| Int64Chunked::new_vec(PlSmallStr::from_static(UP_NAME), upper));
-
-        if options.label == Label::Left {
-            let mut lower = lower.clone().unwrap();
-            if group_by.is_none() {
-                lower.set_sorted_flag(IsSorted::Ascending)
-            }
-            dt = lower.with_name(dt.name().clone());
-        } else if options.label == Label::Right {
-            let mut upper = upper.clone().unwrap();
-            if group_by.is_none() {
-                upper.set_sorted_flag(IsSorted::Ascending)
-            }
-            dt = upper.with_name(dt.name().clone());
-        }
-
-        let mut bounds = vec![];
-        if let (true, Some(mut lower), Some(mut upper)) = (options.include_boundaries, lower, upper)
-        {
-            if group_by.is_none() {
-                lower.set_sorted_flag(IsSorted::Ascending);
-                upper.set_sorted_flag(IsSorted::Ascending);
-            }
-            bounds.push(lower.into_datetime(tu, tz.clone()).into_column());
-            bounds.push(upper.into_datetime(tu, tz.clone()).into_column());
-        }
-
-        dt.into_datetime(tu, None)
-            .into_column()
-            .cast(time_type)
-            .map(|s| (s, bounds, groups.into_sliceable()))
+        let (dt, bounds) = window_keys(
+            dt,
+            &groups,
+            lower_bound,
+            upper_bound,
+            options,
+            tu,
+            time_type,
+            group_by.is_none(),
+        )?;
+        Ok((dt, bounds, groups.into_sliceable()))
     }
 
     /// Returns: time_keys, keys, groupsproxy
@@ -496,6 +527,268 @@ impl Wrap<&DataFrame> {
     }
 }
 
+/// Builds the time key and the boundary columns of the window `groups` of the index column `dt`.
+#[allow(clippy::too_many_arguments)]
+fn window_keys(
+    dt: &DatetimeChunked,
+    groups: &GroupsType,
+    lower_bound: Option<Vec<i64>>,
+    upper_bound: Option<Vec<i64>>,
+    options: &DynamicGroupOptions,
+    tu: TimeUnit,
+    time_type: &DataType,
+    sorted: bool,
+) -> PolarsResult<(Column, Vec<Column>)> {
+    let tz = dt.time_zone();
+    let dt = unsafe { dt.clone().into_series().agg_first(groups) };
+    let mut dt = dt.datetime().unwrap().physical().clone();
+
+    let lower =
+        lower_bound.map(|lower| Int64Chunked::new_vec(PlSmallStr::from_static(LB_NAME), lower));
+    let upper =
+        upper_bound.map(|upper| Int64Chunked::new_vec(PlSmallStr::from_static(UP_NAME), upper));
+
+    if options.label == Label::Left {
+        let mut lower = lower.clone().unwrap();
+        if sorted {
+            lower.set_sorted_flag(IsSorted::Ascending)
+        }
+        dt = lower.with_name(dt.name().clone());
+    } else if options.label == Label::Right {
+        let mut upper = upper.clone().unwrap();
+        if sorted {
+            upper.set_sorted_flag(IsSorted::Ascending)
+        }
+        dt = upper.with_name(dt.name().clone());
+    }
+
+    let mut bounds = vec![];
+    if let (true, Some(mut lower), Some(mut upper)) = (options.include_boundaries, lower, upper) {
+        if sorted {
+            lower.set_sorted_flag(IsSorted::Ascending);
+            upper.set_sorted_flag(IsSorted::Ascending);
+        }
+        bounds.push(lower.into_datetime(tu, tz.clone()).into_column());
+        bounds.push(upper.into_datetime(tu, tz.clone()).into_column());
+    }
+
+    dt.into_datetime(tu, None)
+        .into_column()
+        .cast(time_type)
+        .map(|s| (s, bounds))
+}
+
+/// Splits the `time` column into sessions that end after an inactivity of `gap`, see
+/// [`group_by_session`]. If `group_by` is given, the sessions are computed within each of those
+/// groups, which then must each be sorted.
+pub(crate) fn session_groups(
+    time: &Column,
+    group_by: Option<GroupsSlice>,
+    gap: Duration,
+    fn_name: &str,
+) -> PolarsResult<GroupsSlice> {
+    polars_ensure!(
+        !gap.is_zero() && !gap.negative,
+        ComputeError: "session gap should be strictly positive"
+    );
+    if group_by.is_none() {
+        // If by is given, the column must be sorted in the 'by' arg, which we can not check now
+        // this will be checked when the groups are materialized.
+        time.as_materialized_series().ensure_sorted_arg(fn_name)?;
+    }
+    polars_ensure!(
+        time.null_count() == 0,
+        ComputeError: "null values in `{}` not supported, fill nulls.", fn_name
+    );
+    ensure_duration_matches_dtype(gap, time.dtype(), "gap")?;
+
+    let (dt, tu) = index_to_datetime(&time.rechunk())?;
+    let dt = dt.datetime().unwrap();
+    let tz = match dt.time_zone() {
+        #[cfg(feature = "timezones")]
+        Some(tz) => tz.parse::<Tz>().ok(),
+        _ => None,
+    };
+    let ts = dt
+        .physical()
+        .downcast_iter()
+        .next()
+        .map_or(&[][..], |arr| arr.values().as_slice());
+
+    match group_by {
+        None => group_by_session(gap, ts, tu, tz),
+        Some(groups) => {
+            let iter = groups.into_par_iter().map(|[start, len]| {
+                let group_offset = start;
+                let start = start as usize;
+                let end = start + len as usize;
+                let values = &ts[start..end];
+                check_sortedness_slice(values)?;
+
+                let group = group_by_session(gap, values, tu, tz)?;
+                PolarsResult::Ok(
+                    group
+                        .iter()
+                        .map(|[start, len]| [*start + group_offset, *len])
+                        .collect_vec(),
+                )
+            });
+
+            let groups = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
+            Ok(POOL.install(|| flatten_par(&groups)))
+        },
+    }
+}
+
+/// Casts an index column to a datetime column, of which the physical values are used as the
+/// timestamps of the windows.
+fn index_to_datetime(time: &Column) -> PolarsResult<(Column, TimeUnit)> {
+    use DataType::*;
+    Ok(match time.dtype() {
+        Datetime(tu, _) => (time.clone(), *tu),
+        Date => (
+            time.cast(&Datetime(TimeUnit::Milliseconds, None))?,
+            TimeUnit::Milliseconds,
+        ),
+        Int32 | Int64 => (
+            time.cast(&Int64)?
+                .cast(&Datetime(TimeUnit::Nanoseconds, None))?,
+            TimeUnit::Nanoseconds,
+        ),
+        dt => polars_bail!(
+            ComputeError:
+            "expected any of the following dtypes: {{ Date, Datetime, Int32, Int64 }}, got {}",
+            dt
+        ),
+    })
+}
+
+/// Computes the windows of [`PolarsTemporalGroupby::group_by_dynamic`] incrementally, for an
+/// index column that arrives in sorted chunks. Windows are returned as soon as the index column
+/// has passed their upper bound, so that they can be aggregated without waiting for the rest of
+/// the data. The windows are the same as the ones of a single `group_by_dynamic` over all rows.
+///
+/// Grouping by additional keys is not supported.
+pub struct DynamicGroupByState {
+    options: DynamicGroupOptions,
+    window: Window,
+    // the first window that was not returned yet, `None` before the first row arrived
+    next: Option<Bounds>,
+    // the last timestamp seen so far
+    last: Option<i64>,
+    // the number of leading rows of the buffer that were already validated
+    n_validated: usize,
+}
+
+impl DynamicGroupByState {
+    pub fn try_new(options: DynamicGroupOptions, index_dtype: &DataType) -> PolarsResult<Self> {
+        polars_ensure!(!options.every.negative, ComputeError: "'every' argument must be positive");
+        index_to_datetime(&Column::new_empty(PlSmallStr::EMPTY, index_dtype))?;
+        ensure_duration_matches_dtype(options.every, index_dtype, "every")?;
+        ensure_duration_matches_dtype(options.offset, index_dtype, "offset")?;
+        ensure_duration_matches_dtype(options.period, index_dtype, "period")?;
+
+        Ok(Self {
+            window: Window::new(options.every, options.period, options.offset),
+            options,
+            next: None,
+            last: None,
+            n_validated: 0,
+        })
+    }
+
+    /// Returns the time key, the boundaries and the groups of the windows in `df` that are closed,
+    /// together with the number of leading rows of `df` that don't belong to any of the remaining
+    /// windows. If `is_last` is set, all remaining windows are returned.
+    ///
+    /// `df` must consist of the rows that were kept from the previous call, followed by the rows
+    /// that arrived since.
+    pub fn update(
+        &mut self,
+        df: &DataFrame,
+        is_last: bool,
+    ) -> PolarsResult<(Column, Vec<Column>, GroupPositions, usize)> {
+        let time = df.column(&self.options.index_column)?;
+
+        let new = time.slice(self.n_validated as i64, time.len() - self.n_validated);
+        polars_ensure!(new.null_count() == 0, ComputeError: "null values in dynamic group_by not supported, fill nulls.");
+        let (new, _) = index_to_datetime(&new)?;
+        let new = new.datetime()?.physical().rechunk();
+        let ts = new.cont_slice()?;
+        polars_ensure!(
+            ts.is_sorted_ascending() && self.last.zip(ts.first()).is_none_or(|(l, f)| l <= *f),
+            InvalidOperation: "argument in operation 'group_by_dynamic' is not sorted, please sort the 'expr/series/column' first"
+        );
+        self.last = ts.last().copied().or(self.last);
+        self.n_validated = time.len();
+
+        let closes_window = match (self.last, self.next) {
+            (None, _) => false,
+            (Some(_), _) if is_last => true,
+            (Some(last), Some(next)) => next.is_future(last, self.options.closed_window),
+            (Some(_), None) => true,
+        };
+        if !closes_window {
+            let time_key = Column::new_empty(time.name().clone(), time.dtype());
+            return Ok((time_key, vec![], Default::default(), 0));
+        }
+
+        let (mut dt, tu) = index_to_datetime(&time.rechunk())?;
+        dt.set_sorted_flag(IsSorted::Ascending);
+        let dt = dt.datetime()?;
+        let ts = dt.physical().cont_slice()?;
+
+        let include_lower_bound =
+            self.options.include_boundaries || self.options.label == Label::Left;
+        let include_upper_bound =
+            self.options.include_boundaries || self.options.label == Label::Right;
+        let (groups, lower, upper) = group_by_closed_windows(
+            self.window,
+            ts,
+            self.options.closed_window,
+            tu,
+            dt.time_zone(),
+            include_lower_bound,
+            include_upper_bound,
+            self.options.start_by,
+            &mut self.next,
+            is_last,
+        )?;
+
+        let n_done = match self.next {
+            Some(next) if !is_last => ts.partition_point(|t| *t < next.start),
+            _ => ts.len(),
+        };
+        self.n_validated -= n_done;
+
+        let groups = GroupsType::Slice {
+            groups,
+            rolling: false,
+        };
+        let time_type = match time.dtype() {
+            DataType::Int32 | DataType::Int64 => DataType::Datetime(TimeUnit::Nanoseconds, None),
+            dt => dt.clone(),
+        };
+        let (mut time_key, mut bounds) = window_keys(
+            dt,
+            &groups,
+            Some(lower),
+            Some(upper),
+            &self.options,
+            tu,
+            &time_type,
+            true,
+        )?;
+        if time.dtype().is_integer() {
+            time_key = time_key.cast(&DataType::Int64)?.cast(time.dtype())?;
+            for b in &mut bounds {
+                *b = b.cast(&DataType::Int64)?.cast(time.dtype())?;
+            }
+        }
+        Ok((time_key, bounds, groups.into_sliceable(), n_done))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use polars_compute::rolling::QuantileMethod;
@@ -555,6 +848,102 @@ mod test {
         Ok(())
     }
 
+    fn dynamic_group_by_sum(
+        df: &DataFrame,
+        options: &DynamicGroupOptions,
+        chunk_size: Option<usize>,
+    ) -> PolarsResult<DataFrame> {
+        fn to_df(
+            time_key: Column,
+            mut bounds: Vec<Column>,
+            groups: &GroupPositions,
+            rows: &DataFrame,
+        ) -> PolarsResult<DataFrame> {
+            let sum = unsafe { rows.column("a")?.agg_sum(groups) };
+            bounds.extend([time_key, sum]);
+            DataFrame::new(bounds)
+        }
+        let Some(chunk_size) = chunk_size else {
+            let (time_key, bounds, groups) = df.group_by_dynamic(None, options)?;
+            return to_df(time_key, bounds, &groups, df);
+        };
+
+        let index_dtype = df.column(&options.index_column)?.dtype();
+        let mut state = DynamicGroupByState::try_new(options.clone(), index_dtype)?;
+        let mut buffer = df.clear();
+        let mut out: Option<DataFrame> = None;
+        for offset in (0..df.height() + chunk_size).step_by(chunk_size) {
+            let is_last = offset >= df.height();
+            buffer.vstack_mut(&df.slice(offset as i64, chunk_size))?;
+            let (time_key, bounds, groups, n_done) = state.update(&buffer, is_last)?;
+            if !groups.is_empty() {
+                let windows = to_df(time_key, bounds, &groups, &buffer)?;
+                match &mut out {
+                    Some(out) => _ = out.vstack_mut(&windows)?,
+                    None => out = Some(windows),
+                }
+            }
+            buffer = buffer.slice(n_done as i64, usize::MAX);
+        }
+        Ok(out.unwrap())
+    }
+
+    #[test]
+    fn test_dynamic_group_by_chunked() -> PolarsResult<()> {
+        let mut t = Column::new(
+            "t".into(),
+            [0i64, 1, 1, 2, 4, 5, 9, 9, 10, 11, 16, 17, 17, 30],
+        );
+        t.set_sorted_flag(IsSorted::Ascending);
+        let a = Column::new("a".into(), (1..=t.len() as i64).collect::<Vec<_>>());
+        let df = DataFrame::new(vec![t, a])?;
+
+        for (every, period, offset) in [("2i", "2i", "0i"), ("2i", "5i", "-1i"), ("3i", "2i", "1i")]
+        {
+            for closed_window in [
+                ClosedWindow::Left,
+                ClosedWindow::Right,
+                ClosedWindow::Both,
+                ClosedWindow::None,
+            ] {
+                for label in [Label::Left, Label::Right, Label::DataPoint] {
+                    for start_by in [StartBy::WindowBound, StartBy::DataPoint] {
+                        let options = DynamicGroupOptions {
+                            index_column: "t".into(),
+                            every: Duration::parse(every),
+                            period: Duration::parse(period),
+                            offset: Duration::parse(offset),
+                            label,
+                            include_boundaries: true,
+                            closed_window,
+                            start_by,
+                        };
+                        let expected = dynamic_group_by_sum(&df, &options, None)?;
+                        for chunk_size in [1, 2, 3, 5, 100] {
+                            let out = dynamic_group_by_sum(&df, &options, Some(chunk_size))?;
+                            assert!(out.equals(&expected), "{options:?}\n{out}\n{expected}");
+                        }
+                    }
+                }
+            }
+        }
+
+        // Values must be sorted across chunks.
+        let df = df.sort(
+            ["a"],
+            SortMultipleOptions::default().with_order_descending(true),
+        )?;
+        let options = DynamicGroupOptions {
+            index_column: "t".into(),
+            every: Duration::parse("2i"),
+            period: Duration::parse("2i"),
+            offset: Duration::parse("0i"),
+            ..Default::default()
+        };
+        assert!(dynamic_group_by_sum(&df, &options, Some(1)).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_rolling_group_by_aggs() -> PolarsResult<()> {
         let mut date = StringChunked::new(
@@ -638,4 +1027,42 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_group_by_session() -> PolarsResult<()> {
+        let minute = 60_000;
+        let ts = [0, 10, 50, 60, 70, 100].map(|m: i64| m * minute);
+        let dt = Int64Chunked::new("dt".into(), ts)
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_column();
+        let df = DataFrame::new(vec![dt.clone()])?;
+        let options = SessionGroupOptions {
+            index_column: "dt".into(),
+            gap: Duration::parse("30m"),
+        };
+
+        let (time_key, groups) = df.group_by_session(None, &options)?;
+        assert_eq!(groups.unwrap_slice(), &[[0, 2], [2, 3], [5, 1]]);
+        let expected = Int64Chunked::new("dt".into(), [0, 50, 100].map(|m| m * minute))
+            .into_datetime(TimeUnit::Milliseconds, None)
+            .into_column();
+        assert!(time_key.equals(&expected));
+
+        let ids = dt.as_materialized_series().session_id(options.gap)?;
+        assert_eq!(
+            Vec::from(&ids),
+            &[Some(0), Some(0), Some(1), Some(1), Some(1), Some(2)]
+        );
+
+        // Sessions don't cross the groups of `group_by`.
+        let (_, groups) = df.group_by_session(Some(vec![[0, 3], [3, 3]]), &options)?;
+        assert_eq!(groups.unwrap_slice(), &[[0, 2], [2, 1], [3, 2], [5, 1]]);
+
+        let options = SessionGroupOptions {
+            gap: Duration::parse("0m"),
+            ..options
+        };
+        assert!(df.group_by_session(None, &options).is_err());
+        Ok(())
+    }
 }
@@ -49,5 +49,6 @@ pub use upsample::*;
 #[cfg(feature = "timezones")]
 pub use utils::known_timezones;
 pub use windows::duration::Duration;
+pub use windows::fiscal::{FiscalCalendar, FiscalPattern};
 pub use windows::group_by::ClosedWindow;
 pub use windows::window::Window;
@@ -2,6 +2,7 @@ pub use crate::chunkedarray::*;
 pub use crate::series::TemporalMethods;
 pub use crate::windows::bounds::*;
 pub use crate::windows::duration::*;
+pub use crate::windows::fiscal::*;
 pub use crate::windows::group_by::*;
 pub use crate::windows::window::*;
 pub use crate::*;
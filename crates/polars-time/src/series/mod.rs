@@ -3,8 +3,11 @@ use std::ops::Div;
 use arrow::temporal_conversions::{MICROSECONDS_IN_DAY, MILLISECONDS_IN_DAY, NANOSECONDS_IN_DAY};
 use polars_core::prelude::arity::unary_elementwise_values;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 
 use crate::chunkedarray::*;
+use crate::windows::duration::Duration;
+use crate::windows::fiscal::FiscalCalendar;
 
 pub trait AsSeries {
     fn as_series(&self) -> &Series;
@@ -236,6 +239,53 @@ pub trait TemporalMethods: AsSeries {
         }
     }
 
+    /// Extract the fiscal year in the given fiscal calendar.
+    /// Fiscal years are named after the calendar year in which they end.
+    #[cfg(feature = "dtype-date")]
+    fn fiscal_year(&self, calendar: &FiscalCalendar) -> PolarsResult<Int32Chunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            DataType::Date | DataType::Datetime(_, _) => {
+                Ok(unary_elementwise_values(&local_dates(s)?, |date| {
+                    calendar.fiscal_year(date)
+                }))
+            },
+            dt => polars_bail!(opq = fiscal_year, dt),
+        }
+    }
+
+    /// Extract the fiscal quarter in the given fiscal calendar.
+    /// Quarters range from 1 to 4.
+    #[cfg(feature = "dtype-date")]
+    fn fiscal_quarter(&self, calendar: &FiscalCalendar) -> PolarsResult<Int8Chunked> {
+        let s = self.as_series();
+        match s.dtype() {
+            DataType::Date | DataType::Datetime(_, _) => {
+                Ok(unary_elementwise_values(&local_dates(s)?, |date| {
+                    calendar.fiscal_quarter(date)
+                }))
+            },
+            dt => polars_bail!(opq = fiscal_quarter, dt),
+        }
+    }
+
+    /// Assign the id of its session to every value of a sorted time or index column. A session
+    /// ends after an inactivity of `gap`, so a new session starts at every value that is at least
+    /// `gap` past the value before it. Ids start at 0 and increase by one with every session.
+    #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
+    fn session_id(&self, gap: Duration) -> PolarsResult<IdxCa> {
+        let s = self.as_series();
+        let groups =
+            crate::group_by::dynamic::session_groups(&s.clone().into(), None, gap, "session_id")?;
+        let mut ids = Vec::with_capacity(s.len());
+        for (id, [_, len]) in groups.iter().enumerate() {
+            ids.extend(std::iter::repeat_n(id as IdxSize, *len as usize));
+        }
+        let mut out = IdxCa::from_vec(s.name().clone(), ids);
+        out.set_sorted_flag(IsSorted::Ascending);
+        Ok(out)
+    }
+
     /// Extract month from underlying NaiveDateTime representation.
     /// Returns the month number starting from 1.
     ///
@@ -317,3 +367,21 @@ pub trait TemporalMethods: AsSeries {
 }
 
 impl<T: ?Sized + AsSeries> TemporalMethods for T {}
+
+/// Physical dates of a Date or Datetime series, taking the local date of time zone-aware
+/// datetimes.
+#[cfg(feature = "dtype-date")]
+fn local_dates(s: &Series) -> PolarsResult<Int32Chunked> {
+    let s = match s.dtype() {
+        #[cfg(feature = "timezones")]
+        DataType::Datetime(_, Some(_)) => polars_ops::chunked_array::replace_time_zone(
+            s.datetime()?,
+            None,
+            &StringChunked::new("".into(), ["raise"]),
+            NonExistent::Raise,
+        )?
+        .into_series(),
+        _ => s.clone(),
+    };
+    Ok(s.cast(&DataType::Date)?.date()?.physical().clone())
+}
@@ -10,6 +10,16 @@ pub trait PolarsTruncate {
     fn truncate(&self, tz: Option<&Tz>, every: &StringChunked) -> PolarsResult<Self>
     where
         Self: Sized;
+
+    /// Truncate to the start of the fiscal periods of `every` in the given fiscal calendar.
+    fn truncate_fiscal(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<Self>
+    where
+        Self: Sized;
 }
 
 #[inline(always)]
@@ -38,9 +48,11 @@ impl PolarsTruncate for DatetimeChunked {
                     polars_bail!(ComputeError: "cannot truncate a Datetime to a negative duration")
                 }
                 if (time_zone.is_none() || time_zone.as_ref() == Some(&TimeZone::UTC))
-                    && (every_parsed.months() == 0 && every_parsed.weeks() == 0)
+                    && (every_parsed.months() == 0
+                        && every_parsed.weeks() == 0
+                        && every_parsed.business_days() == 0)
                 {
-                    // ... yes we can! Weeks, months, and time zones require extra logic.
+                    // ... yes we can! Weeks, months, business days, and time zones require extra logic.
                     // But in this simple case, it's just simple integer arithmetic.
                     let every = match self.time_unit() {
                         TimeUnit::Milliseconds => every_parsed.duration_ms(),
@@ -100,6 +112,43 @@ impl PolarsTruncate for DatetimeChunked {
         );
         Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()))
     }
+
+    fn truncate_fiscal(
+        &self,
+        tz: Option<&Tz>,
+        every: &StringChunked,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            self.len() == every.len() || self.len() == 1 || every.len() == 1,
+            length_mismatch = "dt.truncate_fiscal",
+            self.len(),
+            every.len()
+        );
+
+        // A sqrt(n) cache is not too small, not too large.
+        let mut duration_cache = LruCache::with_capacity((every.len() as f64).sqrt() as usize);
+
+        let func = match self.time_unit() {
+            TimeUnit::Nanoseconds => Window::truncate_fiscal_ns,
+            TimeUnit::Microseconds => Window::truncate_fiscal_us,
+            TimeUnit::Milliseconds => Window::truncate_fiscal_ms,
+        };
+
+        let offset = Duration::new(0);
+        let out =
+            broadcast_try_binary_elementwise(self.physical(), every, |opt_timestamp, opt_every| {
+                match (opt_timestamp, opt_every) {
+                    (Some(timestamp), Some(every)) => {
+                        let every = *duration_cache.get_or_insert_with(every, Duration::parse);
+                        let w = Window::new(every, every, offset);
+                        func(&w, timestamp, tz, calendar).map(Some)
+                    },
+                    _ => Ok(None),
+                }
+            });
+        Ok(out?.into_datetime(self.time_unit(), self.time_zone().clone()))
+    }
 }
 
 impl PolarsTruncate for DateChunked {
@@ -152,4 +201,33 @@ impl PolarsTruncate for DateChunked {
         };
         Ok(out?.into_date())
     }
+
+    fn truncate_fiscal(
+        &self,
+        _tz: Option<&Tz>,
+        every: &StringChunked,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            self.len() == every.len() || self.len() == 1 || every.len() == 1,
+            length_mismatch = "dt.truncate_fiscal",
+            self.len(),
+            every.len()
+        );
+
+        // A sqrt(n) cache is not too small, not too large.
+        let mut duration_cache = LruCache::with_capacity((every.len() as f64).sqrt() as usize);
+
+        let out =
+            broadcast_try_binary_elementwise(self.physical(), every, |opt_t, opt_every| {
+                match (opt_t, opt_every) {
+                    (Some(t), Some(every)) => {
+                        let every = *duration_cache.get_or_insert_with(every, Duration::parse);
+                        calendar.truncate(t, &every).map(Some)
+                    },
+                    _ => Ok(None),
+                }
+            });
+        Ok(out?.into_date())
+    }
 }
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::num::NonZeroU16;
 use std::ops::{Mul, Neg};
+use std::sync::{Arc, LazyLock, RwLock};
 
 #[cfg(feature = "timezones")]
 use arrow::legacy::kernels::{Ambiguous, NonExistent};
@@ -12,10 +14,11 @@ use arrow::temporal_conversions::{
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use polars_core::datatypes::DataType;
 use polars_core::prelude::{
-    PolarsResult, TimeZone, datetime_to_timestamp_ms, datetime_to_timestamp_ns,
+    PolarsResult, TimeUnit, TimeZone, datetime_to_timestamp_ms, datetime_to_timestamp_ns,
     datetime_to_timestamp_us, polars_bail,
 };
 use polars_error::polars_ensure;
+use polars_ops::series::{BusinessCalendar, get_business_calendar};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +28,7 @@ use super::calendar::{
 #[cfg(feature = "timezones")]
 use crate::utils::{localize_datetime_opt, try_localize_datetime, unlocalize_datetime};
 use crate::windows::calendar::{DAYS_PER_MONTH, is_leap_year};
+use crate::windows::fiscal::FiscalCalendar;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -36,6 +40,10 @@ pub struct Duration {
     weeks: i64,
     // the number of days for the duration
     days: i64,
+    // the number of business days for the duration
+    business_days: i32,
+    // the name of the registered business calendar, Monday to Friday without holidays if `None`
+    calendar: Option<CalendarName>,
     // the number of nanoseconds for the duration
     nsecs: i64,
     // indicates if the duration is negative
@@ -64,6 +72,8 @@ impl Neg for Duration {
             months: self.months,
             weeks: self.weeks,
             days: self.days,
+            business_days: self.business_days,
+            calendar: self.calendar,
             nsecs: self.nsecs,
             negative: !self.negative,
             parsed_int: self.parsed_int,
@@ -88,6 +98,12 @@ impl Display for Duration {
         if self.days > 0 {
             write!(f, "{}d", self.days)?
         }
+        if self.business_days > 0 {
+            write!(f, "{}bd", self.business_days)?;
+            if let Some(calendar) = self.calendar {
+                write!(f, "[{}]", calendar.as_str())?
+            }
+        }
         if self.nsecs > 0 {
             let secs = self.nsecs / NANOSECONDS;
             if secs * NANOSECONDS == self.nsecs {
@@ -112,6 +128,8 @@ impl Duration {
             months: 0,
             weeks: 0,
             days: 0,
+            business_days: 0,
+            calendar: None,
             nsecs: fixed_slots.abs(),
             negative: fixed_slots < 0,
             parsed_int: true,
@@ -139,6 +157,7 @@ impl Duration {
     /// * `m`:  minute
     /// * `h`:  hour
     /// * `d`:  day
+    /// * `bd`: business day
     /// * `w`:  week
     /// * `mo`: calendar month
     /// * `q`: calendar quarter
@@ -150,6 +169,11 @@ impl Duration {
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// Business days are Monday to Friday by default. A business calendar registered with
+    /// [`register_business_calendar`](polars_ops::series::register_business_calendar) can be
+    /// selected by appending its name in square brackets, e.g. `"3bd[nyse]"`. The calendar is
+    /// looked up when the duration is applied, not when it is parsed.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
@@ -176,7 +200,19 @@ impl Duration {
         let s = if as_interval { s.trim_start() } else { s };
 
         let parse_type = if as_interval { "interval" } else { "duration" };
-        let num_minus_signs = s.matches('-').count();
+        // Business calendar names may contain minus signs.
+        let mut in_calendar_name = false;
+        let num_minus_signs = s
+            .chars()
+            .filter(|&ch| {
+                match ch {
+                    '[' => in_calendar_name = true,
+                    ']' => in_calendar_name = false,
+                    _ => {},
+                }
+                ch == '-' && !in_calendar_name
+            })
+            .count();
         if num_minus_signs > 1 {
             polars_bail!(InvalidOperation: "{} string can only have a single minus sign", parse_type);
         }
@@ -191,6 +227,8 @@ impl Duration {
         let mut months = 0;
         let mut weeks = 0;
         let mut days = 0;
+        let mut business_days = 0;
+        let mut calendar = None;
         let mut nsecs = 0;
 
         let negative = s.starts_with('-');
@@ -256,6 +294,31 @@ impl Duration {
                     "m" => nsecs += n * NS_MINUTE,
                     "h" => nsecs += n * NS_HOUR,
                     "d" => days += n,
+                    "bd" if !as_interval => {
+                        business_days += n;
+                        if ch == '[' {
+                            let Some((end, _)) = iter.find(|(_, ch)| *ch == ']') else {
+                                polars_bail!(InvalidOperation:
+                                    "missing ']' after the business calendar name in the {} string '{}'",
+                                    parse_type, s
+                                );
+                            };
+                            let name = &s[start + 1..end];
+                            polars_ensure!(
+                                !name.is_empty(),
+                                InvalidOperation: "business calendar name in the {} string '{}' cannot be empty",
+                                parse_type, s
+                            );
+                            polars_ensure!(
+                                calendar.is_none_or(|calendar: CalendarName| calendar.as_str() == name),
+                                InvalidOperation: "{} string '{}' can only use a single business calendar",
+                                parse_type, s
+                            );
+                            calendar = Some(CalendarName::new(name)?);
+                            start = end + 1;
+                            ch = ']';
+                        }
+                    },
                     "w" => weeks += n,
                     "mo" => months += n,
                     "q" => months += n * 3,
@@ -283,7 +346,7 @@ impl Duration {
                         },
                     },
                     _ => {
-                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'");
+                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'bd', 'h', 'm', 's', 'ms', 'us', 'ns'");
                     },
                 }
                 unit.clear();
@@ -299,10 +362,16 @@ impl Duration {
             }
         };
 
+        let Ok(business_days) = i32::try_from(business_days.abs()) else {
+            polars_bail!(InvalidOperation: "too many business days in the {} string '{}'", parse_type, s);
+        };
+
         Ok(Duration {
             months: months.abs(),
             weeks: weeks.abs(),
             days: days.abs(),
+            business_days,
+            calendar,
             nsecs: nsecs.abs(),
             negative,
             parsed_int,
@@ -365,6 +434,8 @@ impl Duration {
             months: 0,
             weeks: 0,
             days: 0,
+            business_days: 0,
+            calendar: None,
             nsecs,
             negative,
             parsed_int: false,
@@ -378,6 +449,8 @@ impl Duration {
             months,
             weeks: 0,
             days: 0,
+            business_days: 0,
+            calendar: None,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -391,6 +464,8 @@ impl Duration {
             months: 0,
             weeks,
             days: 0,
+            business_days: 0,
+            calendar: None,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -404,6 +479,8 @@ impl Duration {
             months: 0,
             weeks: 0,
             days,
+            business_days: 0,
+            calendar: None,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -412,11 +489,19 @@ impl Duration {
 
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn months_only(&self) -> bool {
-        self.months != 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months != 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn months(&self) -> i64 {
@@ -424,7 +509,11 @@ impl Duration {
     }
 
     pub fn weeks_only(&self) -> bool {
-        self.months == 0 && self.weeks != 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks != 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn weeks(&self) -> i64 {
@@ -432,13 +521,52 @@ impl Duration {
     }
 
     pub fn days_only(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days != 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days != 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn days(&self) -> i64 {
         self.days
     }
 
+    pub fn business_days(&self) -> i64 {
+        self.business_days as i64
+    }
+
+    /// Multiplies the duration by `rhs`, raising an error if the number of business days
+    /// overflows.
+    pub fn try_mul(self, rhs: i64) -> PolarsResult<Self> {
+        let business_days = rhs
+            .checked_abs()
+            .and_then(|rhs| (self.business_days as i64).checked_mul(rhs));
+        polars_ensure!(
+            business_days.is_some_and(|n| i32::try_from(n).is_ok()),
+            InvalidOperation: "too many business days when multiplying duration '{}' by {}",
+            self, rhs
+        );
+        Ok(self * rhs)
+    }
+
+    /// Name of the business calendar of the business days, `None` for Monday to Friday without
+    /// holidays.
+    pub fn calendar(&self) -> Option<&'static str> {
+        self.calendar.map(CalendarName::as_str)
+    }
+
+    /// The calendar is looked up by name each time the duration is applied, so that it reflects
+    /// the calendar registered at that time rather than when the duration was parsed. Serialized
+    /// plans hence only hold the name of the calendar.
+    fn business_calendar(&self) -> PolarsResult<Arc<BusinessCalendar>> {
+        static DEFAULT: LazyLock<Arc<BusinessCalendar>> = LazyLock::new(Default::default);
+        match self.calendar {
+            Some(name) => get_business_calendar(name.as_str()),
+            None => Ok(DEFAULT.clone()),
+        }
+    }
+
     /// Returns whether the duration consists of full days.
     ///
     /// Note that 24 hours is not considered a full day due to possible
@@ -449,11 +577,11 @@ impl Duration {
 
     pub fn is_constant_duration(&self, time_zone: Option<&TimeZone>) -> bool {
         if time_zone.is_none() || time_zone == Some(&TimeZone::UTC) {
-            self.months == 0
+            self.months == 0 && self.business_days == 0
         } else {
             // For non-native, non-UTC time zones, 1 calendar day is not
             // necessarily 24 hours due to daylight savings time.
-            self.months == 0 && self.weeks == 0 && self.days == 0
+            self.months == 0 && self.weeks == 0 && self.days == 0 && self.business_days == 0
         }
     }
 
@@ -468,18 +596,25 @@ impl Duration {
     }
 
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
+    ///
+    /// Like months, which count as 28 days, business days count as their minimum length of one
+    /// day.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
         self.months * 28 * 24 * 3600 * NANOSECONDS
             + self.weeks * NS_WEEK
             + self.days * NS_DAY
+            + self.business_days as i64 * NS_DAY
             + self.nsecs
     }
 
     #[doc(hidden)]
     pub const fn duration_us(&self) -> i64 {
         self.months * 28 * 24 * 3600 * MICROSECONDS
-            + (self.weeks * NS_WEEK / 1000 + self.nsecs / 1000 + self.days * NS_DAY / 1000)
+            + (self.weeks * NS_WEEK / 1000
+                + self.nsecs / 1000
+                + self.days * NS_DAY / 1000
+                + self.business_days as i64 * NS_DAY / 1000)
     }
 
     #[doc(hidden)]
@@ -487,7 +622,8 @@ impl Duration {
         self.months * 28 * 24 * 3600 * MILLISECONDS
             + (self.weeks * NS_WEEK / 1_000_000
                 + self.nsecs / 1_000_000
-                + self.days * NS_DAY / 1_000_000)
+                + self.days * NS_DAY / 1_000_000
+                + self.business_days as i64 * NS_DAY / 1_000_000)
     }
 
     #[doc(hidden)]
@@ -689,6 +825,162 @@ impl Duration {
             _ => Ok(result_t_local),
         }
     }
+    fn truncate_business_daily<G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        _timestamp_to_datetime: G,
+        _datetime_to_timestamp: J,
+        daily_duration: i64,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let _original_dt_utc: Option<NaiveDateTime>;
+        let _original_dt_local: Option<NaiveDateTime>;
+        let t = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                _original_dt_utc = Some(_timestamp_to_datetime(t));
+                _original_dt_local = Some(unlocalize_datetime(_original_dt_utc.unwrap(), tz));
+                _datetime_to_timestamp(_original_dt_local.unwrap())
+            },
+            _ => {
+                _original_dt_utc = None;
+                _original_dt_local = None;
+                t
+            },
+        };
+        // Non-business days belong to the window of the preceding business day. Windows of
+        // multiple business days are counted from the first business day on or after
+        // 1970-01-01.
+        let calendar = self.business_calendar()?;
+        let date = calendar.roll_backward(t.div_euclid(daily_duration) as i32);
+        let origin = calendar.roll_forward(0);
+        let remainder = (calendar.business_day_count(origin, date) as i64)
+            .rem_euclid(self.business_days as i64) as i32;
+        let result_t_local = calendar.add_business_days(date, -remainder) as i64 * daily_duration;
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let result_dt_local = _timestamp_to_datetime(result_t_local);
+                let result_dt_utc = self.localize_result(
+                    _original_dt_local.unwrap(),
+                    _original_dt_utc.unwrap(),
+                    result_dt_local,
+                    tz,
+                )?;
+                Ok(_datetime_to_timestamp(result_dt_utc))
+            },
+            _ => Ok(result_t_local),
+        }
+    }
+
+    fn truncate_fiscal_impl<G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+        _timestamp_to_datetime: G,
+        _datetime_to_timestamp: J,
+        daily_duration: i64,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let _original_dt_utc: Option<NaiveDateTime>;
+        let _original_dt_local: Option<NaiveDateTime>;
+        let t = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                _original_dt_utc = Some(_timestamp_to_datetime(t));
+                _original_dt_local = Some(unlocalize_datetime(_original_dt_utc.unwrap(), tz));
+                _datetime_to_timestamp(_original_dt_local.unwrap())
+            },
+            _ => {
+                _original_dt_utc = None;
+                _original_dt_local = None;
+                t
+            },
+        };
+        let date = calendar.truncate(t.div_euclid(daily_duration) as i32, self)?;
+        let result_t_local = date as i64 * daily_duration;
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let result_dt_local = _timestamp_to_datetime(result_t_local);
+                let result_dt_utc = self.localize_result(
+                    _original_dt_local.unwrap(),
+                    _original_dt_utc.unwrap(),
+                    result_dt_local,
+                    tz,
+                )?;
+                Ok(_datetime_to_timestamp(result_dt_utc))
+            },
+            _ => Ok(result_t_local),
+        }
+    }
+
+    /// Truncate the given ns timestamp to the start of its period of this duration in the
+    /// given fiscal calendar.
+    pub fn truncate_fiscal_ns(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<i64> {
+        self.truncate_fiscal_impl(
+            t,
+            tz,
+            calendar,
+            timestamp_ns_to_datetime,
+            datetime_to_timestamp_ns,
+            NS_DAY,
+        )
+    }
+
+    /// Truncate the given us timestamp to the start of its period of this duration in the
+    /// given fiscal calendar.
+    pub fn truncate_fiscal_us(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<i64> {
+        self.truncate_fiscal_impl(
+            t,
+            tz,
+            calendar,
+            timestamp_us_to_datetime,
+            datetime_to_timestamp_us,
+            NS_DAY / 1000,
+        )
+    }
+
+    /// Truncate the given ms timestamp to the start of its period of this duration in the
+    /// given fiscal calendar.
+    pub fn truncate_fiscal_ms(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<i64> {
+        self.truncate_fiscal_impl(
+            t,
+            tz,
+            calendar,
+            timestamp_ms_to_datetime,
+            datetime_to_timestamp_ms,
+            NS_DAY / 1_000_000,
+        )
+    }
+
     fn truncate_monthly<G, J>(
         &self,
         t: i64,
@@ -787,10 +1079,16 @@ impl Duration {
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
-        match (self.months, self.weeks, self.days, self.nsecs) {
-            (0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
+        match (
+            self.months,
+            self.weeks,
+            self.days,
+            self.business_days,
+            self.nsecs,
+        ) {
+            (0, 0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
             // truncate by ns/us/ms
-            (0, 0, 0, _) => {
+            (0, 0, 0, 0, _) => {
                 let duration = nsecs_to_unit(self.nsecs);
                 self.truncate_subweekly(
                     t,
@@ -801,7 +1099,7 @@ impl Duration {
                 )
             },
             // truncate by days
-            (0, 0, _, 0) => {
+            (0, 0, _, 0, 0) => {
                 let duration = self.days * nsecs_to_unit(NS_DAY);
                 self.truncate_subweekly(
                     t,
@@ -811,8 +1109,19 @@ impl Duration {
                     datetime_to_timestamp,
                 )
             },
+            // truncate by business days
+            (0, 0, 0, _, 0) => {
+                let duration = nsecs_to_unit(NS_DAY);
+                self.truncate_business_daily(
+                    t,
+                    tz,
+                    timestamp_to_datetime,
+                    datetime_to_timestamp,
+                    duration,
+                )
+            },
             // truncate by weeks
-            (0, _, 0, 0) => {
+            (0, _, 0, 0, 0) => {
                 let duration = nsecs_to_unit(NS_DAY);
                 self.truncate_weekly(
                     t,
@@ -823,7 +1132,7 @@ impl Duration {
                 )
             },
             // truncate by months
-            (_, 0, 0, 0) => {
+            (_, 0, 0, 0, 0) => {
                 let duration = nsecs_to_unit(NS_DAY);
                 self.truncate_monthly(
                     t,
@@ -834,7 +1143,7 @@ impl Duration {
                 )
             },
             _ => {
-                polars_bail!(ComputeError: "cannot mix month, week, day, and sub-daily units for this operation")
+                polars_bail!(ComputeError: "cannot mix month, week, day, business day, and sub-daily units for this operation")
             },
         }
     }
@@ -955,9 +1264,101 @@ impl Duration {
             };
         }
 
+        if d.business_days > 0 {
+            let calendar = d.business_calendar()?;
+            let n_days = if d.negative {
+                -d.business_days
+            } else {
+                d.business_days
+            };
+            t = Self::map_local_date(
+                t,
+                tz,
+                nsecs_to_unit(NS_DAY),
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+                |date| calendar.add_business_days(date, n_days),
+            )?;
+        }
+
         Ok(t)
     }
 
+    /// Replace the local date of `t` by `f(date)`, keeping the time of day.
+    fn map_local_date<G, J, F>(
+        t: i64,
+        tz: Option<&Tz>,
+        daily_duration: i64,
+        _timestamp_to_datetime: G,
+        _datetime_to_timestamp: J,
+        f: F,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+        F: Fn(i32) -> i32,
+    {
+        let map = |t: i64| {
+            let date = f(t.div_euclid(daily_duration) as i32);
+            date as i64 * daily_duration + t.rem_euclid(daily_duration)
+        };
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let t = _datetime_to_timestamp(unlocalize_datetime(_timestamp_to_datetime(t), tz));
+                Ok(_datetime_to_timestamp(
+                    try_localize_datetime(
+                        _timestamp_to_datetime(map(t)),
+                        tz,
+                        Ambiguous::Raise,
+                        NonExistent::Raise,
+                    )?
+                    .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+                ))
+            },
+            _ => Ok(map(t)),
+        }
+    }
+
+    /// Roll `t` forward to the same time of day on the first business day on or after it,
+    /// according to the business calendar of this duration.
+    pub(crate) fn roll_forward_to_business_day(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        time_unit: TimeUnit,
+    ) -> PolarsResult<i64> {
+        let calendar = self.business_calendar()?;
+        let roll_forward = |date| calendar.roll_forward(date);
+        match time_unit {
+            TimeUnit::Nanoseconds => Self::map_local_date(
+                t,
+                tz,
+                NS_DAY,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+                roll_forward,
+            ),
+            TimeUnit::Microseconds => Self::map_local_date(
+                t,
+                tz,
+                NS_DAY / 1_000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+                roll_forward,
+            ),
+            TimeUnit::Milliseconds => Self::map_local_date(
+                t,
+                tz,
+                NS_DAY / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+                roll_forward,
+            ),
+        }
+    }
+
     pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         let d = self;
         let new_t = self.add_impl_month_week_or_day(
@@ -1009,11 +1410,107 @@ impl Mul<i64> for Duration {
         self.months *= rhs;
         self.weeks *= rhs;
         self.days *= rhs;
+        // Saturates rather than panics, see [`Duration::try_mul`] for a checked version.
+        self.business_days = (self.business_days as i64)
+            .saturating_mul(rhs)
+            .try_into()
+            .unwrap_or(i32::MAX);
         self.nsecs *= rhs;
         self
     }
 }
 
+/// The maximum number of distinct business calendar names, as interned names are never freed.
+const MAX_CALENDAR_NAMES: usize = 1024;
+
+/// The maximum length of a business calendar name.
+const MAX_CALENDAR_NAME_LEN: usize = 64;
+
+static CALENDAR_NAMES: LazyLock<RwLock<Vec<&'static str>>> = LazyLock::new(Default::default);
+
+/// Name of a business calendar. Names are interned, so that [`Duration`] can remain small and
+/// `Copy`; this refers to the name by its (one-based) position in [`CALENDAR_NAMES`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct CalendarName(NonZeroU16);
+
+impl CalendarName {
+    fn new(name: &str) -> PolarsResult<Self> {
+        polars_ensure!(
+            !name.is_empty()
+                && name.len() <= MAX_CALENDAR_NAME_LEN
+                && name
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.')),
+            InvalidOperation: "invalid business calendar name '{}'; names consist of up to {} ASCII letters, digits, '_', '-' and '.'",
+            name, MAX_CALENDAR_NAME_LEN
+        );
+        let position = CALENDAR_NAMES
+            .read()
+            .unwrap()
+            .iter()
+            .position(|n| *n == name);
+        let position = match position {
+            Some(position) => position,
+            None => {
+                let mut names = CALENDAR_NAMES.write().unwrap();
+                match names.iter().position(|n| *n == name) {
+                    Some(position) => position,
+                    None => {
+                        polars_ensure!(
+                            names.len() < MAX_CALENDAR_NAMES,
+                            InvalidOperation: "cannot use more than {} distinct business calendar names",
+                            MAX_CALENDAR_NAMES
+                        );
+                        names.push(Box::leak(name.into()));
+                        names.len() - 1
+                    },
+                }
+            },
+        };
+        Ok(Self(NonZeroU16::new(position as u16 + 1).unwrap()))
+    }
+
+    fn as_str(self) -> &'static str {
+        CALENDAR_NAMES.read().unwrap()[self.0.get() as usize - 1]
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CalendarName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CalendarName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        Self::new(&name).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for CalendarName {
+    fn schema_name() -> String {
+        String::schema_name()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        String::schema_id()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(generator)
+    }
+}
+
 fn new_datetime(
     year: i32,
     month: u32,
@@ -1126,5 +1623,99 @@ mod test {
         let duration = Duration::parse_interval("4 weeks");
         let expected = "4w";
         assert_eq!(format!("{duration}"), expected);
+        let duration = Duration::parse("1d3bd[us-nyse]2h");
+        let expected = "1d3bd[us-nyse]7200s";
+        assert_eq!(format!("{duration}"), expected);
+        assert_eq!(Duration::parse(expected), duration);
+    }
+
+    #[test]
+    fn test_parse_business_days() {
+        let out = Duration::parse("3bd");
+        assert_eq!(out.business_days(), 3);
+        assert_eq!(out.calendar(), None);
+        assert!(!out.is_constant_duration(None));
+        let out = Duration::parse("-2bd[nyse]");
+        assert_eq!(out.business_days(), 2);
+        assert_eq!(out.calendar(), Some("nyse"));
+        assert!(out.negative);
+
+        assert!(Duration::try_parse("3bd[nyse").is_err());
+        assert!(Duration::try_parse("3bd[]").is_err());
+        assert!(Duration::try_parse("1bd[nyse]1bd[lse]").is_err());
+        assert!(Duration::try_parse("1bd[ny se]").is_err());
+        assert!(Duration::try_parse(&format!("1bd[{}]", "x".repeat(65))).is_err());
+        assert!(Duration::try_parse_interval("3bd").is_err());
+
+        let out = Duration::parse("1000000000bd");
+        assert_eq!(out.try_mul(-2).unwrap().business_days(), 2_000_000_000);
+        assert!(out.try_mul(3).is_err());
+        assert_eq!((out * 3).business_days(), i32::MAX as i64);
+    }
+
+    fn ts(date: &str, time: &str) -> i64 {
+        let dt = NaiveDateTime::new(date.parse().unwrap(), time.parse().unwrap());
+        datetime_to_timestamp_ns(dt)
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let one = Duration::parse("1bd");
+        assert_eq!(
+            one.add_ns(ts("2024-01-05", "12:00:00"), None).unwrap(),
+            ts("2024-01-08", "12:00:00")
+        );
+        assert_eq!(
+            one.add_ns(ts("2024-01-06", "12:00:00"), None).unwrap(),
+            ts("2024-01-08", "12:00:00")
+        );
+        assert_eq!(
+            (-one).add_ns(ts("2024-01-06", "12:00:00"), None).unwrap(),
+            ts("2024-01-05", "12:00:00")
+        );
+
+        let holidays = [ts("2024-01-08", "00:00:00").div_euclid(NS_DAY) as i32];
+        polars_ops::series::register_business_calendar(
+            "test_add_business_days".into(),
+            BusinessCalendar::try_new([true, true, true, true, true, false, false], &holidays)
+                .unwrap(),
+        );
+        let one = Duration::parse("1bd[test_add_business_days]");
+        assert_eq!(
+            one.add_ns(ts("2024-01-05", "12:00:00"), None).unwrap(),
+            ts("2024-01-09", "12:00:00")
+        );
+
+        let unknown = Duration::parse("1bd[test_unknown_calendar]");
+        assert!(unknown.add_ns(ts("2024-01-05", "12:00:00"), None).is_err());
+    }
+
+    #[test]
+    fn test_truncate_business_days() {
+        let one = Duration::parse("1bd");
+        assert_eq!(
+            one.truncate_ns(ts("2024-01-07", "10:00:00"), None).unwrap(),
+            ts("2024-01-05", "00:00:00")
+        );
+        assert_eq!(
+            one.truncate_ns(ts("2024-01-08", "10:00:00"), None).unwrap(),
+            ts("2024-01-08", "00:00:00")
+        );
+
+        // Windows of two business days start on 1970-01-01, 1970-01-05, ..., 2024-01-04 and
+        // 2024-01-08.
+        let two = Duration::parse("2bd");
+        assert_eq!(
+            two.truncate_ns(ts("2024-01-07", "10:00:00"), None).unwrap(),
+            ts("2024-01-04", "00:00:00")
+        );
+        assert_eq!(
+            two.truncate_ns(ts("2024-01-09", "10:00:00"), None).unwrap(),
+            ts("2024-01-08", "00:00:00")
+        );
+        assert_eq!(
+            two.truncate_ns(ts("1969-12-31", "10:00:00"), None).unwrap(),
+            ts("1969-12-30", "00:00:00")
+        );
     }
 }
@@ -0,0 +1,269 @@
+use arrow::temporal_conversions::{EPOCH_DAYS_FROM_CE, date32_to_date};
+use chrono::{Datelike, NaiveDate};
+use polars_core::prelude::{PolarsResult, polars_bail, polars_ensure};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::duration::Duration;
+
+/// How the twelve periods ("fiscal months") of a fiscal year are laid out.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalPattern {
+    /// Every period is a calendar month.
+    #[default]
+    Monthly,
+    /// Retail calendar whose quarters consist of periods of 4, 4 and 5 weeks.
+    Weeks445,
+    /// Retail calendar whose quarters consist of periods of 4, 5 and 4 weeks.
+    Weeks454,
+    /// Retail calendar whose quarters consist of periods of 5, 4 and 4 weeks.
+    Weeks544,
+}
+
+impl FiscalPattern {
+    /// Number of weeks in each period of a quarter, or `None` for calendar months.
+    fn weeks_per_period(&self) -> Option<[i32; 3]> {
+        match self {
+            Self::Monthly => None,
+            Self::Weeks445 => Some([4, 4, 5]),
+            Self::Weeks454 => Some([4, 5, 4]),
+            Self::Weeks544 => Some([5, 4, 4]),
+        }
+    }
+}
+
+/// A fiscal calendar whose year starts in an arbitrary month.
+///
+/// Fiscal years are named after the calendar year in which they end, so with a `start_month`
+/// of 4 the fiscal year 2025 runs from April 2024 up to and including March 2025. A
+/// `start_month` of 1 gives the Gregorian calendar year.
+///
+/// For the week-based retail patterns, each fiscal year starts on the `week_start` weekday
+/// nearest to the first day of its starting month. Fiscal years then consist of 52 or 53 whole
+/// weeks, and the extra week of a 53-week year is added to its last period.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FiscalCalendar {
+    // the calendar month (1-12) in which the fiscal year starts
+    start_month: u8,
+    pattern: FiscalPattern,
+    // the ISO weekday (1 = Monday, 7 = Sunday) on which fiscal weeks start
+    week_start: u8,
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> Self {
+        Self {
+            start_month: 1,
+            pattern: FiscalPattern::Monthly,
+            week_start: 1,
+        }
+    }
+}
+
+fn first_of_month(year: i32, month: u32) -> i32 {
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .expect("out-of-range date")
+        .num_days_from_ce()
+        - EPOCH_DAYS_FROM_CE
+}
+
+impl FiscalCalendar {
+    pub fn try_new(start_month: u8, pattern: FiscalPattern, week_start: u8) -> PolarsResult<Self> {
+        polars_ensure!(
+            (1..=12).contains(&start_month),
+            ComputeError: "fiscal year start month must be between 1 and 12, got {}", start_month
+        );
+        polars_ensure!(
+            (1..=7).contains(&week_start),
+            ComputeError: "fiscal week start must be an ISO weekday between 1 and 7, got {}", week_start
+        );
+        Ok(Self {
+            start_month,
+            pattern,
+            week_start,
+        })
+    }
+
+    pub fn start_month(&self) -> u8 {
+        self.start_month
+    }
+
+    pub fn pattern(&self) -> FiscalPattern {
+        self.pattern
+    }
+
+    pub fn week_start(&self) -> u8 {
+        self.week_start
+    }
+
+    /// First day of the calendar month in which `fiscal_year` nominally starts.
+    fn nominal_year_start(&self, fiscal_year: i32) -> i32 {
+        let year = fiscal_year - (self.start_month > 1) as i32;
+        first_of_month(year, self.start_month as u32)
+    }
+
+    /// First day of the given fiscal year.
+    pub fn year_start(&self, fiscal_year: i32) -> i32 {
+        let date = self.nominal_year_start(fiscal_year);
+        match self.pattern {
+            FiscalPattern::Monthly => date,
+            _ => {
+                // 1970-01-01 was a Thursday.
+                let weekday = (date + 3).rem_euclid(7) + 1;
+                let shift = (self.week_start as i32 - weekday).rem_euclid(7);
+                if shift > 3 {
+                    date + shift - 7
+                } else {
+                    date + shift
+                }
+            },
+        }
+    }
+
+    /// Fiscal year of the given date.
+    pub fn fiscal_year(&self, date: i32) -> i32 {
+        let nd = date32_to_date(date);
+        let fiscal_year =
+            nd.year() + (self.start_month > 1 && nd.month() >= self.start_month as u32) as i32;
+        // Week-based fiscal years start at most three days away from their nominal start.
+        if date < self.year_start(fiscal_year) {
+            fiscal_year - 1
+        } else if date >= self.year_start(fiscal_year + 1) {
+            fiscal_year + 1
+        } else {
+            fiscal_year
+        }
+    }
+
+    /// Fiscal quarter (1-4) of the given date.
+    pub fn fiscal_quarter(&self, date: i32) -> i8 {
+        let (_, period) = self.fiscal_period(date);
+        (period / 3 + 1) as i8
+    }
+
+    /// The first days of the twelve periods of `fiscal_year`, followed by the first day of the
+    /// next fiscal year.
+    fn period_starts(&self, fiscal_year: i32) -> [i32; 13] {
+        let mut starts = [0; 13];
+        match self.pattern.weeks_per_period() {
+            None => {
+                let year = fiscal_year - (self.start_month > 1) as i32;
+                for (i, start) in starts.iter_mut().enumerate() {
+                    let month = self.start_month as i32 - 1 + i as i32;
+                    *start = first_of_month(year + month / 12, (month % 12 + 1) as u32);
+                }
+            },
+            Some(weeks) => {
+                starts[0] = self.year_start(fiscal_year);
+                for i in 1..12 {
+                    starts[i] = starts[i - 1] + 7 * weeks[(i - 1) % 3];
+                }
+                starts[12] = self.year_start(fiscal_year + 1);
+            },
+        }
+        starts
+    }
+
+    /// Fiscal year and zero-based period of the given date.
+    fn fiscal_period(&self, date: i32) -> (i32, usize) {
+        let fiscal_year = self.fiscal_year(date);
+        let starts = self.period_starts(fiscal_year);
+        let period = starts[1..].partition_point(|&start| start <= date);
+        (fiscal_year, period)
+    }
+
+    /// Truncate `date` to the start of its fiscal period of `every`, which must be a whole number
+    /// of months that evenly divides a year. Months are the periods of the fiscal calendar, so
+    /// `"1mo"` truncates to the fiscal period, `"1q"` to the fiscal quarter and `"1y"` to the
+    /// fiscal year.
+    pub fn truncate(&self, date: i32, every: &Duration) -> PolarsResult<i32> {
+        let months = every.months();
+        if every.negative || !every.months_only() || 12 % months != 0 {
+            polars_bail!(
+                ComputeError: "fiscal truncation requires a duration of whole months that evenly divides a year (e.g. '1mo', '1q' or '1y'), got '{}'", every
+            )
+        }
+        let (fiscal_year, period) = self.fiscal_period(date);
+        let period = period - period % months as usize;
+        Ok(self.period_starts(fiscal_year)[period])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    #[test]
+    fn test_fiscal_monthly() {
+        let calendar = FiscalCalendar::try_new(4, FiscalPattern::Monthly, 1).unwrap();
+        assert_eq!(calendar.fiscal_year(date(2024, 3, 31)), 2024);
+        assert_eq!(calendar.fiscal_quarter(date(2024, 3, 31)), 4);
+        assert_eq!(calendar.fiscal_year(date(2024, 4, 1)), 2025);
+        assert_eq!(calendar.fiscal_quarter(date(2024, 4, 1)), 1);
+        assert_eq!(calendar.fiscal_quarter(date(2024, 12, 31)), 3);
+
+        let t = date(2024, 8, 15);
+        let truncate = |every| calendar.truncate(t, &Duration::parse(every)).unwrap();
+        assert_eq!(truncate("1mo"), date(2024, 8, 1));
+        assert_eq!(truncate("1q"), date(2024, 7, 1));
+        assert_eq!(truncate("6mo"), date(2024, 4, 1));
+        assert_eq!(truncate("1y"), date(2024, 4, 1));
+
+        // The default calendar is the Gregorian one.
+        let calendar = FiscalCalendar::default();
+        assert_eq!(calendar.fiscal_year(date(2024, 3, 31)), 2024);
+        assert_eq!(calendar.fiscal_quarter(date(2024, 3, 31)), 1);
+    }
+
+    #[test]
+    fn test_fiscal_weeks() {
+        // Retail calendar with years starting on the Sunday nearest to February 1st. Fiscal year
+        // 2024 runs from 2023-01-29 up to and including 2024-02-03 and has 53 weeks.
+        let calendar = FiscalCalendar::try_new(2, FiscalPattern::Weeks445, 7).unwrap();
+        assert_eq!(calendar.year_start(2024), date(2023, 1, 29));
+        assert_eq!(calendar.year_start(2025), date(2024, 2, 4));
+        assert_eq!(calendar.fiscal_year(date(2023, 1, 28)), 2023);
+        assert_eq!(calendar.fiscal_year(date(2023, 1, 29)), 2024);
+        assert_eq!(calendar.fiscal_year(date(2024, 2, 3)), 2024);
+        assert_eq!(calendar.fiscal_quarter(date(2024, 2, 3)), 4);
+        assert_eq!(calendar.fiscal_year(date(2024, 2, 4)), 2025);
+        assert_eq!(calendar.fiscal_quarter(date(2024, 2, 4)), 1);
+        assert_eq!(calendar.fiscal_quarter(date(2023, 4, 29)), 1);
+        assert_eq!(calendar.fiscal_quarter(date(2023, 4, 30)), 2);
+
+        // The extra week is added to the last period.
+        let t = date(2024, 2, 3);
+        let truncate = |every| calendar.truncate(t, &Duration::parse(every)).unwrap();
+        assert_eq!(truncate("1mo"), date(2023, 12, 24));
+        assert_eq!(truncate("1q"), date(2023, 10, 29));
+        assert_eq!(truncate("1y"), date(2023, 1, 29));
+
+        let calendar = FiscalCalendar::try_new(2, FiscalPattern::Weeks544, 7).unwrap();
+        let truncate = |every| {
+            calendar
+                .truncate(date(2023, 3, 5), &Duration::parse(every))
+                .unwrap()
+        };
+        assert_eq!(truncate("1mo"), date(2023, 3, 5));
+        assert_eq!(truncate("2mo"), date(2023, 1, 29));
+    }
+
+    #[test]
+    fn test_fiscal_invalid() {
+        assert!(FiscalCalendar::try_new(0, FiscalPattern::Monthly, 1).is_err());
+        assert!(FiscalCalendar::try_new(1, FiscalPattern::Weeks445, 8).is_err());
+
+        let calendar = FiscalCalendar::default();
+        for every in ["1w", "5mo", "2y", "1mo1d", "-1mo"] {
+            assert!(calendar.truncate(0, &Duration::parse(every)).is_err());
+        }
+    }
+}
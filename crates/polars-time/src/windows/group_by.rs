@@ -72,7 +72,7 @@ impl StartBy {
 
 #[allow(clippy::too_many_arguments)]
 fn update_groups_and_bounds(
-    bounds_iter: BoundsIter<'_>,
+    bounds_iter: impl Iterator<Item = Bounds>,
     mut start: usize,
     time: &[i64],
     closed_window: ClosedWindow,
@@ -219,6 +219,112 @@ pub fn group_by_windows(
     Ok((groups, lower_bound, upper_bound))
 }
 
+/// Incremental variant of [`group_by_windows`] for a time column that arrives in sorted chunks.
+///
+/// `time` must hold all values that may still belong to a window that was not returned yet, and
+/// `next` the first such window, which is `None` on the first call. Unless `is_last` is set, only
+/// the windows that are closed are returned, i.e. the windows that can't contain any value
+/// following `time`. Afterwards `next` holds the first window that was not returned.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn group_by_closed_windows(
+    window: Window,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: &Option<TimeZone>,
+    include_lower_bound: bool,
+    include_upper_bound: bool,
+    start_by: StartBy,
+    next: &mut Option<Bounds>,
+    is_last: bool,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => closed_windows_impl(
+            window,
+            time,
+            closed_window,
+            tu,
+            tz.parse::<Tz>().ok().as_ref(),
+            include_lower_bound,
+            include_upper_bound,
+            start_by,
+            next,
+            is_last,
+        ),
+        _ => closed_windows_impl(
+            window,
+            time,
+            closed_window,
+            tu,
+            None,
+            include_lower_bound,
+            include_upper_bound,
+            start_by,
+            next,
+            is_last,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn closed_windows_impl(
+    window: Window,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+    include_lower_bound: bool,
+    include_upper_bound: bool,
+    start_by: StartBy,
+    next: &mut Option<Bounds>,
+    is_last: bool,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    let mut lower_bound = Vec::new();
+    let mut upper_bound = Vec::new();
+    let mut groups = Vec::new();
+    let Some(&last) = time.last() else {
+        return Ok((groups, lower_bound, upper_bound));
+    };
+
+    // Same boundary as `group_by_windows` uses once all values are known.
+    let stop = if is_last { last + 1 } else { i64::MAX };
+    let mut bounds_iter = match *next {
+        Some(bi) => BoundsIter::resume(window, bi, Bounds::new(bi.start, stop), tu, tz),
+        None => window.get_overlapping_bounds_iter(
+            Bounds::new_checked(time[0], stop),
+            closed_window,
+            tu,
+            tz,
+            start_by,
+        )?,
+    };
+
+    let mut windows = Vec::new();
+    while is_last || bounds_iter.peek().is_future(last, closed_window) {
+        match bounds_iter.next() {
+            Some(bi) => windows.push(bi),
+            None => break,
+        }
+    }
+    *next = Some(bounds_iter.peek());
+
+    if !windows.is_empty() {
+        update_groups_and_bounds(
+            windows.into_iter(),
+            0,
+            time,
+            closed_window,
+            include_lower_bound,
+            include_upper_bound,
+            &mut lower_bound,
+            &mut upper_bound,
+            &mut groups,
+        );
+    }
+    Ok((groups, lower_bound, upper_bound))
+}
+
 // t is right at the end of the window
 // ------t---
 // [------]
@@ -781,6 +887,37 @@ pub fn group_by_values(
     }
 }
 
+/// Splits the sorted `time` values into sessions. A session ends when no value follows within
+/// `gap` of its last value, so a new session starts at every value that is at least `gap` past
+/// the value before it.
+///
+/// Returns the `[offset, len]` of every session.
+pub fn group_by_session(
+    gap: Duration,
+    time: &[i64],
+    tu: TimeUnit,
+    tz: Option<Tz>,
+) -> PolarsResult<GroupsSlice> {
+    let add = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for (i, w) in time.windows(2).enumerate() {
+        if w[1] >= add(&gap, w[0], tz.as_ref())? {
+            groups.push([start as IdxSize, (i + 1 - start) as IdxSize]);
+            start = i + 1;
+        }
+    }
+    if !time.is_empty() {
+        groups.push([start as IdxSize, (time.len() - start) as IdxSize]);
+    }
+    Ok(groups)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -794,4 +931,15 @@ mod test {
         prune_splits_on_duplicates(time, &mut splits);
         assert_eq!(splits, &[(0, 6), (6, 2), (8, 3)]);
     }
+
+    #[test]
+    fn test_group_by_session() {
+        let gap = Duration::parse("3i");
+        let time = &[0, 1, 1, 3, 6, 10, 12, 15];
+        let groups = group_by_session(gap, time, TimeUnit::Nanoseconds, None).unwrap();
+        assert_eq!(groups, &[[0, 4], [4, 1], [5, 2], [7, 1]]);
+
+        let groups = group_by_session(gap, &[], TimeUnit::Nanoseconds, None).unwrap();
+        assert!(groups.is_empty());
+    }
 }
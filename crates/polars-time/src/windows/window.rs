@@ -75,6 +75,36 @@ impl Window {
         self.every.truncate_ms(t, tz)
     }
 
+    /// Truncate the given ns timestamp to the window boundary in the given fiscal calendar.
+    pub fn truncate_fiscal_ns(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<i64> {
+        self.every.truncate_fiscal_ns(t, tz, calendar)
+    }
+
+    /// Truncate the given us timestamp to the window boundary in the given fiscal calendar.
+    pub fn truncate_fiscal_us(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<i64> {
+        self.every.truncate_fiscal_us(t, tz, calendar)
+    }
+
+    /// Truncate the given ms timestamp to the window boundary in the given fiscal calendar.
+    pub fn truncate_fiscal_ms(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        calendar: &FiscalCalendar,
+    ) -> PolarsResult<i64> {
+        self.every.truncate_fiscal_ms(t, tz, calendar)
+    }
+
     /// Round the given ns timestamp by the window boundary.
     pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
         let t = t + self.every.duration_ns() / 2_i64;
@@ -314,6 +344,29 @@ impl<'a> BoundsIter<'a> {
             tz,
         })
     }
+
+    /// Continue iterating at the window `bi`, e.g. one that was obtained from
+    /// [`BoundsIter::peek`] on an earlier iterator.
+    pub(crate) fn resume(
+        window: Window,
+        bi: Bounds,
+        boundary: Bounds,
+        tu: TimeUnit,
+        tz: Option<&'a Tz>,
+    ) -> Self {
+        Self {
+            window,
+            boundary,
+            bi,
+            tu,
+            tz,
+        }
+    }
+
+    /// The window that is returned next, ignoring the wrapping boundary.
+    pub(crate) fn peek(&self) -> Bounds {
+        self.bi
+    }
 }
 
 impl Iterator for BoundsIter<'_> {
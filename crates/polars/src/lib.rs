@@ -185,6 +185,9 @@
 //! * `strings` - Extra string utilities for [`StringChunked`]
 //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_similarity` - `levenshtein`, `damerau_levenshtein`, `jaro_winkler`, `hamming`, `jaccard`
+//!     - `string_format` - `format_str_with_specs` for per-placeholder format specs
+//!     - `string_url` - `parse_url`, `url_query_param`, `percent_encode`, `percent_decode`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!   These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -261,6 +264,10 @@
 //!     - `list_count` - Count elements in lists.
 //!     - `list_eval` - Apply expressions over list elements.
 //!     - `list_sets` - Compute UNION, INTERSECTION, and DIFFERENCE on list types.
+//!     - `list_flatten` - Flatten nested lists and reshape lists into arrays.
+//!     - `list_index_of` - Find the first index of an element in lists.
+//!     - `list_pad` - Pad lists to a given length.
+//!     - `list_zip` - Zip two lists into a list of structs.
 //!     - `cumulative_eval` - Apply expressions over cumulatively increasing windows.
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
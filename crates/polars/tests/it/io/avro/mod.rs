@@ -2,5 +2,7 @@
 
 mod read;
 mod read_async;
+#[cfg(feature = "lazy")]
+mod scan;
 mod write;
 mod write_async;
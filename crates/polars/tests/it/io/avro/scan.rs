@@ -0,0 +1,106 @@
+use std::fs::File;
+
+use polars::io::SerWriter;
+use polars::io::avro::{AvroCodec, AvroReader, AvroWriter};
+use polars::io::prelude::SerReader;
+use polars::prelude::*;
+use polars_io::RowIndex;
+
+/// Writes `n` rows spread over multiple Avro blocks.
+fn write_blocks(path: &std::path::Path, n: i64, rows_per_block: i64) -> PolarsResult<DataFrame> {
+    let mut df = DataFrame::empty_with_schema(&Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+
+    for start in (0..n).step_by(rows_per_block as usize) {
+        let a: Vec<i64> = (start..(start + rows_per_block).min(n)).collect();
+        let b: Vec<Option<String>> = a
+            .iter()
+            .map(|v| (v % 7 != 0).then(|| format!("{v}")))
+            .collect();
+        df.vstack_mut(&df!("a" => a, "b" => b)?)?;
+    }
+
+    AvroWriter::new(File::create(path)?).finish(&mut df)?;
+    Ok(df)
+}
+
+fn scan(path: &std::path::Path, args: ScanArgsAvro) -> PolarsResult<LazyFrame> {
+    LazyFrame::scan_avro(PlPath::new(path.to_str().unwrap()), args)
+}
+
+#[test]
+fn test_scan_avro() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("scan.avro");
+    let df = write_blocks(&path, 1000, 75)?;
+
+    let out = scan(&path, Default::default())?.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&df));
+
+    // Projection in a different order than the file.
+    let out = scan(&path, Default::default())?
+        .select([col("b"), col("a")])
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&df.select(["b", "a"])?));
+
+    // Slices that start and end in the middle of a block.
+    for (offset, len) in [(0, 10), (80, 300), (990, 100), (-120, 50)] {
+        let out = scan(&path, Default::default())?
+            .slice(offset, len)
+            .collect_with_engine(Engine::Streaming)?;
+        assert!(out.equals_missing(&df.slice(offset, len as usize)));
+    }
+
+    // Row index combined with a slice.
+    let args = ScanArgsAvro {
+        row_index: Some(RowIndex {
+            name: "index".into(),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let out = scan(&path, args)?
+        .slice(160, 20)
+        .collect_with_engine(Engine::Streaming)?;
+    let expected = df.with_row_index("index".into(), Some(10))?.slice(160, 20);
+    assert!(out.equals_missing(&expected));
+
+    let out = scan(&path, Default::default())?
+        .select([len()])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+
+    Ok(())
+}
+
+#[test]
+fn test_sink_avro() -> PolarsResult<()> {
+    let dir = tempfile::tempdir()?;
+    let in_path = dir.path().join("in.avro");
+    let out_path = dir.path().join("out.avro");
+    let df = write_blocks(&in_path, 1000, 75)?;
+
+    for compression in [None, Some(AvroCodec::Deflate), Some(AvroCodec::Snappy)] {
+        let options = AvroWriterOptions {
+            compression,
+            name: "record".into(),
+        };
+        scan(&in_path, Default::default())?
+            .filter(col("a").gt(lit(100)))
+            .sink_avro(
+                SinkTarget::Path(PlPath::new(out_path.to_str().unwrap())),
+                options,
+                None,
+                SinkOptions::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+
+        let out = AvroReader::new(File::open(&out_path)?).finish()?;
+        let expected = df.clone().lazy().filter(col("a").gt(lit(100))).collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    Ok(())
+}
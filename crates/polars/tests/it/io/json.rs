@@ -185,3 +185,101 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_json() -> PolarsResult<()> {
+    use polars::prelude::*;
+    use polars_io::RowIndex;
+    use polars_io::json::JsonPath;
+    use polars_utils::mmap::MemSlice;
+
+    let json = r#"{
+    "meta": {"count": 5, "items": [{"a": -1}]},
+    "data": {
+        "items": [
+            {"a": 1, "b": "x"},
+            {"a": 2, "b": "y\nz"},
+            {
+                "b": "w",
+                "a": 3
+            },
+            {"a": 4},
+            {"a": 5, "b": "v"}
+        ]
+    }
+}"#;
+
+    let scan = |path: &str| -> PolarsResult<LazyFrame> {
+        LazyJsonReader::new_with_sources(ScanSources::Buffers(
+            [MemSlice::from_vec(json.as_bytes().to_vec())].into(),
+        ))
+        .with_json_path(JsonPath::parse(path)?)
+        .finish()
+    };
+
+    let expected = df!(
+        "a" => [1i64, 2, 3, 4, 5],
+        "b" => [Some("x"), Some("y\nz"), Some("w"), None, Some("v")],
+    )?;
+
+    let out = scan("$.data.items")?.collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&expected));
+
+    let out = scan("$.data.items")?
+        .select([col("b")])
+        .slice(1, 3)
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&expected.select(["b"])?.slice(1, 3)));
+
+    let out = scan("$.data.items")?
+        .tail(2)
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&expected.tail(Some(2))));
+
+    let out = scan("$.data.items")?
+        .select([len()])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(5));
+
+    let out = LazyJsonReader::new_with_sources(ScanSources::Buffers(
+        [MemSlice::from_vec(json.as_bytes().to_vec())].into(),
+    ))
+    .with_json_path(JsonPath::parse("$.data.items")?)
+    .with_row_index(Some(RowIndex {
+        name: "index".into(),
+        offset: 0,
+    }))
+    .finish()?
+    .filter(col("index").gt_eq(lit(3)))
+    .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals_missing(&expected.with_row_index("index".into(), None)?.slice(3, 2)));
+
+    // The document itself is a single row.
+    let out = scan("$.meta")?.collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.shape(), (1, 2));
+
+    assert!(scan("$.missing")?.collect().is_err());
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_json_top_level_array() -> PolarsResult<()> {
+    use polars::prelude::*;
+    use polars_utils::mmap::MemSlice;
+
+    let json = r#"[{"a": 1, "b": [1, 2]}, {"a": 2, "b": null}, {"a": 3, "b": []}]"#;
+
+    let out = LazyJsonReader::new_with_sources(ScanSources::Buffers(
+        [MemSlice::from_vec(json.as_bytes().to_vec())].into(),
+    ))
+    .finish()?
+    .collect_with_engine(Engine::Streaming)?;
+
+    let expected = JsonReader::new(Cursor::new(json)).finish()?;
+    assert!(out.equals_missing(&expected));
+
+    Ok(())
+}
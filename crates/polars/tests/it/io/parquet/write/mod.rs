@@ -297,3 +297,109 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+fn test_write_parquet_bloom_filter() -> PolarsResult<()> {
+    use polars::io::parquet::write::{
+        BloomFilterOptions, ChildFieldOverwrites, ParquetFieldOverwrites,
+    };
+    use polars_parquet::parquet::bloom_filter;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "id" => [10i64, 20, 30],
+        "name" => ["a", "b", "c"],
+        "value" => [1.0, 2.0, 3.0]
+    )?;
+
+    let bloom_filter = |name: &str| ParquetFieldOverwrites {
+        name: Some(name.into()),
+        children: ChildFieldOverwrites::None,
+        required: None,
+        field_id: None,
+        metadata: None,
+        bloom_filter: Some(BloomFilterOptions::default()),
+    };
+    ParquetWriter::new(&mut buf)
+        .with_field_overwrites(vec![bloom_filter("id"), bloom_filter("name")])
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+
+    let mut bitset = vec![];
+    bloom_filter::read(&columns[0], &mut buf, &mut bitset)?;
+    assert!(bloom_filter::is_in_set(
+        &bitset,
+        bloom_filter::hash_native(20i64)
+    ));
+    assert!(!bloom_filter::is_in_set(
+        &bitset,
+        bloom_filter::hash_native(40i64)
+    ));
+
+    bloom_filter::read(&columns[1], &mut buf, &mut bitset)?;
+    assert!(bloom_filter::is_in_set(
+        &bitset,
+        bloom_filter::hash_byte("c")
+    ));
+    assert!(!bloom_filter::is_in_set(
+        &bitset,
+        bloom_filter::hash_byte("d")
+    ));
+
+    // No bloom filter was requested for `value`.
+    bloom_filter::read(&columns[2], &mut buf, &mut bitset)?;
+    assert!(bitset.is_empty());
+
+    buf.set_position(0);
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df_read.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_page_index() -> PolarsResult<()> {
+    use polars_parquet::parquet::read::page_index::{
+        column_index_byte_range, deserialize_column_index, deserialize_offset_index,
+        offset_index_byte_range,
+    };
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!("a" => (0..10_000i64).collect::<Vec<_>>())?;
+
+    ParquetWriter::new(&mut buf)
+        .with_data_page_size(Some(4096))
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let metadata = read_metadata(&mut buf)?;
+    let column = &metadata.row_groups[0].parquet_columns()[0];
+    let bytes = buf.get_ref();
+
+    let range = column_index_byte_range(column).unwrap();
+    let column_index = deserialize_column_index(&bytes[range.start as usize..range.end as usize])?;
+    let range = offset_index_byte_range(column).unwrap();
+    let offset_index = deserialize_offset_index(&bytes[range.start as usize..range.end as usize])?;
+
+    let num_pages = offset_index.page_locations.len();
+    assert!(num_pages > 1);
+    assert_eq!(column_index.min_values.len(), num_pages);
+    assert_eq!(offset_index.page_locations[0].first_row_index, 0);
+
+    // Pages are contiguous, sorted slices of the column.
+    for (i, page) in offset_index.page_locations.iter().enumerate() {
+        let min = i64::from_le_bytes(column_index.min_values[i].as_slice().try_into().unwrap());
+        let max = i64::from_le_bytes(column_index.max_values[i].as_slice().try_into().unwrap());
+        assert_eq!(min, page.first_row_index);
+
+        let end = offset_index
+            .page_locations
+            .get(i + 1)
+            .map_or(10_000, |p| p.first_row_index);
+        assert_eq!(max, end - 1);
+    }
+
+    Ok(())
+}
@@ -127,6 +127,7 @@ from polars.functions import (
     first,
     fold,
     format,
+    format_with_specs,
     from_epoch,
     groups,
     head,
@@ -152,6 +153,7 @@ from polars.functions import (
     ones,
     quantile,
     reduce,
+    register_business_calendar,
     repeat,
     rolling_corr,
     rolling_cov,
@@ -169,6 +171,7 @@ from polars.functions import (
     time,
     time_range,
     time_ranges,
+    unregister_business_calendar,
     var,
     when,
     zeros,
@@ -200,10 +203,12 @@ from polars.io import (
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
+    scan_avro,
     scan_csv,
     scan_delta,
     scan_iceberg,
     scan_ipc,
+    scan_json,
     scan_ndjson,
     scan_parquet,
     scan_pyarrow_dataset,
@@ -312,10 +317,12 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
+    "scan_json",
     "scan_ndjson",
     "scan_parquet",
     "scan_pyarrow_dataset",
@@ -397,6 +404,7 @@ __all__ = [
     "first",
     "fold",
     "format",
+    "format_with_specs",
     "from_epoch",
     "groups",
     "head",
@@ -415,6 +423,7 @@ __all__ = [
     "nth",
     "quantile",
     "reduce",
+    "register_business_calendar",
     "rolling_corr",
     "rolling_cov",
     "row_index",
@@ -423,6 +432,7 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "unregister_business_calendar",
     "var",
     # polars.functions.len
     "len",
@@ -146,6 +146,7 @@ QuantileMethod: TypeAlias = Literal[
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
+FiscalPattern: TypeAlias = Literal["monthly", "4-4-5", "4-5-4", "5-4-4"]
 RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
@@ -182,7 +183,9 @@ MapElementsStrategy: TypeAlias = Literal["thread_local", "threading"]
 # The following have a Rust enum equivalent with a different name
 AsofJoinStrategy: TypeAlias = Literal["backward", "forward", "nearest"]  # AsofStrategy
 ClosedInterval: TypeAlias = Literal["left", "right", "both", "none"]  # ClosedWindow
-InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
+InterpolationMethod: TypeAlias = Literal[
+    "linear", "nearest", "previous", "next", "cubic", "akima", "pchip"
+]
 JoinStrategy: TypeAlias = Literal[
     "inner", "left", "right", "full", "semi", "anti", "cross", "outer"
 ]  # JoinType
@@ -211,6 +214,7 @@ Orientation: TypeAlias = Literal["col", "row"]
 SearchSortedSide: TypeAlias = Literal["any", "left", "right"]
 TorchExportType: TypeAlias = Literal["tensor", "dataset", "dict"]
 TransferEncoding: TypeAlias = Literal["hex", "base64"]
+StringTransferEncoding: TypeAlias = Literal["hex", "base64", "percent"]
 WindowMappingStrategy: TypeAlias = Literal["group_to_rows", "join", "explode"]
 ExplainFormat: TypeAlias = Literal["plain", "tree"]
 
@@ -396,6 +400,7 @@ __all__ = [
     "ExplainFormat",
     "FileSource",
     "FillNullStrategy",
+    "FiscalPattern",
     "FloatFmt",
     "FrameInitTypes",
     "FrameType",
@@ -447,6 +452,7 @@ __all__ = [
     "SingleNameSelector",
     "SizeUnit",
     "StartBy",
+    "StringTransferEncoding",
     "SyncOnCloseMethod",
     "TemporalLiteral",
     "TimeUnit",
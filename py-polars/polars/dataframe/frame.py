@@ -64,7 +64,12 @@ from polars._utils.various import (
 )
 from polars._utils.wrap import wrap_expr, wrap_ldf, wrap_s
 from polars.dataframe._html import NotebookFormatter
-from polars.dataframe.group_by import DynamicGroupBy, GroupBy, RollingGroupBy
+from polars.dataframe.group_by import (
+    DynamicGroupBy,
+    GroupBy,
+    RollingGroupBy,
+    SessionGroupBy,
+)
 from polars.dataframe.plotting import DataFramePlot
 from polars.datatypes import (
     N_INFER_DEFAULT,
@@ -6803,6 +6808,7 @@ class DataFrame:
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -6816,6 +6822,10 @@ class DataFrame:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        By "business day", we mean Monday to Friday, or the business days of the
+        calendar `name` registered with :func:`register_business_calendar` when
+        written as `"1bd[name]"`.
+
         .. versionchanged:: 0.20.14
             The `by` parameter was renamed `group_by`.
 
@@ -7046,6 +7056,7 @@ class DataFrame:
            - 1m    (1 minute)
            - 1h    (1 hour)
            - 1d    (1 calendar day)
+           - 1bd   (1 business day)
            - 1w    (1 calendar week)
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
@@ -7059,6 +7070,10 @@ class DataFrame:
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
            "calendar month", "calendar quarter", and "calendar year".
 
+           By "business day", we mean Monday to Friday, or the business days of the
+           calendar `name` registered with :func:`register_business_calendar` when
+           written as `"1bd[name]"`.
+
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
@@ -7238,6 +7253,118 @@ class DataFrame:
             start_by=start_by,
         )
 
+    @unstable()
+    def group_by_session(
+        self,
+        index_column: IntoExpr,
+        *,
+        gap: str | timedelta,
+        group_by: IntoExpr | Iterable[IntoExpr] | None = None,
+    ) -> SessionGroupBy:
+        """
+        Group based on sessions of a time value (or index value of type Int32, Int64).
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        A session is a run of rows in which each value of the index column is less than
+        `gap` past the value before it; a session thus ends after an inactivity of
+        `gap`. Different from `group_by_dynamic`, the windows are not of constant
+        length and every row is member of exactly one group. The index column of the
+        result holds the first value of every session.
+
+        .. warning::
+            The index column must be sorted in ascending order. If `group_by` is passed, then
+            the index column must be sorted in ascending order within each group.
+
+        Parameters
+        ----------
+        index_column
+            Column used to group based on the sessions.
+            Often of type Date/Datetime.
+            This column must be sorted in ascending order (or, if `group_by` is specified,
+            then it must be sorted in ascending order within each group).
+
+            In case of a session group by on indices, dtype needs to be one of
+            {Int32, Int64}.
+        gap
+            Period of inactivity after which a session ends. A new session starts at
+            every value that is at least `gap` past the value before it.
+        group_by
+            Also group by this column/these columns; sessions are then determined
+            within each group.
+
+        Returns
+        -------
+        SessionGroupBy
+            Object you can call `.agg` on to aggregate by groups, the result
+            of which will be sorted by `index_column` (but note that if `group_by` columns are
+            passed, it will only be sorted within each group).
+
+        See Also
+        --------
+        group_by_dynamic
+        polars.Expr.dt.session_id
+
+        Notes
+        -----
+        `gap` is created according to the following string language:
+
+        - 1ns   (1 nanosecond)
+        - 1us   (1 microsecond)
+        - 1ms   (1 millisecond)
+        - 1s    (1 second)
+        - 1m    (1 minute)
+        - 1h    (1 hour)
+        - 1d    (1 calendar day)
+        - 1w    (1 calendar week)
+        - 1mo   (1 calendar month)
+        - 1q    (1 calendar quarter)
+        - 1y    (1 calendar year)
+        - 1i    (1 index count)
+
+        Or combine them:
+        "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2021, 12, 16, 0, 0),
+        ...             datetime(2021, 12, 16, 0, 10),
+        ...             datetime(2021, 12, 16, 1, 0),
+        ...             datetime(2021, 12, 16, 1, 20),
+        ...             datetime(2021, 12, 16, 3, 0),
+        ...         ],
+        ...         "clicks": [1, 2, 3, 4, 5],
+        ...     }
+        ... )
+        >>> df.group_by_session("time", gap="30m").agg(
+        ...     pl.len(),
+        ...     pl.col("time").last().alias("last"),
+        ...     pl.col("clicks").sum(),
+        ... )
+        shape: (3, 4)
+        ┌─────────────────────┬─────┬─────────────────────┬────────┐
+        │ time                ┆ len ┆ last                ┆ clicks │
+        │ ---                 ┆ --- ┆ ---                 ┆ ---    │
+        │ datetime[μs]        ┆ u32 ┆ datetime[μs]        ┆ i64    │
+        ╞═════════════════════╪═════╪═════════════════════╪════════╡
+        │ 2021-12-16 00:00:00 ┆ 2   ┆ 2021-12-16 00:10:00 ┆ 3      │
+        │ 2021-12-16 01:00:00 ┆ 2   ┆ 2021-12-16 01:20:00 ┆ 7      │
+        │ 2021-12-16 03:00:00 ┆ 1   ┆ 2021-12-16 03:00:00 ┆ 5      │
+        └─────────────────────┴─────┴─────────────────────┴────────┘
+        """  # noqa: W505
+        return SessionGroupBy(
+            self,
+            index_column=index_column,
+            gap=gap,
+            group_by=group_by,
+        )
+
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
     def upsample(
         self,
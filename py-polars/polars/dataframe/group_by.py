@@ -1065,3 +1065,131 @@ class DynamicGroupBy:
             .map_groups(function, schema)
             .collect(optimizations=QueryOptFlags.none())
         )
+
+
+class SessionGroupBy:
+    """
+    A session grouper.
+
+    This has an `.agg` method which allows you to run all polars expressions in a
+    group by context.
+    """
+
+    def __init__(
+        self,
+        df: DataFrame,
+        index_column: IntoExpr,
+        *,
+        gap: str | timedelta,
+        group_by: IntoExpr | Iterable[IntoExpr] | None,
+    ) -> None:
+        gap = parse_as_duration_string(gap)
+
+        self.df = df
+        self.time_column = index_column
+        self.gap = gap
+        self.group_by = group_by
+
+    def __iter__(self) -> Self:
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        temp_col = "__POLARS_GB_GROUP_INDICES"
+        groups_df = (
+            self.df.lazy()
+            .group_by_session(
+                index_column=self.time_column,
+                gap=self.gap,
+                group_by=self.group_by,
+            )
+            .agg(F.first().agg_groups().alias(temp_col))
+            .collect(optimizations=QueryOptFlags.none())
+        )
+
+        self._group_names = groups_df.select(F.all().exclude(temp_col)).iter_rows()
+        self._group_indices = groups_df.select(temp_col).to_series()
+        self._current_index = 0
+
+        return self
+
+    def __next__(self) -> tuple[tuple[object, ...], DataFrame]:
+        if self._current_index >= len(self._group_indices):
+            raise StopIteration
+
+        group_name = next(self._group_names)
+        group_data = self.df[self._group_indices[self._current_index], :]
+        self._current_index += 1
+
+        return group_name, group_data
+
+    def agg(
+        self,
+        *aggs: IntoExpr | Iterable[IntoExpr],
+        **named_aggs: IntoExpr,
+    ) -> DataFrame:
+        """
+        Compute aggregations for each group of a group by operation.
+
+        Parameters
+        ----------
+        *aggs
+            Aggregations to compute for each group of the group by operation,
+            specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names.
+        **named_aggs
+            Additional aggregations, specified as keyword arguments.
+            The resulting columns will be renamed to the keyword used.
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.df.lazy()
+            .group_by_session(
+                index_column=self.time_column,
+                gap=self.gap,
+                group_by=self.group_by,
+            )
+            .agg(*aggs, **named_aggs)
+            .collect(optimizations=QueryOptFlags.none())
+        )
+
+    def map_groups(
+        self,
+        function: Callable[[DataFrame], DataFrame],
+        schema: SchemaDict | None,
+    ) -> DataFrame:
+        """
+        Apply a custom/user-defined function (UDF) over the groups as a new DataFrame.
+
+        Using this is considered an anti-pattern as it will be very slow because:
+
+        - it forces the engine to materialize the whole `DataFrames` for the groups.
+        - it is not parallelized.
+        - it blocks optimizations as the passed python function is opaque to the
+          optimizer.
+
+        The idiomatic way to apply custom functions over multiple columns is using:
+
+        `pl.struct([my_columns]).map_elements(lambda struct_series: ..)`
+
+        Parameters
+        ----------
+        function
+            Function to apply over each group of the `LazyFrame`; it receives
+            a DataFrame and should return a DataFrame.
+        schema
+            Schema of the output function. This has to be known statically. If the
+            given schema is incorrect, this is a bug in the caller's query and may
+            lead to errors. If set to None, polars assumes the schema is unchanged.
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.df.lazy()
+            .group_by_session(
+                index_column=self.time_column,
+                gap=self.gap,
+                group_by=self.group_by,
+            )
+            .map_groups(function, schema)
+            .collect(optimizations=QueryOptFlags.none())
+        )
@@ -21,6 +21,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         Ambiguous,
         EpochTimeUnit,
+        FiscalPattern,
         IntoExpr,
         IntoExprColumn,
         NonExistent,
@@ -202,6 +203,7 @@ class ExprDateTimeNameSpace:
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -211,6 +213,10 @@ class ExprDateTimeNameSpace:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        By "business day", we mean Monday to Friday, or the business days of the
+        calendar `name` registered with :func:`register_business_calendar` when
+        written as `"1bd[name]"`.
+
         Returns
         -------
         Expr
@@ -294,6 +300,157 @@ class ExprDateTimeNameSpace:
         every = parse_into_expression(every, str_as_lit=True)
         return wrap_expr(self._pyexpr.dt_truncate(every))
 
+    @unstable()
+    def truncate_fiscal(
+        self,
+        every: str | Expr,
+        start_month: int = 1,
+        *,
+        pattern: FiscalPattern = "monthly",
+        week_start: int = 1,
+    ) -> Expr:
+        """
+        Divide the date/datetime range into the periods of a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Each date/datetime is mapped to the start of its fiscal period using the
+        corresponding local datetime.
+
+        Fiscal years are named after the calendar year in which they end, so with
+        `start_month=4` the fiscal year 2025 runs from April 2024 up to and including
+        March 2025.
+
+        Parameters
+        ----------
+        every
+            The size of each bucket, which must be a whole number of months that
+            evenly divides a year. Months are the periods of the fiscal calendar, so
+            `'1mo'` truncates to the fiscal period, `'1q'` to the fiscal quarter and
+            `'1y'` to the fiscal year.
+        start_month
+            The calendar month (1-12) in which the fiscal year starts.
+        pattern : {'monthly', '4-4-5', '4-5-4', '5-4-4'}
+            How the fiscal year is divided into periods.
+
+            - 'monthly': every period is a calendar month.
+            - '4-4-5', '4-5-4', '5-4-4': retail calendars of 52 or 53 whole weeks,
+              whose quarters consist of periods of the given numbers of weeks. Each
+              fiscal year starts on the `week_start` weekday nearest to the first day
+              of `start_month`, and the extra week of a 53-week year is added to its
+              last period.
+        week_start
+            The ISO weekday (1 = Monday, 7 = Sunday) on which the weeks of the retail
+            calendars start. Ignored for the 'monthly' pattern.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Date` or :class:`Datetime`.
+
+        See Also
+        --------
+        truncate
+        fiscal_year
+        fiscal_quarter
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 5, 15), date(2024, 8, 15), date(2025, 2, 1)]}
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("date").dt.truncate_fiscal("1q", 4).alias("truncated")
+        ... )
+        shape: (3, 2)
+        ┌────────────┬────────────┐
+        │ date       ┆ truncated  │
+        │ ---        ┆ ---        │
+        │ date       ┆ date       │
+        ╞════════════╪════════════╡
+        │ 2024-05-15 ┆ 2024-04-01 │
+        │ 2024-08-15 ┆ 2024-07-01 │
+        │ 2025-02-01 ┆ 2025-01-01 │
+        └────────────┴────────────┘
+        """
+        every = parse_into_expression(every, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.dt_truncate_fiscal(every, start_month, pattern, week_start)
+        )
+
+    @unstable()
+    def session_id(self, gap: str | dt.timedelta) -> Expr:
+        """
+        Assign a session id to every value of a sorted time or index column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Applies to Date and Datetime columns, and to index columns of type Int32 or
+        Int64.
+
+        A session ends after an inactivity of `gap`: a new session starts at every
+        value that is at least `gap` past the value before it. Session ids start at 0
+        and increase by one with every session, similar to :func:`Expr.rle_id`. The
+        values must be sorted in ascending order and may not contain nulls.
+
+        Parameters
+        ----------
+        gap
+            Period of inactivity after which a session ends, e.g. `"30m"`, or
+            `"5i"` for index columns. Accepts the same string language as
+            :func:`DataFrame.group_by_dynamic`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        polars.DataFrame.group_by_session
+        polars.Expr.rle_id
+
+        Examples
+        --------
+        Sessionize a clickstream per user with :func:`Expr.over`.
+
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "user": ["a", "a", "b", "a", "b"],
+        ...         "time": [
+        ...             datetime(2021, 12, 16, 0, 0),
+        ...             datetime(2021, 12, 16, 0, 10),
+        ...             datetime(2021, 12, 16, 0, 15),
+        ...             datetime(2021, 12, 16, 1, 0),
+        ...             datetime(2021, 12, 16, 1, 30),
+        ...         ],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("time").dt.session_id("30m").over("user").alias("session")
+        ... )
+        shape: (5, 3)
+        ┌──────┬─────────────────────┬─────────┐
+        │ user ┆ time                ┆ session │
+        │ ---  ┆ ---                 ┆ ---     │
+        │ str  ┆ datetime[μs]        ┆ u32     │
+        ╞══════╪═════════════════════╪═════════╡
+        │ a    ┆ 2021-12-16 00:00:00 ┆ 0       │
+        │ a    ┆ 2021-12-16 00:10:00 ┆ 0       │
+        │ b    ┆ 2021-12-16 00:15:00 ┆ 0       │
+        │ a    ┆ 2021-12-16 01:00:00 ┆ 1       │
+        │ b    ┆ 2021-12-16 01:30:00 ┆ 1       │
+        └──────┴─────────────────────┴─────────┘
+        """
+        gap = parse_as_duration_string(gap)
+        return wrap_expr(self._pyexpr.dt_session_id(gap))
+
     def round(self, every: str | dt.timedelta | IntoExprColumn) -> Expr:
         """
         Divide the date/datetime range into buckets.
@@ -1097,6 +1254,157 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_quarter())
 
+    @unstable()
+    def fiscal_year(
+        self,
+        start_month: int = 1,
+        *,
+        pattern: FiscalPattern = "monthly",
+        week_start: int = 1,
+    ) -> Expr:
+        """
+        Extract the fiscal year from underlying Date representation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Applies to Date and Datetime columns.
+
+        Fiscal years are named after the calendar year in which they end, so with
+        `start_month=4` the fiscal year 2025 runs from April 2024 up to and including
+        March 2025.
+
+        Parameters
+        ----------
+        start_month
+            The calendar month (1-12) in which the fiscal year starts.
+        pattern : {'monthly', '4-4-5', '4-5-4', '5-4-4'}
+            How the fiscal year is divided into periods.
+
+            - 'monthly': every period is a calendar month.
+            - '4-4-5', '4-5-4', '5-4-4': retail calendars of 52 or 53 whole weeks,
+              whose quarters consist of periods of the given numbers of weeks. Each
+              fiscal year starts on the `week_start` weekday nearest to the first day
+              of `start_month`, and the extra week of a 53-week year is added to its
+              last period.
+        week_start
+            The ISO weekday (1 = Monday, 7 = Sunday) on which the weeks of the retail
+            calendars start. Ignored for the 'monthly' pattern.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        See Also
+        --------
+        fiscal_quarter
+        truncate_fiscal
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]}
+        ... )
+        >>> df.with_columns(pl.col("date").dt.fiscal_year(4).alias("fiscal_year"))
+        shape: (3, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ fiscal_year │
+        │ ---        ┆ ---         │
+        │ date       ┆ i32         │
+        ╞════════════╪═════════════╡
+        │ 2024-03-31 ┆ 2024        │
+        │ 2024-04-01 ┆ 2025        │
+        │ 2024-12-31 ┆ 2025        │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_year(start_month, pattern, week_start))
+
+    @unstable()
+    def fiscal_quarter(
+        self,
+        start_month: int = 1,
+        *,
+        pattern: FiscalPattern = "monthly",
+        week_start: int = 1,
+    ) -> Expr:
+        """
+        Extract the fiscal quarter from underlying Date representation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter ranging from 1 to 4.
+
+        Parameters
+        ----------
+        start_month
+            The calendar month (1-12) in which the fiscal year starts.
+        pattern : {'monthly', '4-4-5', '4-5-4', '5-4-4'}
+            How the fiscal year is divided into periods.
+
+            - 'monthly': every period is a calendar month.
+            - '4-4-5', '4-5-4', '5-4-4': retail calendars of 52 or 53 whole weeks,
+              whose quarters consist of periods of the given numbers of weeks. Each
+              fiscal year starts on the `week_start` weekday nearest to the first day
+              of `start_month`, and the extra week of a 53-week year is added to its
+              last period.
+        week_start
+            The ISO weekday (1 = Monday, 7 = Sunday) on which the weeks of the retail
+            calendars start. Ignored for the 'monthly' pattern.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        See Also
+        --------
+        fiscal_year
+        truncate_fiscal
+
+        Examples
+        --------
+        A 4-4-5 retail calendar whose years start on the Sunday nearest to
+        February 1st:
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [
+        ...             date(2023, 1, 28),
+        ...             date(2023, 1, 29),
+        ...             date(2023, 4, 30),
+        ...             date(2024, 2, 3),
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     pl.col("date")
+        ...     .dt.fiscal_quarter(2, pattern="4-4-5", week_start=7)
+        ...     .alias("fiscal_quarter")
+        ... )
+        shape: (4, 2)
+        ┌────────────┬────────────────┐
+        │ date       ┆ fiscal_quarter │
+        │ ---        ┆ ---            │
+        │ date       ┆ i8             │
+        ╞════════════╪════════════════╡
+        │ 2023-01-28 ┆ 4              │
+        │ 2023-01-29 ┆ 1              │
+        │ 2023-04-30 ┆ 2              │
+        │ 2024-02-03 ┆ 4              │
+        └────────────┴────────────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.dt_fiscal_quarter(start_month, pattern, week_start)
+        )
+
     def month(self) -> Expr:
         """
         Extract month from underlying Date representation.
@@ -2372,6 +2680,7 @@ class ExprDateTimeNameSpace:
             - 1m    (1 minute)
             - 1h    (1 hour)
             - 1d    (1 calendar day)
+            - 1bd   (1 business day)
             - 1w    (1 calendar week)
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
@@ -2381,6 +2690,10 @@ class ExprDateTimeNameSpace:
             not be 24 hours, due to daylight savings). Similarly for "calendar week",
             "calendar month", "calendar quarter", and "calendar year".
 
+            By "business day", we mean Monday to Friday, or the business days of the
+            calendar `name` registered with :func:`register_business_calendar` when
+            written as `"1bd[name]"`.
+
         Returns
         -------
         Expr
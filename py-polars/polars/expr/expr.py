@@ -3652,6 +3652,7 @@ class Expr:
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -3665,6 +3666,10 @@ class Expr:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        By "business day", we mean Monday to Friday, or the business days of the
+        calendar `name` registered with :func:`register_business_calendar` when
+        written as `"1bd[name]"`.
+
         Parameters
         ----------
         index_column
@@ -6150,9 +6155,22 @@ Consider using {self}.implode() instead"""
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'akima', 'pchip'}
             Interpolation method.
 
+            - 'linear': connect the surrounding values with a straight line.
+            - 'nearest': use the nearest non-null value.
+            - 'previous': use the previous non-null value.
+            - 'next': use the next non-null value.
+            - 'cubic': natural cubic spline through all non-null values.
+            - 'akima': Akima spline, which is less prone to overshoot around
+              outliers than 'cubic'.
+            - 'pchip': piecewise cubic Hermite interpolation, which preserves
+              the monotonicity of the data.
+
+            'nearest', 'previous' and 'next' keep the data type, the other
+            methods return floating point values.
+
         Examples
         --------
         Fill null values using linear interpolation.
@@ -6189,6 +6207,28 @@ Consider using {self}.implode() instead"""
         │ 3   ┆ 3.0 │
         └─────┴─────┘
 
+        Unlike a cubic spline, PCHIP interpolation does not overshoot the data.
+
+        >>> df = pl.DataFrame({"a": [0.0, None, 0.0, None, 1.0, None, 1.0]})
+        >>> df.with_columns(
+        ...     cubic=pl.col("a").interpolate("cubic"),
+        ...     pchip=pl.col("a").interpolate("pchip"),
+        ... )
+        shape: (7, 3)
+        ┌──────┬───────┬───────┐
+        │ a    ┆ cubic ┆ pchip │
+        │ ---  ┆ ---   ┆ ---   │
+        │ f64  ┆ f64   ┆ f64   │
+        ╞══════╪═══════╪═══════╡
+        │ 0.0  ┆ 0.0   ┆ 0.0   │
+        │ null ┆ -0.1  ┆ 0.0   │
+        │ 0.0  ┆ 0.0   ┆ 0.0   │
+        │ null ┆ 0.5   ┆ 0.5   │
+        │ 1.0  ┆ 1.0   ┆ 1.0   │
+        │ null ┆ 1.1   ┆ 1.0   │
+        │ 1.0  ┆ 1.0   ┆ 1.0   │
+        └──────┴───────┴───────┘
+
         Regrid data to a new grid.
 
         >>> df_original_grid = pl.DataFrame(
@@ -6221,7 +6261,9 @@ Consider using {self}.implode() instead"""
         """
         return wrap_expr(self._pyexpr.interpolate(method))
 
-    def interpolate_by(self, by: IntoExpr) -> Expr:
+    def interpolate_by(
+        self, by: IntoExpr, method: InterpolationMethod = "linear"
+    ) -> Expr:
         """
         Fill null values using interpolation based on another column.
 
@@ -6231,6 +6273,20 @@ Consider using {self}.implode() instead"""
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'akima', 'pchip'}
+            Interpolation method.
+
+            - 'linear': connect the surrounding values with a straight line.
+            - 'nearest': use the non-null value with the nearest `by` value.
+            - 'previous': use the previous non-null value.
+            - 'next': use the next non-null value.
+            - 'cubic': natural cubic spline through all non-null values.
+            - 'akima': Akima spline, which is less prone to overshoot around
+              outliers than 'cubic'.
+            - 'pchip': piecewise cubic Hermite interpolation, which preserves
+              the monotonicity of the data.
+
+            The result is always a floating point column.
 
         Examples
         --------
@@ -6256,7 +6312,7 @@ Consider using {self}.implode() instead"""
         └──────┴─────┴────────────────┘
         """
         by = parse_into_expression(by)
-        return wrap_expr(self._pyexpr.interpolate_by(by))
+        return wrap_expr(self._pyexpr.interpolate_by(by, method))
 
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
@@ -715,6 +715,41 @@ class ExprListNameSpace:
         item = parse_into_expression(item, str_as_lit=True)
         return wrap_expr(self._pyexpr.list_contains(item, nulls_equal))
 
+    def index_of(self, element: IntoExpr) -> Expr:
+        """
+        Get the index of the first occurrence of `element` in every sublist.
+
+        Parameters
+        ----------
+        element
+            Value to search for. A null value finds the first null element.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32` or :class:`UInt64`
+            (depending on compilation). The value is null if the element does not
+            occur in the sublist.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 2, 1], [1, 2, 1], [4, 5], None]})
+        >>> df.with_columns(index=pl.col("a").list.index_of(1))
+        shape: (4, 2)
+        ┌───────────┬───────┐
+        │ a         ┆ index │
+        │ ---       ┆ ---   │
+        │ list[i64] ┆ u32   │
+        ╞═══════════╪═══════╡
+        │ [3, 2, 1] ┆ 2     │
+        │ [1, 2, 1] ┆ 0     │
+        │ [4, 5]    ┆ null  │
+        │ null      ┆ null  │
+        └───────────┴───────┘
+        """
+        element = parse_into_expression(element, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_index_of(element))
+
     def join(self, separator: IntoExprColumn, *, ignore_nulls: bool = True) -> Expr:
         """
         Join all string items in a sublist and place a separator between them.
@@ -1006,6 +1041,41 @@ class ExprListNameSpace:
         n = parse_into_expression(n)
         return wrap_expr(self._pyexpr.list_tail(n))
 
+    def pad_end(
+        self, length: int | IntoExprColumn, fill_value: IntoExpr = None
+    ) -> Expr:
+        """
+        Pad every sublist at the end until it has `length` elements.
+
+        Sublists that already have `length` or more elements are left as they are.
+
+        Parameters
+        ----------
+        length
+            Length of the padded sublists.
+        fill_value
+            Value to pad with. It is cast to the inner data type of the list.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1], [2, 3], [4, 5, 6], None]})
+        >>> df.with_columns(padded=pl.col("a").list.pad_end(3, 0))
+        shape: (4, 2)
+        ┌───────────┬───────────┐
+        │ a         ┆ padded    │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [1]       ┆ [1, 0, 0] │
+        │ [2, 3]    ┆ [2, 3, 0] │
+        │ [4, 5, 6] ┆ [4, 5, 6] │
+        │ null      ┆ null      │
+        └───────────┴───────────┘
+        """
+        length = parse_into_expression(length)
+        fill_value = parse_into_expression(fill_value, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_pad_end(length, fill_value))
+
     def explode(self) -> Expr:
         """
         Returns a column with a separate row for every list element.
@@ -1039,6 +1109,35 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.explode())
 
+    def flatten(self, depth: int | None = None) -> Expr:
+        """
+        Remove levels of nesting by concatenating the nested lists of every sublist.
+
+        Null nested lists are skipped.
+
+        Parameters
+        ----------
+        depth
+            Number of nesting levels to remove. By default, all nested `List` and
+            `Array` levels are removed.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[[1, 2], [3]], [[4], None, []], None]})
+        >>> df.with_columns(flat=pl.col("a").list.flatten())
+        shape: (3, 2)
+        ┌─────────────────┬───────────┐
+        │ a               ┆ flat      │
+        │ ---             ┆ ---       │
+        │ list[list[i64]] ┆ list[i64] │
+        ╞═════════════════╪═══════════╡
+        │ [[1, 2], [3]]   ┆ [1, 2, 3] │
+        │ [[4], null, []] ┆ [4]       │
+        │ null            ┆ null      │
+        └─────────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_flatten(depth))
+
     def count_matches(self, element: IntoExpr) -> Expr:
         """
         Count how often the value produced by `element` occurs.
@@ -1101,6 +1200,39 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_to_array(width))
 
+    def reshape(self, dimensions: tuple[int, ...]) -> Expr:
+        """
+        Reshape every sublist into a (nested) Array of the given dimensions.
+
+        Nested lists are flattened first, so every sublist must contain exactly as
+        many values as the product of the dimensions.
+
+        Parameters
+        ----------
+        dimensions
+            Tuple of the dimension sizes of the resulting Array.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 2, 3, 4], [5, 6, 7, 8]]})
+        >>> df.select(pl.col("a").list.reshape((2, 2)))
+        shape: (2, 1)
+        ┌────────────────────┐
+        │ a                  │
+        │ ---                │
+        │ array[i64, (2, 2)] │
+        ╞════════════════════╡
+        │ [[1, 2], [3, 4]]   │
+        │ [[5, 6], [7, 8]]   │
+        └────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.list_reshape(list(dimensions)))
+
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
@@ -1431,3 +1563,38 @@ class ExprListNameSpace:
         else:
             other = parse_into_expression(other)
         return wrap_expr(self._pyexpr.list_set_operation(other, "symmetric_difference"))
+
+    def zip(self, other: IntoExpr) -> Expr:
+        """
+        Zip the elements of every sublist with the elements of the sublist in `other`.
+
+        The result is a list of structs with the fields `field_0` and `field_1`. The
+        sublists are truncated to the length of the shorter input.
+
+        Parameters
+        ----------
+        other
+            List expression to zip with. Strings are parsed as column names.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [[1, 2], [3], []],
+        ...         "b": [["x", "y"], ["z", "w"], ["v"]],
+        ...     }
+        ... )
+        >>> df.select(zipped=pl.col("a").list.zip("b"))
+        shape: (3, 1)
+        ┌────────────────────┐
+        │ zipped             │
+        │ ---                │
+        │ list[struct[2]]    │
+        ╞════════════════════╡
+        │ [{1,"x"}, {2,"y"}] │
+        │ [{3,"z"}]          │
+        │ []                 │
+        └────────────────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.list_zip(other))
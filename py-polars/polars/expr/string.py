@@ -26,8 +26,8 @@ if TYPE_CHECKING:
         PolarsDataType,
         PolarsIntegerType,
         PolarsTemporalType,
+        StringTransferEncoding,
         TimeUnit,
-        TransferEncoding,
         UnicodeForm,
     )
     from polars._utils.various import NoDefault
@@ -1338,14 +1338,15 @@ class ExprStringNameSpace:
         json_path = parse_into_expression(json_path, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_json_path_match(json_path))
 
-    def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Expr:
+    def decode(self, encoding: StringTransferEncoding, *, strict: bool = True) -> Expr:
         r"""
         Decode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'percent'}
+            The encoding to use. `'percent'` decodes the `%XX` escapes of
+            percent-encoded (URL-encoded) strings.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -1369,23 +1370,45 @@ class ExprStringNameSpace:
         │ ffff00 ┆ b"\xff\xff\x00" │
         │ 0000ff ┆ b"\x00\x00\xff" │
         └────────┴─────────────────┘
+
+        Percent-decoded values can be cast back to :class:`String`.
+
+        >>> df = pl.DataFrame({"query": ["caf%C3%A9", "a%20b%2Fc"]})
+        >>> df.with_columns(
+        ...     pl.col("query").str.decode("percent").cast(pl.String).alias("decoded")
+        ... )
+        shape: (2, 2)
+        ┌───────────┬─────────┐
+        │ query     ┆ decoded │
+        │ ---       ┆ ---     │
+        │ str       ┆ str     │
+        ╞═══════════╪═════════╡
+        │ caf%C3%A9 ┆ café    │
+        │ a%20b%2Fc ┆ a b/c   │
+        └───────────┴─────────┘
         """
         if encoding == "hex":
             return wrap_expr(self._pyexpr.str_hex_decode(strict))
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.str_base64_decode(strict))
+        elif encoding == "percent":
+            return wrap_expr(self._pyexpr.str_percent_decode(strict))
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'percent'}, "
+                f"got {encoding!r}"
+            )
             raise ValueError(msg)
 
-    def encode(self, encoding: TransferEncoding) -> Expr:
+    def encode(self, encoding: StringTransferEncoding) -> Expr:
         """
         Encode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'percent'}
+            The encoding to use. `'percent'` escapes every byte other than the
+            unreserved URL characters `A-Z a-z 0-9 - . _ ~` as `%XX`.
 
         Returns
         -------
@@ -1411,10 +1434,110 @@ class ExprStringNameSpace:
             return wrap_expr(self._pyexpr.str_hex_encode())
         elif encoding == "base64":
             return wrap_expr(self._pyexpr.str_base64_encode())
+        elif encoding == "percent":
+            return wrap_expr(self._pyexpr.str_percent_encode())
         else:
-            msg = f"`encoding` must be one of {{'hex', 'base64'}}, got {encoding!r}"
+            msg = (
+                "`encoding` must be one of {'hex', 'base64', 'percent'}, "
+                f"got {encoding!r}"
+            )
             raise ValueError(msg)
 
+    def parse_url(self, *, strict: bool = True) -> Expr:
+        """
+        Parse URLs into their components.
+
+        URLs are parsed and normalized following the WHATWG URL Standard. Strings
+        that start with `/`, such as request paths in server logs, are parsed as URLs
+        without a scheme (and without a host, unless they start with `//`).
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid URL, otherwise mask it out with
+            a null value.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with fields `scheme`, `host`,
+            `port` (:class:`UInt16`, only set if given explicitly), `path`, `query`
+            and `fragment`.
+
+        See Also
+        --------
+        url_query_param : Extract a query parameter from URLs.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"url": ["https://example.com:8080/a/b?q=1#top", "/api/v1?id=7", "bad"]}
+        ... )
+        >>> df.select(pl.col("url").str.parse_url(strict=False)).unnest("url")
+        shape: (3, 6)
+        ┌────────┬─────────────┬──────┬─────────┬───────┬──────────┐
+        │ scheme ┆ host        ┆ port ┆ path    ┆ query ┆ fragment │
+        │ ---    ┆ ---         ┆ ---  ┆ ---     ┆ ---   ┆ ---      │
+        │ str    ┆ str         ┆ u16  ┆ str     ┆ str   ┆ str      │
+        ╞════════╪═════════════╪══════╪═════════╪═══════╪══════════╡
+        │ https  ┆ example.com ┆ 8080 ┆ /a/b    ┆ q=1   ┆ top      │
+        │ null   ┆ null        ┆ null ┆ /api/v1 ┆ id=7  ┆ null     │
+        │ null   ┆ null        ┆ null ┆ null    ┆ null  ┆ null     │
+        └────────┴─────────────┴──────┴─────────┴───────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_url(strict))
+
+    def url_query_param(self, name: str | Expr) -> Expr:
+        """
+        Extract the value of a query parameter from URLs.
+
+        The value is decoded (`+` and `%XX` escapes included). If a parameter occurs
+        multiple times, its first value is returned, and URLs without the parameter
+        yield null.
+
+        Parameters
+        ----------
+        name
+            Name of the query parameter.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        See Also
+        --------
+        parse_url : Parse URLs into their components.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://x.com/search?q=polars+rust&page=2",
+        ...             "/search?page=3",
+        ...             None,
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     q=pl.col("url").str.url_query_param("q"),
+        ...     page=pl.col("url").str.url_query_param("page"),
+        ... )
+        shape: (3, 3)
+        ┌───────────────────────────────────────────┬─────────────┬──────┐
+        │ url                                       ┆ q           ┆ page │
+        │ ---                                       ┆ ---         ┆ ---  │
+        │ str                                       ┆ str         ┆ str  │
+        ╞═══════════════════════════════════════════╪═════════════╪══════╡
+        │ https://x.com/search?q=polars+rust&page=2 ┆ polars rust ┆ 2    │
+        │ /search?page=3                            ┆ null        ┆ 3    │
+        │ null                                      ┆ null        ┆ null │
+        └───────────────────────────────────────────┴─────────────┴──────┘
+        """
+        name = parse_into_expression(name, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_url_query_param(name))
+
     def extract(self, pattern: IntoExprColumn, group_index: int = 1) -> Expr:
         r"""
         Extract the target capture group from provided patterns.
@@ -1731,16 +1854,20 @@ class ExprStringNameSpace:
         pattern = parse_into_expression(pattern, str_as_lit=True)
         return wrap_expr(self._pyexpr.str_count_matches(pattern, literal))
 
-    def split(self, by: IntoExpr, *, inclusive: bool = False) -> Expr:
+    def split(
+        self, by: IntoExpr, *, inclusive: bool = False, literal: bool = True
+    ) -> Expr:
         """
-        Split the string by a substring.
+        Split the string by a substring or regex pattern.
 
         Parameters
         ----------
         by
-            Substring to split by.
+            Substring to split by, or a regex pattern if `literal` is False.
         inclusive
             If True, include the split character/string in the results.
+        literal
+            Treat `by` as a literal string, not as a regular expression.
 
         Examples
         --------
@@ -1780,19 +1907,40 @@ class ExprStringNameSpace:
         │ foo*bar*baz ┆ *   ┆ ["foo", "bar", "baz"] ┆ ["foo*", "bar*", "baz"] │
         └─────────────┴─────┴───────────────────────┴─────────────────────────┘
 
+        Split on the matches of a regex pattern.
+
+        >>> df = pl.DataFrame({"s": ["foo1bar22baz", "foo", "foo 3"]})
+        >>> df.with_columns(
+        ...     pl.col("s").str.split(r"\d+", literal=False).alias("split"),
+        ... )
+        shape: (3, 2)
+        ┌──────────────┬───────────────────────┐
+        │ s            ┆ split                 │
+        │ ---          ┆ ---                   │
+        │ str          ┆ list[str]             │
+        ╞══════════════╪═══════════════════════╡
+        │ foo1bar22baz ┆ ["foo", "bar", "baz"] │
+        │ foo          ┆ ["foo"]               │
+        │ foo 3        ┆ ["foo ", ""]          │
+        └──────────────┴───────────────────────┘
+
         Returns
         -------
         Expr
             Expression of data type :class:`String`.
         """
         by = parse_into_expression(by, str_as_lit=True)
+        if not literal:
+            return wrap_expr(self._pyexpr.str_split_regex(by, inclusive))
         if inclusive:
             return wrap_expr(self._pyexpr.str_split_inclusive(by))
         return wrap_expr(self._pyexpr.str_split(by))
 
-    def split_exact(self, by: IntoExpr, n: int, *, inclusive: bool = False) -> Expr:
+    def split_exact(
+        self, by: IntoExpr, n: int, *, inclusive: bool = False, literal: bool = True
+    ) -> Expr:
         """
-        Split the string by a substring using `n` splits.
+        Split the string by a substring or regex pattern using `n` splits.
 
         Results in a struct of `n+1` fields.
 
@@ -1801,11 +1949,13 @@ class ExprStringNameSpace:
         Parameters
         ----------
         by
-            Substring to split by.
+            Substring to split by, or a regex pattern if `literal` is False.
         n
             Number of splits to make.
         inclusive
             If True, include the split character/string in the results.
+        literal
+            Treat `by` as a literal string, not as a regular expression.
 
         Returns
         -------
@@ -1854,11 +2004,13 @@ class ExprStringNameSpace:
         └──────┴────────────┴─────────────┘
         """
         by = parse_into_expression(by, str_as_lit=True)
+        if not literal:
+            return wrap_expr(self._pyexpr.str_split_exact_regex(by, n, inclusive))
         if inclusive:
             return wrap_expr(self._pyexpr.str_split_exact_inclusive(by, n))
         return wrap_expr(self._pyexpr.str_split_exact(by, n))
 
-    def splitn(self, by: IntoExpr, n: int) -> Expr:
+    def splitn(self, by: IntoExpr, n: int, *, literal: bool = True) -> Expr:
         """
         Split the string by a substring, restricted to returning at most `n` items.
 
@@ -1869,9 +2021,11 @@ class ExprStringNameSpace:
         Parameters
         ----------
         by
-            Substring to split by.
+            Substring to split by, or a regex pattern if `literal` is False.
         n
             Max number of items to return.
+        literal
+            Treat `by` as a literal string, not as a regular expression.
 
         Returns
         -------
@@ -1917,6 +2071,8 @@ class ExprStringNameSpace:
         └─────────────┴────────────┴─────────────┘
         """
         by = parse_into_expression(by, str_as_lit=True)
+        if not literal:
+            return wrap_expr(self._pyexpr.str_splitn_regex(by, n))
         return wrap_expr(self._pyexpr.str_splitn(by, n))
 
     def replace(
@@ -3007,6 +3163,211 @@ class ExprStringNameSpace:
         """  # noqa: RUF002
         return wrap_expr(self._pyexpr.str_normalize(form))
 
+    def levenshtein(self, other: str | Expr) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        This is the minimum number of single-character insertions, deletions and
+        substitutions needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        damerau_levenshtein : Edit distance that also counts transpositions.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "flaw", "mañana", None],
+        ...         "b": ["sitting", "lawn", "manana", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.levenshtein(pl.col("b")))
+        shape: (4, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ kitten ┆ sitting ┆ 3    │
+        │ flaw   ┆ lawn    ┆ 2    │
+        │ mañana ┆ manana  ┆ 1    │
+        │ null   ┆ x       ┆ null │
+        └────────┴─────────┴──────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_levenshtein(other))
+
+    def damerau_levenshtein(self, other: str | Expr) -> Expr:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like the Levenshtein distance, but transposing two characters also counts as
+        a single edit.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        levenshtein
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["ca", "abcdef", "hello"], "b": ["abc", "badcfe", "ehllo"]}
+        ... )
+        >>> df.with_columns(
+        ...     lev=pl.col("a").str.levenshtein(pl.col("b")),
+        ...     dl=pl.col("a").str.damerau_levenshtein(pl.col("b")),
+        ... )
+        shape: (3, 4)
+        ┌────────┬────────┬─────┬─────┐
+        │ a      ┆ b      ┆ lev ┆ dl  │
+        │ ---    ┆ ---    ┆ --- ┆ --- │
+        │ str    ┆ str    ┆ u32 ┆ u32 │
+        ╞════════╪════════╪═════╪═════╡
+        │ ca     ┆ abc    ┆ 3   ┆ 2   │
+        │ abcdef ┆ badcfe ┆ 4   ┆ 3   │
+        │ hello  ┆ ehllo  ┆ 2   ┆ 1   │
+        └────────┴────────┴─────┴─────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_damerau_levenshtein(other))
+
+    def jaro_winkler(self, other: str | Expr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0 (nothing in common) and 1 (equal strings), and
+        favors strings that share a common prefix of up to four characters.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["MARTHA", "DIXON", "JELLYFISH"],
+        ...         "b": ["MARHTA", "DICKSONX", "SMELLYFISH"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler(pl.col("b")))
+        shape: (3, 3)
+        ┌───────────┬────────────┬──────────┐
+        │ a         ┆ b          ┆ sim      │
+        │ ---       ┆ ---        ┆ ---      │
+        │ str       ┆ str        ┆ f64      │
+        ╞═══════════╪════════════╪══════════╡
+        │ MARTHA    ┆ MARHTA     ┆ 0.961111 │
+        │ DIXON     ┆ DICKSONX   ┆ 0.813333 │
+        │ JELLYFISH ┆ SMELLYFISH ┆ 0.896296 │
+        └───────────┴────────────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
+    def hamming(self, other: str | Expr) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        This is the number of positions at which the characters differ. Strings of
+        different lengths have no Hamming distance and yield null.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"a": ["karolin", "1011101", "abc"], "b": ["kathrin", "1001001", "ab"]}
+        ... )
+        >>> df.with_columns(dist=pl.col("a").str.hamming(pl.col("b")))
+        shape: (3, 3)
+        ┌─────────┬─────────┬──────┐
+        │ a       ┆ b       ┆ dist │
+        │ ---     ┆ ---     ┆ ---  │
+        │ str     ┆ str     ┆ u32  │
+        ╞═════════╪═════════╪══════╡
+        │ karolin ┆ kathrin ┆ 3    │
+        │ 1011101 ┆ 1001001 ┆ 2    │
+        │ abc     ┆ ab      ┆ null │
+        └─────────┴─────────┴──────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_hamming(other))
+
+    def jaccard(self, other: str | Expr, *, ngram: int = 2) -> Expr:
+        """
+        Compute the Jaccard similarity to another string over character n-grams.
+
+        This is the size of the intersection divided by the size of the union of
+        the sets of `ngram`-character substrings of both strings. A non-empty string
+        shorter than `ngram` counts as a single n-gram, and two empty strings have a
+        similarity of 1.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+        ngram
+            Number of characters per n-gram. Must be positive.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"word": ["night", "nacht", "nigh"]})
+        >>> df.with_columns(sim=pl.col("word").str.jaccard("night", ngram=2))
+        shape: (3, 2)
+        ┌───────┬──────────┐
+        │ word  ┆ sim      │
+        │ ---   ┆ ---      │
+        │ str   ┆ f64      │
+        ╞═══════╪══════════╡
+        │ night ┆ 1.0      │
+        │ nacht ┆ 0.142857 │
+        │ nigh  ┆ 0.75     │
+        └───────┴──────────┘
+        """
+        other = parse_into_expression(other, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_jaccard(other, ngram))
+
 
 def _validate_format_argument(format: str | None) -> None:
     if format is not None and ".%f" in format:
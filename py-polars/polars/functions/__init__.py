@@ -19,12 +19,17 @@ from polars.functions.as_datatype import (
     concat_str,
     duration,
     format,
+    format_with_specs,
     struct,
 )
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import (
+    business_day_count,
+    register_business_calendar,
+    unregister_business_calendar,
+)
 from polars.functions.col import col
 from polars.functions.datatype import dtype_of, self_dtype, struct_with_fields
 from polars.functions.eager import align_frames, concat
@@ -154,6 +159,7 @@ __all__ = [
     "first",
     "fold",
     "format",
+    "format_with_specs",
     "from_epoch",
     "groups",
     "head",
@@ -173,6 +179,7 @@ __all__ = [
     "nth",
     "quantile",
     "reduce",
+    "register_business_calendar",
     "rolling_corr",
     "rolling_cov",
     "row_index",
@@ -182,6 +189,7 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "unregister_business_calendar",
     "var",
     # polars.functions.len
     "len",
@@ -8,7 +8,7 @@ from polars._utils.parse import (
     parse_into_expression,
     parse_into_list_of_expressions,
 )
-from polars._utils.unstable import issue_unstable_warning
+from polars._utils.unstable import issue_unstable_warning, unstable
 from polars._utils.wrap import wrap_expr
 from polars.datatypes import Date, Struct, Time
 
@@ -849,3 +849,61 @@ def format(f_string: str, *args: Expr | str) -> Expr:
             exprs.append(F.lit(s))
 
     return concat_str(exprs, separator="")
+
+
+@unstable()
+def format_with_specs(f_string: str, *args: Expr | str) -> Expr:
+    """
+    Format expressions as a string, using format specifications.
+
+    Placeholders follow Python's format syntax: `{}` inserts the value as a
+    string, while `{:spec}` formats it with a format specification such as
+    `{:>8.2f}` or `{:08d}` (fill, alignment, sign, width, grouping, precision
+    and type), or a strftime format such as `{:%Y-%m-%d}` for temporal values.
+    Unlike in :func:`format`, `{{` and `}}` are literal braces, and any other
+    unmatched brace raises an error.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    f_string
+        A string with placeholders.
+        For example: "{:>8.2f}" or "{:%Y-%m-%d}_{}"
+    args
+        Expression(s) that fill the placeholders
+
+    See Also
+    --------
+    format
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "name": ["apple", "kiwi"],
+    ...         "price": [1.5, 12.25],
+    ...         "qty": [3, 12],
+    ...         "date": [date(2024, 1, 5), date(2024, 11, 20)],
+    ...     }
+    ... )
+    >>> df.select(
+    ...     pl.format_with_specs(
+    ...         "{:<6}|{:>7.2f}|{:03d}|{:%d %b %Y}", "name", "price", "qty", "date"
+    ...     ).alias("fmt")
+    ... )
+    shape: (2, 1)
+    ┌────────────────────────────────┐
+    │ fmt                            │
+    │ ---                            │
+    │ str                            │
+    ╞════════════════════════════════╡
+    │ apple |   1.50|003|05 Jan 2024 │
+    │ kiwi  |  12.25|012|20 Nov 2024 │
+    └────────────────────────────────┘
+    """
+    exprs = parse_into_list_of_expressions(*args)
+    return wrap_expr(plr.format_str_with_specs(f_string, exprs))
@@ -136,3 +136,104 @@ def business_day_count(
             [(holiday - unix_epoch).days for holiday in holidays],
         )
     )
+
+
+@unstable()
+def register_business_calendar(
+    name: str,
+    *,
+    week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+    holidays: Iterable[date] = (),
+) -> None:
+    """
+    Register a business calendar for use in business-day durations.
+
+    Durations such as `"3bd"` count business days from Monday to Friday. Once a
+    calendar has been registered, durations like `"3bd[name]"` use its week mask and
+    holidays instead, wherever duration strings are accepted, such as in
+    :meth:`Expr.dt.offset_by`, :func:`date_range`,
+    :meth:`DataFrame.group_by_dynamic` and :meth:`DataFrame.rolling`.
+
+    Registering a calendar under an existing name replaces that calendar.
+
+    Durations only refer to a calendar by its name, which is looked up when a query
+    is executed rather than when it is defined. Queries therefore use the calendar
+    registered at the time they are collected, and serialized queries do not
+    include the calendars they use. Calendar names consist of at most 64 ASCII
+    letters, digits, `_`, `-` and `.`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        Name of the calendar.
+    week_mask
+        Which days of the week are business days. The default is Monday to Friday.
+        If you wanted to count only Monday to Thursday, you would pass
+        `(True, True, True, True, False, False, False)`.
+    holidays
+        Holidays that are not business days.
+
+    See Also
+    --------
+    unregister_business_calendar
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> pl.register_business_calendar("nl", holidays=[date(2024, 12, 25)])
+    >>> df = pl.DataFrame({"date": [date(2024, 12, 20), date(2024, 12, 24)]})
+    >>> df.with_columns(
+    ...     next_business_day=pl.col("date").dt.offset_by("1bd"),
+    ...     next_business_day_nl=pl.col("date").dt.offset_by("1bd[nl]"),
+    ... )
+    shape: (2, 3)
+    ┌────────────┬───────────────────┬──────────────────────┐
+    │ date       ┆ next_business_day ┆ next_business_day_nl │
+    │ ---        ┆ ---               ┆ ---                  │
+    │ date       ┆ date              ┆ date                 │
+    ╞════════════╪═══════════════════╪══════════════════════╡
+    │ 2024-12-20 ┆ 2024-12-23        ┆ 2024-12-23           │
+    │ 2024-12-24 ┆ 2024-12-25        ┆ 2024-12-26           │
+    └────────────┴───────────────────┴──────────────────────┘
+    """
+    unix_epoch = date(1970, 1, 1)
+    plr.register_business_calendar(
+        name,
+        tuple(week_mask),
+        [(holiday - unix_epoch).days for holiday in holidays],
+    )
+
+
+@unstable()
+def unregister_business_calendar(name: str) -> bool:
+    """
+    Remove a business calendar registered with :func:`register_business_calendar`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        Name of the calendar.
+
+    Returns
+    -------
+    bool
+        Whether a calendar was registered under `name`.
+
+    Examples
+    --------
+    >>> week_mask = (True, True, True, True, False, False, False)
+    >>> pl.register_business_calendar("four_day_week", week_mask=week_mask)
+    >>> pl.unregister_business_calendar("four_day_week")
+    True
+    >>> pl.unregister_business_calendar("four_day_week")
+    False
+    """
+    return plr.unregister_business_calendar(name)
@@ -94,6 +94,7 @@ def date_range(
     `interval` is created according to the following string language:
 
     - 1d    (1 calendar day)
+    - 1bd   (1 business day)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -106,6 +107,10 @@ def date_range(
     not be 24 hours, due to daylight savings). Similarly for "calendar week",
     "calendar month", "calendar quarter", and "calendar year".
 
+    By "business day", we mean Monday to Friday, or the business days of the
+    calendar `name` registered with :func:`register_business_calendar` when
+    written as `"1bd[name]"`.
+
     Examples
     --------
     Using Polars duration string to specify the interval:
@@ -258,6 +263,7 @@ def date_ranges(
     `interval` is created according to the following string language:
 
     - 1d    (1 calendar day)
+    - 1bd   (1 business day)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -270,6 +276,10 @@ def date_ranges(
     not be 24 hours, due to daylight savings). Similarly for "calendar week",
     "calendar month", "calendar quarter", and "calendar year".
 
+    By "business day", we mean Monday to Friday, or the business days of the
+    calendar `name` registered with :func:`register_business_calendar` when
+    written as `"1bd[name]"`.
+
     Examples
     --------
     >>> from datetime import date
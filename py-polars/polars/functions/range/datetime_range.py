@@ -111,6 +111,7 @@ def datetime_range(
     - 1m    (1 minute)
     - 1h    (1 hour)
     - 1d    (1 calendar day)
+    - 1bd   (1 business day)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -123,6 +124,10 @@ def datetime_range(
     not be 24 hours, due to daylight savings). Similarly for "calendar week",
     "calendar month", "calendar quarter", and "calendar year".
 
+    By "business day", we mean Monday to Friday, or the business days of the
+    calendar `name` registered with :func:`register_business_calendar` when
+    written as `"1bd[name]"`.
+
     Examples
     --------
     Using Polars duration string to specify the interval:
@@ -308,6 +313,7 @@ def datetime_ranges(
     - 1m    (1 minute)
     - 1h    (1 hour)
     - 1d    (1 calendar day)
+    - 1bd   (1 business day)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -320,6 +326,10 @@ def datetime_ranges(
     not be 24 hours, due to daylight savings). Similarly for "calendar week",
     "calendar month", "calendar quarter", and "calendar year".
 
+    By "business day", we mean Monday to Friday, or the business days of the
+    calendar `name` registered with :func:`register_business_calendar` when
+    written as `"1bd[name]"`.
+
     Returns
     -------
     Expr or Series
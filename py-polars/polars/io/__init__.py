@@ -1,13 +1,13 @@
 """Functions for reading data."""
 
-from polars.io.avro import read_avro
+from polars.io.avro import read_avro, scan_avro
 from polars.io.clipboard import read_clipboard
 from polars.io.csv import read_csv, read_csv_batched, scan_csv
 from polars.io.database import read_database, read_database_uri
 from polars.io.delta import read_delta, scan_delta
 from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
-from polars.io.json import read_json
+from polars.io.json import read_json, scan_json
 from polars.io.ndjson import read_ndjson, scan_ndjson
 from polars.io.parquet import (
     read_parquet,
@@ -53,10 +53,12 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
     "scan_ipc",
+    "scan_json",
     "scan_ndjson",
     "scan_parquet",
     "scan_pyarrow_dataset",
@@ -1,3 +1,4 @@
 from polars.io.json.read import read_json
+from polars.io.json.scan import scan_json
 
-__all__ = ["read_json"]
+__all__ = ["read_json", "scan_json"]
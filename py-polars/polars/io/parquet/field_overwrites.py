@@ -46,6 +46,9 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.required is not None:
         d["required"] = pqo.required
 
+    if pqo.bloom_filter:
+        d["bloom_filter"] = (pqo.bloom_filter_fpp, pqo.bloom_filter_ndv)
+
     return d
 
 
@@ -89,6 +92,15 @@ class ParquetFieldOverwrites:
     ...         ),
     ...     },
     ... )  # doctest: +SKIP
+
+    Write a bloom filter for a high-cardinality column to speed up point lookups.
+
+    >>> lf.sink_parquet(
+    ...     "./out/parquet",
+    ...     field_overwrites={
+    ...         "a": ParquetFieldOverwrites(bloom_filter=True, bloom_filter_fpp=0.01),
+    ...     },
+    ... )  # doctest: +SKIP
     """
 
     name: None | str  #: Name of the column or field
@@ -108,6 +120,9 @@ class ParquetFieldOverwrites:
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
     required: bool | None = None  #: Is the field not allowed to have missing values
+    bloom_filter: bool = False  #: Write a split-block bloom filter for the field
+    bloom_filter_fpp: float | None = None  #: Bloom filter false positive probability
+    bloom_filter_ndv: int | None = None  #: Expected distinct values per row group
 
     def __init__(
         self,
@@ -122,6 +137,9 @@ class ParquetFieldOverwrites:
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
         required: bool | None = None,
+        bloom_filter: bool = False,
+        bloom_filter_fpp: float | None = None,
+        bloom_filter_ndv: int | None = None,
     ) -> None:
         self.name = name
 
@@ -138,3 +156,8 @@ class ParquetFieldOverwrites:
         else:
             self.metadata = metadata
         self.required = required
+        self.bloom_filter = (
+            bloom_filter or bloom_filter_fpp is not None or bloom_filter_ndv is not None
+        )
+        self.bloom_filter_fpp = bloom_filter_fpp
+        self.bloom_filter_ndv = bloom_filter_ndv
@@ -121,6 +121,7 @@ if TYPE_CHECKING:
     from polars import DataFrame, DataType, Expr
     from polars._typing import (
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
         CsvQuoteStyle,
@@ -3016,6 +3017,178 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf)
 
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    @unstable()
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache Avro file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate'}
+            Compression method of the data blocks. Defaults to "uncompressed".
+        name
+            Name of the record in the Avro schema. Defaults to empty string.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+        mkdir: bool
+            Recursively create all the directories in the path.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro")  # doctest: +SKIP
+        """
+        engine = _select_engine(engine)
+
+        from polars.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        if storage_options:
+            storage_options = list(storage_options.items())  # type: ignore[assignment]
+        else:
+            # Handle empty dict input
+            storage_options = None
+
+        target = _to_sink_target(path)
+        sink_options = {
+            "sync_on_close": sync_on_close or "none",
+            "maintain_order": maintain_order,
+            "mkdir": mkdir,
+        }
+
+        ldf = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
+            cloud_options=storage_options,
+            credential_provider=credential_provider_builder,
+            retries=retries,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf = ldf.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf)
+
     @overload
     def sink_csv(
         self,
@@ -4418,6 +4591,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -4431,6 +4605,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        By "business day", we mean Monday to Friday, or the business days of the
+        calendar `name` registered with :func:`register_business_calendar` when
+        written as `"1bd[name]"`.
+
         .. versionchanged:: 0.20.14
             The `by` parameter was renamed `group_by`.
 
@@ -4643,6 +4821,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            - 1m    (1 minute)
            - 1h    (1 hour)
            - 1d    (1 calendar day)
+           - 1bd   (1 business day)
            - 1w    (1 calendar week)
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
@@ -4656,6 +4835,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            not be 24 hours, due to daylight savings). Similarly for "calendar week",
            "calendar month", "calendar quarter", and "calendar year".
 
+           By "business day", we mean Monday to Friday, or the business days of the
+           calendar `name` registered with :func:`register_business_calendar` when
+           written as `"1bd[name]"`.
+
            In case of a group_by_dynamic on an integer column, the windows are defined by:
 
            - "1i"      # length 1
@@ -4853,6 +5036,124 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return LazyGroupBy(lgb)
 
+    @unstable()
+    def group_by_session(
+        self,
+        index_column: IntoExpr,
+        *,
+        gap: str | timedelta,
+        group_by: IntoExpr | Iterable[IntoExpr] | None = None,
+    ) -> LazyGroupBy:
+        """
+        Group based on sessions of a time value (or index value of type Int32, Int64).
+
+        .. warning::
+            This functionality is currently considered **unstable**. It may be
+            changed at any point without it being considered a breaking change.
+
+        A session is a run of rows in which each value of the index column is less than
+        `gap` past the value before it; a session thus ends after an inactivity of
+        `gap`. Different from `group_by_dynamic`, the windows are not of constant
+        length and every row is member of exactly one group. The index column of the
+        result holds the first value of every session.
+
+        .. warning::
+            The index column must be sorted in ascending order. If `group_by` is passed, then
+            the index column must be sorted in ascending order within each group.
+
+        Parameters
+        ----------
+        index_column
+            Column used to group based on the sessions.
+            Often of type Date/Datetime.
+            This column must be sorted in ascending order (or, if `group_by` is specified,
+            then it must be sorted in ascending order within each group).
+
+            In case of a session group by on indices, dtype needs to be one of
+            {Int32, Int64}.
+        gap
+            Period of inactivity after which a session ends. A new session starts at
+            every value that is at least `gap` past the value before it.
+        group_by
+            Also group by this column/these columns; sessions are then determined
+            within each group.
+
+        Returns
+        -------
+        LazyGroupBy
+            Object you can call `.agg` on to aggregate by groups, the result
+            of which will be sorted by `index_column` (but note that if `group_by` columns are
+            passed, it will only be sorted within each group).
+
+        See Also
+        --------
+        group_by_dynamic
+        polars.Expr.dt.session_id
+
+        Notes
+        -----
+        `gap` is created according to the following string language:
+
+        - 1ns   (1 nanosecond)
+        - 1us   (1 microsecond)
+        - 1ms   (1 millisecond)
+        - 1s    (1 second)
+        - 1m    (1 minute)
+        - 1h    (1 hour)
+        - 1d    (1 calendar day)
+        - 1w    (1 calendar week)
+        - 1mo   (1 calendar month)
+        - 1q    (1 calendar quarter)
+        - 1y    (1 calendar year)
+        - 1i    (1 index count)
+
+        Or combine them:
+        "3d12h4m25s" # 3 days, 12 hours, 4 minutes, and 25 seconds
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "time": [
+        ...             datetime(2021, 12, 16, 0, 0),
+        ...             datetime(2021, 12, 16, 0, 10),
+        ...             datetime(2021, 12, 16, 1, 0),
+        ...             datetime(2021, 12, 16, 1, 20),
+        ...             datetime(2021, 12, 16, 3, 0),
+        ...         ],
+        ...         "clicks": [1, 2, 3, 4, 5],
+        ...     }
+        ... )
+        >>> (
+        ...     lf.group_by_session("time", gap="30m")
+        ...     .agg(
+        ...         pl.len(),
+        ...         pl.col("time").last().alias("last"),
+        ...         pl.col("clicks").sum(),
+        ...     )
+        ...     .collect()
+        ... )
+        shape: (3, 4)
+        ┌─────────────────────┬─────┬─────────────────────┬────────┐
+        │ time                ┆ len ┆ last                ┆ clicks │
+        │ ---                 ┆ --- ┆ ---                 ┆ ---    │
+        │ datetime[μs]        ┆ u32 ┆ datetime[μs]        ┆ i64    │
+        ╞═════════════════════╪═════╪═════════════════════╪════════╡
+        │ 2021-12-16 00:00:00 ┆ 2   ┆ 2021-12-16 00:10:00 ┆ 3      │
+        │ 2021-12-16 01:00:00 ┆ 2   ┆ 2021-12-16 01:20:00 ┆ 7      │
+        │ 2021-12-16 03:00:00 ┆ 1   ┆ 2021-12-16 03:00:00 ┆ 5      │
+        └─────────────────────┴─────┴─────────────────────┴────────┘
+        """  # noqa: W505
+        index_column = parse_into_expression(index_column)
+        gap = parse_as_duration_string(gap)
+
+        pyexprs_by = (
+            parse_into_list_of_expressions(group_by) if group_by is not None else []
+        )
+        lgb = self._ldf.group_by_session(index_column, gap, pyexprs_by)
+        return LazyGroupBy(lgb)
+
     def join_asof(
         self,
         other: LazyFrame,
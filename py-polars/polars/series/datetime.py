@@ -17,6 +17,7 @@ if TYPE_CHECKING:
     from polars._typing import (
         Ambiguous,
         EpochTimeUnit,
+        FiscalPattern,
         IntoExpr,
         IntoExprColumn,
         NonExistent,
@@ -648,6 +649,132 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def fiscal_year(
+        self,
+        start_month: int = 1,
+        *,
+        pattern: FiscalPattern = "monthly",
+        week_start: int = 1,
+    ) -> Series:
+        """
+        Extract the fiscal year from underlying Date representation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Applies to Date and Datetime columns.
+
+        Fiscal years are named after the calendar year in which they end, so with
+        `start_month=4` the fiscal year 2025 runs from April 2024 up to and including
+        March 2025.
+
+        Parameters
+        ----------
+        start_month
+            The calendar month (1-12) in which the fiscal year starts.
+        pattern : {'monthly', '4-4-5', '4-5-4', '5-4-4'}
+            How the fiscal year is divided into periods.
+
+            - 'monthly': every period is a calendar month.
+            - '4-4-5', '4-5-4', '5-4-4': retail calendars of 52 or 53 whole weeks,
+              whose quarters consist of periods of the given numbers of weeks. Each
+              fiscal year starts on the `week_start` weekday nearest to the first day
+              of `start_month`, and the extra week of a 53-week year is added to its
+              last period.
+        week_start
+            The ISO weekday (1 = Monday, 7 = Sunday) on which the weeks of the retail
+            calendars start. Ignored for the 'monthly' pattern.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        See Also
+        --------
+        fiscal_quarter
+        truncate_fiscal
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]
+        ... )
+        >>> s.dt.fiscal_year(4)
+        shape: (3,)
+        Series: 'date' [i32]
+        [
+                2024
+                2025
+                2025
+        ]
+        """
+
+    @unstable()
+    def fiscal_quarter(
+        self,
+        start_month: int = 1,
+        *,
+        pattern: FiscalPattern = "monthly",
+        week_start: int = 1,
+    ) -> Series:
+        """
+        Extract the fiscal quarter from underlying Date representation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Applies to Date and Datetime columns.
+
+        Returns the quarter ranging from 1 to 4.
+
+        Parameters
+        ----------
+        start_month
+            The calendar month (1-12) in which the fiscal year starts.
+        pattern : {'monthly', '4-4-5', '4-5-4', '5-4-4'}
+            How the fiscal year is divided into periods.
+
+            - 'monthly': every period is a calendar month.
+            - '4-4-5', '4-5-4', '5-4-4': retail calendars of 52 or 53 whole weeks,
+              whose quarters consist of periods of the given numbers of weeks. Each
+              fiscal year starts on the `week_start` weekday nearest to the first day
+              of `start_month`, and the extra week of a 53-week year is added to its
+              last period.
+        week_start
+            The ISO weekday (1 = Monday, 7 = Sunday) on which the weeks of the retail
+            calendars start. Ignored for the 'monthly' pattern.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        See Also
+        --------
+        fiscal_year
+        truncate_fiscal
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31)]
+        ... )
+        >>> s.dt.fiscal_quarter(4)
+        shape: (3,)
+        Series: 'date' [i8]
+        [
+                4
+                1
+                3
+        ]
+        """
+
     def month(self) -> Series:
         """
         Extract the month from the underlying date representation.
@@ -1770,6 +1897,7 @@ class DateTimeNameSpace:
             - 1m    (1 minute)
             - 1h    (1 hour)
             - 1d    (1 calendar day)
+            - 1bd   (1 business day)
             - 1w    (1 calendar week)
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
@@ -1780,6 +1908,10 @@ class DateTimeNameSpace:
             "calendar week", "calendar month", "calendar quarter", and
             "calendar year".
 
+            By "business day", we mean Monday to Friday, or the business days of
+            the calendar `name` registered with :func:`register_business_calendar`
+            when written as `"1bd[name]"`.
+
         Returns
         -------
         Series
@@ -1858,6 +1990,7 @@ class DateTimeNameSpace:
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -1867,6 +2000,10 @@ class DateTimeNameSpace:
         not be 24 hours, due to daylight savings). Similarly for "calendar week",
         "calendar month", "calendar quarter", and "calendar year".
 
+        By "business day", we mean Monday to Friday, or the business days of the
+        calendar `name` registered with :func:`register_business_calendar` when
+        written as `"1bd[name]"`.
+
         Returns
         -------
         Series
@@ -1939,6 +2076,137 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def truncate_fiscal(
+        self,
+        every: str | IntoExprColumn,
+        start_month: int = 1,
+        *,
+        pattern: FiscalPattern = "monthly",
+        week_start: int = 1,
+    ) -> Series:
+        """
+        Divide the date/datetime range into the periods of a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Each date/datetime is mapped to the start of its fiscal period using the
+        corresponding local datetime.
+
+        Fiscal years are named after the calendar year in which they end, so with
+        `start_month=4` the fiscal year 2025 runs from April 2024 up to and including
+        March 2025.
+
+        Parameters
+        ----------
+        every
+            The size of each bucket, which must be a whole number of months that
+            evenly divides a year. Months are the periods of the fiscal calendar, so
+            `'1mo'` truncates to the fiscal period, `'1q'` to the fiscal quarter and
+            `'1y'` to the fiscal year.
+        start_month
+            The calendar month (1-12) in which the fiscal year starts.
+        pattern : {'monthly', '4-4-5', '4-5-4', '5-4-4'}
+            How the fiscal year is divided into periods.
+
+            - 'monthly': every period is a calendar month.
+            - '4-4-5', '4-5-4', '5-4-4': retail calendars of 52 or 53 whole weeks,
+              whose quarters consist of periods of the given numbers of weeks. Each
+              fiscal year starts on the `week_start` weekday nearest to the first day
+              of `start_month`, and the extra week of a 53-week year is added to its
+              last period.
+        week_start
+            The ISO weekday (1 = Monday, 7 = Sunday) on which the weeks of the retail
+            calendars start. Ignored for the 'monthly' pattern.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Date` or :class:`Datetime`.
+
+        See Also
+        --------
+        truncate
+        fiscal_year
+        fiscal_quarter
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series(
+        ...     "date", [date(2024, 5, 15), date(2024, 8, 15), date(2025, 2, 1)]
+        ... )
+        >>> s.dt.truncate_fiscal("1q", 4)
+        shape: (3,)
+        Series: 'date' [date]
+        [
+                2024-04-01
+                2024-07-01
+                2025-01-01
+        ]
+        """
+
+    @unstable()
+    def session_id(self, gap: str | dt.timedelta) -> Series:
+        """
+        Assign a session id to every value of a sorted time or index column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Applies to Date and Datetime columns, and to index columns of type Int32 or
+        Int64.
+
+        A session ends after an inactivity of `gap`: a new session starts at every
+        value that is at least `gap` past the value before it. Session ids start at 0
+        and increase by one with every session, similar to :func:`Expr.rle_id`. The
+        values must be sorted in ascending order and may not contain nulls.
+
+        Parameters
+        ----------
+        gap
+            Period of inactivity after which a session ends, e.g. `"30m"`, or
+            `"5i"` for index columns. Accepts the same string language as
+            :func:`DataFrame.group_by_dynamic`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        See Also
+        --------
+        polars.DataFrame.group_by_session
+        polars.Series.rle_id
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> s = pl.Series(
+        ...     "time",
+        ...     [
+        ...         datetime(2021, 12, 16, 0, 0),
+        ...         datetime(2021, 12, 16, 0, 10),
+        ...         datetime(2021, 12, 16, 1, 0),
+        ...         datetime(2021, 12, 16, 1, 20),
+        ...         datetime(2021, 12, 16, 3, 0),
+        ...     ],
+        ... )
+        >>> s.dt.session_id("30m")
+        shape: (5,)
+        Series: 'time' [u32]
+        [
+                0
+                0
+                1
+                1
+                2
+        ]
+        """
+
     def round(self, every: str | dt.timedelta | IntoExprColumn) -> Series:
         """
         Divide the date/ datetime range into buckets.
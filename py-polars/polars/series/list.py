@@ -598,6 +598,36 @@ class ListNameSpace:
         ]
         """
 
+    def index_of(self, element: IntoExpr) -> Series:
+        """
+        Get the index of the first occurrence of `element` in every sublist.
+
+        Parameters
+        ----------
+        element
+            Value to search for. A null value finds the first null element.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32` or :class:`UInt64`
+            (depending on compilation). The value is null if the element does not
+            occur in the sublist.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 2, 1], [1, 2, 1], [4, 5], None])
+        >>> s.list.index_of(1)
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            2
+            0
+            null
+            null
+        ]
+        """
+
     def arg_min(self) -> Series:
         """
         Retrieve the index of the minimal value in every sublist.
@@ -786,6 +816,35 @@ class ListNameSpace:
         ]
         """
 
+    def pad_end(
+        self, length: int | IntoExprColumn, fill_value: IntoExpr = None
+    ) -> Series:
+        """
+        Pad every sublist at the end until it has `length` elements.
+
+        Sublists that already have `length` or more elements are left as they are.
+
+        Parameters
+        ----------
+        length
+            Length of the padded sublists.
+        fill_value
+            Value to pad with. It is cast to the inner data type of the list.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1], [2, 3], [4, 5, 6], None])
+        >>> s.list.pad_end(3, 0)
+        shape: (4,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 0, 0]
+            [2, 3, 0]
+            [4, 5, 6]
+            null
+        ]
+        """
+
     def explode(self) -> Series:
         """
         Returns a column with a separate row for every list element.
@@ -815,6 +874,31 @@ class ListNameSpace:
         ]
         """
 
+    def flatten(self, depth: int | None = None) -> Series:
+        """
+        Remove levels of nesting by concatenating the nested lists of every sublist.
+
+        Null nested lists are skipped.
+
+        Parameters
+        ----------
+        depth
+            Number of nesting levels to remove. By default, all nested `List` and
+            `Array` levels are removed.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[[1, 2], [3]], [[4], None, []], None])
+        >>> s.list.flatten()
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [1, 2, 3]
+            [4]
+            null
+        ]
+        """
+
     def count_matches(self, element: IntoExpr) -> Series:
         """
         Count how often the value produced by `element` occurs.
@@ -865,6 +949,35 @@ class ListNameSpace:
         ]
         """
 
+    def reshape(self, dimensions: tuple[int, ...]) -> Series:
+        """
+        Reshape every sublist into a (nested) Array of the given dimensions.
+
+        Nested lists are flattened first, so every sublist must contain exactly as
+        many values as the product of the dimensions.
+
+        Parameters
+        ----------
+        dimensions
+            Tuple of the dimension sizes of the resulting Array.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Array`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 2, 3, 4], [5, 6, 7, 8]])
+        >>> s.list.reshape((2, 2))
+        shape: (2,)
+        Series: 'a' [array[i64, (2, 2)]]
+        [
+            [[1, 2], [3, 4]]
+            [[5, 6], [7, 8]]
+        ]
+        """
+
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
@@ -1089,3 +1202,29 @@ class ListNameSpace:
             [5, 7, 8]
         ]
         """  # noqa: W505
+
+    def zip(self, other: Series) -> Series:
+        """
+        Zip the elements of every sublist with the elements of the sublist in `other`.
+
+        The result is a list of structs with the fields `field_0` and `field_1`. The
+        sublists are truncated to the length of the shorter input.
+
+        Parameters
+        ----------
+        other
+            List Series to zip with.
+
+        Examples
+        --------
+        >>> a = pl.Series("a", [[1, 2], [3], []])
+        >>> b = pl.Series("b", [["x", "y"], ["z", "w"], ["v"]])
+        >>> a.list.zip(b)
+        shape: (3,)
+        Series: 'a' [list[struct[2]]]
+        [
+            [{1,"x"}, {2,"y"}]
+            [{3,"z"}]
+            []
+        ]
+        """
@@ -7744,9 +7744,22 @@ class Series:
 
         Parameters
         ----------
-        method : {'linear', 'nearest'}
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'akima', 'pchip'}
             Interpolation method.
 
+            - 'linear': connect the surrounding values with a straight line.
+            - 'nearest': use the nearest non-null value.
+            - 'previous': use the previous non-null value.
+            - 'next': use the next non-null value.
+            - 'cubic': natural cubic spline through all non-null values.
+            - 'akima': Akima spline, which is less prone to overshoot around
+              outliers than 'cubic'.
+            - 'pchip': piecewise cubic Hermite interpolation, which preserves
+              the monotonicity of the data.
+
+            'nearest', 'previous' and 'next' keep the data type, the other
+            methods return floating point values.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, None, None, 5])
@@ -7762,7 +7775,9 @@ class Series:
         ]
         """
 
-    def interpolate_by(self, by: IntoExpr) -> Series:
+    def interpolate_by(
+        self, by: IntoExpr, method: InterpolationMethod = "linear"
+    ) -> Series:
         """
         Interpolate intermediate values with x-coordinate based on another column.
 
@@ -7772,6 +7787,20 @@ class Series:
         ----------
         by
             Column to interpolate values based on.
+        method : {'linear', 'nearest', 'previous', 'next', 'cubic', 'akima', 'pchip'}
+            Interpolation method.
+
+            - 'linear': connect the surrounding values with a straight line.
+            - 'nearest': use the non-null value with the nearest `by` value.
+            - 'previous': use the previous non-null value.
+            - 'next': use the next non-null value.
+            - 'cubic': natural cubic spline through all non-null values.
+            - 'akima': Akima spline, which is less prone to overshoot around
+              outliers than 'cubic'.
+            - 'pchip': piecewise cubic Hermite interpolation, which preserves
+              the monotonicity of the data.
+
+            The result is always a floating point column.
 
         Examples
         --------
@@ -22,8 +22,8 @@ if TYPE_CHECKING:
         PolarsDataType,
         PolarsIntegerType,
         PolarsTemporalType,
+        StringTransferEncoding,
         TimeUnit,
-        TransferEncoding,
         UnicodeForm,
     )
     from polars._utils.various import NoDefault
@@ -595,14 +595,17 @@ class StringNameSpace:
         ]
         """
 
-    def decode(self, encoding: TransferEncoding, *, strict: bool = True) -> Series:
+    def decode(
+        self, encoding: StringTransferEncoding, *, strict: bool = True
+    ) -> Series:
         r"""
         Decode values using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'percent'}
+            The encoding to use. `'percent'` decodes the `%XX` escapes of
+            percent-encoded (URL-encoded) strings.
         strict
             Raise an error if the underlying value cannot be decoded,
             otherwise mask out with a null value.
@@ -623,16 +626,24 @@ class StringNameSpace:
                 b"\xff\xff\x00"
                 b"\x00\x00\xff"
         ]
+        >>> pl.Series(["caf%C3%A9", "a%20b%2Fc"]).str.decode("percent")
+        shape: (2,)
+        Series: '' [binary]
+        [
+                b"caf\xc3\xa9"
+                b"a b/c"
+        ]
         """
 
-    def encode(self, encoding: TransferEncoding) -> Series:
+    def encode(self, encoding: StringTransferEncoding) -> Series:
         """
         Encode a value using the provided encoding.
 
         Parameters
         ----------
-        encoding : {'hex', 'base64'}
-            The encoding to use.
+        encoding : {'hex', 'base64', 'percent'}
+            The encoding to use. `'percent'` escapes every byte other than the
+            unreserved URL characters `A-Z a-z 0-9 - . _ ~` as `%XX`.
 
         Returns
         -------
@@ -650,6 +661,87 @@ class StringNameSpace:
             "626172"
             null
         ]
+        >>> pl.Series(["café", "a b/c"]).str.encode("percent")
+        shape: (2,)
+        Series: '' [str]
+        [
+            "caf%C3%A9"
+            "a%20b%2Fc"
+        ]
+        """
+
+    def parse_url(self, *, strict: bool = True) -> Series:
+        """
+        Parse URLs into their components.
+
+        URLs are parsed and normalized following the WHATWG URL Standard. Strings
+        that start with `/`, such as request paths in server logs, are parsed as URLs
+        without a scheme (and without a host, unless they start with `//`).
+
+        Parameters
+        ----------
+        strict
+            Raise an error if a value is not a valid URL, otherwise mask it out with
+            a null value.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with fields `scheme`, `host`,
+            `port` (:class:`UInt16`, only set if given explicitly), `path`, `query`
+            and `fragment`.
+
+        See Also
+        --------
+        url_query_param : Extract a query parameter from URLs.
+
+        Examples
+        --------
+        >>> s = pl.Series("url", ["https://example.com:8080/a/b?q=1#top", "/api/v1"])
+        >>> s.str.parse_url().struct.unnest()
+        shape: (2, 6)
+        ┌────────┬─────────────┬──────┬─────────┬───────┬──────────┐
+        │ scheme ┆ host        ┆ port ┆ path    ┆ query ┆ fragment │
+        │ ---    ┆ ---         ┆ ---  ┆ ---     ┆ ---   ┆ ---      │
+        │ str    ┆ str         ┆ u16  ┆ str     ┆ str   ┆ str      │
+        ╞════════╪═════════════╪══════╪═════════╪═══════╪══════════╡
+        │ https  ┆ example.com ┆ 8080 ┆ /a/b    ┆ q=1   ┆ top      │
+        │ null   ┆ null        ┆ null ┆ /api/v1 ┆ null  ┆ null     │
+        └────────┴─────────────┴──────┴─────────┴───────┴──────────┘
+        """
+
+    def url_query_param(self, name: str | Expr) -> Series:
+        """
+        Extract the value of a query parameter from URLs.
+
+        The value is decoded (`+` and `%XX` escapes included). If a parameter occurs
+        multiple times, its first value is returned, and URLs without the parameter
+        yield null.
+
+        Parameters
+        ----------
+        name
+            Name of the query parameter.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        See Also
+        --------
+        parse_url : Parse URLs into their components.
+
+        Examples
+        --------
+        >>> s = pl.Series("url", ["https://x.com/search?q=polars+rust", "/search?p=2"])
+        >>> s.str.url_query_param("q")
+        shape: (2,)
+        Series: 'url' [str]
+        [
+            "polars rust"
+            null
+        ]
         """
 
     def json_decode(
@@ -963,16 +1055,20 @@ class StringNameSpace:
         ]
         """
 
-    def split(self, by: IntoExpr, *, inclusive: bool = False) -> Series:
+    def split(
+        self, by: IntoExpr, *, inclusive: bool = False, literal: bool = True
+    ) -> Series:
         """
-        Split the string by a substring.
+        Split the string by a substring or regex pattern.
 
         Parameters
         ----------
         by
-            Substring to split by.
+            Substring to split by, or a regex pattern if `literal` is False.
         inclusive
             If True, include the split character/string in the results.
+        literal
+            Treat `by` as a literal string, not as a regular expression.
 
         Returns
         -------
@@ -980,9 +1076,11 @@ class StringNameSpace:
             Series of data type `List(String)`.
         """
 
-    def split_exact(self, by: IntoExpr, n: int, *, inclusive: bool = False) -> Series:
+    def split_exact(
+        self, by: IntoExpr, n: int, *, inclusive: bool = False, literal: bool = True
+    ) -> Series:
         """
-        Split the string by a substring using `n` splits.
+        Split the string by a substring or regex pattern using `n` splits.
 
         Results in a struct of `n+1` fields.
 
@@ -991,11 +1089,13 @@ class StringNameSpace:
         Parameters
         ----------
         by
-            Substring to split by.
+            Substring to split by, or a regex pattern if `literal` is False.
         n
             Number of splits to make.
         inclusive
             If True, include the split character/string in the results.
+        literal
+            Treat `by` as a literal string, not as a regular expression.
 
         Examples
         --------
@@ -1040,7 +1140,7 @@ class StringNameSpace:
             :class:`String`.
         """
 
-    def splitn(self, by: IntoExpr, n: int) -> Series:
+    def splitn(self, by: IntoExpr, n: int, *, literal: bool = True) -> Series:
         """
         Split the string by a substring, restricted to returning at most `n` items.
 
@@ -1051,9 +1151,11 @@ class StringNameSpace:
         Parameters
         ----------
         by
-            Substring to split by.
+            Substring to split by, or a regex pattern if `literal` is False.
         n
             Max number of items to return.
+        literal
+            Treat `by` as a literal string, not as a regular expression.
 
         Examples
         --------
@@ -2272,3 +2374,166 @@ class StringNameSpace:
                 "KADOKAWA"
         ]
         """  # noqa: RUF002
+
+    def levenshtein(self, other: str | Expr) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        This is the minimum number of single-character insertions, deletions and
+        substitutions needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        See Also
+        --------
+        damerau_levenshtein : Edit distance that also counts transpositions.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["kitten", "sitting", "mitten", None])
+        >>> s.str.levenshtein("kitten")
+        shape: (4,)
+        Series: 'a' [u32]
+        [
+            0
+            3
+            1
+            null
+        ]
+        """
+
+    def damerau_levenshtein(self, other: str | Expr) -> Series:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like the Levenshtein distance, but transposing two characters also counts as
+        a single edit.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        See Also
+        --------
+        levenshtein
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["hello", "ehllo", "hlelo"])
+        >>> s.str.damerau_levenshtein("hello")
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            0
+            1
+            1
+        ]
+        """
+
+    def jaro_winkler(self, other: str | Expr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0 (nothing in common) and 1 (equal strings), and
+        favors strings that share a common prefix of up to four characters.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["MARTHA", "MARHTA", "ARTHUR"])
+        >>> s.str.jaro_winkler("MARTHA")
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            1.0
+            0.961111
+            0.777778
+        ]
+        """
+
+    def hamming(self, other: str | Expr) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        This is the number of positions at which the characters differ. Strings of
+        different lengths have no Hamming distance and yield null.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["karolin", "kathrin", "karl"])
+        >>> s.str.hamming("karolin")
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+            0
+            3
+            null
+        ]
+        """
+
+    def jaccard(self, other: str | Expr, *, ngram: int = 2) -> Series:
+        """
+        Compute the Jaccard similarity to another string over character n-grams.
+
+        This is the size of the intersection divided by the size of the union of
+        the sets of `ngram`-character substrings of both strings. A non-empty string
+        shorter than `ngram` counts as a single n-gram, and two empty strings have a
+        similarity of 1.
+
+        Parameters
+        ----------
+        other
+            String, or expression of strings, to compare with.
+        ngram
+            Number of characters per n-gram. Must be positive.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", ["night", "nacht", "nigh"])
+        >>> s.str.jaccard("night", ngram=2)
+        shape: (3,)
+        Series: 'a' [f64]
+        [
+            1.0
+            0.142857
+            0.75
+        ]
+        """
@@ -124,6 +124,7 @@ class SQLContext(Generic[FrameType]):
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
         eager: Literal[False] = False,
+        max_recursive_cte_iterations: int = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -135,6 +136,7 @@ class SQLContext(Generic[FrameType]):
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
         eager: Literal[True],
+        max_recursive_cte_iterations: int = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -146,6 +148,7 @@ class SQLContext(Generic[FrameType]):
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
         eager: bool,
+        max_recursive_cte_iterations: int = ...,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
 
@@ -156,6 +159,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = False,
         eager: bool = False,
+        max_recursive_cte_iterations: int = 1000,
         **named_frames: CompatibleFrameType | None,
     ) -> None:
         """
@@ -179,6 +183,11 @@ class SQLContext(Generic[FrameType]):
             If True, returns execution results as `DataFrame` instead of `LazyFrame`.
             (Note that the query itself is always executed in lazy-mode; this parameter
             impacts whether :meth:`execute` returns an eager or lazy result frame).
+        max_recursive_cte_iterations
+            The maximum number of iterations a recursive CTE (`WITH RECURSIVE`) may
+            run for before the query is aborted with an error. Note that recursive
+            CTEs are evaluated eagerly when the query is planned, that is, when
+            :meth:`execute` is called.
         **named_frames
             Named eager/lazy frames, provided as kwargs.
 
@@ -202,7 +211,7 @@ class SQLContext(Generic[FrameType]):
         issue_unstable_warning(
             "`SQLContext` is considered **unstable**, although it is close to being considered stable."
         )
-        self._ctxt = PySQLContext.new()
+        self._ctxt = PySQLContext.new(max_recursive_cte_iterations)
         self._eager_execution = eager
 
         frames = dict(frames or {})
@@ -1,4 +1,12 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
 import polars as pl
+from polars.exceptions import InvalidOperationError, ShapeError
+from polars.testing import assert_frame_equal
 
 
 def test_format() -> None:
@@ -6,3 +14,59 @@ def test_format() -> None:
 
     out = df.select([pl.format("foo_{}_bar_{}", pl.col("a"), "b").alias("fmt")])
     assert out["fmt"].to_list() == ["foo_a_bar_1", "foo_b_bar_2", "foo_c_bar_3"]
+
+
+def test_format_literal_braces() -> None:
+    # only `{}` is a placeholder; any other braces are kept as-is
+    out = pl.select(pl.format("{{}}} {:d} {", pl.lit("a"))).item()
+    assert out == "{a}} {:d} {"
+
+
+def test_format_specs() -> None:
+    df = pl.DataFrame(
+        {
+            "f": [3.14159, -2.5, None],
+            "i": [42, -7, 1],
+            "s": ["ab", "abcdef", "x"],
+        }
+    )
+    out = df.select(
+        a=pl.format_with_specs("{:>8.2f} | {:08d}", "f", "i"),
+        b=pl.format_with_specs("[{:*^6.4}] {:+,}", "s", pl.col("i") * 1000),
+        c=pl.format_with_specs("{:.1%} {:.2e} {:#x}", "f", "f", "i"),
+    )
+    expected = pl.DataFrame(
+        {
+            "a": ["    3.14 | 00000042", "   -2.50 | -0000007", None],
+            "b": ["[**ab**] +42,000", "[*abcd*] -7,000", "[**x***] +1,000"],
+            "c": ["314.2% 3.14e+00 0x2a", "-250.0% -2.50e+00 -0x7", None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_format_temporal() -> None:
+    df = pl.DataFrame(
+        {
+            "d": [date(2024, 1, 5), None],
+            "dt": [datetime(2024, 1, 5, 13, 45), datetime(2024, 11, 20, 8, 0)],
+        }
+    )
+    out = df.select(pl.format_with_specs("{:%d/%m/%Y} {:%H:%M} {}", "d", "dt", "d"))
+    assert out.to_series().to_list() == ["05/01/2024 13:45 2024-01-05", None]
+
+
+def test_format_escaped_braces() -> None:
+    out = pl.select(pl.format_with_specs("{{{}}} {{:d}}", pl.lit("a"))).item()
+    assert out == "{a} {:d}"
+
+
+def test_format_invalid() -> None:
+    with pytest.raises(ShapeError, match="number of placeholders"):
+        pl.format_with_specs("{} {}", "a")
+    with pytest.raises(InvalidOperationError, match="invalid format spec"):
+        pl.format_with_specs("{:8.2q}", "a")
+    with pytest.raises(InvalidOperationError, match="single '}'"):
+        pl.format_with_specs("}", "a")
+    with pytest.raises(InvalidOperationError, match="requires an integer"):
+        pl.select(pl.format_with_specs("{:d}", pl.lit(1.5)))
@@ -0,0 +1,111 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+@pytest.fixture
+def holiday_calendar() -> Iterator[str]:
+    pl.register_business_calendar("test_holiday", holidays=[date(2024, 1, 8)])
+    yield "test_holiday"
+    pl.unregister_business_calendar("test_holiday")
+
+
+def test_offset_by_business_days(holiday_calendar: str) -> None:
+    # Friday, Saturday and Monday.
+    s = pl.Series("date", [date(2024, 1, 5), date(2024, 1, 6), date(2024, 1, 8)])
+
+    result = s.dt.offset_by("1bd")
+    expected = pl.Series("date", [date(2024, 1, 8), date(2024, 1, 8), date(2024, 1, 9)])
+    assert_series_equal(result, expected)
+
+    result = s.dt.offset_by("-1bd")
+    expected = pl.Series("date", [date(2024, 1, 4), date(2024, 1, 5), date(2024, 1, 5)])
+    assert_series_equal(result, expected)
+
+    result = s.dt.offset_by(f"1bd[{holiday_calendar}]")
+    expected = pl.Series("date", [date(2024, 1, 9), date(2024, 1, 9), date(2024, 1, 9)])
+    assert_series_equal(result, expected)
+
+    result = pl.Series([datetime(2024, 1, 5, 12)]).dt.offset_by("2bd")
+    assert_series_equal(result, pl.Series([datetime(2024, 1, 9, 12)]))
+
+
+def test_offset_by_business_days_unknown_calendar() -> None:
+    s = pl.Series([date(2024, 1, 5)])
+    with pytest.raises(ComputeError, match="no business calendar registered"):
+        s.dt.offset_by("1bd[test_unknown]")
+
+
+def test_date_range_business_days(holiday_calendar: str) -> None:
+    result = pl.date_range(date(2024, 1, 5), date(2024, 1, 10), "1bd", eager=True)
+    assert result.to_list() == [
+        date(2024, 1, 5),
+        date(2024, 1, 8),
+        date(2024, 1, 9),
+        date(2024, 1, 10),
+    ]
+
+    result = pl.date_range(
+        date(2024, 1, 5), date(2024, 1, 10), f"1bd[{holiday_calendar}]", eager=True
+    )
+    assert result.to_list() == [date(2024, 1, 5), date(2024, 1, 9), date(2024, 1, 10)]
+
+    # Ranges start on the first business day.
+    result = pl.date_range(date(2024, 1, 6), date(2024, 1, 10), "1bd", eager=True)
+    assert result.to_list() == [date(2024, 1, 8), date(2024, 1, 9), date(2024, 1, 10)]
+
+
+def test_group_by_dynamic_business_days() -> None:
+    df = pl.DataFrame(
+        {
+            "date": pl.date_range(date(2024, 1, 4), date(2024, 1, 9), "1d", eager=True),
+            "value": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    # The weekend belongs to the window of the preceding Friday.
+    result = df.group_by_dynamic("date", every="1bd").agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "date": [
+                date(2024, 1, 4),
+                date(2024, 1, 5),
+                date(2024, 1, 8),
+                date(2024, 1, 9),
+            ],
+            "value": [1, 9, 5, 6],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_rolling_business_days() -> None:
+    df = pl.DataFrame(
+        {
+            "date": pl.date_range(date(2024, 1, 4), date(2024, 1, 9), "1d", eager=True),
+            "value": [1, 2, 3, 4, 5, 6],
+        }
+    )
+    result = df.rolling("date", period="2bd").agg(pl.col("value").sum())
+    expected = df.with_columns(value=pl.Series([1, 3, 5, 9, 14, 18]))
+    assert_frame_equal(result, expected)
+
+
+def test_unregister_business_calendar() -> None:
+    pl.register_business_calendar("test_unregister")
+    assert pl.unregister_business_calendar("test_unregister")
+    assert not pl.unregister_business_calendar("test_unregister")
+
+
+def test_register_business_calendar_empty_week_mask() -> None:
+    with pytest.raises(ComputeError, match="at least one business day"):
+        pl.register_business_calendar("test_empty", week_mask=[False] * 7)
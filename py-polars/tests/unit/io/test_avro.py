@@ -89,3 +89,48 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.mark.write_disk
+def test_scan_avro(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": list(range(1000)),
+            "b": [None if i % 7 == 0 else str(i) for i in range(1000)],
+        }
+    )
+    path = tmp_path / "scan.avro"
+    df.write_avro(path)
+
+    assert_frame_equal(pl.scan_avro(path).collect(), df)
+    assert_frame_equal(
+        pl.scan_avro(path).select("b", "a").slice(80, 300).collect(),
+        df.select("b", "a").slice(80, 300),
+    )
+    assert_frame_equal(
+        pl.scan_avro(path, n_rows=10, row_index_name="index").collect(),
+        df.head(10).with_row_index("index"),
+    )
+    assert pl.scan_avro(path).select(pl.len()).collect().item() == 1000
+    assert_frame_equal(pl.scan_avro([path, path]).collect(), pl.concat([df, df]))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_avro(
+    example_df: pl.DataFrame, compression: AvroCompression, tmp_path: Path
+) -> None:
+    path = tmp_path / "sink.avro"
+    example_df.lazy().filter(pl.col("i64") > 1).sink_avro(
+        path, compression=compression, name="record"
+    )
+
+    expected = example_df.filter(pl.col("i64") > 1)
+    assert_frame_equal(pl.read_avro(path), expected)
+    assert_frame_equal(pl.scan_avro(path).collect(), expected)
+
+
+def test_sink_avro_invalid_compression(example_df: pl.DataFrame) -> None:
+    lf = example_df.lazy()
+    with pytest.raises(ValueError, match="avro `compression` must be one of"):
+        lf.sink_avro(io.BytesIO(), compression="lz4")  # type: ignore[arg-type]
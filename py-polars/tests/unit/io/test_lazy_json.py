@@ -1,10 +1,13 @@
 from __future__ import annotations
 
+import io
+import json
 from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
+from polars.exceptions import ComputeError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -327,3 +330,59 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("chunk_size", [None, 7])
+def test_scan_json(
+    tmp_path: Path, chunk_size: int | None, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    if chunk_size is not None:
+        monkeypatch.setenv("POLARS_FORCE_NDJSON_CHUNK_SIZE", str(chunk_size))
+
+    df = pl.DataFrame(
+        {
+            "a": list(range(100)),
+            "b": [None if i % 7 == 0 else f"s\n{i}" for i in range(100)],
+        }
+    )
+    path = tmp_path / "data.json"
+    path.write_text(json.dumps(df.to_dicts(), indent=2))
+
+    assert_frame_equal(pl.scan_json(path).collect(), df)
+    assert_frame_equal(pl.scan_json(path).select("b").collect(), df.select("b"))
+    assert_frame_equal(pl.scan_json(path, n_rows=5).collect(), df.head(5))
+    assert_frame_equal(
+        pl.scan_json(path, row_index_name="idx").slice(40, 20).collect(),
+        df.with_row_index("idx").slice(40, 20),
+    )
+    assert_frame_equal(pl.scan_json(path).tail(15).collect(), df.tail(15))
+    assert pl.scan_json(path).select(pl.len()).collect().item() == 100
+
+
+def test_scan_json_path() -> None:
+    source = b"""{
+        "meta": {"items": [{"a": 0}]},
+        "data": {"items": [{"a": 1, "b": "x"}, {"a": 2}], "a.b": {"a": 3}}
+    }"""
+
+    assert_frame_equal(
+        pl.scan_json(io.BytesIO(source), json_path="$.data.items").collect(),
+        pl.DataFrame({"a": [1, 2], "b": ["x", None]}),
+    )
+    assert_frame_equal(
+        pl.scan_json(io.BytesIO(source), json_path="$.data['a.b']").collect(),
+        pl.DataFrame({"a": [3]}),
+    )
+
+    with pytest.raises(ComputeError, match="key 'missing' not found"):
+        pl.scan_json(io.BytesIO(source), json_path="$.missing").collect()
+
+    with pytest.raises(ComputeError, match="invalid JSON path"):
+        pl.scan_json(io.BytesIO(source), json_path="data.items")
+
+
+def test_scan_json_truncated() -> None:
+    source = io.BytesIO(b'[{"a": 1}, {"a": "\\')
+    with pytest.raises(ComputeError, match="unterminated string"):
+        pl.scan_json(source, schema={"a": pl.Int64}).collect()
@@ -272,6 +272,105 @@ def test_parquet_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> Non
     assert "Predicate pushdown: reading 1 / 2 row groups" in captured
 
 
+@pytest.mark.write_disk
+def test_parquet_bloom_filter_pushdown(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    # Both row groups have the same min / max, so only the bloom filter can skip.
+    df = pl.DataFrame({"idx": [*range(0, 100, 2), *range(1, 100, 2)]}).with_columns(
+        s=pl.format("v{}", "idx")
+    )
+
+    file_path = tmp_path / "bloom.parquet"
+    df.lazy().sink_parquet(
+        file_path,
+        row_group_size=50,
+        field_overwrites=[
+            pl.io.parquet.ParquetFieldOverwrites(name="idx", bloom_filter=True),
+            pl.io.parquet.ParquetFieldOverwrites(name="s", bloom_filter=True),
+        ],
+    )
+
+    for pred in [
+        pl.col("idx") == 51,
+        pl.col("idx").is_in([1, 3]),
+        pl.col("s") == "v3",
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+    captured = capfd.readouterr().err
+    assert "Bloom filter pushdown: skipping 1 / 2 row groups" in captured
+
+    # Within the min / max of both row groups, but not present in either.
+    result = pl.scan_parquet(file_path).filter(pl.col("s") == "v100").collect()
+    assert result.height == 0
+
+    captured = capfd.readouterr().err
+    assert "Bloom filter pushdown: skipping 2 / 2 row groups" in captured
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("parallel", ["auto", "prefiltered", "columns"])
+@pytest.mark.parametrize("use_pyarrow", [False, True])
+@pytest.mark.parametrize("force_async", [False, True])
+def test_parquet_page_index_pushdown(
+    parallel: ParallelStrategy,
+    use_pyarrow: bool,
+    force_async: bool,
+    tmp_path: Path,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    if force_async:
+        # Only the pages that are needed are fetched from object stores.
+        monkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "idx": pl.arange(0, n, eager=True),
+            "s": pl.format("{}", pl.arange(0, n, eager=True)).str.zfill(5),
+            "nullable": [None if i % 3 == 0 else i for i in range(n)],
+            "list": [[i] for i in range(n)],
+        },
+        schema_overrides={"nullable": pl.Int64},
+    )
+
+    file_path = tmp_path / "page_index.parquet"
+    if use_pyarrow:
+        pq.write_table(
+            df.to_arrow(),
+            file_path,
+            data_page_size=512,
+            write_page_index=True,
+            row_group_size=n // 4,
+        )
+    else:
+        df.write_parquet(file_path, data_page_size=512, row_group_size=n // 4)
+
+    for pred in [
+        pl.col("idx") == 5000,
+        pl.col("idx").is_in([17, 9999]),
+        pl.col("idx").is_between(4000, 4010),
+        pl.col("s") == "00042",
+        pl.col("nullable") == 301,
+        (pl.col("idx") > 100) & (pl.col("nullable") == 9998),
+        pl.col("idx") == n,
+    ]:
+        q = pl.scan_parquet(file_path, parallel=parallel)
+        assert_frame_equal(q.filter(pred).collect(), df.filter(pred))
+
+        q = pl.scan_parquet(file_path, parallel=parallel, row_index_name="ri")
+        assert_frame_equal(
+            q.filter(pred).collect(), df.with_row_index("ri").filter(pred)
+        )
+
+
 @pytest.mark.write_disk
 def test_categorical(tmp_path: Path) -> None:
     tmp_path.mkdir(exist_ok=True)
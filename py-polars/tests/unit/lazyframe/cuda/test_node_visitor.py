@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import io
+import json
 import sys
 import time
 from functools import lru_cache, partial
@@ -155,3 +157,11 @@ def test_path_uri_to_python_conversion_22766(tmp_path: Path) -> None:
     # Windows fails because it turns everything into `\\`
     if sys.platform != "win32":
         assert out == [path]
+
+
+def test_json_scan_type() -> None:
+    q = pl.scan_json(io.BytesIO(b'{"data": [{"x": 1}]}'), json_path="$.data")
+
+    scan_type = q._ldf.visit().view_current_node().scan_type
+    assert scan_type[0] == "json"
+    assert json.loads(scan_type[1])["json_path"] == {"keys": ["data"]}
@@ -1240,3 +1240,149 @@ def test_list_contains() -> None:
 def test_list_diff_invalid_type() -> None:
     with pytest.raises(pl.exceptions.InvalidOperationError):
         pl.Series([1, 2, 3]).list.diff()
+
+
+def test_list_index_of() -> None:
+    s = pl.Series("a", [[3, 2, 1], [1, None, 1], [], None])
+    idx = pl.get_index_type()
+
+    assert_series_equal(s.list.index_of(1), pl.Series("a", [2, 0, None, None], idx))
+    assert_series_equal(
+        s.list.index_of(None), pl.Series("a", [None, 1, None, None], idx)
+    )
+
+    df = pl.DataFrame(
+        {
+            "a": [["x", "y"], ["y", "x"], ["z"]],
+            "needle": ["y", "y", "y"],
+        }
+    )
+    result = df.select(
+        lit=pl.col("a").list.index_of("x"),
+        by_col=pl.col("a").list.index_of(pl.col("needle")),
+    )
+    expected = pl.DataFrame(
+        {"lit": [0, 1, None], "by_col": [1, 0, None]},
+        schema={"lit": idx, "by_col": idx},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_list_index_of_lossy() -> None:
+    with pytest.raises(InvalidOperationError, match="cannot cast lossless"):
+        pl.Series([[1, 2]]).list.index_of(1.5)
+
+
+def test_list_pad_end() -> None:
+    s = pl.Series("a", [[1], [2, 3], [4, 5, 6, 7], None, []])
+
+    assert_series_equal(
+        s.list.pad_end(3, 0),
+        pl.Series("a", [[1, 0, 0], [2, 3, 0], [4, 5, 6, 7], None, [0, 0, 0]]),
+    )
+    assert_series_equal(
+        s.list.pad_end(2),
+        pl.Series("a", [[1, None], [2, 3], [4, 5, 6, 7], None, [None, None]]),
+    )
+
+    df = pl.DataFrame(
+        {
+            "a": [["a"], ["b", "c"], []],
+            "length": [2, 4, None],
+            "fill": ["x", "y", "z"],
+        }
+    )
+    result = df.select(pl.col("a").list.pad_end("length", pl.col("fill")))
+    expected = pl.DataFrame({"a": [["a", "x"], ["b", "c", "y", "y"], None]})
+    assert_frame_equal(result, expected)
+
+
+def test_list_zip() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [4], [], None],
+            "b": [["x", "y"], ["z", "w"], ["v"], ["u"]],
+        }
+    )
+    result = df.select(pl.col("a").list.zip("b"))
+    expected = pl.DataFrame(
+        {
+            "a": [
+                [{"field_0": 1, "field_1": "x"}, {"field_0": 2, "field_1": "y"}],
+                [{"field_0": 4, "field_1": "z"}],
+                [],
+                None,
+            ]
+        },
+        schema={
+            "a": pl.List(pl.Struct({"field_0": pl.Int64, "field_1": pl.String}))
+        },
+    )
+    assert_frame_equal(result, expected)
+    assert_series_equal(df["a"].list.zip(df["b"]), expected.to_series())
+
+
+def test_list_zip_broadcast() -> None:
+    s = pl.Series("a", [[1, 2], [3]])
+    result = s.list.zip(pl.Series([[True, False]]))
+    assert result.to_list() == [
+        [{"field_0": 1, "field_1": True}, {"field_0": 2, "field_1": False}],
+        [{"field_0": 3, "field_1": True}],
+    ]
+
+    with pytest.raises(pl.exceptions.ShapeError):
+        s.list.zip(pl.Series([[1], [2], [3]]))
+
+
+def test_list_flatten() -> None:
+    s = pl.Series("a", [[[[1, 2], [3]], [[4]]], [[None, [5]], []], [None], None])
+
+    assert_series_equal(
+        s.list.flatten(),
+        pl.Series("a", [[1, 2, 3, 4], [5], [], None]),
+    )
+    assert_series_equal(
+        s.list.flatten(1),
+        pl.Series("a", [[[1, 2], [3], [4]], [None, [5]], [], None]),
+    )
+    assert_series_equal(s.list.flatten(0), s)
+
+    with pytest.raises(InvalidOperationError, match="cannot flatten 4 levels"):
+        s.list.flatten(4)
+
+
+def test_list_flatten_array() -> None:
+    s = pl.Series(
+        "a",
+        [[[1, 2], [3, 4]], [None, [5, 6]], None],
+        dtype=pl.List(pl.Array(pl.Int64, 2)),
+    )
+    assert_series_equal(s.list.flatten(), pl.Series("a", [[1, 2, 3, 4], [5, 6], None]))
+
+
+def test_list_flatten_sliced() -> None:
+    s = pl.Series([[["a"], ["b", "c"]], [["d"]], [["e"], None, ["f"]]])
+    result = s.slice(1).list.flatten()
+    assert_series_equal(result, pl.Series([["d"], ["e", "f"]]))
+
+
+def test_list_reshape() -> None:
+    s = pl.Series("a", [[1, 2, 3, 4], None, [5, 6, 7, 8]])
+
+    result = s.list.reshape((2, 2))
+    expected = pl.Series(
+        "a",
+        [[[1, 2], [3, 4]], None, [[5, 6], [7, 8]]],
+        dtype=pl.Array(pl.Int64, (2, 2)),
+    )
+    assert_series_equal(result, expected)
+    assert_series_equal(
+        s.list.reshape((4,)),
+        pl.Series("a", [[1, 2, 3, 4], None, [5, 6, 7, 8]], pl.Array(pl.Int64, 4)),
+    )
+
+    nested = pl.Series("a", [[[1, 2], [3, 4], [5, 6]]])
+    assert nested.list.reshape((2, 3)).to_list() == [[[1, 2, 3], [4, 5, 6]]]
+
+    with pytest.raises(InvalidOperationError, match="cannot reshape list of size 4"):
+        s.list.reshape((3,))
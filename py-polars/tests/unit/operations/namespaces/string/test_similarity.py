@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError, ShapeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_str_edit_distances() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "ca", "abcdef", "", "héllo", None, "abc"],
+            "b": ["sitting", "abc", "badcfe", "xyz", "hlélo", "abc", None],
+        }
+    )
+    result = df.select(
+        lev=pl.col("a").str.levenshtein(pl.col("b")),
+        dl=pl.col("a").str.damerau_levenshtein(pl.col("b")),
+    )
+    expected = pl.DataFrame(
+        {
+            "lev": [3, 3, 4, 3, 2, None, None],
+            "dl": [3, 2, 3, 3, 1, None, None],
+        },
+        schema={"lev": pl.UInt32, "dl": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_str_hamming() -> None:
+    s = pl.Series("a", ["karolin", "kathrin", "karl", "kärolin", None])
+    result = s.str.hamming("karolin")
+    expected = pl.Series("a", [0, 3, None, 1, None], dtype=pl.UInt32)
+    assert_series_equal(result, expected)
+
+
+def test_str_jaro_winkler() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["MARTHA", "DIXON", "", "abc", "héllo", None],
+            "b": ["MARHTA", "DICKSONX", "", "", "héllo", "x"],
+        }
+    )
+    result = df.select(pl.col("a").str.jaro_winkler(pl.col("b")))
+    expected = pl.DataFrame(
+        {"a": [0.961111, 0.813333, 1.0, 0.0, 1.0, None]},
+    )
+    assert_frame_equal(result, expected, abs_tol=1e-6)
+
+
+def test_str_jaccard() -> None:
+    s = pl.Series("a", ["night", "nacht", "nigh", "", "n", None])
+    result = s.str.jaccard("night", ngram=2)
+    expected = pl.Series("a", [1.0, 1 / 7, 0.75, 0.0, 0.0, None])
+    assert_series_equal(result, expected)
+
+    # Strings shorter than the n-gram size are a single n-gram.
+    other = pl.Series(["ab", "", "abc"])
+    result = pl.Series(["ab", "", "ab"]).str.jaccard(other, ngram=3)
+    assert result.to_list() == [1.0, 1.0, 0.0]
+
+    with pytest.raises(InvalidOperationError, match="must be positive"):
+        s.str.jaccard("night", ngram=0)
+
+
+def test_str_similarity_broadcast_lhs_literal() -> None:
+    df = pl.DataFrame({"b": ["kitten", "sitting"]})
+    result = df.select(pl.lit("kitten").str.levenshtein(pl.col("b")))
+    expected = pl.DataFrame({"literal": [0, 3]}, schema={"literal": pl.UInt32})
+    assert_frame_equal(result, expected)
+
+
+def test_str_similarity_length_mismatch() -> None:
+    df = pl.DataFrame({"a": ["a", "b", "c"]})
+    with pytest.raises(ShapeError):
+        df.select(pl.col("a").str.levenshtein(pl.Series(["a", "b"])))
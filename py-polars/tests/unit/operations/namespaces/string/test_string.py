@@ -1823,6 +1823,74 @@ def test_splitn_expr() -> None:
     assert_frame_equal(out, expected)
 
 
+def test_split_regex() -> None:
+    s = pl.Series("s", ["a1b22c", "foo", "x 9", "", None])
+
+    result = s.str.split(r"\d+", literal=False)
+    expected = pl.Series("s", [["a", "b", "c"], ["foo"], ["x ", ""], [""], None])
+    assert_series_equal(result, expected)
+
+    result = s.str.split(r"\d+", inclusive=True, literal=False)
+    expected = pl.Series("s", [["a1", "b22", "c"], ["foo"], ["x 9"], [], None])
+    assert_series_equal(result, expected)
+
+    # Metacharacters are only special when not splitting literally.
+    assert s.str.split(".", literal=True).to_list()[0] == ["a1b22c"]
+
+
+def test_split_regex_expr() -> None:
+    df = pl.DataFrame({"x": ["a1b", "a.b", "a b"], "by": [r"\d", r"\.", None]})
+    out = df.select(pl.col("x").str.split(pl.col("by"), literal=False))
+    expected = pl.DataFrame({"x": [["a", "b"], ["a", "b"], None]})
+    assert_frame_equal(out, expected)
+
+    df = pl.DataFrame({"by": [r"\d", "[a-z]"]})
+    out = df.select(pl.lit("a1b2c").str.split(pl.col("by"), literal=False))
+    expected = pl.DataFrame({"literal": [["a", "b", "c"], ["", "1", "2", ""]]})
+    assert_frame_equal(out, expected)
+
+
+def test_split_exact_regex() -> None:
+    df = pl.DataFrame({"x": ["a_1", "b-2-3", "c", None]})
+
+    out = df.select(pl.col("x").str.split_exact(r"[_-]", 1, literal=False))
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a", "b", "c", None],
+            "field_1": ["1", "2", None, None],
+        }
+    )
+    assert_frame_equal(out.unnest("x"), expected)
+
+    out = df["x"].str.split_exact(r"[_-]", 1, inclusive=True, literal=False)
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a_", "b-", "c", None],
+            "field_1": ["1", "2-", None, None],
+        }
+    )
+    assert_frame_equal(out.to_frame().unnest("x"), expected)
+
+
+def test_splitn_regex() -> None:
+    df = pl.DataFrame({"x": ["a_1", "b-2-3", "c", None]})
+    out = df.select(pl.col("x").str.splitn(r"[_-]", 2, literal=False))
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a", "b", "c", None],
+            "field_1": ["1", "2-3", None, None],
+        }
+    )
+    assert_frame_equal(out.unnest("x"), expected)
+
+
+def test_split_regex_invalid() -> None:
+    s = pl.Series(["a(b"])
+    with pytest.raises(ComputeError, match="regex error"):
+        s.str.split("(", literal=False)
+    assert s.str.split("(").to_list() == [["a", "b"]]
+
+
 def test_titlecase() -> None:
     df = pl.DataFrame(
         {
@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_str_parse_url() -> None:
+    s = pl.Series(
+        "url",
+        [
+            "HTTPS://Example.com:8443/a/./b?q=1&r=2#frag",
+            "http://example.com",
+            "/api/v1?id=7",
+            "//cdn.example.com/x.js",
+            "mailto:joe@example.com",
+            None,
+        ],
+    )
+    result = s.str.parse_url().struct.unnest()
+    expected = pl.DataFrame(
+        {
+            "scheme": ["https", "http", None, None, "mailto", None],
+            "host": ["example.com", "example.com", None, "cdn.example.com", None, None],
+            "port": [8443, None, None, None, None, None],
+            "path": ["/a/b", "/", "/api/v1", "/x.js", "joe@example.com", None],
+            "query": ["q=1&r=2", None, "id=7", None, None, None],
+            "fragment": ["frag", None, None, None, None, None],
+        },
+        schema_overrides={"port": pl.UInt16},
+    )
+    assert_frame_equal(result, expected)
+    assert s.str.parse_url().null_count() == 1
+
+
+def test_str_parse_url_strict() -> None:
+    s = pl.Series(["https://example.com", "not a url"])
+    with pytest.raises(ComputeError, match="invalid URL"):
+        s.str.parse_url()
+
+    result = s.str.parse_url(strict=False)
+    assert result.is_null().to_list() == [False, True]
+
+
+def test_str_url_query_param() -> None:
+    df = pl.DataFrame(
+        {
+            "url": [
+                "https://x.com/search?q=polars+rust&page=2#q=frag",
+                "/search?page=3&page=4",
+                "/search?q=caf%C3%A9",
+                "/search",
+                "http://h/p#a?q=1",
+                None,
+            ],
+            "name": ["page", "page", "q", "q", "q", "q"],
+        }
+    )
+    result = df.select(
+        q=pl.col("url").str.url_query_param("q"),
+        by_name=pl.col("url").str.url_query_param(pl.col("name")),
+    )
+    expected = pl.DataFrame(
+        {
+            "q": ["polars rust", None, "café", None, None, None],
+            "by_name": ["2", "3", "café", None, None, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_str_percent_encoding() -> None:
+    s = pl.Series(["café au lait", "a/b?c=d&e", "-._~", "", None])
+    encoded = s.str.encode("percent")
+    expected = pl.Series(
+        ["caf%C3%A9%20au%20lait", "a%2Fb%3Fc%3Dd%26e", "-._~", "", None]
+    )
+    assert_series_equal(encoded, expected)
+    assert_series_equal(encoded.str.decode("percent").cast(pl.String), s)
+
+
+def test_str_percent_decode_binary() -> None:
+    s = pl.Series(["%FF%00", "%41", "a+b"])
+    result = s.str.decode("percent")
+    assert_series_equal(result, pl.Series([b"\xff\x00", b"A", b"a+b"]))
+
+
+def test_str_percent_decode_invalid() -> None:
+    s = pl.Series(["100%", "%4", "%zz", "%41"])
+    with pytest.raises(ComputeError, match="invalid `percent` encoding"):
+        s.str.decode("percent")
+
+    result = s.str.decode("percent", strict=False)
+    assert_series_equal(result, pl.Series([None, None, None, b"A"]))
+
+
+def test_str_encoding_invalid() -> None:
+    with pytest.raises(ValueError, match="must be one of"):
+        pl.col("a").str.encode("url")  # type: ignore[arg-type]
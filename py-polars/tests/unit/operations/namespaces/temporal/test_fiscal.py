@@ -0,0 +1,125 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+from typing import TYPE_CHECKING
+from zoneinfo import ZoneInfo
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError
+from polars.testing import assert_series_equal
+
+if TYPE_CHECKING:
+    from polars._typing import FiscalPattern
+
+
+def test_fiscal_year_quarter_monthly() -> None:
+    s = pl.Series(
+        "date",
+        [date(2024, 3, 31), date(2024, 4, 1), date(2024, 12, 31), None],
+    )
+    result = s.dt.fiscal_year(4)
+    expected = pl.Series("date", [2024, 2025, 2025, None], dtype=pl.Int32)
+    assert_series_equal(result, expected)
+
+    result = s.dt.fiscal_quarter(4)
+    expected = pl.Series("date", [4, 1, 3, None], dtype=pl.Int8)
+    assert_series_equal(result, expected)
+
+
+def test_fiscal_defaults_to_calendar_year() -> None:
+    s = pl.date_range(date(2023, 11, 15), date(2025, 2, 15), "1w", eager=True)
+    assert_series_equal(s.dt.fiscal_year(), s.dt.year())
+    assert_series_equal(s.dt.fiscal_quarter(), s.dt.quarter())
+    assert_series_equal(s.dt.truncate_fiscal("1q"), s.dt.truncate("1q"))
+
+
+def test_fiscal_retail_calendar() -> None:
+    # Years start on the Sunday nearest to February 1st, so that fiscal year 2024 runs
+    # from 2023-01-29 up to and including 2024-02-03 and has 53 weeks.
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2023, 1, 28),
+                date(2023, 1, 29),
+                date(2023, 4, 30),
+                date(2024, 2, 3),
+                date(2024, 2, 4),
+            ]
+        }
+    )
+    dt = pl.col("date").dt
+    result = df.select(
+        year=dt.fiscal_year(2, pattern="4-4-5", week_start=7),
+        quarter=dt.fiscal_quarter(2, pattern="4-4-5", week_start=7),
+        period=dt.truncate_fiscal("1mo", 2, pattern="4-4-5", week_start=7),
+    )
+    expected = pl.DataFrame(
+        {
+            "year": [2023, 2024, 2024, 2024, 2025],
+            "quarter": [4, 1, 2, 4, 1],
+            # The last period of a 53-week year has 6 weeks.
+            "period": [
+                date(2022, 12, 25),
+                date(2023, 1, 29),
+                date(2023, 4, 30),
+                date(2023, 12, 24),
+                date(2024, 2, 4),
+            ],
+        },
+        schema_overrides={"year": pl.Int32, "quarter": pl.Int8},
+    )
+    assert result.equals(expected)
+
+
+@pytest.mark.parametrize(
+    ("pattern", "expected"),
+    [
+        ("4-4-5", [date(2023, 1, 29), date(2023, 2, 26), date(2023, 3, 26)]),
+        ("4-5-4", [date(2023, 1, 29), date(2023, 2, 26), date(2023, 4, 2)]),
+        ("5-4-4", [date(2023, 1, 29), date(2023, 3, 5), date(2023, 4, 2)]),
+    ],
+)
+def test_truncate_fiscal_patterns(pattern: FiscalPattern, expected: list[date]) -> None:
+    s = pl.Series([date(2023, 2, 25), date(2023, 3, 25), date(2023, 4, 29)])
+    result = s.dt.truncate_fiscal("1mo", 2, pattern=pattern, week_start=7)
+    assert result.to_list() == expected
+
+
+def test_truncate_fiscal_datetime() -> None:
+    s = pl.Series([datetime(2024, 5, 15, 13), datetime(2025, 3, 31, 23, 59)])
+    assert s.dt.truncate_fiscal("1y", 4).to_list() == [
+        datetime(2024, 4, 1),
+        datetime(2024, 4, 1),
+    ]
+
+    tz = ZoneInfo("Europe/Amsterdam")
+    result = s.dt.replace_time_zone("Europe/Amsterdam").dt.truncate_fiscal("1q", 4)
+    assert result.to_list() == [
+        datetime(2024, 4, 1, tzinfo=tz),
+        datetime(2025, 1, 1, tzinfo=tz),
+    ]
+
+
+def test_fiscal_time_zone_uses_local_date() -> None:
+    s = pl.Series([datetime(2024, 3, 31, 23)]).dt.replace_time_zone("UTC")
+    assert s.dt.fiscal_year(4).item() == 2024
+    assert s.dt.convert_time_zone("Asia/Tokyo").dt.fiscal_year(4).item() == 2025
+
+
+@pytest.mark.parametrize("every", ["1w", "5mo", "2y", "1mo1d"])
+def test_truncate_fiscal_invalid_every(every: str) -> None:
+    s = pl.Series([date(2024, 1, 1)])
+    with pytest.raises(ComputeError, match="fiscal truncation requires"):
+        s.dt.truncate_fiscal(every)
+
+
+def test_fiscal_invalid_calendar() -> None:
+    s = pl.Series([date(2024, 1, 1)])
+    with pytest.raises(ComputeError, match="start month must be between 1 and 12"):
+        s.dt.fiscal_year(13)
+    with pytest.raises(ComputeError, match="ISO weekday between 1 and 7"):
+        s.dt.fiscal_quarter(pattern="4-4-5", week_start=0)
+    with pytest.raises(ValueError, match="`pattern` must be one of"):
+        s.dt.fiscal_year(pattern="4-4-4")  # type: ignore[arg-type]
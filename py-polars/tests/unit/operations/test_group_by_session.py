@@ -0,0 +1,189 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+from zoneinfo import ZoneInfo
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def clicks() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "user": ["a", "a", "b", "a", "b", "a"],
+            "time": [
+                datetime(2024, 1, 1, 0, 0),
+                datetime(2024, 1, 1, 0, 10),
+                datetime(2024, 1, 1, 0, 15),
+                datetime(2024, 1, 1, 1, 0),
+                datetime(2024, 1, 1, 1, 20),
+                datetime(2024, 1, 1, 1, 25),
+            ],
+            "value": [1, 2, 3, 4, 5, 6],
+        }
+    )
+
+
+def test_group_by_session(clicks: pl.DataFrame) -> None:
+    result = clicks.group_by_session("time", gap="30m").agg(
+        pl.len(),
+        pl.col("time").last().alias("end"),
+        pl.col("value").sum(),
+    )
+    expected = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 1, 1, 0, 0),
+                datetime(2024, 1, 1, 1, 0),
+            ],
+            "len": pl.Series([3, 3], dtype=pl.UInt32),
+            "end": [
+                datetime(2024, 1, 1, 0, 15),
+                datetime(2024, 1, 1, 1, 25),
+            ],
+            "value": [6, 15],
+        }
+    )
+    assert_frame_equal(result, expected)
+    assert result["time"].flags["SORTED_ASC"]
+
+    result = clicks.group_by_session("time", gap=timedelta(minutes=6)).agg(pl.len())
+    assert result["len"].to_list() == [1, 2, 1, 1, 2]
+
+    # An inactivity of exactly `gap` starts a new session.
+    result = clicks.group_by_session("time", gap="5m").agg(pl.len())
+    assert result["len"].to_list() == [1, 1, 1, 1, 1, 1]
+
+    lazy_result = (
+        clicks.lazy()
+        .group_by_session("time", gap="30m")
+        .agg(pl.col("value").sum())
+        .collect()
+    )
+    assert lazy_result["value"].to_list() == [6, 15]
+
+
+def test_group_by_session_by(clicks: pl.DataFrame) -> None:
+    result = clicks.group_by_session("time", gap="30m", group_by="user").agg(
+        pl.len(), pl.col("value")
+    )
+    expected = pl.DataFrame(
+        {
+            "user": ["a", "a", "b", "b"],
+            "time": [
+                datetime(2024, 1, 1, 0, 0),
+                datetime(2024, 1, 1, 1, 0),
+                datetime(2024, 1, 1, 0, 15),
+                datetime(2024, 1, 1, 1, 20),
+            ],
+            "len": pl.Series([2, 2, 1, 1], dtype=pl.UInt32),
+            "value": [[1, 2], [4, 6], [3], [5]],
+        }
+    )
+    assert_frame_equal(result, expected, check_row_order=False)
+
+    groups = {
+        key: df["value"].to_list()
+        for key, df in clicks.group_by_session("time", gap="30m", group_by="user")
+    }
+    assert groups == {
+        ("a", datetime(2024, 1, 1, 0, 0)): [1, 2],
+        ("a", datetime(2024, 1, 1, 1, 0)): [4, 6],
+        ("b", datetime(2024, 1, 1, 0, 15)): [3],
+        ("b", datetime(2024, 1, 1, 1, 20)): [5],
+    }
+
+
+@pytest.mark.parametrize("dtype", [pl.Int32, pl.Int64])
+def test_group_by_session_int(dtype: pl.DataType) -> None:
+    df = pl.DataFrame({"idx": [0, 1, 3, 6, 7, 10]}, schema={"idx": dtype})
+    result = df.group_by_session("idx", gap="3i").agg(pl.len())
+    expected = pl.DataFrame(
+        {"idx": [0, 6, 10], "len": [3, 2, 1]},
+        schema={"idx": dtype, "len": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="must be a parsed integer"):
+        df.group_by_session("idx", gap="3d").agg(pl.len())
+
+
+def test_group_by_session_calendar_gap() -> None:
+    tz = "Europe/Amsterdam"
+    # 23 hours apart, due to the DST transition on 2024-03-31.
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 3, 30, 12, tzinfo=ZoneInfo(tz)),
+                datetime(2024, 3, 31, 12, tzinfo=ZoneInfo(tz)),
+            ]
+        }
+    )
+    assert df.group_by_session("time", gap="1d").agg(pl.len()).height == 2
+    assert df.group_by_session("time", gap="24h").agg(pl.len()).height == 1
+    assert df["time"].dt.session_id("1d").to_list() == [0, 1]
+    assert df["time"].dt.session_id("24h").to_list() == [0, 0]
+
+
+def test_session_id(clicks: pl.DataFrame) -> None:
+    result = clicks.select(
+        pl.col("time").dt.session_id("30m").alias("all"),
+        pl.col("time").dt.session_id("30m").over("user").alias("per_user"),
+    )
+    expected = pl.DataFrame(
+        {"all": [0, 0, 0, 1, 1, 1], "per_user": [0, 0, 0, 1, 1, 1]},
+        schema={"all": pl.UInt32, "per_user": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("idx", [1, 2, 5, 5, 9])
+    assert_series_equal(
+        s.dt.session_id("3i"), pl.Series("idx", [0, 0, 1, 1, 2], dtype=pl.UInt32)
+    )
+    assert s.clear().dt.session_id("3i").dtype == pl.UInt32
+
+
+def test_session_id_matches_group_by_session(clicks: pl.DataFrame) -> None:
+    lf = clicks.lazy()
+    via_id = (
+        lf.with_columns(
+            pl.col("time").dt.session_id("30m").over("user").alias("session")
+        )
+        .group_by("user", "session", maintain_order=True)
+        .agg(pl.col("time").first(), pl.col("value").sum())
+        .drop("session")
+    )
+    via_group_by = lf.group_by_session("time", gap="30m", group_by="user").agg(
+        pl.col("value").sum()
+    )
+    assert_frame_equal(
+        via_id.collect(), via_group_by.collect(), check_row_order=False
+    )
+
+
+def test_group_by_session_streaming(clicks: pl.DataFrame) -> None:
+    q = clicks.lazy().group_by_session("time", gap="30m").agg(pl.col("value").sum())
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_group_by_session_errors(clicks: pl.DataFrame) -> None:
+    unsorted = clicks.reverse()
+    with pytest.raises(InvalidOperationError, match="not sorted"):
+        unsorted.group_by_session("time", gap="30m").agg(pl.len())
+    with pytest.raises(InvalidOperationError, match="not sorted"):
+        unsorted.select(pl.col("time").dt.session_id("30m"))
+    with pytest.raises(ComputeError, match="input data is not sorted"):
+        unsorted.group_by_session("time", gap="30m", group_by="user").agg(pl.len())
+
+    with pytest.raises(ComputeError, match="strictly positive"):
+        clicks.group_by_session("time", gap="0m").agg(pl.len())
+    with pytest.raises(ComputeError, match="strictly positive"):
+        clicks.select(pl.col("time").dt.session_id("-30m"))
+
+    with_nulls = pl.DataFrame({"time": [None, datetime(2024, 1, 1)]})
+    with pytest.raises(ComputeError, match="null values"):
+        with_nulls.group_by_session("time", gap="30m").agg(pl.len())
@@ -6,7 +6,7 @@ from typing import TYPE_CHECKING, Any
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import NUMERIC_DTYPES
 
 if TYPE_CHECKING:
@@ -163,3 +163,65 @@ def test_interpolate_decimal_22475(
 
     q = df_decimal.lazy().with_columns(pl.col("data").interpolate(method=method))
     assert q.collect_schema() == q.collect().schema
+
+
+@pytest.mark.parametrize("input_dtype", NUMERIC_DTYPES)
+@pytest.mark.parametrize(
+    ("method", "output"),
+    [
+        ("previous", [None, 1, 1, 1, 3, 3, 5, None]),
+        ("next", [None, 1, 3, 3, 3, 5, 5, None]),
+    ],
+)
+def test_interpolate_previous_next(
+    input_dtype: PolarsDataType, method: InterpolationMethod, output: list[Any]
+) -> None:
+    df = pl.LazyFrame(
+        {"a": [None, 1, None, None, 3, None, 5, None]}, schema={"a": input_dtype}
+    )
+    result = df.with_columns(pl.all().interpolate(method=method))
+    assert result.collect_schema()["a"] == input_dtype
+    expected = pl.DataFrame({"a": output}, schema={"a": input_dtype})
+    assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize(
+    ("method", "output"),
+    [
+        ("cubic", [None, 0.0, 0.6875, 1.0, 0.6875, 0.0, None]),
+        ("akima", [None, 0.0, 0.75, 1.0, 0.75, 0.0, None]),
+        ("pchip", [None, 0.0, 0.75, 1.0, 0.75, 0.0, None]),
+    ],
+)
+@pytest.mark.parametrize("input_dtype", [pl.Int64, pl.Float32, pl.Float64])
+def test_interpolate_cubic(
+    method: InterpolationMethod, output: list[Any], input_dtype: PolarsDataType
+) -> None:
+    df = pl.LazyFrame(
+        {"a": [None, 0, None, 1, None, 0, None]}, schema={"a": input_dtype}
+    )
+    result = df.with_columns(pl.all().interpolate(method=method))
+    output_dtype = pl.Float32 if input_dtype == pl.Float32 else pl.Float64
+    assert result.collect_schema()["a"] == output_dtype
+    expected = pl.DataFrame({"a": output}, schema={"a": output_dtype})
+    assert_frame_equal(result.collect(), expected)
+
+
+@pytest.mark.parametrize("method", ["cubic", "akima", "pchip"])
+def test_interpolate_cubic_two_values(method: InterpolationMethod) -> None:
+    # Without enough values for a spline all methods fall back to linear.
+    s = pl.Series("a", [1.0, None, None, 4.0])
+    assert_series_equal(s.interpolate(method), pl.Series("a", [1.0, 2.0, 3.0, 4.0]))
+
+    s = pl.Series("a", [date(2020, 1, 1), None, date(2020, 1, 3)])
+    expected = pl.Series("a", [date(2020, 1, 1), date(2020, 1, 2), date(2020, 1, 3)])
+    assert_series_equal(s.interpolate(method), expected)
+
+
+def test_interpolate_pchip_monotone() -> None:
+    s = pl.Series([0, None, 0, None, 1, None, 1])
+    assert s.interpolate("pchip").to_list() == [0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]
+
+    cubic = s.interpolate("cubic")
+    assert cubic.min() < 0.0  # type: ignore[operator]
+    assert cubic.max() > 1.0  # type: ignore[operator]
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date
+from datetime import date, datetime
 from typing import TYPE_CHECKING
 
 import hypothesis.strategies as st
@@ -14,7 +14,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from polars.testing.parametric import column, dataframes
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType
+    from polars._typing import InterpolationMethod, PolarsDataType
 
 
 @pytest.mark.parametrize(
@@ -249,3 +249,51 @@ def test_interpolate_by_invalid() -> None:
         match="null values in `by` column are not yet supported in 'interpolate_by'",
     ):
         s.interpolate_by(by)
+
+
+@pytest.mark.parametrize(
+    ("method", "expected"),
+    [
+        ("linear", [0.0, 0.25, 1.0, 0.5, 0.0]),
+        ("nearest", [0.0, 0.0, 1.0, 0.0, 0.0]),
+        ("previous", [0.0, 0.0, 1.0, 1.0, 0.0]),
+        ("next", [0.0, 1.0, 1.0, 0.0, 0.0]),
+        ("cubic", [0.0, 0.3671875, 1.0, 0.6875, 0.0]),
+        ("akima", [0.0, 0.4375, 1.0, 0.75, 0.0]),
+        ("pchip", [0.0, 0.4375, 1.0, 0.75, 0.0]),
+    ],
+)
+def test_interpolate_by_method(
+    method: InterpolationMethod, expected: list[float]
+) -> None:
+    df = pl.DataFrame(
+        {
+            "times": [datetime(2020, 1, 1, hour) for hour in [0, 1, 4, 6, 8]],
+            "values": [0, None, 1, None, 0],
+        }
+    )
+    result = df.select(pl.col("values").interpolate_by("times", method=method))
+    assert_series_equal(result["values"], pl.Series("values", expected))
+
+    # Unsorted `by` values.
+    result = df.reverse().select(
+        pl.col("values").interpolate_by("times", method=method)
+    )
+    assert_series_equal(result["values"], pl.Series("values", expected[::-1]))
+
+
+@pytest.mark.parametrize("method", ["cubic", "akima", "pchip"])
+def test_interpolate_by_method_ns_precision(method: InterpolationMethod) -> None:
+    # Nanosecond timestamps exceed the precision of f64, but their offsets don't.
+    start = datetime(2024, 1, 1)
+    df = pl.DataFrame(
+        {
+            "times": pl.Series([start] * 5, dtype=pl.Datetime("ns"))
+            + pl.Series([0, 1, 2, 3, 4], dtype=pl.Duration("ns")),
+            "values": [0.0, None, 2.0, None, 4.0],
+        }
+    )
+    result = df.select(pl.col("values").interpolate_by("times", method=method))
+    assert_series_equal(
+        result["values"], pl.Series("values", [0.0, 1.0, 2.0, 3.0, 4.0])
+    )
@@ -5,7 +5,7 @@ from typing import Any
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.exceptions import SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -201,6 +201,31 @@ def test_unnest_table_function(array_keyword: str) -> None:
         )
 
 
+def test_unnest_table_function_ordinality() -> None:
+    with pl.SQLContext(df=None, eager=True) as ctx:
+        res = ctx.execute(
+            "SELECT * FROM UNNEST(['a', 'b', 'c']) WITH ORDINALITY AS tbl (x, pos)"
+        )
+        assert_frame_equal(res, pl.DataFrame({"x": ["a", "b", "c"], "pos": [1, 2, 3]}))
+        res = ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) tbl (colx) WITH OFFSET")
+        assert_frame_equal(res, pl.DataFrame({"colx": [1, 2, 3], "offset": [0, 1, 2]}))
+
+
+def test_unnest_lateral() -> None:
+    df = pl.DataFrame({"id": [1, 2, 3], "tags": [["x", "y"], ["z"], []]})
+    with pl.SQLContext(df=df, eager=True) as ctx:
+        res = ctx.execute(
+            """
+            SELECT id, tag, pos
+            FROM df CROSS JOIN UNNEST(df.tags) WITH ORDINALITY AS t (tag, pos)
+            """
+        )
+        assert_frame_equal(
+            res,
+            pl.DataFrame({"id": [1, 1, 2], "tag": ["x", "y", "z"], "pos": [1, 2, 1]}),
+        )
+
+
 def test_unnest_table_function_errors() -> None:
     with pl.SQLContext(df=None, eager=True) as ctx:
         with pytest.raises(
@@ -226,9 +251,3 @@ def test_unnest_table_function_errors() -> None:
             match=r"UNNEST table must have an alias",
         ):
             ctx.execute("SELECT * FROM UNNEST([1, 2, 3])")
-
-        with pytest.raises(
-            SQLInterfaceError,
-            match=r"UNNEST tables do not \(yet\) support WITH OFFSET|ORDINALITY",
-        ):
-            ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) tbl (colx) WITH OFFSET")
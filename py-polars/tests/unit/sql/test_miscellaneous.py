@@ -540,3 +540,22 @@ def test_count_partition_22665(query: str, result: list[Any]) -> None:
     out = df.sql(query).select("b")
     expected = pl.DataFrame({"b": result}).cast({"b": pl.UInt32})
     assert_frame_equal(out, expected)
+
+
+def test_recursive_cte_max_iterations() -> None:
+    query = """
+        WITH RECURSIVE seq(n) AS (
+            SELECT 1
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < 20
+        )
+        SELECT n FROM seq
+    """
+    res = pl.SQLContext().execute(query, eager=True)
+    assert res["n"].to_list() == list(range(1, 21))
+
+    ctx = pl.SQLContext(max_recursive_cte_iterations=10)
+    with pytest.raises(
+        SQLInterfaceError, match="did not terminate within 10 iterations"
+    ):
+        ctx.execute(query)
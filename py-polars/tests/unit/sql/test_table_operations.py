@@ -117,3 +117,31 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_insert_update_merge(test_frame: pl.LazyFrame) -> None:
+    source = pl.LazyFrame({"x": [3, 4], "y": ["xyz", "ddd"]})
+    with pl.SQLContext(frame=test_frame, source=source, eager=True) as ctx:
+        ctx.execute("INSERT INTO frame (x, y) VALUES (9, 'zzz')")
+        ctx.execute("UPDATE frame SET y = UPPER(y) WHERE x < 3")
+        res = ctx.execute(
+            """
+            MERGE INTO frame AS f USING source AS s ON f.x = s.x
+            WHEN MATCHED THEN UPDATE SET y = s.y
+            WHEN NOT MATCHED THEN INSERT (x, y) VALUES (s.x, s.y)
+            """
+        )
+        expected = pl.DataFrame(
+            {
+                "x": [1, 2, 3, 9, 4],
+                "y": ["AAA", "BBB", "xyz", "zzz", "ddd"],
+                "z": [date(2000, 12, 31), date(1978, 11, 15), date(2077, 10, 20)]
+                + [None, None],
+            },
+            schema_overrides={"x": pl.UInt8},
+        )
+        assert_frame_equal(res, expected)
+        assert_frame_equal(ctx.execute("SELECT * FROM frame"), expected)
+
+        with pytest.raises(SQLInterfaceError, match="cannot assign to unknown column"):
+            ctx.execute("UPDATE frame SET w = 0")
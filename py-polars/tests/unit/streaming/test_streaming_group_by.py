@@ -7,13 +7,15 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import DuplicateError
+from polars.exceptions import DuplicateError, InvalidOperationError
 from polars.testing import assert_frame_equal
 from tests.unit.conftest import INTEGER_DTYPES
 
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import ClosedInterval, Label, StartBy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -512,3 +514,102 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+def test_streaming_group_by_spill(monkeypatch: pytest.MonkeyPatch) -> None:
+    # Force the group-by sink to spill every cold morsel to disk.
+    monkeypatch.setenv("POLARS_GROUP_BY_MEMORY_BUDGET", "1")
+
+    n = 50_000
+    df = pl.DataFrame(
+        {
+            "a": [None if i % 7 == 0 else i * 7919 % 5003 for i in range(n)],
+            "s": [None if i % 11 == 0 else f"k{i * 31 % 3001}" for i in range(n)],
+            "v": range(n),
+        }
+    )
+
+    q = df.lazy().group_by("a").agg(
+        pl.col("v").sum().alias("sum"),
+        pl.col("v").first().alias("first"),
+        pl.col("v").last().alias("last"),
+        pl.len(),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+    q = df.lazy().group_by("s", "a").agg(
+        pl.col("v").min().alias("min"),
+        pl.col("s").count().alias("count"),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+def test_streaming_group_by_invalid_memory_budget(
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    monkeypatch.setenv("POLARS_GROUP_BY_MEMORY_BUDGET", "4GB")
+    q = pl.LazyFrame({"a": [1, 2, 1]}).group_by("a").agg(pl.len())
+    with pytest.raises(InvalidOperationError, match="POLARS_GROUP_BY_MEMORY_BUDGET"):
+        q.collect(engine="streaming")
+
+
+@pytest.mark.parametrize("closed", ["left", "right", "both", "none"])
+@pytest.mark.parametrize("label", ["left", "right", "datapoint"])
+@pytest.mark.parametrize("start_by", ["window", "datapoint", "monday"])
+def test_streaming_group_by_dynamic(
+    closed: ClosedInterval, label: Label, start_by: StartBy
+) -> None:
+    # Large enough to be split into several morsels.
+    n = 250_000
+    df = pl.select(
+        t=(pl.int_range(n) // 3 * 7_000).cast(pl.Datetime("ms", "Europe/Amsterdam")),
+        v=pl.int_range(n) % 101,
+    )
+    q = (
+        df.lazy()
+        .group_by_dynamic(
+            "t",
+            every="1h",
+            period="90m",
+            offset="10m",
+            closed=closed,
+            label=label,
+            start_by=start_by,
+            include_boundaries=True,
+        )
+        .agg(
+            pl.col("v").sum().alias("sum"),
+            (pl.col("v").max() - pl.col("v").first()).alias("diff"),
+            pl.len(),
+        )
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_group_by_dynamic_node() -> None:
+    q = (
+        pl.LazyFrame({"t": [1, 2, 4, 4, 8], "v": [1, 2, 3, 4, 5]})
+        .group_by_dynamic("t", every="2i", period="3i")
+        .agg(pl.col("v").sum())
+    )
+    graph = q.show_graph(raw_output=True, plan_stage="physical", engine="streaming")
+    assert isinstance(graph, str)
+    assert "dynamic-group-by" in graph
+    assert q.collect(engine="streaming").to_dict(as_series=False) == {
+        "t": [0, 2, 4, 6, 8],
+        "v": [3, 9, 7, 5, 5],
+    }
+
+    q = pl.LazyFrame({"t": [1, 2, 0], "v": [1, 2, 3]}).group_by_dynamic(
+        "t", every="2i"
+    )
+    with pytest.raises(InvalidOperationError, match="not sorted"):
+        q.agg(pl.col("v").sum()).collect(engine="streaming")
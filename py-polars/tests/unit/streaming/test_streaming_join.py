@@ -13,7 +13,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import JoinStrategy
+    from polars._typing import AsofJoinStrategy, JoinStrategy
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -386,3 +386,132 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full"])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_streaming_join_spill(
+    how: JoinStrategy, nulls_equal: bool, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    # Force both sides of the join to be spilled and joined out-of-core.
+    monkeypatch.setenv("POLARS_JOIN_MEMORY_BUDGET", "1")
+
+    left = pl.DataFrame(
+        {
+            "a": [None if i % 13 == 0 else i * 7919 % 1009 for i in range(5000)],
+            "s": [f"s{i % 3}" for i in range(5000)],
+            "x": range(5000),
+        }
+    )
+    right = pl.DataFrame(
+        {
+            "a": [None if i % 17 == 0 else i * 31 % 1201 for i in range(3000)],
+            "s": [f"s{i % 5}" for i in range(3000)],
+            "y": range(3000),
+        }
+    )
+
+    q = left.lazy().join(
+        right.lazy(), on=["a", "s"], how=how, nulls_equal=nulls_equal
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("allow_exact_matches", [False, True])
+@pytest.mark.parametrize("tolerance", [None, 7])
+@pytest.mark.parametrize("by", [None, "g"])
+def test_streaming_join_asof(
+    strategy: AsofJoinStrategy,
+    allow_exact_matches: bool,
+    tolerance: int | None,
+    by: str | None,
+) -> None:
+    # Large enough for both sides to be split over several morsels.
+    n = 250_000
+    left = pl.LazyFrame(
+        {
+            "t": [i * 3 + i % 3 for i in range(n)],
+            "g": [i % 3 for i in range(n)],
+            "x": range(n),
+        }
+    ).set_sorted("t")
+    right = pl.LazyFrame(
+        {
+            "t": [i * 7 for i in range(n // 2)],
+            "g": [i % 4 for i in range(n // 2)],
+            "y": range(n // 2),
+        }
+    ).set_sorted("t")
+
+    q = left.join_asof(
+        right,
+        on="t",
+        by=by,
+        strategy=strategy,
+        tolerance=tolerance,
+        allow_exact_matches=allow_exact_matches,
+        check_sortedness=by is None,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_join_asof_unsorted() -> None:
+    left = pl.LazyFrame({"t": [3, 1, 2]})
+    right = pl.LazyFrame({"t": [1, 2, 3], "y": [1, 2, 3]})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not sorted"):
+        left.join_asof(right, on="t").collect(engine="streaming")
+
+
+@pytest.mark.parametrize("spill", [False, True])
+def test_streaming_join_where(spill: bool, monkeypatch: pytest.MonkeyPatch) -> None:
+    if spill:
+        # Force the build side to be spilled to disk in sorted chunks.
+        monkeypatch.setenv("POLARS_JOIN_MEMORY_BUDGET", "1")
+
+    events = pl.LazyFrame(
+        {
+            "t": [None if i % 31 == 0 else i * 7919 % 5003 for i in range(4000)],
+            "event": range(4000),
+        }
+    )
+    sessions = pl.LazyFrame(
+        {
+            "start": [i * 17 % 4999 for i in range(1500)],
+            "end": [i * 17 % 4999 + i % 40 for i in range(1500)],
+            "session": range(1500),
+        }
+    )
+
+    for predicates in [
+        [pl.col("t") >= pl.col("start"), pl.col("t") < pl.col("end")],
+        [pl.col("t") <= pl.col("start"), pl.col("t") + 20 > pl.col("start")],
+        [
+            pl.col("t") < pl.col("end"),
+            pl.col("t") + 5 > pl.col("end"),
+            pl.col("event") != pl.col("session"),
+        ],
+    ]:
+        q = events.join_where(sessions, *predicates)
+        assert_frame_equal(
+            q.collect(engine="streaming"),
+            q.collect(engine="in-memory"),
+            check_row_order=False,
+        )
+
+
+def test_streaming_join_asof_by_sorted_within_groups() -> None:
+    # Only sorted within each group, which the in-memory join allows.
+    n = 100_000
+    left = pl.LazyFrame({"g": [0] * n + [1] * n, "t": [*range(n), *range(n)]})
+    right = pl.LazyFrame(
+        {"g": [1] * n + [0] * n, "t": [*range(0, 2 * n, 2)] * 2, "y": range(2 * n)}
+    )
+
+    q = left.join_asof(right, on="t", by="g")
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
@@ -183,3 +183,31 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.parametrize("descending", [True, False])
+@pytest.mark.parametrize("nulls_last", [True, False])
+def test_streaming_sort_spill(
+    descending: bool, nulls_last: bool, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    # Force the sort node to spill every morsel as a sorted run.
+    monkeypatch.setenv("POLARS_SORT_MEMORY_BUDGET", "1")
+
+    n = 50_000
+    df = pl.DataFrame(
+        {
+            "a": [None if i % 7 == 0 else i * 7919 % 113 for i in range(n)],
+            "s": [f"s{i * 31 % 17}" for i in range(n)],
+            "l": [[i, i + 1] for i in range(n)],
+            "t": [datetime(2020, 1, 1 + i % 28) for i in range(n)],
+            "idx": range(n),
+        }
+    )
+
+    q = df.lazy().sort(
+        ["a", "s"], descending=descending, nulls_last=nulls_last, maintain_order=True
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    q = df.lazy().sort("a", nulls_last=nulls_last).slice(1234, 5000).select("a")
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))